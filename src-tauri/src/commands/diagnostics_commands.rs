@@ -0,0 +1,57 @@
+use crate::db::Database;
+use crate::error::AppError;
+use crate::models::{CompactionResult, Diagnostics, IntegrityRepair};
+use crate::services::{DiagnosticsService, NotificationService};
+use std::sync::{Arc, Mutex};
+use tauri::State;
+
+/// Collect app and database health information for support
+#[tauri::command]
+pub async fn get_diagnostics(
+    db_state: State<'_, Arc<Mutex<Database>>>,
+    notification_state: State<'_, Mutex<NotificationService>>,
+) -> Result<Diagnostics, String> {
+    let notification_thread_running = notification_state
+        .lock()
+        .map_err(|e| e.to_string())?
+        .is_running();
+
+    let db = db_state
+        .lock()
+        .map_err(|_| AppError::DatabaseLock("Failed to acquire database lock".to_string()))?;
+
+    DiagnosticsService::new(&db)
+        .collect(notification_thread_running)
+        .map_err(|e| AppError::from(e).to_string())
+}
+
+/// Compact the database file, reclaiming space left by deleted rows
+///
+/// Holds the database lock for the whole run so the notification thread
+/// cannot read mid-`VACUUM`.
+#[tauri::command]
+pub async fn compact_database(
+    db_state: State<'_, Arc<Mutex<Database>>>,
+) -> Result<CompactionResult, String> {
+    let db = db_state
+        .lock()
+        .map_err(|_| AppError::DatabaseLock("Failed to acquire database lock".to_string()))?;
+
+    DiagnosticsService::new(&db)
+        .compact()
+        .map_err(|e| AppError::from(e).to_string())
+}
+
+/// Remove orphaned tag links and unlink reminders from deleted tasks, reporting what was fixed
+#[tauri::command]
+pub async fn repair_integrity(
+    db_state: State<'_, Arc<Mutex<Database>>>,
+) -> Result<IntegrityRepair, String> {
+    let db = db_state
+        .lock()
+        .map_err(|_| AppError::DatabaseLock("Failed to acquire database lock".to_string()))?;
+
+    DiagnosticsService::new(&db)
+        .repair_integrity()
+        .map_err(|e| AppError::from(e).to_string())
+}
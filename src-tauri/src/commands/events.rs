@@ -0,0 +1,29 @@
+use crate::models::event::{REMINDERS_CHANGED_EVENT, TASKS_CHANGED_EVENT};
+use crate::models::{ChangeKind, ReminderChangedEvent, TaskChangedEvent};
+use tauri::{AppHandle, Emitter};
+
+/// Notify the frontend that a task changed
+///
+/// Emit failures are logged only; the mutation itself already succeeded.
+pub(crate) fn emit_task_changed(app: &AppHandle, id: &str, kind: ChangeKind) {
+    let payload = TaskChangedEvent {
+        id: id.to_string(),
+        kind,
+    };
+    if let Err(e) = app.emit(TASKS_CHANGED_EVENT, payload) {
+        eprintln!("Failed to emit {} event: {}", TASKS_CHANGED_EVENT, e);
+    }
+}
+
+/// Notify the frontend that a reminder changed
+///
+/// Emit failures are logged only; the mutation itself already succeeded.
+pub(crate) fn emit_reminder_changed(app: &AppHandle, id: &str, kind: ChangeKind) {
+    let payload = ReminderChangedEvent {
+        id: id.to_string(),
+        kind,
+    };
+    if let Err(e) = app.emit(REMINDERS_CHANGED_EVENT, payload) {
+        eprintln!("Failed to emit {} event: {}", REMINDERS_CHANGED_EVENT, e);
+    }
+}
@@ -1,9 +1,16 @@
+pub mod diagnostics_commands;
+pub mod events;
+pub mod notification_commands;
 pub mod reminder_commands;
 pub mod settings_commands;
 pub mod tag_commands;
 pub mod task_commands;
+pub mod undo_commands;
 
+pub use diagnostics_commands::*;
+pub use notification_commands::*;
 pub use reminder_commands::*;
 pub use settings_commands::*;
 pub use tag_commands::*;
 pub use task_commands::*;
+pub use undo_commands::*;
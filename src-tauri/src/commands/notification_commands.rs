@@ -0,0 +1,30 @@
+use crate::services::NotificationService;
+use std::sync::Mutex;
+use tauri::State;
+
+/// Pause reminder notifications; due reminders are delivered on resume
+#[tauri::command]
+pub async fn pause_notifications(
+    notification_state: State<'_, Mutex<NotificationService>>,
+) -> Result<(), String> {
+    let service = notification_state.lock().map_err(|e| e.to_string())?;
+    service.pause().map_err(|e| e.to_string())
+}
+
+/// Resume reminder notifications, immediately delivering anything that came due
+#[tauri::command]
+pub async fn resume_notifications(
+    notification_state: State<'_, Mutex<NotificationService>>,
+) -> Result<(), String> {
+    let service = notification_state.lock().map_err(|e| e.to_string())?;
+    service.resume().map_err(|e| e.to_string())
+}
+
+/// Check whether reminder notifications are paused
+#[tauri::command]
+pub async fn get_notifications_paused(
+    notification_state: State<'_, Mutex<NotificationService>>,
+) -> Result<bool, String> {
+    let service = notification_state.lock().map_err(|e| e.to_string())?;
+    Ok(service.is_paused())
+}
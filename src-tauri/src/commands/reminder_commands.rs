@@ -1,17 +1,23 @@
+use super::events::{emit_reminder_changed, emit_task_changed};
 use crate::db::Database;
 use crate::error::AppError;
-use crate::models::{CreateReminderDto, Reminder, UpdateReminderDto};
-use crate::repositories::ReminderRepository;
-use crate::services::ReminderService;
-use chrono::{DateTime, Utc};
+use crate::models::{
+    ChangeKind, CreateReminderDto, CreateReminderTemplateDto, ExportFormat, ExportOptions,
+    ImportConflictPolicy, ImportReport, PaginatedResponse, Pagination, Reminder, ReminderContext,
+    ReminderDay, ReminderFilter, ReminderShiftResult, ReminderSort, ReminderTemplate, Task,
+    TaskPriority, UpdateReminderDto, UpdateReminderTemplateDto,
+};
+use crate::services::{ExportService, ImportService, ReminderService};
+use chrono::{DateTime, Duration, Utc};
 use std::sync::{Arc, Mutex};
-use tauri::State;
+use tauri::{AppHandle, State};
 
 /// Get reminders for a task
 #[tauri::command]
 pub async fn get_reminders(
     db_state: State<'_, Arc<Mutex<Database>>>,
     task_id: Option<String>,
+    sort: Option<Vec<ReminderSort>>,
 ) -> Result<Vec<Reminder>, String> {
     let db = db_state
         .lock()
@@ -19,10 +25,68 @@ pub async fn get_reminders(
     let service = ReminderService::new(&db);
 
     service
-        .get_reminders(task_id.as_deref())
+        .get_reminders(task_id.as_deref(), sort)
         .map_err(|e| AppError::from(e).to_string())
 }
 
+/// Get reminders matching a filter (active state, due window, task)
+#[tauri::command]
+pub async fn get_reminders_filtered(
+    db_state: State<'_, Arc<Mutex<Database>>>,
+    filter: ReminderFilter,
+) -> Result<Vec<Reminder>, String> {
+    let db = db_state
+        .lock()
+        .map_err(|_| AppError::DatabaseLock("Failed to acquire database lock".to_string()))?;
+    let service = ReminderService::new(&db);
+
+    service
+        .get_reminders_filtered(filter)
+        .map_err(|e| AppError::from(e).to_string())
+}
+
+/// Get one page of reminders, optionally filtered
+///
+/// `pagination` defaults to the first page of 20
+#[tauri::command]
+pub async fn get_reminders_paginated(
+    db_state: State<'_, Arc<Mutex<Database>>>,
+    filter: Option<ReminderFilter>,
+    pagination: Option<Pagination>,
+) -> Result<PaginatedResponse<Reminder>, String> {
+    let db = db_state
+        .lock()
+        .map_err(|_| AppError::DatabaseLock("Failed to acquire database lock".to_string()))?;
+    let service = ReminderService::new(&db);
+
+    service
+        .get_reminders_paginated(filter, pagination.unwrap_or_default())
+        .map_err(|e| AppError::from(e).to_string())
+}
+
+/// Get active reminders grouped by local calendar day, earliest day first
+///
+/// `utc_offset_minutes` defaults to 0 (UTC) when not provided
+#[tauri::command]
+pub async fn get_reminders_by_day(
+    db_state: State<'_, Arc<Mutex<Database>>>,
+    utc_offset_minutes: Option<i32>,
+) -> Result<Vec<ReminderDay>, String> {
+    let db = db_state
+        .lock()
+        .map_err(|_| AppError::DatabaseLock("Failed to acquire database lock".to_string()))?;
+    let service = ReminderService::new(&db);
+
+    let days = service
+        .group_by_day(utc_offset_minutes.unwrap_or(0))
+        .map_err(|e| AppError::from(e).to_string())?;
+
+    Ok(days
+        .into_iter()
+        .map(|(date, reminders)| ReminderDay { date, reminders })
+        .collect())
+}
+
 /// Get a single reminder by ID
 #[tauri::command]
 pub async fn get_reminder(
@@ -39,42 +103,79 @@ pub async fn get_reminder(
         .map_err(|e| AppError::from(e).to_string())
 }
 
+/// Get when a reminder fires next, for showing "next fires at"
+#[tauri::command]
+pub async fn get_reminder_next_trigger(
+    db_state: State<'_, Arc<Mutex<Database>>>,
+    id: String,
+) -> Result<Option<DateTime<Utc>>, String> {
+    let db = db_state
+        .lock()
+        .map_err(|_| AppError::DatabaseLock("Failed to acquire database lock".to_string()))?;
+    let service = ReminderService::new(&db);
+
+    service
+        .get_next_trigger(&id)
+        .map_err(|e| AppError::from(e).to_string())
+}
+
+/// Get a reminder with its task and the task's other reminders
+#[tauri::command]
+pub async fn get_reminder_context(
+    db_state: State<'_, Arc<Mutex<Database>>>,
+    id: String,
+) -> Result<ReminderContext, String> {
+    let db = db_state
+        .lock()
+        .map_err(|_| AppError::DatabaseLock("Failed to acquire database lock".to_string()))?;
+    let service = ReminderService::new(&db);
+
+    service
+        .get_reminder_context(&id)
+        .map_err(|e| AppError::from(e).to_string())
+}
+
 /// Create a new reminder
 #[tauri::command]
 pub async fn create_reminder(
+    app: AppHandle,
     db_state: State<'_, Arc<Mutex<Database>>>,
     data: CreateReminderDto,
-) -> Result<Reminder, String> {
+) -> Result<Reminder, AppError> {
     let db = db_state
         .lock()
         .map_err(|_| AppError::DatabaseLock("Failed to acquire database lock".to_string()))?;
     let service = ReminderService::new(&db);
 
-    service
-        .create_reminder(data)
-        .map_err(|e| AppError::from(e).to_string())
+    let reminder = service.create_reminder(data)?;
+
+    emit_reminder_changed(&app, &reminder.id, ChangeKind::Created);
+    Ok(reminder)
 }
 
 /// Update an existing reminder
 #[tauri::command]
 pub async fn update_reminder(
+    app: AppHandle,
     db_state: State<'_, Arc<Mutex<Database>>>,
     id: String,
     data: UpdateReminderDto,
-) -> Result<Reminder, String> {
+) -> Result<Reminder, AppError> {
     let db = db_state
         .lock()
         .map_err(|_| AppError::DatabaseLock("Failed to acquire database lock".to_string()))?;
     let service = ReminderService::new(&db);
 
-    service
-        .update_reminder(&id, data)
-        .map_err(|e| AppError::from(e).to_string())
+    let reminder = service.update_reminder(&id, data)?;
+
+    emit_reminder_changed(&app, &reminder.id, ChangeKind::Updated);
+    Ok(reminder)
 }
 
 /// Delete a reminder
 #[tauri::command]
 pub async fn delete_reminder(
+    app: AppHandle,
     db_state: State<'_, Arc<Mutex<Database>>>,
     id: String,
 ) -> Result<bool, String> {
@@ -83,9 +184,12 @@ pub async fn delete_reminder(
         .map_err(|_| AppError::DatabaseLock("Failed to acquire database lock".to_string()))?;
     let service = ReminderService::new(&db);
 
-    service
+    let deleted = service
         .delete_reminder(&id)
-        .map_err(|e| AppError::from(e).to_string())
+        .map_err(|e| AppError::from(e).to_string())?;
+
+    emit_reminder_changed(&app, &id, ChangeKind::Deleted);
+    Ok(deleted)
 }
 
 /// Get due reminders
@@ -103,152 +207,407 @@ pub async fn get_due_reminders(
         .map_err(|e| AppError::from(e).to_string())
 }
 
+/// Count due reminders, for a badge that does not need the full rows
+#[tauri::command]
+pub async fn count_due_reminders(db_state: State<'_, Arc<Mutex<Database>>>) -> Result<u32, String> {
+    let db = db_state
+        .lock()
+        .map_err(|_| AppError::DatabaseLock("Failed to acquire database lock".to_string()))?;
+    let service = ReminderService::new(&db);
+
+    service
+        .count_due_reminders()
+        .map_err(|e| AppError::from(e).to_string())
+}
+
+/// Get active reminders that fired but have not been acknowledged
+#[tauri::command]
+pub async fn get_unacknowledged_reminders(
+    db_state: State<'_, Arc<Mutex<Database>>>,
+) -> Result<Vec<Reminder>, String> {
+    let db = db_state
+        .lock()
+        .map_err(|_| AppError::DatabaseLock("Failed to acquire database lock".to_string()))?;
+    let service = ReminderService::new(&db);
+
+    service
+        .get_unacknowledged_reminders()
+        .map_err(|e| AppError::from(e).to_string())
+}
+
+/// Take in-app reminders that fired while the window was unfocused
+///
+/// Called when the window gains focus; the returned reminders are acknowledged.
+#[tauri::command]
+pub async fn get_pending_inapp_reminders(
+    app: AppHandle,
+    db_state: State<'_, Arc<Mutex<Database>>>,
+) -> Result<Vec<Reminder>, String> {
+    let db = db_state
+        .lock()
+        .map_err(|_| AppError::DatabaseLock("Failed to acquire database lock".to_string()))?;
+    let service = ReminderService::new(&db);
+
+    let reminders = service
+        .take_pending_in_app_reminders()
+        .map_err(|e| AppError::from(e).to_string())?;
+
+    for reminder in &reminders {
+        emit_reminder_changed(&app, &reminder.id, ChangeKind::Updated);
+    }
+    Ok(reminders)
+}
+
+/// Acknowledge a fired reminder
+#[tauri::command]
+pub async fn acknowledge_reminder(
+    app: AppHandle,
+    db_state: State<'_, Arc<Mutex<Database>>>,
+    id: String,
+) -> Result<Reminder, String> {
+    let db = db_state
+        .lock()
+        .map_err(|_| AppError::DatabaseLock("Failed to acquire database lock".to_string()))?;
+    let service = ReminderService::new(&db);
+
+    let reminder = service
+        .acknowledge_reminder(&id)
+        .map_err(|e| AppError::from(e).to_string())?;
+
+    emit_reminder_changed(&app, &reminder.id, ChangeKind::Updated);
+    Ok(reminder)
+}
+
+/// Dismiss the current occurrence of a fired reminder
+///
+/// Repeating reminders move on to their next slot; one-off reminders are deactivated.
+#[tauri::command]
+pub async fn dismiss_reminder_occurrence(
+    app: AppHandle,
+    db_state: State<'_, Arc<Mutex<Database>>>,
+    id: String,
+) -> Result<Reminder, String> {
+    let db = db_state
+        .lock()
+        .map_err(|_| AppError::DatabaseLock("Failed to acquire database lock".to_string()))?;
+    let service = ReminderService::new(&db);
+
+    let reminder = service
+        .dismiss_occurrence(&id)
+        .map_err(|e| AppError::from(e).to_string())?;
+
+    emit_reminder_changed(&app, &reminder.id, ChangeKind::Updated);
+    Ok(reminder)
+}
+
+/// Turn a standalone reminder into a task, linking the reminder to it
+#[tauri::command]
+pub async fn promote_reminder_to_task(
+    app: AppHandle,
+    db_state: State<'_, Arc<Mutex<Database>>>,
+    id: String,
+    priority: TaskPriority,
+) -> Result<Task, AppError> {
+    let db = db_state
+        .lock()
+        .map_err(|_| AppError::DatabaseLock("Failed to acquire database lock".to_string()))?;
+    let service = ReminderService::new(&db);
+
+    let task = service.promote_to_task(&id, priority)?;
+
+    emit_task_changed(&app, &task.id, ChangeKind::Created);
+    emit_reminder_changed(&app, &id, ChangeKind::Updated);
+    Ok(task)
+}
+
+/// Move a reminder to another task, or detach it when `task_id` is None
+#[tauri::command]
+pub async fn reassign_reminder(
+    app: AppHandle,
+    db_state: State<'_, Arc<Mutex<Database>>>,
+    id: String,
+    task_id: Option<String>,
+) -> Result<Reminder, AppError> {
+    let db = db_state
+        .lock()
+        .map_err(|_| AppError::DatabaseLock("Failed to acquire database lock".to_string()))?;
+    let service = ReminderService::new(&db);
+
+    let reminder = service.reassign(&id, task_id)?;
+
+    emit_reminder_changed(&app, &reminder.id, ChangeKind::Updated);
+    Ok(reminder)
+}
+
+/// Set or clear how often a fired reminder re-notifies while its task is incomplete
+#[tauri::command]
+pub async fn set_reminder_escalation(
+    app: AppHandle,
+    db_state: State<'_, Arc<Mutex<Database>>>,
+    id: String,
+    escalation_minutes: Option<i64>,
+) -> Result<Reminder, AppError> {
+    let db = db_state
+        .lock()
+        .map_err(|_| AppError::DatabaseLock("Failed to acquire database lock".to_string()))?;
+    let service = ReminderService::new(&db);
+
+    let reminder = service.set_reminder_escalation(&id, escalation_minutes)?;
+
+    emit_reminder_changed(&app, &reminder.id, ChangeKind::Updated);
+    Ok(reminder)
+}
+
+/// Snooze every currently due reminder by `minutes`, returning how many were snoozed
+#[tauri::command]
+pub async fn snooze_all_due_reminders(
+    app: AppHandle,
+    db_state: State<'_, Arc<Mutex<Database>>>,
+    minutes: i64,
+) -> Result<usize, AppError> {
+    let db = db_state
+        .lock()
+        .map_err(|_| AppError::DatabaseLock("Failed to acquire database lock".to_string()))?;
+    let service = ReminderService::new(&db);
+
+    let due = service.get_due_reminders()?;
+    let snoozed = service.snooze_all_due(minutes)?;
+
+    for reminder in &due {
+        emit_reminder_changed(&app, &reminder.id, ChangeKind::Updated);
+    }
+    Ok(snoozed)
+}
+
+/// Shift all reminders by `hours` plus `minutes` (negative moves them earlier)
+///
+/// `only_active` defaults to true; with `clamp_to_now`, reminders that would land in
+/// the past are moved to now instead of being skipped.
+#[tauri::command]
+pub async fn shift_reminders(
+    app: AppHandle,
+    db_state: State<'_, Arc<Mutex<Database>>>,
+    minutes: Option<i64>,
+    hours: Option<i64>,
+    only_active: Option<bool>,
+    clamp_to_now: Option<bool>,
+) -> Result<ReminderShiftResult, AppError> {
+    let total_minutes = hours
+        .unwrap_or(0)
+        .saturating_mul(60)
+        .saturating_add(minutes.unwrap_or(0));
+    let delta = Duration::try_minutes(total_minutes).ok_or_else(|| AppError::FieldValidation {
+        field: "hours".to_string(),
+        message: "Shift duration is too large".to_string(),
+    })?;
+
+    let db = db_state
+        .lock()
+        .map_err(|_| AppError::DatabaseLock("Failed to acquire database lock".to_string()))?;
+    let service = ReminderService::new(&db);
+
+    let result = service.shift_all(
+        delta,
+        only_active.unwrap_or(true),
+        clamp_to_now.unwrap_or(false),
+    )?;
+
+    for id in &result.shifted_ids {
+        emit_reminder_changed(&app, id, ChangeKind::Updated);
+    }
+    Ok(result)
+}
+
+/// Get all reminder templates
+#[tauri::command]
+pub async fn get_reminder_templates(
+    db_state: State<'_, Arc<Mutex<Database>>>,
+) -> Result<Vec<ReminderTemplate>, String> {
+    let db = db_state
+        .lock()
+        .map_err(|_| AppError::DatabaseLock("Failed to acquire database lock".to_string()))?;
+    let service = ReminderService::new(&db);
+
+    service
+        .get_templates()
+        .map_err(|e| AppError::from(e).to_string())
+}
+
+/// Create a reminder template
+#[tauri::command]
+pub async fn create_reminder_template(
+    db_state: State<'_, Arc<Mutex<Database>>>,
+    data: CreateReminderTemplateDto,
+) -> Result<ReminderTemplate, AppError> {
+    let db = db_state
+        .lock()
+        .map_err(|_| AppError::DatabaseLock("Failed to acquire database lock".to_string()))?;
+    let service = ReminderService::new(&db);
+
+    service.create_template(data).map_err(AppError::from)
+}
+
+/// Update a reminder template
+#[tauri::command]
+pub async fn update_reminder_template(
+    db_state: State<'_, Arc<Mutex<Database>>>,
+    id: String,
+    data: UpdateReminderTemplateDto,
+) -> Result<ReminderTemplate, AppError> {
+    let db = db_state
+        .lock()
+        .map_err(|_| AppError::DatabaseLock("Failed to acquire database lock".to_string()))?;
+    let service = ReminderService::new(&db);
+
+    service.update_template(&id, data).map_err(AppError::from)
+}
+
+/// Delete a reminder template
+#[tauri::command]
+pub async fn delete_reminder_template(
+    db_state: State<'_, Arc<Mutex<Database>>>,
+    id: String,
+) -> Result<bool, String> {
+    let db = db_state
+        .lock()
+        .map_err(|_| AppError::DatabaseLock("Failed to acquire database lock".to_string()))?;
+    let service = ReminderService::new(&db);
+
+    service
+        .delete_template(&id)
+        .map_err(|e| AppError::from(e).to_string())
+}
+
+/// Create a reminder from a template, due the template's default offset from now
+#[tauri::command]
+pub async fn create_reminder_from_template(
+    app: AppHandle,
+    db_state: State<'_, Arc<Mutex<Database>>>,
+    template_id: String,
+) -> Result<Reminder, AppError> {
+    let db = db_state
+        .lock()
+        .map_err(|_| AppError::DatabaseLock("Failed to acquire database lock".to_string()))?;
+    let service = ReminderService::new(&db);
+
+    let reminder = service.create_reminder_from_template(&template_id)?;
+
+    emit_reminder_changed(&app, &reminder.id, ChangeKind::Created);
+    Ok(reminder)
+}
+
 /// Export all reminders to JSON
 #[tauri::command]
 pub async fn export_reminders_json(
     db_state: State<'_, Arc<Mutex<Database>>>,
-) -> Result<String, String> {
+    options: Option<ExportOptions>,
+) -> Result<String, AppError> {
     let db = db_state
         .lock()
         .map_err(|_| AppError::DatabaseLock("Failed to acquire database lock".to_string()))?;
-    let repo = ReminderRepository::new(&db);
 
-    // Get all reminders
-    let reminders = repo
-        .find_all()
-        .map_err(|e| format!("Failed to get reminders: {}", e))?;
-
-    // Serialize to pretty JSON
-    serde_json::to_string_pretty(&reminders)
-        .map_err(|e| format!("Failed to serialize reminders: {}", e))
+    ExportService::new(&db)
+        .export_reminders(ExportFormat::Json, &options.unwrap_or_default())
+        .map_err(AppError::from)
 }
 
 /// Export all reminders to CSV
 #[tauri::command]
 pub async fn export_reminders_csv(
     db_state: State<'_, Arc<Mutex<Database>>>,
-) -> Result<String, String> {
+    options: Option<ExportOptions>,
+) -> Result<String, AppError> {
     let db = db_state
         .lock()
         .map_err(|_| AppError::DatabaseLock("Failed to acquire database lock".to_string()))?;
-    let repo = ReminderRepository::new(&db);
 
-    let reminders = repo
-        .find_all()
-        .map_err(|e| format!("Failed to get reminders: {}", e))?;
+    ExportService::new(&db)
+        .export_reminders(ExportFormat::Csv, &options.unwrap_or_default())
+        .map_err(AppError::from)
+}
 
-    // Build CSV header
-    let mut csv = String::from("id,task_id,title,remind_at,is_active,created_at,updated_at\n");
+/// Export all reminders straight to a file, returning the number of bytes written
+#[tauri::command]
+pub async fn export_reminders_to_file(
+    db_state: State<'_, Arc<Mutex<Database>>>,
+    path: String,
+    format: ExportFormat,
+    options: Option<ExportOptions>,
+) -> Result<u64, AppError> {
+    let db = db_state
+        .lock()
+        .map_err(|_| AppError::DatabaseLock("Failed to acquire database lock".to_string()))?;
 
-    // Add each reminder as a row
-    for reminder in reminders {
-        let task_id = reminder.task_id.unwrap_or_default();
-        csv.push_str(&format!(
-            "\"{}\",\"{}\",\"{}\",\"{}\",{},\"{}\",\"{}\"\n",
-            reminder.id,
-            task_id,
-            reminder.title.replace("\"", "\"\""),
-            reminder.remind_at.to_rfc3339(),
-            reminder.is_active,
-            reminder.created_at.to_rfc3339(),
-            reminder.updated_at.to_rfc3339()
-        ));
-    }
+    ExportService::new(&db)
+        .export_reminders_to_file(
+            std::path::Path::new(&path),
+            format,
+            &options.unwrap_or_default(),
+        )
+        .map_err(AppError::from)
+}
 
-    Ok(csv)
+/// Build an iCalendar feed of the reminders triggering between `start` and `end`
+#[tauri::command]
+pub async fn generate_ics_feed(
+    db_state: State<'_, Arc<Mutex<Database>>>,
+    start: DateTime<Utc>,
+    end: DateTime<Utc>,
+) -> Result<String, AppError> {
+    let db = db_state
+        .lock()
+        .map_err(|_| AppError::DatabaseLock("Failed to acquire database lock".to_string()))?;
+
+    ExportService::new(&db)
+        .generate_ics_feed(start, end)
+        .map_err(AppError::from)
 }
 
-/// Import reminders from JSON
+/// Write the iCalendar feed for `start`..`end` to a file, returning the number of bytes written
 #[tauri::command]
-pub async fn import_reminders_json(
+pub async fn export_ics_feed_to_file(
     db_state: State<'_, Arc<Mutex<Database>>>,
-    json_data: String,
-) -> Result<usize, String> {
+    path: String,
+    start: DateTime<Utc>,
+    end: DateTime<Utc>,
+) -> Result<u64, AppError> {
     let db = db_state
         .lock()
         .map_err(|_| AppError::DatabaseLock("Failed to acquire database lock".to_string()))?;
-    let service = ReminderService::new(&db);
 
-    // Parse JSON to reminders
-    let reminders: Vec<CreateReminderDto> =
-        serde_json::from_str(&json_data).map_err(|e| format!("Failed to parse JSON: {}", e))?;
+    ExportService::new(&db)
+        .export_ics_feed_to_file(std::path::Path::new(&path), start, end)
+        .map_err(AppError::from)
+}
 
-    // Import each reminder
-    let mut count = 0;
-    for reminder_data in reminders {
-        match service.create_reminder(reminder_data) {
-            Ok(_) => count += 1,
-            Err(e) => {
-                // Log error but continue with other reminders
-                eprintln!("Failed to import reminder: {}", e);
-            }
-        }
-    }
+/// Import reminders from JSON, resolving id conflicts per `conflict_policy` (default skip)
+#[tauri::command]
+pub async fn import_reminders_json(
+    db_state: State<'_, Arc<Mutex<Database>>>,
+    json_data: String,
+    conflict_policy: Option<ImportConflictPolicy>,
+) -> Result<ImportReport, String> {
+    let db = db_state
+        .lock()
+        .map_err(|_| AppError::DatabaseLock("Failed to acquire database lock".to_string()))?;
 
-    Ok(count)
+    ImportService::new(&db)
+        .import_reminders_json(json_data.as_bytes(), conflict_policy.unwrap_or_default())
+        .map_err(|e| AppError::from(e).to_string())
 }
 
-/// Import reminders from CSV
+/// Import reminders from CSV, resolving id conflicts per `conflict_policy` (default skip)
 #[tauri::command]
 pub async fn import_reminders_csv(
     db_state: State<'_, Arc<Mutex<Database>>>,
     csv_data: String,
-) -> Result<usize, String> {
-    let db = db_state
-        .lock()
-        .map_err(|_| AppError::DatabaseLock("Failed to acquire database lock".to_string()))?;
-    let service = ReminderService::new(&db);
-
-    let mut count = 0;
-    let lines: Vec<&str> = csv_data.lines().collect();
-
-    // Skip header row
-    for line in lines.iter().skip(1) {
-        if line.trim().is_empty() {
-            continue;
-        }
-
-        // Parse CSV line
-        let fields: Vec<&str> = line
-            .split(',')
-            .map(|s| s.trim_matches('"').trim())
-            .collect();
-
-        if fields.len() < 7 {
-            eprintln!("Skipping invalid CSV line: {}", line);
-            continue;
-        }
-
-        // Parse remind_at
-        let remind_at = match DateTime::parse_from_rfc3339(fields[3]) {
-            Ok(dt) => dt.with_timezone(&Utc),
-            Err(e) => {
-                eprintln!("Failed to parse remind_at: {}", e);
-                continue;
-            }
-        };
-
-        // Create reminder DTO
-        let task_id_value = fields[1];
-        let reminder_data = CreateReminderDto {
-            task_id: if !task_id_value.is_empty() {
-                Some(task_id_value.to_string())
-            } else {
-                None
-            },
-            title: fields[2].to_string(),
-            description: None,
-            remind_at,
-            repeat_interval: crate::models::RepeatInterval::none(),
-        };
-
-        match service.create_reminder(reminder_data) {
-            Ok(_) => count += 1,
-            Err(e) => {
-                eprintln!("Failed to import reminder from CSV: {}", e);
-            }
-        }
-    }
+    conflict_policy: Option<ImportConflictPolicy>,
+) -> Result<ImportReport, String> {
+    let db = db_state
+        .lock()
+        .map_err(|_| AppError::DatabaseLock("Failed to acquire database lock".to_string()))?;
 
-    Ok(count)
+    ImportService::new(&db)
+        .import_reminders_csv(csv_data.as_bytes(), conflict_policy.unwrap_or_default())
+        .map_err(|e| AppError::from(e).to_string())
 }
@@ -1,6 +1,15 @@
 use crate::db::Database;
-use crate::models::{AppSettings, Setting};
+use crate::error::{AppError, DomainError, DomainResult};
+use crate::models::settings::{
+    DIGEST_TIME_FORMAT, MAX_AUTO_REMINDER_LEAD_MINUTES, MAX_OVERDUE_GRACE_MINUTES,
+    SUPPORTED_CLOSED_TASK_REMINDER_MODES, SUPPORTED_DIGEST_FREQUENCIES,
+    SUPPORTED_FIRST_DAYS_OF_WEEK, SUPPORTED_LANGUAGES, SUPPORTED_THEMES,
+};
+use crate::models::{AppSettings, TaskPriority};
+use crate::repositories::SettingsRepository;
+use chrono::NaiveTime;
 use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
 use std::sync::{Arc, Mutex};
 use tauri::State;
 
@@ -11,36 +20,54 @@ pub struct UpdateSettingsDto {
     pub notification_sound: Option<bool>,
     pub show_completed_tasks: Option<bool>,
     pub default_task_priority: Option<String>,
+    /// Lead time for automatic due-date reminders; 0 turns them off
+    #[serde(default)]
+    pub auto_reminder_lead_minutes: Option<i64>,
+    #[serde(default)]
+    pub first_day_of_week: Option<String>,
+    /// Minutes past the due date before a task counts as overdue
+    #[serde(default)]
+    pub overdue_grace_minutes: Option<i64>,
+    /// Most rows a single import may contain; must be at least 1
+    #[serde(default)]
+    pub max_import_rows: Option<usize>,
+    #[serde(default)]
+    pub digest_frequency: Option<String>,
+    /// Local time of day for the digest, "HH:MM"
+    #[serde(default)]
+    pub digest_time: Option<String>,
+    /// "fire", "skip" or "deactivate" reminders of completed and cancelled tasks
+    #[serde(default)]
+    pub closed_task_reminders: Option<String>,
 }
 
-/// Get application settings
+/// Get application settings, storing the defaults on first run
 #[tauri::command]
 pub async fn get_settings(
     db_state: State<'_, Arc<Mutex<Database>>>,
 ) -> Result<AppSettings, String> {
     let db = db_state.lock().map_err(|e| e.to_string())?;
 
-    // Query all settings from database
-    let query = "SELECT key, value, updated_at FROM settings";
-    let mut stmt = db.connection().prepare(query).map_err(|e| e.to_string())?;
+    load_settings(&db)
+}
 
-    let settings_iter = stmt
-        .query_map([], |row| {
-            Ok(Setting {
-                key: row.get(0)?,
-                value: row.get(1)?,
-                updated_at: row.get(2)?,
-            })
-        })
-        .map_err(|e| e.to_string())?;
+/// Restore every application setting to its default value
+#[tauri::command]
+pub async fn reset_settings(
+    db_state: State<'_, Arc<Mutex<Database>>>,
+) -> Result<AppSettings, String> {
+    let db = db_state.lock().map_err(|e| e.to_string())?;
 
-    let mut settings = Vec::new();
-    for setting in settings_iter {
-        settings.push(setting.map_err(|e| e.to_string())?);
-    }
+    SettingsRepository::new(&db)
+        .reset_app_settings()
+        .map_err(|e| e.to_string())
+}
 
-    // Convert to AppSettings
-    Ok(AppSettings::from_key_value_pairs(settings))
+/// Seed any missing default rows, then load the typed settings
+fn load_settings(db: &Database) -> Result<AppSettings, String> {
+    let repo = SettingsRepository::new(db);
+    repo.seed_defaults().map_err(|e| e.to_string())?;
+    repo.get_app_settings().map_err(|e| e.to_string())
 }
 
 /// Update application settings
@@ -48,16 +75,55 @@ pub async fn get_settings(
 pub async fn update_settings(
     db_state: State<'_, Arc<Mutex<Database>>>,
     dto: UpdateSettingsDto,
-) -> Result<AppSettings, String> {
+) -> Result<AppSettings, AppError> {
     // First get current settings (no lock held during await)
-    let current = get_settings(db_state.clone()).await?;
+    let current = get_settings(db_state.clone())
+        .await
+        .map_err(AppError::DatabaseOperation)?;
+
+    // Validate and apply updates before anything is written
+    let updated = apply_settings_update(&current, dto)?;
+
+    // Now lock database and save
+    let db = db_state
+        .lock()
+        .map_err(|_| AppError::DatabaseLock("Failed to acquire database lock".to_string()))?;
+    let repo = SettingsRepository::new(&db);
 
-    // Apply updates
+    let pairs = updated.to_key_value_pairs();
+    for (key, value) in pairs {
+        repo.set(&key, &value)?;
+    }
+
+    Ok(updated)
+}
+
+/// Validate the provided fields and apply them on top of the current settings
+fn apply_settings_update(
+    current: &AppSettings,
+    dto: UpdateSettingsDto,
+) -> DomainResult<AppSettings> {
     let mut updated = current.clone();
     if let Some(theme) = dto.theme {
+        if !SUPPORTED_THEMES.contains(&theme.as_str()) {
+            return Err(DomainError::field(
+                "theme",
+                format!("'{}' is not one of {}", theme, SUPPORTED_THEMES.join(", ")),
+            ));
+        }
         updated.theme = theme;
     }
     if let Some(language) = dto.language {
+        if !SUPPORTED_LANGUAGES.contains(&language.as_str()) {
+            return Err(DomainError::field(
+                "language",
+                format!(
+                    "'{}' is not one of {}",
+                    language,
+                    SUPPORTED_LANGUAGES.join(", ")
+                ),
+            ));
+        }
         updated.language = language;
     }
     if let Some(notification_sound) = dto.notification_sound {
@@ -67,20 +133,419 @@ pub async fn update_settings(
         updated.show_completed_tasks = show_completed_tasks;
     }
     if let Some(default_task_priority) = dto.default_task_priority {
+        if TaskPriority::from_str(&default_task_priority).is_none() {
+            return Err(DomainError::field(
+                "default_task_priority",
+                format!(
+                    "'{}' is not one of low, medium, high, urgent",
+                    default_task_priority
+                ),
+            ));
+        }
         updated.default_task_priority = default_task_priority;
     }
+    if let Some(lead) = dto.auto_reminder_lead_minutes {
+        if lead > MAX_AUTO_REMINDER_LEAD_MINUTES {
+            return Err(DomainError::field(
+                "auto_reminder_lead_minutes",
+                format!(
+                    "{} is above the maximum of {} minutes",
+                    lead, MAX_AUTO_REMINDER_LEAD_MINUTES
+                ),
+            ));
+        }
+        updated.auto_reminder_lead_minutes = (lead > 0).then_some(lead);
+    }
+    if let Some(first_day_of_week) = dto.first_day_of_week {
+        if !SUPPORTED_FIRST_DAYS_OF_WEEK.contains(&first_day_of_week.as_str()) {
+            return Err(DomainError::field(
+                "first_day_of_week",
+                format!(
+                    "'{}' is not one of {}",
+                    first_day_of_week,
+                    SUPPORTED_FIRST_DAYS_OF_WEEK.join(", ")
+                ),
+            ));
+        }
+        updated.first_day_of_week = first_day_of_week;
+    }
+    if let Some(grace) = dto.overdue_grace_minutes {
+        if !(0..=MAX_OVERDUE_GRACE_MINUTES).contains(&grace) {
+            return Err(DomainError::field(
+                "overdue_grace_minutes",
+                format!(
+                    "{} is outside 0 to {} minutes",
+                    grace, MAX_OVERDUE_GRACE_MINUTES
+                ),
+            ));
+        }
+        updated.overdue_grace_minutes = grace;
+    }
+    if let Some(max_import_rows) = dto.max_import_rows {
+        if max_import_rows == 0 {
+            return Err(DomainError::field(
+                "max_import_rows",
+                "0 is below the minimum of 1",
+            ));
+        }
+        updated.max_import_rows = max_import_rows;
+    }
+    if let Some(digest_frequency) = dto.digest_frequency {
+        if !SUPPORTED_DIGEST_FREQUENCIES.contains(&digest_frequency.as_str()) {
+            return Err(DomainError::field(
+                "digest_frequency",
+                format!(
+                    "'{}' is not one of {}",
+                    digest_frequency,
+                    SUPPORTED_DIGEST_FREQUENCIES.join(", ")
+                ),
+            ));
+        }
+        updated.digest_frequency = digest_frequency;
+    }
+    if let Some(digest_time) = dto.digest_time {
+        if NaiveTime::parse_from_str(&digest_time, DIGEST_TIME_FORMAT).is_err() {
+            return Err(DomainError::field(
+                "digest_time",
+                format!("'{}' is not an HH:MM time", digest_time),
+            ));
+        }
+        updated.digest_time = digest_time;
+    }
+    if let Some(mode) = dto.closed_task_reminders {
+        if !SUPPORTED_CLOSED_TASK_REMINDER_MODES.contains(&mode.as_str()) {
+            return Err(DomainError::field(
+                "closed_task_reminders",
+                format!(
+                    "'{}' is not one of {}",
+                    mode,
+                    SUPPORTED_CLOSED_TASK_REMINDER_MODES.join(", ")
+                ),
+            ));
+        }
+        updated.closed_task_reminders = mode;
+    }
 
-    // Now lock database and save
+    Ok(updated)
+}
+
+/// Get a single setting by key (any key, including ones not in `AppSettings`)
+#[tauri::command]
+pub async fn get_setting(
+    db_state: State<'_, Arc<Mutex<Database>>>,
+    key: String,
+) -> Result<Option<String>, String> {
     let db = db_state.lock().map_err(|e| e.to_string())?;
-    let conn = db.connection();
 
-    let pairs = updated.to_key_value_pairs();
-    for (key, value) in pairs {
+    SettingsRepository::new(&db)
+        .get(&key)
+        .map_err(|e| e.to_string())
+}
+
+/// Store a single setting by key, e.g. frontend view preferences
+///
+/// Keys of `AppSettings` are rejected; those go through `update_settings` so
+/// their values are validated.
+#[tauri::command]
+pub async fn set_setting(
+    db_state: State<'_, Arc<Mutex<Database>>>,
+    key: String,
+    value: String,
+) -> Result<(), AppError> {
+    let key = validate_setting_key(&key)?;
+
+    let db = db_state
+        .lock()
+        .map_err(|_| AppError::DatabaseLock("Failed to acquire database lock".to_string()))?;
+
+    SettingsRepository::new(&db).set(key, &value)?;
+    Ok(())
+}
+
+/// Trim the key and reject empty keys and keys owned by `AppSettings`
+fn validate_setting_key(key: &str) -> DomainResult<&str> {
+    let key = key.trim();
+    if key.is_empty() {
+        return Err(DomainError::field("key", "Setting key cannot be empty"));
+    }
+    if AppSettings::default()
+        .to_key_value_pairs()
+        .iter()
+        .any(|(typed_key, _)| typed_key == key)
+    {
+        return Err(DomainError::field(
+            "key",
+            format!("'{}' is an application setting; use update_settings", key),
+        ));
+    }
+    Ok(key)
+}
+
+/// Get all stored settings as a key-value map
+#[tauri::command]
+pub async fn get_all_settings(
+    db_state: State<'_, Arc<Mutex<Database>>>,
+) -> Result<HashMap<String, String>, String> {
+    let db = db_state.lock().map_err(|e| e.to_string())?;
+
+    SettingsRepository::new(&db)
+        .get_all()
+        .map_err(|e| e.to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn setup_test_db() -> Database {
+        let conn = rusqlite::Connection::open_in_memory().unwrap();
+
         conn.execute(
-            "INSERT OR REPLACE INTO settings (key, value, updated_at) VALUES (?1, ?2, datetime('now'))",
-            rusqlite::params![key, value],
-        ).map_err(|e| e.to_string())?;
+            "CREATE TABLE settings (
+                key TEXT PRIMARY KEY,
+                value TEXT NOT NULL,
+                updated_at TEXT NOT NULL DEFAULT (datetime('now'))
+            )",
+            [],
+        )
+        .unwrap();
+
+        Database::new_from_connection(conn)
     }
 
-    Ok(updated)
+    fn assert_field_error(err: DomainError, expected: &str) {
+        match err {
+            DomainError::FieldValidation { field, .. } => assert_eq!(field, expected),
+            other => panic!("expected a {} field error, got {:?}", expected, other),
+        }
+    }
+
+    fn empty_update() -> UpdateSettingsDto {
+        UpdateSettingsDto {
+            theme: None,
+            language: None,
+            notification_sound: None,
+            show_completed_tasks: None,
+            default_task_priority: None,
+            auto_reminder_lead_minutes: None,
+            first_day_of_week: None,
+            overdue_grace_minutes: None,
+            max_import_rows: None,
+            digest_frequency: None,
+            digest_time: None,
+            closed_task_reminders: None,
+        }
+    }
+
+    #[test]
+    fn test_first_load_seeds_defaults() {
+        let db = setup_test_db();
+        let repo = SettingsRepository::new(&db);
+        assert!(repo.get_all().unwrap().is_empty());
+
+        let settings = load_settings(&db).unwrap();
+        assert_eq!(settings, AppSettings::default());
+
+        let stored = repo.get_all().unwrap();
+        assert_eq!(
+            stored.len(),
+            AppSettings::default().to_key_value_pairs().len()
+        );
+        assert_eq!(stored.get("theme"), Some(&settings.theme));
+
+        // Values chosen later are not overwritten by the seeding
+        repo.set("theme", "dark").unwrap();
+        assert_eq!(load_settings(&db).unwrap().theme, "dark");
+    }
+
+    #[test]
+    fn test_reset_settings_overwrites_customized_values() {
+        let db = setup_test_db();
+        let repo = SettingsRepository::new(&db);
+        repo.set("theme", "dark").unwrap();
+        repo.set("overdue_grace_minutes", "30").unwrap();
+        repo.set("view.sort_field", "due_date").unwrap();
+
+        let reset = repo.reset_app_settings().unwrap();
+        assert_eq!(reset, AppSettings::default());
+        assert_eq!(load_settings(&db).unwrap(), AppSettings::default());
+        assert_eq!(
+            repo.get("view.sort_field").unwrap(),
+            Some("due_date".to_string())
+        );
+    }
+
+    #[test]
+    fn test_validate_setting_key() {
+        assert_eq!(validate_setting_key(" view.mode ").unwrap(), "view.mode");
+        assert_field_error(validate_setting_key("  ").unwrap_err(), "key");
+        assert_field_error(validate_setting_key("theme").unwrap_err(), "key");
+        assert_field_error(
+            validate_setting_key("overdue_grace_minutes").unwrap_err(),
+            "key",
+        );
+    }
+
+    #[test]
+    fn test_apply_settings_update_success() {
+        let current = AppSettings::default();
+        let dto = UpdateSettingsDto {
+            theme: Some("dark".to_string()),
+            language: Some("vi".to_string()),
+            default_task_priority: Some("high".to_string()),
+            ..empty_update()
+        };
+
+        let updated = apply_settings_update(&current, dto).unwrap();
+        assert_eq!(updated.theme, "dark");
+        assert_eq!(updated.language, "vi");
+        assert_eq!(updated.default_task_priority, "high");
+        assert_eq!(updated.notification_sound, current.notification_sound);
+    }
+
+    #[test]
+    fn test_apply_settings_update_first_day_of_week() {
+        let dto = UpdateSettingsDto {
+            first_day_of_week: Some("sunday".to_string()),
+            ..empty_update()
+        };
+        let updated = apply_settings_update(&AppSettings::default(), dto).unwrap();
+        assert_eq!(updated.week_start(), chrono::Weekday::Sun);
+
+        let dto = UpdateSettingsDto {
+            first_day_of_week: Some("friday".to_string()),
+            ..empty_update()
+        };
+        let err = apply_settings_update(&AppSettings::default(), dto).unwrap_err();
+        assert_field_error(err, "first_day_of_week");
+    }
+
+    #[test]
+    fn test_apply_settings_update_auto_reminder_lead() {
+        let dto = UpdateSettingsDto {
+            auto_reminder_lead_minutes: Some(MAX_AUTO_REMINDER_LEAD_MINUTES),
+            ..empty_update()
+        };
+        let updated = apply_settings_update(&AppSettings::default(), dto).unwrap();
+        assert_eq!(
+            updated.auto_reminder_lead_minutes,
+            Some(MAX_AUTO_REMINDER_LEAD_MINUTES)
+        );
+
+        let dto = UpdateSettingsDto {
+            auto_reminder_lead_minutes: Some(MAX_AUTO_REMINDER_LEAD_MINUTES + 1),
+            ..empty_update()
+        };
+        let err = apply_settings_update(&AppSettings::default(), dto).unwrap_err();
+        assert_field_error(err, "auto_reminder_lead_minutes");
+    }
+
+    #[test]
+    fn test_apply_settings_update_overdue_grace() {
+        let dto = UpdateSettingsDto {
+            overdue_grace_minutes: Some(15),
+            ..empty_update()
+        };
+        let updated = apply_settings_update(&AppSettings::default(), dto).unwrap();
+        assert_eq!(updated.overdue_grace(), chrono::Duration::minutes(15));
+
+        for invalid in [-1, MAX_OVERDUE_GRACE_MINUTES + 1] {
+            let dto = UpdateSettingsDto {
+                overdue_grace_minutes: Some(invalid),
+                ..empty_update()
+            };
+            let err = apply_settings_update(&AppSettings::default(), dto).unwrap_err();
+            assert_field_error(err, "overdue_grace_minutes");
+        }
+    }
+
+    #[test]
+    fn test_apply_settings_update_max_import_rows() {
+        let dto = UpdateSettingsDto {
+            max_import_rows: Some(500),
+            ..empty_update()
+        };
+        let updated = apply_settings_update(&AppSettings::default(), dto).unwrap();
+        assert_eq!(updated.max_import_rows, 500);
+
+        let dto = UpdateSettingsDto {
+            max_import_rows: Some(0),
+            ..empty_update()
+        };
+        let err = apply_settings_update(&AppSettings::default(), dto).unwrap_err();
+        assert_field_error(err, "max_import_rows");
+    }
+
+    #[test]
+    fn test_apply_settings_update_digest() {
+        let dto = UpdateSettingsDto {
+            digest_frequency: Some("weekly".to_string()),
+            digest_time: Some("07:30".to_string()),
+            ..empty_update()
+        };
+        let updated = apply_settings_update(&AppSettings::default(), dto).unwrap();
+        assert_eq!(updated.digest_frequency, "weekly");
+        assert_eq!(updated.digest_time, "07:30");
+
+        let dto = UpdateSettingsDto {
+            digest_frequency: Some("hourly".to_string()),
+            ..empty_update()
+        };
+        let err = apply_settings_update(&AppSettings::default(), dto).unwrap_err();
+        assert_field_error(err, "digest_frequency");
+
+        let dto = UpdateSettingsDto {
+            digest_time: Some("25:00".to_string()),
+            ..empty_update()
+        };
+        let err = apply_settings_update(&AppSettings::default(), dto).unwrap_err();
+        assert_field_error(err, "digest_time");
+    }
+
+    #[test]
+    fn test_apply_settings_update_closed_task_reminders() {
+        let dto = UpdateSettingsDto {
+            closed_task_reminders: Some("deactivate".to_string()),
+            ..empty_update()
+        };
+        let updated = apply_settings_update(&AppSettings::default(), dto).unwrap();
+        assert_eq!(updated.closed_task_reminders, "deactivate");
+
+        let dto = UpdateSettingsDto {
+            closed_task_reminders: Some("mute".to_string()),
+            ..empty_update()
+        };
+        let err = apply_settings_update(&AppSettings::default(), dto).unwrap_err();
+        assert_field_error(err, "closed_task_reminders");
+    }
+
+    #[test]
+    fn test_apply_settings_update_rejects_invalid_theme() {
+        let dto = UpdateSettingsDto {
+            theme: Some("purple".to_string()),
+            ..empty_update()
+        };
+        let err = apply_settings_update(&AppSettings::default(), dto).unwrap_err();
+        assert_field_error(err, "theme");
+    }
+
+    #[test]
+    fn test_apply_settings_update_rejects_invalid_priority() {
+        let dto = UpdateSettingsDto {
+            default_task_priority: Some("critical".to_string()),
+            ..empty_update()
+        };
+        let err = apply_settings_update(&AppSettings::default(), dto).unwrap_err();
+        assert_field_error(err, "default_task_priority");
+    }
+
+    #[test]
+    fn test_apply_settings_update_rejects_invalid_language() {
+        let dto = UpdateSettingsDto {
+            language: Some("klingon".to_string()),
+            ..empty_update()
+        };
+        let err = apply_settings_update(&AppSettings::default(), dto).unwrap_err();
+        assert_field_error(err, "language");
+    }
 }
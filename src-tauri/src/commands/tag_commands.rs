@@ -1,7 +1,11 @@
-use crate::models::Tag;
+use crate::db::Database;
+use crate::error::{AppError, DomainError, DomainResult};
+use crate::models::{Tag, UndoEntityType};
+use crate::repositories::TagRepository;
+use crate::services::UndoService;
 use rusqlite::OptionalExtension;
 use serde::{Deserialize, Serialize};
-use std::sync::Mutex;
+use std::sync::{Arc, Mutex};
 use tauri::State;
 
 #[derive(Debug, Serialize, Deserialize)]
@@ -88,6 +92,10 @@ pub async fn create_tag(
     db: State<'_, Mutex<rusqlite::Connection>>,
     dto: CreateTagDto,
 ) -> Result<Tag, String> {
+    if !Tag::is_valid_color(&dto.color) {
+        return Err(format!("Invalid tag color: {}", dto.color));
+    }
+
     let conn = db.lock().map_err(|e| e.to_string())?;
     let id = uuid::Uuid::new_v4().to_string();
     let now = chrono::Utc::now().to_rfc3339();
@@ -118,6 +126,10 @@ pub async fn update_tag(
     id: String,
     dto: UpdateTagDto,
 ) -> Result<Tag, String> {
+    if let Some(color) = dto.color.as_deref().filter(|c| !Tag::is_valid_color(c)) {
+        return Err(format!("Invalid tag color: {}", color));
+    }
+
     let conn = db.lock().map_err(|e| e.to_string())?;
     let now = chrono::Utc::now().to_rfc3339();
 
@@ -167,34 +179,136 @@ pub async fn update_tag(
     Ok(tag)
 }
 
-/// Delete a tag
+/// Delete a tag along with its task associations
 #[tauri::command]
 pub async fn delete_tag(
-    db: State<'_, Mutex<rusqlite::Connection>>,
+    db_state: State<'_, Arc<Mutex<Database>>>,
     id: String,
-) -> Result<(), String> {
-    let conn = db.lock().map_err(|e| e.to_string())?;
+) -> Result<(), AppError> {
+    let db = db_state
+        .lock()
+        .map_err(|_| AppError::DatabaseLock("Failed to acquire database lock".to_string()))?;
 
-    // Delete task_tags associations first (foreign key constraint)
-    conn.execute("DELETE FROM task_tags WHERE tag_id = ?1", [&id])
-        .map_err(|e| e.to_string())?;
+    remove_tag(&db, &id).map_err(AppError::from)
+}
 
-    // Delete the tag
-    let rows_affected = conn
-        .execute("DELETE FROM tags WHERE id = ?1", [&id])
-        .map_err(|e| e.to_string())?;
+fn remove_tag(db: &Database, id: &str) -> DomainResult<()> {
+    // Snapshot, delete and undo entry commit together
+    db.try_transaction(|_| {
+        let undo = UndoService::new(db);
+        let snapshot = undo.snapshot(UndoEntityType::Tag, id)?;
 
-    if rows_affected == 0 {
-        return Err("Tag not found".to_string());
-    }
+        let deleted = TagRepository::new(db).delete(id).map_err(|e| {
+            DomainError::BusinessRuleViolation(format!("Failed to delete tag: {}", e))
+        })?;
+        if !deleted {
+            return Err(DomainError::TagNotFound(id.to_string()));
+        }
 
-    Ok(())
+        undo.record(UndoEntityType::Tag, id, &snapshot)
+    })
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
 
+    fn setup_test_db() -> Database {
+        let conn = rusqlite::Connection::open_in_memory().unwrap();
+
+        conn.execute_batch(
+            "CREATE TABLE tags (
+                id TEXT PRIMARY KEY NOT NULL,
+                name TEXT NOT NULL UNIQUE,
+                color TEXT NOT NULL DEFAULT '#3b82f6',
+                created_at TEXT NOT NULL DEFAULT (datetime('now'))
+            );
+            CREATE TABLE task_tags (
+                task_id TEXT NOT NULL,
+                tag_id TEXT NOT NULL,
+                created_at TEXT NOT NULL DEFAULT (datetime('now')),
+                PRIMARY KEY (task_id, tag_id)
+            );
+            CREATE TABLE undo_log (
+                id INTEGER PRIMARY KEY AUTOINCREMENT,
+                entity_type TEXT NOT NULL,
+                entity_id TEXT NOT NULL,
+                snapshot TEXT NOT NULL,
+                deleted_at TEXT NOT NULL
+            );
+            INSERT INTO tags (id, name) VALUES ('tag-1', 'Work');
+            INSERT INTO task_tags (task_id, tag_id) VALUES ('task-1', 'tag-1'), ('task-2', 'tag-1');",
+        )
+        .unwrap();
+
+        Database::new_from_connection(conn)
+    }
+
+    fn association_count(db: &Database) -> i64 {
+        db.connection()
+            .query_row("SELECT COUNT(*) FROM task_tags", [], |row| row.get(0))
+            .unwrap()
+    }
+
+    #[test]
+    fn test_remove_tag_deletes_associations() {
+        let db = setup_test_db();
+
+        remove_tag(&db, "tag-1").unwrap();
+
+        assert!(TagRepository::new(&db)
+            .find_by_id("tag-1")
+            .unwrap()
+            .is_none());
+        assert_eq!(association_count(&db), 0);
+    }
+
+    #[test]
+    fn test_remove_tag_is_atomic() {
+        let db = setup_test_db();
+        db.connection()
+            .execute_batch(
+                "CREATE TRIGGER block_tag_delete BEFORE DELETE ON tags
+                 BEGIN SELECT RAISE(ABORT, 'blocked'); END;",
+            )
+            .unwrap();
+
+        let result = remove_tag(&db, "tag-1");
+
+        assert!(matches!(result, Err(DomainError::BusinessRuleViolation(_))));
+        assert_eq!(association_count(&db), 2);
+    }
+
+    #[test]
+    fn test_remove_tag_rolls_back_when_undo_entry_fails() {
+        let db = setup_test_db();
+        db.connection()
+            .execute_batch("DROP TABLE undo_log")
+            .unwrap();
+
+        assert!(remove_tag(&db, "tag-1").is_err());
+
+        assert!(TagRepository::new(&db)
+            .find_by_id("tag-1")
+            .unwrap()
+            .is_some());
+        assert_eq!(association_count(&db), 2);
+    }
+
+    #[test]
+    fn test_remove_missing_tag_is_not_found() {
+        let db = setup_test_db();
+
+        let result = remove_tag(&db, "missing");
+
+        assert!(matches!(result, Err(DomainError::TagNotFound(ref id)) if id == "missing"));
+        assert!(matches!(
+            AppError::from(result.unwrap_err()),
+            AppError::NotFound(_)
+        ));
+        assert_eq!(association_count(&db), 2);
+    }
+
     #[test]
     fn test_create_tag_dto() {
         let dto = CreateTagDto {
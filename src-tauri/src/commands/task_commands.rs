@@ -1,14 +1,20 @@
+use super::events::{emit_reminder_changed, emit_task_changed};
 use crate::db::Database;
 use crate::error::AppError;
 use crate::models::{
-    CreateReminderDto, CreateTaskDto, Task, TaskPriority, TaskStatus, UpdateTaskDto,
+    ChangeKind, ChecklistItem, CreateTaskDto, CreateTaskResult, EditScope, EstimationReport,
+    ExportFormat, ExportOptions, FilterFacets, FocusSession, GlobalSearchResult,
+    ImportConflictPolicy, ImportReport, MarkDoneResult, PaginatedResponse, SortDirection, Tag,
+    TagAssignment, Task, TaskAttachment, TaskComment, TaskDelta, TaskDetail, TaskFilter,
+    TaskPriority, TaskSort, TaskSortField, TaskStatus, TaskStatusChange, TaskSummary, TaskView,
+    TaskWithProgress, TimeSummary, UpdateTaskDto, UpdateTaskResult,
 };
-use crate::repositories::{ReminderRepository, TaskRepository};
-use crate::services::TaskService;
-use chrono::{DateTime, Utc};
+use crate::repositories::{SettingsRepository, TaskRepository};
+use crate::services::{BackupService, ExportService, ImportService, TaskService};
+use chrono::{DateTime, Duration, Utc};
 use serde::{Deserialize, Serialize};
 use std::sync::{Arc, Mutex};
-use tauri::State;
+use tauri::{AppHandle, State};
 
 #[derive(Debug, Serialize, Deserialize)]
 pub struct TaskFilters {
@@ -16,6 +22,12 @@ pub struct TaskFilters {
     pub priority: Option<String>,
     pub tag_id: Option<String>,
     pub search: Option<String>,
+    /// Overrides the `show_completed_tasks` setting when provided
+    #[serde(default)]
+    pub include_completed: Option<bool>,
+    /// Include deferred ("someday/maybe") tasks, hidden by default
+    #[serde(default)]
+    pub include_deferred: Option<bool>,
 }
 
 #[derive(Debug, Serialize, Deserialize)]
@@ -26,23 +38,41 @@ pub struct PaginationParams {
 
 #[derive(Debug, Serialize, Deserialize)]
 pub struct TaskListResponse {
-    pub tasks: Vec<Task>,
+    pub tasks: Vec<TaskView>,
     pub total: u32,
     pub page: u32,
     pub page_size: u32,
 }
 
-/// Get all tasks with optional filtering
+/// Get all tasks with optional filtering and sorting
+///
+/// Sorts default to newest first; pinned tasks are always listed first.
 #[tauri::command]
 pub async fn get_tasks(
     db_state: State<'_, Arc<Mutex<Database>>>,
     filters: Option<TaskFilters>,
     pagination: Option<PaginationParams>,
+    sort: Option<Vec<TaskSort>>,
 ) -> Result<TaskListResponse, String> {
     let db = db_state
         .lock()
         .map_err(|_| AppError::DatabaseLock("Failed to acquire database lock".to_string()))?;
-    let repo = TaskRepository::new(&db);
+
+    list_tasks(&db, filters, pagination, sort)
+}
+
+/// Query a page of tasks for `get_tasks`
+fn list_tasks(
+    db: &Database,
+    filters: Option<TaskFilters>,
+    pagination: Option<PaginationParams>,
+    sort: Option<Vec<TaskSort>>,
+) -> Result<TaskListResponse, String> {
+    let sort = build_task_sort(sort)?;
+    let repo = TaskRepository::new(db);
+    let settings = SettingsRepository::new(db)
+        .get_app_settings()
+        .map_err(|e| e.to_string())?;
 
     let page_params = pagination.unwrap_or(PaginationParams {
         page: 1,
@@ -56,426 +86,1384 @@ pub async fn get_tasks(
 
     let tasks = repo
         .find_all(
-            None, // filter
-            None, // sort
+            Some(build_task_filter(
+                filters.as_ref(),
+                settings.show_completed_tasks,
+            )),
+            Some(sort),
             pagination,
         )
         .map_err(|e| e.to_string())?;
 
-    // Filter tasks based on parameters
-    let filtered_tasks: Vec<Task> = if let Some(f) = filters {
-        tasks
-            .items
-            .into_iter()
-            .filter(|task| {
-                let status_match = f.status.as_ref().map_or(true, |s| {
-                    task.status.as_str().to_lowercase() == s.to_lowercase()
-                });
-                let priority_match = f.priority.as_ref().map_or(true, |p| {
-                    task.priority.as_str().to_lowercase() == p.to_lowercase()
-                });
-                let search_match = f.search.as_ref().map_or(true, |q| {
-                    let query = q.to_lowercase();
-                    task.title.to_lowercase().contains(&query)
-                        || task
-                            .description
-                            .as_ref()
-                            .map_or(false, |d| d.to_lowercase().contains(&query))
-                });
-                let tag_match = f
-                    .tag_id
-                    .as_ref()
-                    .map_or(true, |tag_id| task.tags.iter().any(|tag| &tag.id == tag_id));
-
-                status_match && priority_match && search_match && tag_match
-            })
-            .collect()
-    } else {
-        tasks.items
-    };
-
-    let total = filtered_tasks.len() as u32;
-
     Ok(TaskListResponse {
-        tasks: filtered_tasks,
-        total,
+        tasks: tasks.items.into_iter().map(TaskView::from).collect(),
+        total: tasks.total,
         page: page_params.page,
         page_size: page_params.page_size,
     })
 }
 
-/// Get a single task by ID
+/// Validate the requested sort, defaulting to `created_at` descending
+///
+/// Unknown field names are rejected when deserializing `TaskSortField`; each field
+/// may appear only once.
+fn build_task_sort(sort: Option<Vec<TaskSort>>) -> Result<Vec<TaskSort>, String> {
+    let sort = sort.unwrap_or_default();
+    if sort.is_empty() {
+        return Ok(vec![TaskSort {
+            field: TaskSortField::CreatedAt,
+            direction: SortDirection::Desc,
+        }]);
+    }
+
+    for (i, s) in sort.iter().enumerate() {
+        if sort[..i]
+            .iter()
+            .any(|prev| prev.field.as_str() == s.field.as_str())
+        {
+            return Err(format!("Duplicate sort field: {}", s.field.as_str()));
+        }
+    }
+
+    Ok(sort)
+}
+
+/// Build the SQL-level filter, defaulting `include_completed` from the setting
+fn build_task_filter(filters: Option<&TaskFilters>, show_completed_tasks: bool) -> TaskFilter {
+    let status = filters
+        .and_then(|f| f.status.as_deref())
+        .and_then(|s| TaskStatus::from_str(&s.to_lowercase()));
+    let include_completed = filters
+        .and_then(|f| f.include_completed)
+        .unwrap_or(show_completed_tasks);
+
+    let priority = filters
+        .and_then(|f| f.priority.as_deref())
+        .and_then(|p| TaskPriority::from_str(&p.to_lowercase()));
+    let search = filters
+        .and_then(|f| f.search.as_deref())
+        .map(str::trim)
+        .filter(|q| !q.is_empty())
+        .map(str::to_string);
+    let tag_ids = filters
+        .and_then(|f| f.tag_id.clone())
+        .map(|tag_id| vec![tag_id]);
+
+    TaskFilter {
+        status,
+        priority,
+        search,
+        tag_ids,
+        include_completed: Some(include_completed),
+        include_deferred: filters.and_then(|f| f.include_deferred).unwrap_or(false),
+        ..Default::default()
+    }
+}
+
+/// Get lightweight task summaries with the same filters, sorting and paging as `get_tasks`
 #[tauri::command]
-pub async fn get_task(
+pub async fn get_task_summaries(
     db_state: State<'_, Arc<Mutex<Database>>>,
-    id: String,
-) -> Result<Task, String> {
+    filters: Option<TaskFilters>,
+    pagination: Option<PaginationParams>,
+    sort: Option<Vec<TaskSort>>,
+) -> Result<PaginatedResponse<TaskSummary>, String> {
+    let db = db_state
+        .lock()
+        .map_err(|_| AppError::DatabaseLock("Failed to acquire database lock".to_string()))?;
+    let settings = SettingsRepository::new(&db)
+        .get_app_settings()
+        .map_err(|e| e.to_string())?;
+
+    let sort = build_task_sort(sort)?;
+    let filter = build_task_filter(filters.as_ref(), settings.show_completed_tasks);
+    let page_params = pagination.unwrap_or(PaginationParams {
+        page: 1,
+        page_size: 50,
+    });
+
+    TaskRepository::new(&db)
+        .find_all_summaries(
+            Some(filter),
+            Some(sort),
+            crate::models::Pagination {
+                page: page_params.page,
+                page_size: page_params.page_size,
+            },
+        )
+        .map_err(|e| format!("Failed to load task summaries: {}", e))
+}
+
+/// Total estimated and actual minutes across tasks matching the same filters as `get_tasks`
+#[tauri::command]
+pub async fn get_time_summary(
+    db_state: State<'_, Arc<Mutex<Database>>>,
+    filters: Option<TaskFilters>,
+) -> Result<TimeSummary, String> {
+    let db = db_state
+        .lock()
+        .map_err(|_| AppError::DatabaseLock("Failed to acquire database lock".to_string()))?;
+    let settings = SettingsRepository::new(&db)
+        .get_app_settings()
+        .map_err(|e| e.to_string())?;
+
+    let filter = build_task_filter(filters.as_ref(), settings.show_completed_tasks);
+    let (estimated_minutes, actual_minutes) = TaskRepository::new(&db)
+        .sum_minutes(Some(filter))
+        .map_err(|e| format!("Failed to sum task minutes: {}", e))?;
+
+    Ok(TimeSummary {
+        estimated_minutes,
+        actual_minutes,
+    })
+}
+
+/// Estimated vs actual minutes of tasks completed in the last `days` days
+#[tauri::command]
+pub async fn get_estimation_report(
+    db_state: State<'_, Arc<Mutex<Database>>>,
+    days: u32,
+) -> Result<EstimationReport, AppError> {
+    let db = db_state
+        .lock()
+        .map_err(|_| AppError::DatabaseLock("Failed to acquire database lock".to_string()))?;
+    let service = TaskService::new(&db);
+
+    service.get_estimation_report(days).map_err(AppError::from)
+}
+
+/// Tasks changed after `since` and ids of tasks deleted since then, for incremental refreshes
+#[tauri::command]
+pub async fn get_tasks_modified_since(
+    db_state: State<'_, Arc<Mutex<Database>>>,
+    since: DateTime<Utc>,
+) -> Result<TaskDelta, String> {
     let db = db_state
         .lock()
         .map_err(|_| AppError::DatabaseLock("Failed to acquire database lock".to_string()))?;
     let service = TaskService::new(&db);
 
     service
-        .get_task(&id)
+        .get_tasks_modified_since(since)
         .map_err(|e| AppError::from(e).to_string())
 }
 
-/// Create a new task
+/// Distinct statuses, priorities and applied tags with task counts, for filter dropdowns
 #[tauri::command]
-pub async fn create_task(
+pub async fn get_filter_facets(
     db_state: State<'_, Arc<Mutex<Database>>>,
-    data: CreateTaskDto,
-) -> Result<Task, String> {
+) -> Result<FilterFacets, String> {
     let db = db_state
         .lock()
         .map_err(|_| AppError::DatabaseLock("Failed to acquire database lock".to_string()))?;
     let service = TaskService::new(&db);
 
     service
-        .create_task(data)
+        .get_filter_facets()
         .map_err(|e| AppError::from(e).to_string())
 }
 
-/// Update an existing task
+/// Get a single task by ID
 #[tauri::command]
-pub async fn update_task(
+pub async fn get_task(
     db_state: State<'_, Arc<Mutex<Database>>>,
     id: String,
-    data: UpdateTaskDto,
-) -> Result<Task, String> {
+) -> Result<TaskView, String> {
     let db = db_state
         .lock()
         .map_err(|_| AppError::DatabaseLock("Failed to acquire database lock".to_string()))?;
     let service = TaskService::new(&db);
 
     service
-        .update_task(&id, data)
+        .get_task(&id)
+        .map(TaskView::from)
         .map_err(|e| AppError::from(e).to_string())
 }
 
-/// Delete a task
+/// Get a task with its reminders, subtasks and checklist in one call
 #[tauri::command]
-pub async fn delete_task(
+pub async fn get_task_detail(
     db_state: State<'_, Arc<Mutex<Database>>>,
     id: String,
-) -> Result<bool, String> {
+) -> Result<TaskDetail, String> {
     let db = db_state
         .lock()
         .map_err(|_| AppError::DatabaseLock("Failed to acquire database lock".to_string()))?;
     let service = TaskService::new(&db);
 
     service
-        .delete_task(&id)
+        .get_task_detail(&id)
         .map_err(|e| AppError::from(e).to_string())
 }
 
-/// Mark a task as done
+/// Start a focus session on a task with work/break transition reminders
 #[tauri::command]
-pub async fn mark_task_done(
+pub async fn start_focus_session(
+    app: AppHandle,
     db_state: State<'_, Arc<Mutex<Database>>>,
-    id: String,
-) -> Result<Task, String> {
+    task_id: String,
+    work_minutes: i32,
+    break_minutes: i32,
+) -> Result<FocusSession, AppError> {
     let db = db_state
         .lock()
         .map_err(|_| AppError::DatabaseLock("Failed to acquire database lock".to_string()))?;
     let service = TaskService::new(&db);
 
-    let dto = UpdateTaskDto {
-        title: None,
-        description: None,
-        status: Some(TaskStatus::Completed),
-        priority: None,
-        due_date: None,
-        image_path: None,
-        notes: None,
-        estimated_minutes: None,
-        actual_minutes: None,
-        tag_ids: None,
-    };
+    let session = service.start_focus_session(&task_id, work_minutes, break_minutes)?;
 
-    service
-        .update_task(&id, dto)
-        .map_err(|e| AppError::from(e).to_string())
+    for reminder_id in [&session.break_reminder_id, &session.work_reminder_id]
+        .into_iter()
+        .flatten()
+    {
+        emit_reminder_changed(&app, reminder_id, ChangeKind::Created);
+    }
+    Ok(session)
 }
 
-/// Search tasks by query
+/// Stop a task's focus session and log its work minutes
 #[tauri::command]
-pub async fn search_tasks(
+pub async fn stop_focus_session(
+    app: AppHandle,
     db_state: State<'_, Arc<Mutex<Database>>>,
-    query: String,
-) -> Result<Vec<Task>, String> {
+    task_id: String,
+) -> Result<FocusSession, String> {
+    let db = db_state
+        .lock()
+        .map_err(|_| AppError::DatabaseLock("Failed to acquire database lock".to_string()))?;
+    let service = TaskService::new(&db);
+
+    let session = service
+        .stop_focus_session(&task_id)
+        .map_err(|e| AppError::from(e).to_string())?;
+
+    for reminder_id in [&session.break_reminder_id, &session.work_reminder_id]
+        .into_iter()
+        .flatten()
+    {
+        emit_reminder_changed(&app, reminder_id, ChangeKind::Deleted);
+    }
+    emit_task_changed(&app, &task_id, ChangeKind::Updated);
+    Ok(session)
+}
+
+/// Get the tags applied to a task
+#[tauri::command]
+pub async fn get_task_tags(
+    db_state: State<'_, Arc<Mutex<Database>>>,
+    task_id: String,
+) -> Result<Vec<Tag>, String> {
     let db = db_state
         .lock()
         .map_err(|_| AppError::DatabaseLock("Failed to acquire database lock".to_string()))?;
     let service = TaskService::new(&db);
 
     service
-        .search_tasks(&query)
+        .get_task_tags(&task_id)
         .map_err(|e| AppError::from(e).to_string())
 }
 
-/// Export all tasks to JSON
+/// Replace a task's tags without editing the rest of the task; an empty list clears them
+///
+/// Unknown tag ids are skipped and returned in `invalid_tag_ids`.
 #[tauri::command]
-pub async fn export_tasks_json(
+pub async fn set_task_tags(
+    app: AppHandle,
     db_state: State<'_, Arc<Mutex<Database>>>,
-) -> Result<String, String> {
+    task_id: String,
+    tag_ids: Vec<String>,
+) -> Result<TagAssignment, String> {
     let db = db_state
         .lock()
         .map_err(|_| AppError::DatabaseLock("Failed to acquire database lock".to_string()))?;
-    let repo = TaskRepository::new(&db);
+    let service = TaskService::new(&db);
 
-    // Get all tasks using find_all with large page size
-    let pagination = crate::models::Pagination {
-        page: 1,
-        page_size: 10000, // Large enough to get all tasks
-    };
-    let response = repo
-        .find_all(None, None, pagination)
-        .map_err(|e| format!("Failed to get tasks: {}", e))?;
-    let tasks = response.items;
+    let assignment = service
+        .set_task_tags(&task_id, tag_ids)
+        .map_err(|e| AppError::from(e).to_string())?;
 
-    // Serialize to pretty JSON
-    serde_json::to_string_pretty(&tasks).map_err(|e| format!("Failed to serialize tasks: {}", e))
+    emit_task_changed(&app, &task_id, ChangeKind::Updated);
+    Ok(assignment)
 }
 
-/// Export all tasks to CSV
+/// Get a single task with its subtask-derived progress
 #[tauri::command]
-pub async fn export_tasks_csv(db_state: State<'_, Arc<Mutex<Database>>>) -> Result<String, String> {
+pub async fn get_task_with_progress(
+    db_state: State<'_, Arc<Mutex<Database>>>,
+    id: String,
+) -> Result<TaskWithProgress, String> {
     let db = db_state
         .lock()
         .map_err(|_| AppError::DatabaseLock("Failed to acquire database lock".to_string()))?;
-    let repo = TaskRepository::new(&db);
+    let service = TaskService::new(&db);
 
-    let pagination = crate::models::Pagination {
-        page: 1,
-        page_size: 10000,
-    };
-    let response = repo
-        .find_all(None, None, pagination)
-        .map_err(|e| format!("Failed to get tasks: {}", e))?;
-    let tasks = response.items;
-
-    // Build CSV header
-    let mut csv =
-        String::from("id,title,description,status,priority,due_date,tags,created_at,updated_at\n");
-
-    // Add each task as a row
-    for task in tasks {
-        let description = task.description.unwrap_or_default().replace("\"", "\"\"");
-        let due_date = task.due_date.map(|d| d.to_rfc3339()).unwrap_or_default();
-        let tag_names: Vec<String> = task.tags.iter().map(|t| t.name.clone()).collect();
-        let tags = tag_names.join("|");
-
-        csv.push_str(&format!(
-            "\"{}\",\"{}\",\"{}\",\"{}\",\"{}\",\"{}\",\"{}\",\"{}\",\"{}\"\n",
-            task.id,
-            task.title.replace("\"", "\"\""),
-            description,
-            task.status.as_str(),
-            task.priority.as_str(),
-            due_date,
-            tags,
-            task.created_at.to_rfc3339(),
-            task.updated_at.to_rfc3339()
-        ));
-    }
+    service
+        .get_task_with_progress(&id)
+        .map_err(|e| AppError::from(e).to_string())
+}
+
+/// Create a new task
+#[tauri::command]
+pub async fn create_task(
+    app: AppHandle,
+    db_state: State<'_, Arc<Mutex<Database>>>,
+    data: CreateTaskDto,
+) -> Result<CreateTaskResult, AppError> {
+    let db = db_state
+        .lock()
+        .map_err(|_| AppError::DatabaseLock("Failed to acquire database lock".to_string()))?;
+    let service = TaskService::new(&db);
+
+    let result = service.create_task_with_result(data)?;
 
-    Ok(csv)
+    emit_task_changed(&app, &result.task.id, ChangeKind::Created);
+    Ok(result)
 }
 
-/// Import tasks from JSON
+/// Update an existing task
+///
+/// When the due date moves before some of the task's reminders, those reminders are
+/// moved to the due date if `shift_reminders` is set, otherwise reported with a `warning`.
+/// For a recurring task, `edit_scope` picks between this occurrence only (default)
+/// and every open occurrence of the series.
 #[tauri::command]
-pub async fn import_tasks_json(
+pub async fn update_task(
+    app: AppHandle,
     db_state: State<'_, Arc<Mutex<Database>>>,
-    json_data: String,
-) -> Result<usize, String> {
+    id: String,
+    data: UpdateTaskDto,
+    shift_reminders: Option<bool>,
+    edit_scope: Option<EditScope>,
+) -> Result<UpdateTaskResult, AppError> {
     let db = db_state
         .lock()
         .map_err(|_| AppError::DatabaseLock("Failed to acquire database lock".to_string()))?;
     let service = TaskService::new(&db);
 
-    // Parse JSON to tasks
-    let tasks: Vec<CreateTaskDto> =
-        serde_json::from_str(&json_data).map_err(|e| format!("Failed to parse JSON: {}", e))?;
+    let result = service.update_task_scoped(
+        &id,
+        data,
+        edit_scope.unwrap_or_default(),
+        shift_reminders.unwrap_or(false),
+    )?;
 
-    // Import each task
-    let mut count = 0;
-    for task_data in tasks {
-        match service.create_task(task_data) {
-            Ok(_) => count += 1,
-            Err(e) => {
-                // Log error but continue with other tasks
-                eprintln!("Failed to import task: {}", e);
-            }
-        }
+    emit_task_changed(&app, &result.task.id, ChangeKind::Updated);
+    for task_id in &result.updated_occurrence_ids {
+        emit_task_changed(&app, task_id, ChangeKind::Updated);
     }
-
-    Ok(count)
+    for reminder_id in &result.shifted_reminder_ids {
+        emit_reminder_changed(&app, reminder_id, ChangeKind::Updated);
+    }
+    Ok(result)
 }
 
-/// Import tasks from CSV
+/// Delete a task
 #[tauri::command]
-pub async fn import_tasks_csv(
+pub async fn delete_task(
+    app: AppHandle,
     db_state: State<'_, Arc<Mutex<Database>>>,
-    csv_data: String,
-) -> Result<usize, String> {
+    id: String,
+) -> Result<bool, String> {
     let db = db_state
         .lock()
         .map_err(|_| AppError::DatabaseLock("Failed to acquire database lock".to_string()))?;
     let service = TaskService::new(&db);
 
-    let mut count = 0;
-    let lines: Vec<&str> = csv_data.lines().collect();
-
-    // Skip header row
-    for line in lines.iter().skip(1) {
-        if line.trim().is_empty() {
-            continue;
-        }
+    let deleted = service
+        .delete_task(&id)
+        .map_err(|e| AppError::from(e).to_string())?;
 
-        // Parse CSV line (simple implementation)
-        let fields: Vec<&str> = line
-            .split(',')
-            .map(|s| s.trim_matches('"').trim())
-            .collect();
+    emit_task_changed(&app, &id, ChangeKind::Deleted);
+    Ok(deleted)
+}
 
-        if fields.len() < 9 {
-            eprintln!("Skipping invalid CSV line: {}", line);
-            continue;
-        }
+/// Mark a task as done
+///
+/// An optional `completion_note` records the outcome alongside the task.
+/// Fails while the task has incomplete blockers unless `force` is true.
+/// Active reminders of the task are deactivated unless `keep_reminders` is true.
+/// The returned task carries `overrun_ratio`/`warning` when it took longer than estimated.
+#[tauri::command]
+pub async fn mark_task_done(
+    app: AppHandle,
+    db_state: State<'_, Arc<Mutex<Database>>>,
+    id: String,
+    actual_minutes: Option<i32>,
+    completion_note: Option<String>,
+    force: Option<bool>,
+    keep_reminders: Option<bool>,
+) -> Result<MarkDoneResult, AppError> {
+    let db = db_state
+        .lock()
+        .map_err(|_| AppError::DatabaseLock("Failed to acquire database lock".to_string()))?;
+    let service = TaskService::new(&db);
 
-        // Parse due_date
-        let due_date = if !fields[5].is_empty() {
-            match DateTime::parse_from_rfc3339(fields[5]) {
-                Ok(dt) => Some(dt.with_timezone(&Utc)),
-                Err(_) => None,
-            }
-        } else {
-            None
-        };
+    let result = service.mark_done(
+        &id,
+        actual_minutes,
+        completion_note,
+        force.unwrap_or(false),
+        keep_reminders.unwrap_or(false),
+    )?;
 
-        // Parse tags
-        let tags: Vec<String> = if !fields[6].is_empty() {
-            fields[6].split('|').map(|s| s.to_string()).collect()
-        } else {
-            vec![]
-        };
+    emit_task_changed(&app, &result.task.id, ChangeKind::Updated);
+    Ok(result)
+}
 
-        // Create task DTO
-        let task_data = CreateTaskDto {
-            title: fields[1].to_string(),
-            description: if !fields[2].is_empty() {
-                Some(fields[2].to_string())
-            } else {
-                None
-            },
-            priority: TaskPriority::from_str(fields[4]).unwrap_or(TaskPriority::Medium),
-            due_date,
-            image_path: None,
-            notes: None,
-            estimated_minutes: None,
-            tag_ids: tags,
-        };
+/// Reopen a completed task as pending, or as `status` (pending or in_progress)
+#[tauri::command]
+pub async fn reopen_task(
+    app: AppHandle,
+    db_state: State<'_, Arc<Mutex<Database>>>,
+    id: String,
+    status: Option<TaskStatus>,
+) -> Result<Task, String> {
+    let db = db_state
+        .lock()
+        .map_err(|_| AppError::DatabaseLock("Failed to acquire database lock".to_string()))?;
+    let service = TaskService::new(&db);
 
-        match service.create_task(task_data) {
-            Ok(_) => count += 1,
-            Err(e) => {
-                eprintln!("Failed to import task from CSV: {}", e);
-            }
-        }
-    }
+    let task = service
+        .reopen(&id, status)
+        .map_err(|e| AppError::from(e).to_string())?;
 
-    Ok(count)
+    emit_task_changed(&app, &task.id, ChangeKind::Updated);
+    Ok(task)
 }
 
-/// Backup all data (tasks + reminders) to JSON
+/// Get the tasks blocking a task
 #[tauri::command]
-pub async fn backup_data(db_state: State<'_, Arc<Mutex<Database>>>) -> Result<String, String> {
-    use serde_json::json;
+pub async fn get_task_dependencies(
+    db_state: State<'_, Arc<Mutex<Database>>>,
+    id: String,
+) -> Result<Vec<Task>, String> {
+    let db = db_state
+        .lock()
+        .map_err(|_| AppError::DatabaseLock("Failed to acquire database lock".to_string()))?;
+    let service = TaskService::new(&db);
+
+    service
+        .get_dependencies(&id)
+        .map_err(|e| AppError::from(e).to_string())
+}
 
+/// Make a task blocked by another task
+#[tauri::command]
+pub async fn add_task_dependency(
+    db_state: State<'_, Arc<Mutex<Database>>>,
+    task_id: String,
+    depends_on_id: String,
+) -> Result<(), String> {
     let db = db_state
         .lock()
         .map_err(|_| AppError::DatabaseLock("Failed to acquire database lock".to_string()))?;
-    let task_repo = TaskRepository::new(&db);
-    let reminder_repo = ReminderRepository::new(&db);
+    let service = TaskService::new(&db);
 
-    // Get all data
-    let pagination = crate::models::Pagination {
-        page: 1,
-        page_size: 10000,
-    };
-    let tasks_response = task_repo
-        .find_all(None, None, pagination)
-        .map_err(|e| format!("Failed to get tasks: {}", e))?;
-    let tasks = tasks_response.items;
-
-    let reminders = reminder_repo
-        .find_all()
-        .map_err(|e| format!("Failed to get reminders: {}", e))?;
-
-    // Create backup object with timestamp
-    let backup = json!({
-        "version": "1.0",
-        "timestamp": chrono::Utc::now().to_rfc3339(),
-        "tasks": tasks,
-        "reminders": reminders
-    });
+    service
+        .add_dependency(&task_id, &depends_on_id)
+        .map_err(|e| AppError::from(e).to_string())
+}
 
-    serde_json::to_string_pretty(&backup).map_err(|e| format!("Failed to serialize backup: {}", e))
+/// Remove a blocked-by relationship between two tasks
+#[tauri::command]
+pub async fn remove_task_dependency(
+    db_state: State<'_, Arc<Mutex<Database>>>,
+    task_id: String,
+    depends_on_id: String,
+) -> Result<bool, String> {
+    let db = db_state
+        .lock()
+        .map_err(|_| AppError::DatabaseLock("Failed to acquire database lock".to_string()))?;
+    let service = TaskService::new(&db);
+
+    service
+        .remove_dependency(&task_id, &depends_on_id)
+        .map_err(|e| AppError::from(e).to_string())
 }
 
-/// Restore data from backup JSON
+/// Get the status-change history of a task, oldest first
 #[tauri::command]
-pub async fn restore_data(
+pub async fn get_task_history(
     db_state: State<'_, Arc<Mutex<Database>>>,
-    backup_data: String,
-) -> Result<(usize, usize), String> {
-    use crate::services::ReminderService;
+    id: String,
+) -> Result<Vec<TaskStatusChange>, String> {
+    let db = db_state
+        .lock()
+        .map_err(|_| AppError::DatabaseLock("Failed to acquire database lock".to_string()))?;
+    let service = TaskService::new(&db);
+
+    service
+        .get_task_history(&id)
+        .map_err(|e| AppError::from(e).to_string())
+}
 
+/// Add a comment to a task
+#[tauri::command]
+pub async fn add_comment(
+    db_state: State<'_, Arc<Mutex<Database>>>,
+    task_id: String,
+    body: String,
+) -> Result<TaskComment, String> {
     let db = db_state
         .lock()
         .map_err(|_| AppError::DatabaseLock("Failed to acquire database lock".to_string()))?;
-    let task_service = TaskService::new(&db);
-    let reminder_service = ReminderService::new(&db);
+    let service = TaskService::new(&db);
 
-    // Parse backup JSON
-    let backup: serde_json::Value =
-        serde_json::from_str(&backup_data).map_err(|e| format!("Failed to parse backup: {}", e))?;
+    service
+        .add_comment(&task_id, &body)
+        .map_err(|e| AppError::from(e).to_string())
+}
 
-    // Extract tasks and reminders
-    let tasks: Vec<CreateTaskDto> = serde_json::from_value(
-        backup
-            .get("tasks")
-            .ok_or("Missing tasks in backup")?
-            .clone(),
-    )
-    .map_err(|e| format!("Failed to parse tasks: {}", e))?;
+/// List a task's comments, oldest first
+#[tauri::command]
+pub async fn list_comments(
+    db_state: State<'_, Arc<Mutex<Database>>>,
+    task_id: String,
+) -> Result<Vec<TaskComment>, String> {
+    let db = db_state
+        .lock()
+        .map_err(|_| AppError::DatabaseLock("Failed to acquire database lock".to_string()))?;
+    let service = TaskService::new(&db);
 
-    let reminders: Vec<CreateReminderDto> = serde_json::from_value(
-        backup
-            .get("reminders")
-            .ok_or("Missing reminders in backup")?
-            .clone(),
-    )
-    .map_err(|e| format!("Failed to parse reminders: {}", e))?;
+    service
+        .list_comments(&task_id)
+        .map_err(|e| AppError::from(e).to_string())
+}
 
-    // Restore tasks
-    let mut task_count = 0;
-    for task_data in tasks {
-        match task_service.create_task(task_data) {
-            Ok(_) => task_count += 1,
-            Err(e) => eprintln!("Failed to restore task: {}", e),
-        }
-    }
+/// Delete a comment
+#[tauri::command]
+pub async fn delete_comment(
+    db_state: State<'_, Arc<Mutex<Database>>>,
+    id: String,
+) -> Result<bool, String> {
+    let db = db_state
+        .lock()
+        .map_err(|_| AppError::DatabaseLock("Failed to acquire database lock".to_string()))?;
+    let service = TaskService::new(&db);
 
-    // Restore reminders
-    let mut reminder_count = 0;
-    for reminder_data in reminders {
-        match reminder_service.create_reminder(reminder_data) {
-            Ok(_) => reminder_count += 1,
-            Err(e) => eprintln!("Failed to restore reminder: {}", e),
-        }
-    }
+    service
+        .delete_comment(&id)
+        .map_err(|e| AppError::from(e).to_string())
+}
 
-    Ok((task_count, reminder_count))
+/// Add an item to a task's checklist
+#[tauri::command]
+pub async fn add_checklist_item(
+    db_state: State<'_, Arc<Mutex<Database>>>,
+    task_id: String,
+    text: String,
+) -> Result<ChecklistItem, String> {
+    let db = db_state
+        .lock()
+        .map_err(|_| AppError::DatabaseLock("Failed to acquire database lock".to_string()))?;
+    let service = TaskService::new(&db);
+
+    service
+        .add_checklist_item(&task_id, &text)
+        .map_err(|e| AppError::from(e).to_string())
+}
+
+/// Toggle a checklist item's done state
+#[tauri::command]
+pub async fn toggle_checklist_item(
+    db_state: State<'_, Arc<Mutex<Database>>>,
+    id: String,
+) -> Result<ChecklistItem, String> {
+    let db = db_state
+        .lock()
+        .map_err(|_| AppError::DatabaseLock("Failed to acquire database lock".to_string()))?;
+    let service = TaskService::new(&db);
+
+    service
+        .toggle_checklist_item(&id)
+        .map_err(|e| AppError::from(e).to_string())
+}
+
+/// Delete a checklist item
+#[tauri::command]
+pub async fn delete_checklist_item(
+    db_state: State<'_, Arc<Mutex<Database>>>,
+    id: String,
+) -> Result<bool, String> {
+    let db = db_state
+        .lock()
+        .map_err(|_| AppError::DatabaseLock("Failed to acquire database lock".to_string()))?;
+    let service = TaskService::new(&db);
+
+    service
+        .delete_checklist_item(&id)
+        .map_err(|e| AppError::from(e).to_string())
+}
+
+/// List a task's checklist items in order
+#[tauri::command]
+pub async fn list_checklist_items(
+    db_state: State<'_, Arc<Mutex<Database>>>,
+    task_id: String,
+) -> Result<Vec<ChecklistItem>, String> {
+    let db = db_state
+        .lock()
+        .map_err(|_| AppError::DatabaseLock("Failed to acquire database lock".to_string()))?;
+    let service = TaskService::new(&db);
+
+    service
+        .list_checklist_items(&task_id)
+        .map_err(|e| AppError::from(e).to_string())
+}
+
+/// Attach a local file to a task
+#[tauri::command]
+pub async fn add_attachment(
+    db_state: State<'_, Arc<Mutex<Database>>>,
+    task_id: String,
+    file_path: String,
+    mime_type: Option<String>,
+) -> Result<TaskAttachment, String> {
+    let db = db_state
+        .lock()
+        .map_err(|_| AppError::DatabaseLock("Failed to acquire database lock".to_string()))?;
+    let service = TaskService::new(&db);
+
+    service
+        .add_attachment(&task_id, &file_path, mime_type)
+        .map_err(|e| AppError::from(e).to_string())
+}
+
+/// List the attachments of a task
+#[tauri::command]
+pub async fn list_attachments(
+    db_state: State<'_, Arc<Mutex<Database>>>,
+    task_id: String,
+) -> Result<Vec<TaskAttachment>, String> {
+    let db = db_state
+        .lock()
+        .map_err(|_| AppError::DatabaseLock("Failed to acquire database lock".to_string()))?;
+    let service = TaskService::new(&db);
+
+    service
+        .list_attachments(&task_id)
+        .map_err(|e| AppError::from(e).to_string())
+}
+
+/// Remove an attachment from its task
+#[tauri::command]
+pub async fn remove_attachment(
+    db_state: State<'_, Arc<Mutex<Database>>>,
+    id: String,
+) -> Result<bool, String> {
+    let db = db_state
+        .lock()
+        .map_err(|_| AppError::DatabaseLock("Failed to acquire database lock".to_string()))?;
+    let service = TaskService::new(&db);
+
+    service
+        .remove_attachment(&id)
+        .map_err(|e| AppError::from(e).to_string())
+}
+
+/// Generate the next occurrence of a recurring task, linked to the same series
+#[tauri::command]
+pub async fn create_next_occurrence(
+    app: AppHandle,
+    db_state: State<'_, Arc<Mutex<Database>>>,
+    id: String,
+    due_date: DateTime<Utc>,
+) -> Result<Task, AppError> {
+    let db = db_state
+        .lock()
+        .map_err(|_| AppError::DatabaseLock("Failed to acquire database lock".to_string()))?;
+    let service = TaskService::new(&db);
+
+    let task = service.create_next_occurrence(&id, due_date)?;
+
+    emit_task_changed(&app, &task.id, ChangeKind::Created);
+    Ok(task)
+}
+
+/// Duplicate a task, optionally copying its tags and reminders
+#[tauri::command]
+pub async fn duplicate_task(
+    app: AppHandle,
+    db_state: State<'_, Arc<Mutex<Database>>>,
+    id: String,
+    include_tags: Option<bool>,
+    include_reminders: Option<bool>,
+) -> Result<Task, String> {
+    let db = db_state
+        .lock()
+        .map_err(|_| AppError::DatabaseLock("Failed to acquire database lock".to_string()))?;
+    let service = TaskService::new(&db);
+
+    let task = service
+        .duplicate(
+            &id,
+            include_tags.unwrap_or(true),
+            include_reminders.unwrap_or(false),
+        )
+        .map_err(|e| AppError::from(e).to_string())?;
+
+    emit_task_changed(&app, &task.id, ChangeKind::Created);
+    Ok(task)
+}
+
+/// Move a task in the manual ordering, placing it after `after_id` (or first if None)
+#[tauri::command]
+pub async fn reorder_task(
+    app: AppHandle,
+    db_state: State<'_, Arc<Mutex<Database>>>,
+    id: String,
+    after_id: Option<String>,
+) -> Result<Task, String> {
+    let db = db_state
+        .lock()
+        .map_err(|_| AppError::DatabaseLock("Failed to acquire database lock".to_string()))?;
+    let service = TaskService::new(&db);
+
+    let task = service
+        .reorder(&id, after_id)
+        .map_err(|e| AppError::from(e).to_string())?;
+
+    emit_task_changed(&app, &task.id, ChangeKind::Updated);
+    Ok(task)
+}
+
+/// Pin a task so it is listed first
+#[tauri::command]
+pub async fn pin_task(
+    app: AppHandle,
+    db_state: State<'_, Arc<Mutex<Database>>>,
+    id: String,
+) -> Result<Task, String> {
+    let db = db_state
+        .lock()
+        .map_err(|_| AppError::DatabaseLock("Failed to acquire database lock".to_string()))?;
+    let service = TaskService::new(&db);
+
+    let task = service
+        .pin_task(&id)
+        .map_err(|e| AppError::from(e).to_string())?;
+
+    emit_task_changed(&app, &task.id, ChangeKind::Updated);
+    Ok(task)
+}
+
+/// Defer a task to the "someday/maybe" list, optionally until `until`
+#[tauri::command]
+pub async fn defer_task(
+    app: AppHandle,
+    db_state: State<'_, Arc<Mutex<Database>>>,
+    id: String,
+    until: Option<DateTime<Utc>>,
+) -> Result<Task, AppError> {
+    let db = db_state
+        .lock()
+        .map_err(|_| AppError::DatabaseLock("Failed to acquire database lock".to_string()))?;
+    let service = TaskService::new(&db);
+
+    let task = service.defer_task(&id, until)?;
+
+    emit_task_changed(&app, &task.id, ChangeKind::Updated);
+    Ok(task)
+}
+
+/// Bring a deferred task back to the active lists
+#[tauri::command]
+pub async fn undefer_task(
+    app: AppHandle,
+    db_state: State<'_, Arc<Mutex<Database>>>,
+    id: String,
+) -> Result<Task, String> {
+    let db = db_state
+        .lock()
+        .map_err(|_| AppError::DatabaseLock("Failed to acquire database lock".to_string()))?;
+    let service = TaskService::new(&db);
+
+    let task = service
+        .undefer_task(&id)
+        .map_err(|e| AppError::from(e).to_string())?;
+
+    emit_task_changed(&app, &task.id, ChangeKind::Updated);
+    Ok(task)
+}
+
+/// Get deferred ("someday/maybe") tasks, oldest first
+#[tauri::command]
+pub async fn get_deferred_tasks(
+    db_state: State<'_, Arc<Mutex<Database>>>,
+) -> Result<Vec<Task>, String> {
+    let db = db_state
+        .lock()
+        .map_err(|_| AppError::DatabaseLock("Failed to acquire database lock".to_string()))?;
+    let service = TaskService::new(&db);
+
+    service
+        .get_deferred_tasks()
+        .map_err(|e| AppError::from(e).to_string())
+}
+
+/// Unpin a task
+#[tauri::command]
+pub async fn unpin_task(
+    app: AppHandle,
+    db_state: State<'_, Arc<Mutex<Database>>>,
+    id: String,
+) -> Result<Task, String> {
+    let db = db_state
+        .lock()
+        .map_err(|_| AppError::DatabaseLock("Failed to acquire database lock".to_string()))?;
+    let service = TaskService::new(&db);
+
+    let task = service
+        .unpin_task(&id)
+        .map_err(|e| AppError::from(e).to_string())?;
+
+    emit_task_changed(&app, &task.id, ChangeKind::Updated);
+    Ok(task)
+}
+
+/// Push all overdue tasks forward by `hours` plus `minutes`
+#[tauri::command]
+pub async fn postpone_overdue_tasks(
+    app: AppHandle,
+    db_state: State<'_, Arc<Mutex<Database>>>,
+    minutes: Option<i64>,
+    hours: Option<i64>,
+) -> Result<usize, String> {
+    let total_minutes = hours
+        .unwrap_or(0)
+        .saturating_mul(60)
+        .saturating_add(minutes.unwrap_or(0));
+    let by = Duration::try_minutes(total_minutes)
+        .ok_or_else(|| "Postpone duration is too large".to_string())?;
+
+    let db = db_state
+        .lock()
+        .map_err(|_| AppError::DatabaseLock("Failed to acquire database lock".to_string()))?;
+    let service = TaskService::new(&db);
+
+    let overdue_ids: Vec<String> = service
+        .auto_update_overdue_status()
+        .map_err(|e| AppError::from(e).to_string())?
+        .into_iter()
+        .map(|task| task.id)
+        .collect();
+
+    let updated = service
+        .postpone_overdue(by)
+        .map_err(|e| AppError::from(e).to_string())?;
+
+    for id in &overdue_ids {
+        emit_task_changed(&app, id, ChangeKind::Updated);
+    }
+    Ok(updated)
+}
+
+/// Get tasks having ALL (`match_all`) or ANY of the given tags
+#[tauri::command]
+pub async fn get_tasks_by_tags(
+    db_state: State<'_, Arc<Mutex<Database>>>,
+    tag_ids: Vec<String>,
+    match_all: Option<bool>,
+) -> Result<Vec<Task>, String> {
+    let db = db_state
+        .lock()
+        .map_err(|_| AppError::DatabaseLock("Failed to acquire database lock".to_string()))?;
+    let service = TaskService::new(&db);
+
+    service
+        .get_tasks_by_tags(&tag_ids, match_all.unwrap_or(false))
+        .map_err(|e| AppError::from(e).to_string())
+}
+
+/// Search tasks by query, best matches first
+#[tauri::command]
+pub async fn search_tasks(
+    db_state: State<'_, Arc<Mutex<Database>>>,
+    query: String,
+    limit: Option<u32>,
+) -> Result<Vec<Task>, AppError> {
+    let db = db_state
+        .lock()
+        .map_err(|_| AppError::DatabaseLock("Failed to acquire database lock".to_string()))?;
+    let service = TaskService::new(&db);
+
+    service.search_tasks(&query, limit).map_err(AppError::from)
+}
+
+/// Search tasks and reminders together
+#[tauri::command]
+pub async fn global_search(
+    db_state: State<'_, Arc<Mutex<Database>>>,
+    query: String,
+) -> Result<GlobalSearchResult, String> {
+    let db = db_state
+        .lock()
+        .map_err(|_| AppError::DatabaseLock("Failed to acquire database lock".to_string()))?;
+    let service = TaskService::new(&db);
+
+    service
+        .global_search(&query)
+        .map_err(|e| AppError::from(e).to_string())
+}
+
+/// Get tasks due within `[start, end)`, ordered by due date
+#[tauri::command]
+pub async fn get_tasks_due_between(
+    db_state: State<'_, Arc<Mutex<Database>>>,
+    start: DateTime<Utc>,
+    end: DateTime<Utc>,
+) -> Result<Vec<Task>, String> {
+    let db = db_state
+        .lock()
+        .map_err(|_| AppError::DatabaseLock("Failed to acquire database lock".to_string()))?;
+    let service = TaskService::new(&db);
+
+    service
+        .get_tasks_due_between(start, end)
+        .map_err(|e| AppError::from(e).to_string())
+}
+
+/// Get tasks due during the user's local day
+///
+/// `utc_offset_minutes` defaults to 0 (UTC) when not provided
+#[tauri::command]
+pub async fn get_tasks_due_today(
+    db_state: State<'_, Arc<Mutex<Database>>>,
+    utc_offset_minutes: Option<i32>,
+) -> Result<Vec<Task>, AppError> {
+    let db = db_state
+        .lock()
+        .map_err(|_| AppError::DatabaseLock("Failed to acquire database lock".to_string()))?;
+    let service = TaskService::new(&db);
+
+    service
+        .get_tasks_due_today(utc_offset_minutes.unwrap_or(0))
+        .map_err(AppError::from)
+}
+
+/// Get tasks due during the user's local week
+///
+/// The week starts on the `first_day_of_week` setting; `utc_offset_minutes` defaults to 0 (UTC)
+#[tauri::command]
+pub async fn get_tasks_due_this_week(
+    db_state: State<'_, Arc<Mutex<Database>>>,
+    utc_offset_minutes: Option<i32>,
+) -> Result<Vec<Task>, AppError> {
+    let db = db_state
+        .lock()
+        .map_err(|_| AppError::DatabaseLock("Failed to acquire database lock".to_string()))?;
+    let service = TaskService::new(&db);
+
+    service
+        .get_tasks_due_this_week(utc_offset_minutes.unwrap_or(0))
+        .map_err(AppError::from)
+}
+
+/// Get the inbox: tasks without a due date, oldest first
+///
+/// `include_completed` defaults to the `show_completed_tasks` setting
+#[tauri::command]
+pub async fn get_inbox_tasks(
+    db_state: State<'_, Arc<Mutex<Database>>>,
+    status: Option<TaskStatus>,
+    include_completed: Option<bool>,
+) -> Result<Vec<Task>, String> {
+    let db = db_state
+        .lock()
+        .map_err(|_| AppError::DatabaseLock("Failed to acquire database lock".to_string()))?;
+    let include_completed = match include_completed {
+        Some(include) => include,
+        None => {
+            SettingsRepository::new(&db)
+                .get_app_settings()
+                .map_err(|e| e.to_string())?
+                .show_completed_tasks
+        }
+    };
+    let service = TaskService::new(&db);
+
+    service
+        .get_inbox_tasks(status, include_completed)
+        .map_err(|e| AppError::from(e).to_string())
+}
+
+/// Count finished tasks that `clear_completed_tasks` would delete, for confirmation
+#[tauri::command]
+pub async fn count_completed_before(
+    db_state: State<'_, Arc<Mutex<Database>>>,
+    cutoff: Option<DateTime<Utc>>,
+    include_cancelled: Option<bool>,
+) -> Result<usize, String> {
+    let db = db_state
+        .lock()
+        .map_err(|_| AppError::DatabaseLock("Failed to acquire database lock".to_string()))?;
+    let service = TaskService::new(&db);
+
+    service
+        .completed_ids_before(cutoff, include_cancelled.unwrap_or(false))
+        .map(|ids| ids.len())
+        .map_err(|e| AppError::from(e).to_string())
+}
+
+/// Delete completed (and optionally cancelled) tasks finished before `cutoff`
+#[tauri::command]
+pub async fn clear_completed_tasks(
+    app: AppHandle,
+    db_state: State<'_, Arc<Mutex<Database>>>,
+    cutoff: Option<DateTime<Utc>>,
+    include_cancelled: Option<bool>,
+) -> Result<usize, String> {
+    let include_cancelled = include_cancelled.unwrap_or(false);
+
+    let db = db_state
+        .lock()
+        .map_err(|_| AppError::DatabaseLock("Failed to acquire database lock".to_string()))?;
+    let service = TaskService::new(&db);
+
+    let ids = service
+        .completed_ids_before(cutoff, include_cancelled)
+        .map_err(|e| AppError::from(e).to_string())?;
+
+    let deleted = service
+        .delete_completed_before(cutoff, include_cancelled)
+        .map_err(|e| AppError::from(e).to_string())?;
+
+    for id in &ids {
+        emit_task_changed(&app, id, ChangeKind::Deleted);
+    }
+
+    Ok(deleted)
+}
+
+/// Export all tasks to JSON
+#[tauri::command]
+pub async fn export_tasks_json(
+    db_state: State<'_, Arc<Mutex<Database>>>,
+    options: Option<ExportOptions>,
+) -> Result<String, AppError> {
+    let db = db_state
+        .lock()
+        .map_err(|_| AppError::DatabaseLock("Failed to acquire database lock".to_string()))?;
+
+    ExportService::new(&db)
+        .export_tasks(ExportFormat::Json, &options.unwrap_or_default())
+        .map_err(AppError::from)
+}
+
+/// Export all tasks to CSV
+#[tauri::command]
+pub async fn export_tasks_csv(
+    db_state: State<'_, Arc<Mutex<Database>>>,
+    options: Option<ExportOptions>,
+) -> Result<String, AppError> {
+    let db = db_state
+        .lock()
+        .map_err(|_| AppError::DatabaseLock("Failed to acquire database lock".to_string()))?;
+
+    ExportService::new(&db)
+        .export_tasks(ExportFormat::Csv, &options.unwrap_or_default())
+        .map_err(AppError::from)
+}
+
+/// Export all tasks straight to a file, returning the number of bytes written
+#[tauri::command]
+pub async fn export_tasks_to_file(
+    db_state: State<'_, Arc<Mutex<Database>>>,
+    path: String,
+    format: ExportFormat,
+    options: Option<ExportOptions>,
+) -> Result<u64, AppError> {
+    let db = db_state
+        .lock()
+        .map_err(|_| AppError::DatabaseLock("Failed to acquire database lock".to_string()))?;
+
+    ExportService::new(&db)
+        .export_tasks_to_file(
+            std::path::Path::new(&path),
+            format,
+            &options.unwrap_or_default(),
+        )
+        .map_err(AppError::from)
+}
+
+/// Import tasks from JSON, resolving id conflicts per `conflict_policy` (default skip)
+#[tauri::command]
+pub async fn import_tasks_json(
+    db_state: State<'_, Arc<Mutex<Database>>>,
+    json_data: String,
+    conflict_policy: Option<ImportConflictPolicy>,
+) -> Result<ImportReport, String> {
+    let db = db_state
+        .lock()
+        .map_err(|_| AppError::DatabaseLock("Failed to acquire database lock".to_string()))?;
+
+    ImportService::new(&db)
+        .import_tasks_json(json_data.as_bytes(), conflict_policy.unwrap_or_default())
+        .map_err(|e| AppError::from(e).to_string())
+}
+
+/// Import tasks from CSV, resolving id conflicts per `conflict_policy` (default skip)
+#[tauri::command]
+pub async fn import_tasks_csv(
+    db_state: State<'_, Arc<Mutex<Database>>>,
+    csv_data: String,
+    conflict_policy: Option<ImportConflictPolicy>,
+) -> Result<ImportReport, String> {
+    let db = db_state
+        .lock()
+        .map_err(|_| AppError::DatabaseLock("Failed to acquire database lock".to_string()))?;
+
+    ImportService::new(&db)
+        .import_tasks_csv(csv_data.as_bytes(), conflict_policy.unwrap_or_default())
+        .map_err(|e| AppError::from(e).to_string())
+}
+
+/// Import tasks from a `.json` or `.csv` file read directly from disk
+#[tauri::command]
+pub async fn import_tasks_from_file(
+    db_state: State<'_, Arc<Mutex<Database>>>,
+    path: String,
+    conflict_policy: Option<ImportConflictPolicy>,
+) -> Result<ImportReport, AppError> {
+    let db = db_state
+        .lock()
+        .map_err(|_| AppError::DatabaseLock("Failed to acquire database lock".to_string()))?;
+
+    ImportService::new(&db)
+        .import_tasks_from_file(
+            std::path::Path::new(&path),
+            conflict_policy.unwrap_or_default(),
+        )
+        .map_err(AppError::from)
+}
+
+/// Backup all data (tasks, reminders and tags) to versioned JSON
+#[tauri::command]
+pub async fn backup_data(db_state: State<'_, Arc<Mutex<Database>>>) -> Result<String, String> {
+    let db = db_state
+        .lock()
+        .map_err(|_| AppError::DatabaseLock("Failed to acquire database lock".to_string()))?;
+    let service = BackupService::new(&db);
+
+    let backup = service
+        .backup()
+        .map_err(|e| AppError::from(e).to_string())?;
+
+    serde_json::to_string_pretty(&backup).map_err(|e| format!("Failed to serialize backup: {}", e))
+}
+
+/// Restore data from backup JSON
+#[tauri::command]
+pub async fn restore_data(
+    db_state: State<'_, Arc<Mutex<Database>>>,
+    backup_data: String,
+) -> Result<(usize, usize), String> {
+    let db = db_state
+        .lock()
+        .map_err(|_| AppError::DatabaseLock("Failed to acquire database lock".to_string()))?;
+    let service = BackupService::new(&db);
+
+    service
+        .restore(&backup_data)
+        .map_err(|e| AppError::from(e).to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn filters(status: Option<&str>, include_completed: Option<bool>) -> TaskFilters {
+        TaskFilters {
+            status: status.map(str::to_string),
+            priority: None,
+            tag_id: None,
+            search: None,
+            include_completed,
+            include_deferred: None,
+        }
+    }
+
+    #[test]
+    fn test_build_task_filter_defaults_from_setting() {
+        assert_eq!(build_task_filter(None, true).include_completed, Some(true));
+        assert_eq!(
+            build_task_filter(None, false).include_completed,
+            Some(false)
+        );
+    }
+
+    #[test]
+    fn test_build_task_filter_explicit_include_completed_wins() {
+        let f = filters(None, Some(true));
+        assert_eq!(
+            build_task_filter(Some(&f), false).include_completed,
+            Some(true)
+        );
+    }
+
+    #[test]
+    fn test_build_task_filter_maps_priority_search_and_tag() {
+        let f = TaskFilters {
+            priority: Some("High".to_string()),
+            tag_id: Some("tag-1".to_string()),
+            search: Some("  report ".to_string()),
+            ..filters(None, None)
+        };
+        let filter = build_task_filter(Some(&f), true);
+
+        assert_eq!(filter.priority, Some(TaskPriority::High));
+        assert_eq!(filter.search.as_deref(), Some("report"));
+        assert_eq!(filter.tag_ids, Some(vec!["tag-1".to_string()]));
+    }
+
+    #[test]
+    fn test_build_task_filter_passes_explicit_status() {
+        let f = filters(Some("Completed"), None);
+        let filter = build_task_filter(Some(&f), false);
+
+        assert_eq!(filter.status, Some(TaskStatus::Completed));
+        assert_eq!(filter.include_completed, Some(false));
+    }
+
+    fn setup_test_db() -> Database {
+        let conn = rusqlite::Connection::open_in_memory().unwrap();
+        conn.execute_batch(
+            "CREATE TABLE tasks (
+                id TEXT PRIMARY KEY,
+                title TEXT NOT NULL,
+                description TEXT,
+                status TEXT NOT NULL,
+                priority TEXT NOT NULL,
+                due_date TEXT,
+                completed_at TEXT,
+                image_path TEXT,
+                notes TEXT,
+                estimated_minutes INTEGER,
+                actual_minutes INTEGER,
+                color TEXT,
+                is_pinned INTEGER NOT NULL DEFAULT 0,
+                series_id TEXT,
+                is_deferred INTEGER NOT NULL DEFAULT 0,
+                deferred_until TEXT,
+                completion_note TEXT,
+                parent_id TEXT,
+                sort_order INTEGER NOT NULL DEFAULT 0,
+                overdue_notified_at TEXT,
+                created_at TEXT NOT NULL,
+                updated_at TEXT NOT NULL
+            );
+            CREATE TABLE tags (
+                id TEXT PRIMARY KEY,
+                name TEXT NOT NULL,
+                color TEXT NOT NULL,
+                created_at TEXT NOT NULL
+            );
+            CREATE TABLE task_tags (
+                task_id TEXT NOT NULL,
+                tag_id TEXT NOT NULL,
+                PRIMARY KEY (task_id, tag_id)
+            );
+            CREATE TABLE task_checklist_items (
+                id TEXT PRIMARY KEY,
+                task_id TEXT NOT NULL,
+                text TEXT NOT NULL,
+                is_done INTEGER NOT NULL DEFAULT 0,
+                position INTEGER NOT NULL DEFAULT 0
+            );
+            CREATE TABLE settings (
+                key TEXT PRIMARY KEY,
+                value TEXT NOT NULL,
+                updated_at TEXT NOT NULL DEFAULT (datetime('now'))
+            );",
+        )
+        .unwrap();
+
+        let db = Database::new_from_connection(conn);
+        let repo = TaskRepository::new(&db);
+        for (title, due_in_days) in [("Banana", 3), ("Cherry", 1), ("Apple", 2)] {
+            repo.create(CreateTaskDto {
+                title: title.to_string(),
+                description: None,
+                priority: TaskPriority::Medium,
+                due_date: Some(Utc::now() + Duration::days(due_in_days)),
+                image_path: None,
+                notes: None,
+                estimated_minutes: None,
+                tag_ids: vec![],
+                parent_id: None,
+                color: None,
+            })
+            .unwrap();
+        }
+        db
+    }
+
+    /// Titles returned by `get_tasks` for a sort given as the frontend sends it
+    fn sorted_titles(db: &Database, sort: serde_json::Value) -> Vec<String> {
+        let sort: Option<Vec<TaskSort>> = serde_json::from_value(sort).unwrap();
+        list_tasks(db, None, None, sort)
+            .unwrap()
+            .tasks
+            .into_iter()
+            .map(|t| t.task.title)
+            .collect()
+    }
+
+    #[test]
+    fn test_get_tasks_sorts_by_due_date_asc() {
+        let db = setup_test_db();
+        let titles = sorted_titles(
+            &db,
+            serde_json::json!([{ "field": "due_date", "direction": "asc" }]),
+        );
+        assert_eq!(titles, vec!["Cherry", "Apple", "Banana"]);
+    }
+
+    #[test]
+    fn test_get_tasks_sorts_by_title_asc() {
+        let db = setup_test_db();
+        let titles = sorted_titles(
+            &db,
+            serde_json::json!([{ "field": "title", "direction": "asc" }]),
+        );
+        assert_eq!(titles, vec!["Apple", "Banana", "Cherry"]);
+    }
+
+    #[test]
+    fn test_get_tasks_total_counts_all_pages() {
+        let db = setup_test_db();
+        let page = list_tasks(
+            &db,
+            None,
+            Some(PaginationParams {
+                page: 1,
+                page_size: 2,
+            }),
+            None,
+        )
+        .unwrap();
+        assert_eq!(page.tasks.len(), 2);
+        assert_eq!(page.total, 3);
+    }
+
+    #[test]
+    fn test_build_task_sort_validation() {
+        let default = build_task_sort(None).unwrap();
+        assert_eq!(default.len(), 1);
+        assert_eq!(default[0].field.as_str(), "created_at");
+        assert_eq!(default[0].direction.as_str(), "DESC");
+        assert_eq!(build_task_sort(Some(vec![])).unwrap().len(), 1);
+
+        let duplicate = serde_json::from_value::<Vec<TaskSort>>(serde_json::json!([
+            { "field": "title", "direction": "asc" },
+            { "field": "title", "direction": "desc" }
+        ]))
+        .unwrap();
+        assert!(build_task_sort(Some(duplicate)).is_err());
+
+        let unknown = serde_json::from_value::<Vec<TaskSort>>(serde_json::json!([
+            { "field": "nonsense", "direction": "asc" }
+        ]));
+        assert!(unknown.is_err());
+    }
 }
@@ -0,0 +1,34 @@
+use super::events::{emit_reminder_changed, emit_task_changed};
+use crate::db::Database;
+use crate::error::AppError;
+use crate::models::{ChangeKind, UndoEntityType, UndoEntry};
+use crate::services::UndoService;
+use std::sync::{Arc, Mutex};
+use tauri::{AppHandle, State};
+
+/// Restore the most recently deleted task, reminder or tag
+///
+/// A restored task comes back with its subtasks, tags, reminders and other dependent rows.
+#[tauri::command]
+pub async fn undo_last_delete(
+    app: AppHandle,
+    db_state: State<'_, Arc<Mutex<Database>>>,
+) -> Result<UndoEntry, String> {
+    let db = db_state
+        .lock()
+        .map_err(|_| AppError::DatabaseLock("Failed to acquire database lock".to_string()))?;
+    let service = UndoService::new(&db);
+
+    let entry = service
+        .undo_last_delete()
+        .map_err(|e| AppError::from(e).to_string())?;
+
+    match entry.entity_type {
+        UndoEntityType::Task => emit_task_changed(&app, &entry.entity_id, ChangeKind::Created),
+        UndoEntityType::Reminder => {
+            emit_reminder_changed(&app, &entry.entity_id, ChangeKind::Created)
+        }
+        UndoEntityType::Tag => {}
+    }
+    Ok(entry)
+}
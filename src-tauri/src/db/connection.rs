@@ -1,3 +1,5 @@
+use crate::models::search::fold_for_search;
+use rusqlite::functions::FunctionFlags;
 use rusqlite::{Connection, Result};
 use std::path::PathBuf;
 use tauri::{AppHandle, Manager};
@@ -21,6 +23,7 @@ impl Database {
 
         // Enable foreign keys
         conn.execute_batch("PRAGMA foreign_keys = ON;")?;
+        register_functions(&conn)?;
 
         let db = Database { conn };
 
@@ -33,14 +36,113 @@ impl Database {
     /// Create database from existing connection (for testing)
     #[cfg(test)]
     pub fn new_from_connection(conn: Connection) -> Self {
+        register_functions(&conn).expect("Failed to register SQL functions");
         Database { conn }
     }
 
+    /// In-memory database with the real schema and all migrations applied (for testing)
+    #[cfg(test)]
+    pub fn open_in_memory() -> Result<Self> {
+        let conn = Connection::open_in_memory()?;
+        conn.execute_batch("PRAGMA foreign_keys = ON;")?;
+        let db = Self::new_from_connection(conn);
+        db.run_migrations()?;
+        Ok(db)
+    }
+
     /// Get the database connection
     pub fn connection(&self) -> &Connection {
         &self.conn
     }
 
+    /// Run `f` inside a transaction, committing on `Ok` and rolling back on `Err`
+    ///
+    /// A call made while another transaction is open runs in a savepoint of it, so
+    /// services can group repository calls that use their own transactions.
+    pub fn transaction<F, T>(&self, f: F) -> Result<T>
+    where
+        F: FnOnce(&Connection) -> Result<T>,
+    {
+        self.try_transaction(f)
+    }
+
+    /// `transaction` for closures failing with another error type, e.g. `DomainError`
+    pub fn try_transaction<F, T, E>(&self, f: F) -> std::result::Result<T, E>
+    where
+        F: FnOnce(&Connection) -> std::result::Result<T, E>,
+        E: From<rusqlite::Error>,
+    {
+        if !self.conn.is_autocommit() {
+            self.conn.execute_batch("SAVEPOINT nested_transaction")?;
+            return match f(&self.conn) {
+                Ok(value) => {
+                    self.conn.execute_batch("RELEASE nested_transaction")?;
+                    Ok(value)
+                }
+                Err(e) => {
+                    self.conn.execute_batch(
+                        "ROLLBACK TO nested_transaction; RELEASE nested_transaction",
+                    )?;
+                    Err(e)
+                }
+            };
+        }
+
+        let tx = self.conn.unchecked_transaction()?;
+        let value = f(&tx)?;
+        tx.commit()?;
+        Ok(value)
+    }
+
+    /// Run `PRAGMA integrity_check`, returning "ok" or the reported problems joined by newlines
+    pub fn integrity_check(&self) -> Result<String> {
+        let mut stmt = self.conn.prepare("PRAGMA integrity_check")?;
+        let messages = stmt
+            .query_map([], |row| row.get::<_, String>(0))?
+            .collect::<Result<Vec<String>>>()?;
+        Ok(messages.join("\n"))
+    }
+
+    /// Highest applied migration version, or 0 when none have been recorded
+    pub fn schema_version(&self) -> Result<i32> {
+        self.conn.query_row(
+            "SELECT COALESCE(MAX(version), 0) FROM schema_version",
+            [],
+            |row| row.get(0),
+        )
+    }
+
+    /// Current journal mode as reported by SQLite (e.g. "wal", "delete", "memory")
+    pub fn journal_mode(&self) -> Result<String> {
+        self.conn
+            .query_row("PRAGMA journal_mode", [], |row| row.get(0))
+    }
+
+    /// Size of the database file in bytes; `None` for in-memory databases
+    pub fn file_size(&self) -> Option<u64> {
+        self.conn
+            .path()
+            .filter(|path| !path.is_empty())
+            .and_then(|path| std::fs::metadata(path).ok())
+            .map(|metadata| metadata.len())
+    }
+
+    /// Reclaim unused space, returning the file size before and after
+    ///
+    /// Checkpoints and truncates the WAL first when WAL mode is on, so the main
+    /// file holds every page before it is rebuilt by `VACUUM`.
+    pub fn compact(&self) -> Result<(Option<u64>, Option<u64>)> {
+        let size_before = self.file_size();
+
+        if self.journal_mode()?.eq_ignore_ascii_case("wal") {
+            self.conn
+                .query_row("PRAGMA wal_checkpoint(TRUNCATE)", [], |_| Ok(()))?;
+        }
+        self.conn.execute_batch("VACUUM;")?;
+
+        Ok((size_before, self.file_size()))
+    }
+
     /// Run all database migrations
     fn run_migrations(&self) -> Result<()> {
         // Read and execute schema.sql
@@ -57,19 +159,51 @@ impl Database {
         )?;
 
         // Check current version
-        let current_version: i32 = self
-            .conn
-            .query_row(
-                "SELECT COALESCE(MAX(version), 0) FROM schema_version",
-                [],
-                |row| row.get(0),
-            )
-            .unwrap_or(0);
+        let current_version = self.schema_version().unwrap_or(0);
 
         // Apply migrations if needed
         if current_version < 1 {
             self.apply_migration_v1()?;
         }
+        if current_version < 2 {
+            self.apply_migration_v2()?;
+        }
+        if current_version < 3 {
+            self.apply_migration_v3()?;
+        }
+        if current_version < 4 {
+            self.apply_migration_v4()?;
+        }
+        if current_version < 5 {
+            self.apply_migration_v5()?;
+        }
+        if current_version < 6 {
+            self.apply_migration_v6()?;
+        }
+        if current_version < 7 {
+            self.apply_migration_v7()?;
+        }
+        if current_version < 8 {
+            self.apply_migration_v8()?;
+        }
+        if current_version < 9 {
+            self.apply_migration_v9()?;
+        }
+        if current_version < 10 {
+            self.apply_migration_v10()?;
+        }
+        if current_version < 11 {
+            self.apply_migration_v11()?;
+        }
+        if current_version < 12 {
+            self.apply_migration_v12()?;
+        }
+        if current_version < 13 {
+            self.apply_migration_v13()?;
+        }
+        if current_version < 14 {
+            self.apply_migration_v14()?;
+        }
 
         Ok(())
     }
@@ -84,6 +218,201 @@ impl Database {
         println!("Applied migration v1: Initial schema");
         Ok(())
     }
+
+    /// Migration version 2: Subtasks (tasks.parent_id)
+    fn apply_migration_v2(&self) -> Result<()> {
+        self.conn.execute_batch(
+            "ALTER TABLE tasks ADD COLUMN parent_id TEXT REFERENCES tasks(id) ON DELETE CASCADE;
+             CREATE INDEX IF NOT EXISTS idx_tasks_parent_id ON tasks(parent_id);",
+        )?;
+        self.conn
+            .execute("INSERT INTO schema_version (version) VALUES (?1)", [2])?;
+
+        println!("Applied migration v2: Subtasks");
+        Ok(())
+    }
+
+    /// Migration version 3: Manual task ordering (tasks.sort_order)
+    fn apply_migration_v3(&self) -> Result<()> {
+        // Existing tasks are spaced out by creation order so they can be reordered
+        self.conn.execute_batch(
+            "ALTER TABLE tasks ADD COLUMN sort_order INTEGER NOT NULL DEFAULT 0;
+             UPDATE tasks SET sort_order = 1024 * (
+                 SELECT COUNT(*) FROM tasks t2 WHERE t2.created_at <= tasks.created_at
+             );
+             CREATE INDEX IF NOT EXISTS idx_tasks_sort_order ON tasks(sort_order);",
+        )?;
+        self.conn
+            .execute("INSERT INTO schema_version (version) VALUES (?1)", [3])?;
+
+        println!("Applied migration v3: Task sort order");
+        Ok(())
+    }
+
+    /// Migration version 4: One-time overdue notifications (tasks.overdue_notified_at)
+    fn apply_migration_v4(&self) -> Result<()> {
+        self.conn
+            .execute_batch("ALTER TABLE tasks ADD COLUMN overdue_notified_at TEXT;")?;
+        self.conn
+            .execute("INSERT INTO schema_version (version) VALUES (?1)", [4])?;
+
+        println!("Applied migration v4: Overdue notifications");
+        Ok(())
+    }
+
+    /// Migration version 5: Reminder acknowledgment (reminders.acknowledged_at)
+    fn apply_migration_v5(&self) -> Result<()> {
+        // Reminders that already fired are treated as seen so they keep firing
+        self.conn.execute_batch(
+            "ALTER TABLE reminders ADD COLUMN acknowledged_at TEXT;
+             UPDATE reminders SET acknowledged_at = last_triggered_at
+             WHERE last_triggered_at IS NOT NULL;",
+        )?;
+        self.conn
+            .execute("INSERT INTO schema_version (version) VALUES (?1)", [5])?;
+
+        println!("Applied migration v5: Reminder acknowledgment");
+        Ok(())
+    }
+
+    /// Migration version 6: Reminder escalation (reminders.escalation_minutes/escalation_count)
+    fn apply_migration_v6(&self) -> Result<()> {
+        self.conn.execute_batch(
+            "ALTER TABLE reminders ADD COLUMN escalation_minutes INTEGER;
+             ALTER TABLE reminders ADD COLUMN escalation_count INTEGER NOT NULL DEFAULT 0;",
+        )?;
+        self.conn
+            .execute("INSERT INTO schema_version (version) VALUES (?1)", [6])?;
+
+        println!("Applied migration v6: Reminder escalation");
+        Ok(())
+    }
+
+    /// Migration version 7: Task accent color (tasks.color)
+    fn apply_migration_v7(&self) -> Result<()> {
+        self.conn
+            .execute_batch("ALTER TABLE tasks ADD COLUMN color TEXT;")?;
+        self.conn
+            .execute("INSERT INTO schema_version (version) VALUES (?1)", [7])?;
+
+        println!("Applied migration v7: Task color");
+        Ok(())
+    }
+
+    /// Migration version 8: Pinned tasks (tasks.is_pinned)
+    fn apply_migration_v8(&self) -> Result<()> {
+        self.conn
+            .execute_batch("ALTER TABLE tasks ADD COLUMN is_pinned INTEGER NOT NULL DEFAULT 0;")?;
+        self.conn
+            .execute("INSERT INTO schema_version (version) VALUES (?1)", [8])?;
+
+        println!("Applied migration v8: Pinned tasks");
+        Ok(())
+    }
+
+    /// Migration version 9: Recurring task series (tasks.series_id)
+    fn apply_migration_v9(&self) -> Result<()> {
+        self.conn.execute_batch(
+            "ALTER TABLE tasks ADD COLUMN series_id TEXT;
+             CREATE INDEX IF NOT EXISTS idx_tasks_series_id ON tasks(series_id);",
+        )?;
+        self.conn
+            .execute("INSERT INTO schema_version (version) VALUES (?1)", [9])?;
+
+        println!("Applied migration v9: Task series");
+        Ok(())
+    }
+
+    /// Migration version 10: Deferred tasks (tasks.is_deferred/deferred_until)
+    fn apply_migration_v10(&self) -> Result<()> {
+        self.conn.execute_batch(
+            "ALTER TABLE tasks ADD COLUMN is_deferred INTEGER NOT NULL DEFAULT 0;
+             ALTER TABLE tasks ADD COLUMN deferred_until TEXT;",
+        )?;
+        self.conn
+            .execute("INSERT INTO schema_version (version) VALUES (?1)", [10])?;
+
+        println!("Applied migration v10: Deferred tasks");
+        Ok(())
+    }
+
+    /// Migration version 11: In-app reminder delivery (reminders.delivery)
+    fn apply_migration_v11(&self) -> Result<()> {
+        self.conn.execute(
+            "ALTER TABLE reminders ADD COLUMN delivery TEXT NOT NULL DEFAULT 'notification'",
+            [],
+        )?;
+        self.conn
+            .execute("INSERT INTO schema_version (version) VALUES (?1)", [11])?;
+
+        println!("Applied migration v11: In-app reminder delivery");
+        Ok(())
+    }
+
+    /// Migration version 12: Completion notes (tasks.completion_note)
+    fn apply_migration_v12(&self) -> Result<()> {
+        self.conn
+            .execute("ALTER TABLE tasks ADD COLUMN completion_note TEXT", [])?;
+        self.conn
+            .execute("INSERT INTO schema_version (version) VALUES (?1)", [12])?;
+
+        println!("Applied migration v12: Completion notes");
+        Ok(())
+    }
+
+    /// Migration version 13: RFC 3339 `updated_at` for tasks and reminders
+    ///
+    /// The timestamp triggers overwrote the repositories' values with `datetime('now')`,
+    /// which `map_row_to_*` can't parse. Existing values of that form are converted.
+    fn apply_migration_v13(&self) -> Result<()> {
+        self.conn.execute_batch(
+            "DROP TRIGGER IF EXISTS update_tasks_timestamp;
+             DROP TRIGGER IF EXISTS update_reminders_timestamp;
+             UPDATE tasks SET updated_at = replace(updated_at, ' ', 'T') || '+00:00'
+             WHERE updated_at GLOB '[0-9][0-9][0-9][0-9]-[0-9][0-9]-[0-9][0-9] *';
+             UPDATE reminders SET updated_at = replace(updated_at, ' ', 'T') || '+00:00'
+             WHERE updated_at GLOB '[0-9][0-9][0-9][0-9]-[0-9][0-9]-[0-9][0-9] *';",
+        )?;
+        self.conn
+            .execute("INSERT INTO schema_version (version) VALUES (?1)", [13])?;
+
+        println!("Applied migration v13: RFC 3339 updated_at");
+        Ok(())
+    }
+
+    /// Migration version 14: RFC 3339 `completed_at`
+    ///
+    /// The `set_completed_at` trigger replaced the repository's value with
+    /// `datetime('now')`, read back as no completion time. Existing values are converted.
+    fn apply_migration_v14(&self) -> Result<()> {
+        self.conn.execute_batch(
+            "DROP TRIGGER IF EXISTS set_completed_at;
+             DROP TRIGGER IF EXISTS clear_completed_at;
+             UPDATE tasks SET completed_at = replace(completed_at, ' ', 'T') || '+00:00'
+             WHERE completed_at GLOB '[0-9][0-9][0-9][0-9]-[0-9][0-9]-[0-9][0-9] *';",
+        )?;
+        self.conn
+            .execute("INSERT INTO schema_version (version) VALUES (?1)", [14])?;
+
+        println!("Applied migration v14: RFC 3339 completed_at");
+        Ok(())
+    }
+}
+
+/// Register the application's SQL functions on `conn`
+///
+/// - `fold(text)`: case- and accent-folded text for search, see `fold_for_search`
+fn register_functions(conn: &Connection) -> Result<()> {
+    conn.create_scalar_function(
+        "fold",
+        1,
+        FunctionFlags::SQLITE_UTF8 | FunctionFlags::SQLITE_DETERMINISTIC,
+        |ctx| {
+            Ok(ctx
+                .get::<Option<String>>(0)?
+                .map(|text| fold_for_search(&text)))
+        },
+    )
 }
 
 /// Get the database file path based on the platform
@@ -105,4 +434,231 @@ mod tests {
         // This test would require a mock AppHandle
         // For now, it's a placeholder for future testing
     }
+
+    fn setup_test_db() -> Database {
+        let conn = Connection::open_in_memory().unwrap();
+        conn.execute("CREATE TABLE items (name TEXT NOT NULL)", [])
+            .unwrap();
+        Database::new_from_connection(conn)
+    }
+
+    fn count_items(db: &Database) -> i64 {
+        db.connection()
+            .query_row("SELECT COUNT(*) FROM items", [], |row| row.get(0))
+            .unwrap()
+    }
+
+    #[test]
+    fn test_transaction_commits_on_ok() {
+        let db = setup_test_db();
+
+        let value = db
+            .transaction(|conn| {
+                conn.execute("INSERT INTO items (name) VALUES ('a')", [])?;
+                conn.execute("INSERT INTO items (name) VALUES ('b')", [])?;
+                Ok(42)
+            })
+            .unwrap();
+
+        assert_eq!(value, 42);
+        assert_eq!(count_items(&db), 2);
+    }
+
+    #[test]
+    fn test_transaction_rolls_back_on_err() {
+        let db = setup_test_db();
+
+        let result: Result<()> = db.transaction(|conn| {
+            conn.execute("INSERT INTO items (name) VALUES ('a')", [])?;
+            conn.execute("INSERT INTO items (name) VALUES (NULL)", [])?;
+            Ok(())
+        });
+
+        assert!(result.is_err());
+        assert_eq!(count_items(&db), 0);
+    }
+
+    #[test]
+    fn test_nested_transaction_rolls_back_with_outer() {
+        let db = setup_test_db();
+
+        let result: Result<()> = db.transaction(|conn| {
+            conn.execute("INSERT INTO items (name) VALUES ('outer')", [])?;
+            db.transaction(|conn| conn.execute("INSERT INTO items (name) VALUES ('inner')", []))?;
+            conn.execute("INSERT INTO items (name) VALUES (NULL)", [])?;
+            Ok(())
+        });
+
+        assert!(result.is_err());
+        assert_eq!(count_items(&db), 0);
+    }
+
+    #[test]
+    fn test_failed_nested_transaction_keeps_outer_work() {
+        let db = setup_test_db();
+
+        db.transaction(|conn| {
+            conn.execute("INSERT INTO items (name) VALUES ('outer')", [])?;
+            let inner: Result<()> = db.transaction(|conn| {
+                conn.execute("INSERT INTO items (name) VALUES ('inner')", [])?;
+                conn.execute("INSERT INTO items (name) VALUES (NULL)", [])?;
+                Ok(())
+            });
+            assert!(inner.is_err());
+            Ok(())
+        })
+        .unwrap();
+
+        assert_eq!(count_items(&db), 1);
+    }
+
+    #[test]
+    fn test_compact_shrinks_file_after_deletes() {
+        let path = std::env::temp_dir().join(format!("compact-{}.db", uuid::Uuid::new_v4()));
+        let db = Database::new_from_connection(Connection::open(&path).unwrap());
+        db.connection()
+            .execute("CREATE TABLE items (name TEXT NOT NULL)", [])
+            .unwrap();
+
+        db.transaction(|conn| {
+            for i in 0..2000 {
+                conn.execute(
+                    "INSERT INTO items (name) VALUES (?1)",
+                    [format!("{:0>200}", i)],
+                )?;
+            }
+            Ok(())
+        })
+        .unwrap();
+        db.connection().execute("DELETE FROM items", []).unwrap();
+
+        let (before, after) = db.compact().unwrap();
+        let _ = std::fs::remove_file(&path);
+
+        assert!(after.unwrap() < before.unwrap());
+    }
+
+    #[test]
+    fn test_updated_at_written_by_repositories_is_kept() {
+        use crate::models::{CreateReminderDto, CreateTaskDto, RepeatInterval, TaskPriority};
+        use crate::repositories::{ReminderRepository, TaskRepository};
+        use chrono::{Duration, Utc};
+
+        let db = Database::open_in_memory().unwrap();
+        let task = TaskRepository::new(&db)
+            .create(CreateTaskDto {
+                title: "Write report".to_string(),
+                description: None,
+                priority: TaskPriority::Medium,
+                due_date: None,
+                image_path: None,
+                notes: None,
+                estimated_minutes: None,
+                tag_ids: vec![],
+                parent_id: None,
+                color: None,
+            })
+            .unwrap();
+        let reminder = ReminderRepository::new(&db)
+            .create(CreateReminderDto {
+                task_id: Some(task.id.clone()),
+                title: "Write report".to_string(),
+                description: None,
+                remind_at: Utc::now() + Duration::hours(1),
+                repeat_interval: RepeatInterval::none(),
+                escalation_minutes: None,
+                delivery: None,
+            })
+            .unwrap();
+
+        let stamp = (Utc::now() - Duration::days(3)).to_rfc3339();
+        db.connection()
+            .execute(
+                "UPDATE tasks SET title = 'Edited', updated_at = ?1 WHERE id = ?2",
+                [&stamp, &task.id],
+            )
+            .unwrap();
+        db.connection()
+            .execute(
+                "UPDATE reminders SET title = 'Edited', updated_at = ?1 WHERE id = ?2",
+                [&stamp, &reminder.id],
+            )
+            .unwrap();
+
+        let task = TaskRepository::new(&db)
+            .find_by_id(&task.id)
+            .unwrap()
+            .unwrap();
+        let reminder = ReminderRepository::new(&db)
+            .find_by_id(&reminder.id)
+            .unwrap()
+            .unwrap();
+        assert_eq!(task.updated_at.to_rfc3339(), stamp);
+        assert_eq!(reminder.updated_at.to_rfc3339(), stamp);
+    }
+
+    #[test]
+    fn test_completed_at_survives_real_schema() {
+        use crate::models::{CreateTaskDto, TaskPriority, TaskStatus, UpdateTaskDto};
+        use crate::repositories::TaskRepository;
+
+        let db = Database::open_in_memory().unwrap();
+        let repo = TaskRepository::new(&db);
+        let task = repo
+            .create(CreateTaskDto {
+                title: "Ship release".to_string(),
+                description: None,
+                priority: TaskPriority::High,
+                due_date: None,
+                image_path: None,
+                notes: None,
+                estimated_minutes: None,
+                tag_ids: vec![],
+                parent_id: None,
+                color: None,
+            })
+            .unwrap();
+
+        let status_update = |status| UpdateTaskDto {
+            title: None,
+            description: None,
+            status: Some(status),
+            priority: None,
+            due_date: None,
+            image_path: None,
+            notes: None,
+            estimated_minutes: None,
+            actual_minutes: None,
+            tag_ids: None,
+            color: None,
+            clear_fields: vec![],
+        };
+
+        let completed = repo
+            .update(&task.id, status_update(TaskStatus::Completed))
+            .unwrap();
+        assert!(completed.completed_at.is_some());
+        assert!(completed.validate().is_ok());
+        let stored: String = db
+            .connection()
+            .query_row(
+                "SELECT completed_at FROM tasks WHERE id = ?1",
+                [&task.id],
+                |row| row.get(0),
+            )
+            .unwrap();
+        assert!(chrono::DateTime::parse_from_rfc3339(&stored).is_ok());
+
+        let reopened = repo
+            .update(&task.id, status_update(TaskStatus::Pending))
+            .unwrap();
+        assert!(reopened.completed_at.is_none());
+    }
+
+    #[test]
+    fn test_integrity_check_ok() {
+        let db = setup_test_db();
+        assert_eq!(db.integrity_check().unwrap(), "ok");
+        assert_eq!(db.file_size(), None);
+    }
 }
@@ -59,6 +59,112 @@ CREATE TABLE IF NOT EXISTS task_tags (
     FOREIGN KEY (tag_id) REFERENCES tags(id) ON DELETE CASCADE
 );
 
+-- ============================================================================
+-- TASK_DEPENDENCIES TABLE (task_id is blocked by depends_on_id)
+-- ============================================================================
+CREATE TABLE IF NOT EXISTS task_dependencies (
+    task_id TEXT NOT NULL,
+    depends_on_id TEXT NOT NULL,
+    created_at TEXT NOT NULL DEFAULT (datetime('now')),
+    PRIMARY KEY (task_id, depends_on_id),
+    FOREIGN KEY (task_id) REFERENCES tasks(id) ON DELETE CASCADE,
+    FOREIGN KEY (depends_on_id) REFERENCES tasks(id) ON DELETE CASCADE,
+    CHECK (task_id != depends_on_id)
+);
+
+-- ============================================================================
+-- TASK_ATTACHMENTS TABLE
+-- ============================================================================
+CREATE TABLE IF NOT EXISTS task_attachments (
+    id TEXT PRIMARY KEY NOT NULL,
+    task_id TEXT NOT NULL,
+    file_path TEXT NOT NULL,
+    mime_type TEXT,
+    created_at TEXT NOT NULL DEFAULT (datetime('now')),
+    FOREIGN KEY (task_id) REFERENCES tasks(id) ON DELETE CASCADE
+);
+
+-- ============================================================================
+-- TASK_CHECKLIST_ITEMS TABLE
+-- ============================================================================
+CREATE TABLE IF NOT EXISTS task_checklist_items (
+    id TEXT PRIMARY KEY NOT NULL,
+    task_id TEXT NOT NULL,
+    text TEXT NOT NULL,
+    is_done INTEGER NOT NULL DEFAULT 0 CHECK (is_done IN (0, 1)),
+    position INTEGER NOT NULL DEFAULT 0,
+    FOREIGN KEY (task_id) REFERENCES tasks(id) ON DELETE CASCADE
+);
+
+-- ============================================================================
+-- TASK_COMMENTS TABLE
+-- ============================================================================
+CREATE TABLE IF NOT EXISTS task_comments (
+    id TEXT PRIMARY KEY NOT NULL,
+    task_id TEXT NOT NULL,
+    body TEXT NOT NULL,
+    created_at TEXT NOT NULL DEFAULT (datetime('now')),
+    FOREIGN KEY (task_id) REFERENCES tasks(id) ON DELETE CASCADE
+);
+
+-- ============================================================================
+-- TASK_STATUS_HISTORY TABLE
+-- ============================================================================
+CREATE TABLE IF NOT EXISTS task_status_history (
+    id TEXT PRIMARY KEY NOT NULL,
+    task_id TEXT NOT NULL,
+    from_status TEXT NOT NULL,
+    to_status TEXT NOT NULL,
+    changed_at TEXT NOT NULL DEFAULT (datetime('now')),
+    FOREIGN KEY (task_id) REFERENCES tasks(id) ON DELETE CASCADE
+);
+
+-- ============================================================================
+-- TASK_FOCUS_SESSIONS TABLE
+-- ============================================================================
+-- Work/break sessions on a task; at most one per task has ended_at NULL
+CREATE TABLE IF NOT EXISTS task_focus_sessions (
+    id TEXT PRIMARY KEY NOT NULL,
+    task_id TEXT NOT NULL,
+    work_minutes INTEGER NOT NULL,
+    break_minutes INTEGER NOT NULL,
+    break_reminder_id TEXT,
+    work_reminder_id TEXT,
+    started_at TEXT NOT NULL,
+    ended_at TEXT,
+    logged_minutes INTEGER,
+    FOREIGN KEY (task_id) REFERENCES tasks(id) ON DELETE CASCADE,
+    FOREIGN KEY (break_reminder_id) REFERENCES reminders(id) ON DELETE SET NULL,
+    FOREIGN KEY (work_reminder_id) REFERENCES reminders(id) ON DELETE SET NULL
+);
+
+-- ============================================================================
+-- REMINDER_TRIGGER_LOG TABLE
+-- ============================================================================
+-- One row per fired reminder slot, so a slot is never delivered twice
+CREATE TABLE IF NOT EXISTS reminder_trigger_log (
+    reminder_id TEXT NOT NULL,
+    scheduled_at TEXT NOT NULL,
+    fired_at TEXT NOT NULL,
+    PRIMARY KEY (reminder_id, scheduled_at),
+    FOREIGN KEY (reminder_id) REFERENCES reminders(id) ON DELETE CASCADE
+);
+
+-- ============================================================================
+-- REMINDER_TEMPLATES TABLE
+-- ============================================================================
+-- Presets for quickly creating reminders relative to the current time
+CREATE TABLE IF NOT EXISTS reminder_templates (
+    id TEXT PRIMARY KEY NOT NULL,
+    name TEXT NOT NULL,
+    title TEXT NOT NULL,
+    description TEXT,
+    repeat_interval TEXT NOT NULL DEFAULT 'none',
+    default_offset_minutes INTEGER NOT NULL,
+    created_at TEXT NOT NULL DEFAULT (datetime('now')),
+    updated_at TEXT NOT NULL DEFAULT (datetime('now'))
+);
+
 -- ============================================================================
 -- SETTINGS TABLE
 -- ============================================================================
@@ -68,6 +174,18 @@ CREATE TABLE IF NOT EXISTS settings (
     updated_at TEXT NOT NULL DEFAULT (datetime('now'))
 );
 
+-- ============================================================================
+-- UNDO_LOG TABLE
+-- ============================================================================
+-- JSON snapshots of recently deleted tasks, reminders and tags, newest last
+CREATE TABLE IF NOT EXISTS undo_log (
+    id INTEGER PRIMARY KEY AUTOINCREMENT,
+    entity_type TEXT NOT NULL,
+    entity_id TEXT NOT NULL,
+    snapshot TEXT NOT NULL,
+    deleted_at TEXT NOT NULL
+);
+
 -- ============================================================================
 -- INDEXES FOR PERFORMANCE
 -- ============================================================================
@@ -77,6 +195,8 @@ CREATE INDEX IF NOT EXISTS idx_tasks_status ON tasks(status);
 CREATE INDEX IF NOT EXISTS idx_tasks_priority ON tasks(priority);
 CREATE INDEX IF NOT EXISTS idx_tasks_due_date ON tasks(due_date);
 CREATE INDEX IF NOT EXISTS idx_tasks_created_at ON tasks(created_at);
+-- updated_at is compared via julianday() so differing offsets and fractions order by instant
+CREATE INDEX IF NOT EXISTS idx_tasks_updated_at ON tasks(julianday(updated_at));
 CREATE INDEX IF NOT EXISTS idx_tasks_status_priority ON tasks(status, priority);
 CREATE INDEX IF NOT EXISTS idx_tasks_status_due_date ON tasks(status, due_date);
 
@@ -92,23 +212,29 @@ CREATE INDEX IF NOT EXISTS idx_tags_name ON tags(name);
 -- Task tags indexes
 CREATE INDEX IF NOT EXISTS idx_task_tags_tag_id ON task_tags(tag_id);
 
+-- Task attachments indexes
+CREATE INDEX IF NOT EXISTS idx_task_attachments_task_id ON task_attachments(task_id);
+
+-- Task checklist indexes
+CREATE INDEX IF NOT EXISTS idx_task_checklist_items_task_id ON task_checklist_items(task_id, position);
+
+-- Task comments indexes
+CREATE INDEX IF NOT EXISTS idx_task_comments_task_id ON task_comments(task_id, created_at);
+
+-- Task status history indexes
+CREATE INDEX IF NOT EXISTS idx_task_status_history_task_id ON task_status_history(task_id, changed_at);
+
+-- Task focus session indexes
+CREATE INDEX IF NOT EXISTS idx_task_focus_sessions_task_id ON task_focus_sessions(task_id, ended_at);
+
+-- Task dependencies indexes
+CREATE INDEX IF NOT EXISTS idx_task_dependencies_depends_on_id ON task_dependencies(depends_on_id);
+
 -- ============================================================================
 -- TRIGGERS FOR AUTOMATIC TIMESTAMP UPDATES
 -- ============================================================================
 
--- Update tasks.updated_at on any update
-CREATE TRIGGER IF NOT EXISTS update_tasks_timestamp 
-AFTER UPDATE ON tasks
-BEGIN
-    UPDATE tasks SET updated_at = datetime('now') WHERE id = NEW.id;
-END;
-
--- Update reminders.updated_at on any update
-CREATE TRIGGER IF NOT EXISTS update_reminders_timestamp 
-AFTER UPDATE ON reminders
-BEGIN
-    UPDATE reminders SET updated_at = datetime('now') WHERE id = NEW.id;
-END;
+-- tasks.updated_at and reminders.updated_at are written by the repositories as RFC 3339
 
 -- Update settings.updated_at on any update
 CREATE TRIGGER IF NOT EXISTS update_settings_timestamp 
@@ -117,21 +243,7 @@ BEGIN
     UPDATE settings SET updated_at = datetime('now') WHERE key = NEW.key;
 END;
 
--- Auto-set completed_at when task status changes to completed
-CREATE TRIGGER IF NOT EXISTS set_completed_at 
-AFTER UPDATE OF status ON tasks
-WHEN NEW.status = 'completed' AND OLD.status != 'completed'
-BEGIN
-    UPDATE tasks SET completed_at = datetime('now') WHERE id = NEW.id;
-END;
-
--- Clear completed_at when task status changes from completed
-CREATE TRIGGER IF NOT EXISTS clear_completed_at 
-AFTER UPDATE OF status ON tasks
-WHEN NEW.status != 'completed' AND OLD.status = 'completed'
-BEGIN
-    UPDATE tasks SET completed_at = NULL WHERE id = NEW.id;
-END;
+-- tasks.completed_at is set and cleared by TaskRepository::update along with the status
 
 -- ============================================================================
 -- INITIAL DATA (Default settings)
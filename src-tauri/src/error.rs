@@ -1,3 +1,4 @@
+use serde::ser::{SerializeStruct, Serializer};
 use serde::Serialize;
 use thiserror::Error;
 
@@ -8,6 +9,10 @@ pub enum DomainError {
     #[error("Task validation failed: {0}")]
     ValidationError(String),
 
+    /// Validation failed for a specific input field
+    #[error("Invalid {field}: {message}")]
+    FieldValidation { field: String, message: String },
+
     /// Invalid status transition
     #[error("Invalid status transition from {from} to {to}: {reason}")]
     InvalidStatusTransition {
@@ -20,6 +25,18 @@ pub enum DomainError {
     #[error("Task with id '{0}' not found")]
     TaskNotFound(String),
 
+    /// Reminder not found
+    #[error("Reminder with id '{0}' not found")]
+    ReminderNotFound(String),
+
+    /// Reminder template not found
+    #[error("Reminder template with id '{0}' not found")]
+    ReminderTemplateNotFound(String),
+
+    /// Tag not found
+    #[error("Tag with id '{0}' not found")]
+    TagNotFound(String),
+
     /// Task cannot be modified (terminal state)
     #[error("Task cannot be modified: {0}")]
     TaskNotModifiable(String),
@@ -37,18 +54,40 @@ pub enum DomainError {
     InvalidInput(String),
 }
 
+impl DomainError {
+    /// Build a `FieldValidation` error for `field`
+    pub fn field(field: &str, message: impl Into<String>) -> Self {
+        DomainError::FieldValidation {
+            field: field.to_string(),
+            message: message.into(),
+        }
+    }
+}
+
+/// Convert rusqlite::Error to DomainError, e.g. when a service transaction fails to commit
+impl From<rusqlite::Error> for DomainError {
+    fn from(err: rusqlite::Error) -> Self {
+        DomainError::BusinessRuleViolation(format!("Database error: {}", err))
+    }
+}
+
 /// Result type for domain operations
 pub type DomainResult<T> = Result<T, DomainError>;
 
 /// Application-level errors for Tauri commands
 /// This wraps domain errors and adds infrastructure-level errors
-#[derive(Error, Debug, Serialize)]
-#[serde(tag = "type", content = "message")]
+///
+/// Serializes as `{ type, message }`, plus `field` for field validation errors
+#[derive(Error, Debug)]
 pub enum AppError {
     /// Domain/business logic error
     #[error("{0}")]
     Domain(String),
 
+    /// Invalid value for a specific input field
+    #[error("Invalid {field}: {message}")]
+    FieldValidation { field: String, message: String },
+
     /// Database connection/lock error
     #[error("Database error: {0}")]
     DatabaseLock(String),
@@ -71,11 +110,42 @@ impl From<DomainError> for AppError {
     fn from(err: DomainError) -> Self {
         match err {
             DomainError::TaskNotFound(msg) => AppError::NotFound(msg),
+            DomainError::FieldValidation { field, message } => {
+                AppError::FieldValidation { field, message }
+            }
+            DomainError::ReminderNotFound(id) => AppError::NotFound(format!("Reminder '{}'", id)),
+            DomainError::ReminderTemplateNotFound(id) => {
+                AppError::NotFound(format!("Reminder template '{}'", id))
+            }
+            DomainError::TagNotFound(id) => AppError::NotFound(format!("Tag '{}'", id)),
             other => AppError::Domain(other.to_string()),
         }
     }
 }
 
+impl Serialize for AppError {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let (kind, field, message) = match self {
+            AppError::Domain(message) => ("Domain", None, message),
+            AppError::FieldValidation { field, message } => {
+                ("FieldValidation", Some(field), message)
+            }
+            AppError::DatabaseLock(message) => ("DatabaseLock", None, message),
+            AppError::DatabaseOperation(message) => ("DatabaseOperation", None, message),
+            AppError::NotFound(message) => ("NotFound", None, message),
+            AppError::Internal(message) => ("Internal", None, message),
+        };
+
+        let mut state = serializer.serialize_struct("AppError", 2 + field.is_some() as usize)?;
+        state.serialize_field("type", kind)?;
+        if let Some(field) = field {
+            state.serialize_field("field", field)?;
+        }
+        state.serialize_field("message", message)?;
+        state.end()
+    }
+}
+
 /// Convert rusqlite::Error to AppError
 impl From<rusqlite::Error> for AppError {
     fn from(err: rusqlite::Error) -> Self {
@@ -89,3 +159,34 @@ impl From<AppError> for String {
         err.to_string()
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_field_validation_serializes_field() {
+        let err = AppError::from(DomainError::field("title", "Title cannot be empty"));
+
+        let json = serde_json::to_value(&err).unwrap();
+        assert_eq!(
+            json,
+            serde_json::json!({
+                "type": "FieldValidation",
+                "field": "title",
+                "message": "Title cannot be empty"
+            })
+        );
+    }
+
+    #[test]
+    fn test_other_errors_serialize_type_and_message() {
+        let err = AppError::NotFound("task-1".to_string());
+
+        let json = serde_json::to_value(&err).unwrap();
+        assert_eq!(
+            json,
+            serde_json::json!({ "type": "NotFound", "message": "task-1" })
+        );
+    }
+}
@@ -5,13 +5,12 @@ mod commands;
 mod db;
 mod error;
 mod models;
-mod notification;
 mod repositories;
 mod services;
 
 use commands::*;
 use db::Database;
-use notification::NotificationService;
+use services::NotificationService;
 use std::sync::{Arc, Mutex};
 use tauri::Manager;
 
@@ -56,35 +55,114 @@ fn main() {
         })
         .invoke_handler(tauri::generate_handler![
             get_tasks,
+            get_task_summaries,
+            get_time_summary,
+            get_estimation_report,
+            get_tasks_modified_since,
+            get_filter_facets,
             get_task,
+            get_task_detail,
+            start_focus_session,
+            stop_focus_session,
+            get_task_tags,
+            set_task_tags,
+            get_task_with_progress,
             create_task,
             update_task,
             delete_task,
             mark_task_done,
+            reopen_task,
+            reorder_task,
+            pin_task,
+            unpin_task,
+            defer_task,
+            undefer_task,
+            get_deferred_tasks,
+            postpone_overdue_tasks,
+            count_completed_before,
+            clear_completed_tasks,
+            duplicate_task,
+            create_next_occurrence,
+            get_task_dependencies,
+            add_task_dependency,
+            remove_task_dependency,
+            get_task_history,
+            add_comment,
+            list_comments,
+            delete_comment,
+            add_checklist_item,
+            toggle_checklist_item,
+            delete_checklist_item,
+            list_checklist_items,
+            add_attachment,
+            list_attachments,
+            remove_attachment,
             search_tasks,
+            global_search,
+            get_tasks_by_tags,
+            get_tasks_due_between,
+            get_tasks_due_today,
+            get_tasks_due_this_week,
+            get_inbox_tasks,
             export_tasks_json,
             export_tasks_csv,
+            export_tasks_to_file,
             import_tasks_json,
             import_tasks_csv,
+            import_tasks_from_file,
             backup_data,
             restore_data,
             get_reminders,
+            get_reminders_filtered,
+            get_reminders_paginated,
+            get_reminders_by_day,
             get_reminder,
+            get_reminder_context,
             create_reminder,
             update_reminder,
             delete_reminder,
             get_due_reminders,
+            count_due_reminders,
+            get_unacknowledged_reminders,
+            get_reminder_next_trigger,
+            get_pending_inapp_reminders,
+            acknowledge_reminder,
+            dismiss_reminder_occurrence,
+            promote_reminder_to_task,
+            reassign_reminder,
+            set_reminder_escalation,
+            snooze_all_due_reminders,
+            shift_reminders,
+            get_reminder_templates,
+            create_reminder_template,
+            update_reminder_template,
+            delete_reminder_template,
+            create_reminder_from_template,
             export_reminders_json,
             export_reminders_csv,
+            export_reminders_to_file,
+            generate_ics_feed,
+            export_ics_feed_to_file,
             import_reminders_json,
             import_reminders_csv,
             get_settings,
+            reset_settings,
             update_settings,
+            get_setting,
+            set_setting,
+            get_all_settings,
+            pause_notifications,
+            resume_notifications,
+            get_notifications_paused,
+            get_diagnostics,
+            compact_database,
+            repair_integrity,
             list_tags,
             get_tag,
             create_tag,
             update_tag,
             delete_tag,
+            undo_last_delete,
         ])
         .run(tauri::generate_context!())
         .expect("error while running tauri application");
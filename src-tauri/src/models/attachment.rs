@@ -0,0 +1,37 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+/// File reference attached to a task (screenshot, PDF, etc.)
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TaskAttachment {
+    pub id: String,
+    pub task_id: String,
+    pub file_path: String,
+    pub mime_type: Option<String>,
+    pub created_at: DateTime<Utc>,
+}
+
+/// Guess a MIME type from a file extension
+pub fn guess_mime_type(file_path: &str) -> Option<String> {
+    let extension = std::path::Path::new(file_path)
+        .extension()?
+        .to_str()?
+        .to_lowercase();
+
+    let mime = match extension.as_str() {
+        "png" => "image/png",
+        "jpg" | "jpeg" => "image/jpeg",
+        "gif" => "image/gif",
+        "webp" => "image/webp",
+        "svg" => "image/svg+xml",
+        "pdf" => "application/pdf",
+        "txt" => "text/plain",
+        "md" => "text/markdown",
+        "csv" => "text/csv",
+        "json" => "application/json",
+        "zip" => "application/zip",
+        _ => return None,
+    };
+
+    Some(mime.to_string())
+}
@@ -0,0 +1,92 @@
+use super::{Reminder, Tag, Task};
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+/// Format version written by `backup_data`
+pub const BACKUP_VERSION: &str = "1.0";
+
+/// Versioned container for a full data backup
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct BackupEnvelope {
+    pub version: String,
+    pub timestamp: DateTime<Utc>,
+    pub tasks: Vec<Task>,
+    pub reminders: Vec<Reminder>,
+    #[serde(default)]
+    pub tags: Vec<Tag>,
+}
+
+impl BackupEnvelope {
+    /// Create a backup in the current format, stamped with the current time
+    pub fn new(tasks: Vec<Task>, reminders: Vec<Reminder>, tags: Vec<Tag>) -> Self {
+        Self {
+            version: BACKUP_VERSION.to_string(),
+            timestamp: Utc::now(),
+            tasks,
+            reminders,
+            tags,
+        }
+    }
+
+    /// Parse a backup, dispatching on its `version` to the matching format parser
+    pub fn parse(json: &str) -> Result<Self, String> {
+        let value: serde_json::Value =
+            serde_json::from_str(json).map_err(|e| format!("Failed to parse backup: {}", e))?;
+
+        let version = value
+            .get("version")
+            .and_then(|v| v.as_str())
+            .ok_or("Backup is missing a version")?;
+
+        match version {
+            "1.0" => Self::parse_v1(value),
+            other => Err(format!("Unsupported backup version: {}", other)),
+        }
+    }
+
+    /// Version 1.0: tasks and reminders as serialized entities, tags optional
+    fn parse_v1(value: serde_json::Value) -> Result<Self, String> {
+        serde_json::from_value(value).map_err(|e| format!("Failed to parse v1.0 backup: {}", e))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_v1_without_tags() {
+        let json = r#"{
+            "version": "1.0",
+            "timestamp": "2024-01-01T00:00:00Z",
+            "tasks": [],
+            "reminders": []
+        }"#;
+
+        let backup = BackupEnvelope::parse(json).unwrap();
+        assert_eq!(backup.version, "1.0");
+        assert!(backup.tags.is_empty());
+    }
+
+    #[test]
+    fn test_parse_rejects_unknown_version() {
+        let json = r#"{"version": "9.9", "timestamp": "2024-01-01T00:00:00Z"}"#;
+
+        let err = BackupEnvelope::parse(json).unwrap_err();
+        assert!(err.contains("Unsupported backup version: 9.9"));
+    }
+
+    #[test]
+    fn test_parse_rejects_missing_version() {
+        let err = BackupEnvelope::parse(r#"{"tasks": []}"#).unwrap_err();
+        assert!(err.contains("missing a version"));
+    }
+
+    #[test]
+    fn test_new_round_trips_through_parse() {
+        let json = serde_json::to_string(&BackupEnvelope::new(vec![], vec![], vec![])).unwrap();
+
+        let backup = BackupEnvelope::parse(&json).unwrap();
+        assert_eq!(backup.version, BACKUP_VERSION);
+    }
+}
@@ -0,0 +1,12 @@
+use serde::{Deserialize, Serialize};
+
+/// Lightweight checklist entry inside a single task
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ChecklistItem {
+    pub id: String,
+    pub task_id: String,
+    pub text: String,
+    pub is_done: bool,
+    /// Position within the task's checklist (lower comes first)
+    pub position: i64,
+}
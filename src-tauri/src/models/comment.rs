@@ -0,0 +1,14 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+/// Maximum length of a comment body, in characters
+pub const MAX_COMMENT_LENGTH: usize = 5000;
+
+/// Free-form note left on a task
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TaskComment {
+    pub id: String,
+    pub task_id: String,
+    pub body: String,
+    pub created_at: DateTime<Utc>,
+}
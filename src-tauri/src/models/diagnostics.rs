@@ -0,0 +1,41 @@
+use serde::{Deserialize, Serialize};
+
+/// Number of stored records per entity
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct RecordCounts {
+    pub tasks: u32,
+    pub reminders: u32,
+    pub tags: u32,
+}
+
+/// Snapshot of application and database health, for support requests
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Diagnostics {
+    pub app_version: String,
+    pub sqlite_version: String,
+    pub schema_version: i32,
+    pub counts: RecordCounts,
+    /// `None` when the database is not backed by a file
+    pub database_size_bytes: Option<u64>,
+    pub journal_mode: String,
+    pub wal_enabled: bool,
+    pub notification_thread_running: bool,
+    /// "ok", or the problems reported by `PRAGMA integrity_check`
+    pub integrity_check: String,
+}
+
+/// File sizes around a `compact_database` run
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CompactionResult {
+    pub size_before_bytes: Option<u64>,
+    pub size_after_bytes: Option<u64>,
+}
+
+/// Rows fixed by a `repair_integrity` run
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct IntegrityRepair {
+    /// `task_tags` rows pointing at a missing task or tag, now deleted
+    pub orphaned_task_tags_removed: usize,
+    /// Reminders whose `task_id` pointed at a missing task, now unlinked
+    pub dangling_reminder_links_cleared: usize,
+}
@@ -0,0 +1,212 @@
+use super::{Reminder, Task};
+use serde::{Deserialize, Serialize};
+
+/// Event emitted after a task is created, updated or deleted
+pub const TASKS_CHANGED_EVENT: &str = "tasks-changed";
+
+/// Event emitted after a reminder is created, updated, deleted or triggered
+pub const REMINDERS_CHANGED_EVENT: &str = "reminders-changed";
+
+/// Event emitted when a reminder fires, carrying the reminder itself
+pub const REMINDER_TRIGGERED_EVENT: &str = "reminder-triggered";
+
+/// Event emitted when the scheduled digest of due tasks is sent
+pub const TASK_DIGEST_EVENT: &str = "task-digest";
+
+/// Most due tasks listed by name in the digest notification
+const DIGEST_LISTED_TASKS: usize = 5;
+
+/// What happened to the entity carried by a change event
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "lowercase")]
+pub enum ChangeKind {
+    Created,
+    Updated,
+    Deleted,
+    Triggered,
+}
+
+/// Payload of the `tasks-changed` event
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct TaskChangedEvent {
+    pub id: String,
+    pub kind: ChangeKind,
+}
+
+/// Payload of the `reminders-changed` event
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct ReminderChangedEvent {
+    pub id: String,
+    pub kind: ChangeKind,
+}
+
+/// Payload of the `reminder-triggered` event
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ReminderTriggeredEvent {
+    #[serde(flatten)]
+    pub reminder: Reminder,
+    /// Time until the linked task is due (e.g. "in 2 hours"), see `Reminder::lead_description`
+    pub lead_description: Option<String>,
+    /// Title of the linked task, if it still exists
+    pub task_title: Option<String>,
+}
+
+impl ReminderTriggeredEvent {
+    /// Body of the OS notification: linked task, description and due line, one per line
+    pub fn notification_body(&self) -> Option<String> {
+        let lines: Vec<String> = [
+            self.task_title
+                .as_ref()
+                .map(|title| format!("Reminder • {}", title)),
+            self.reminder.description.clone(),
+            self.lead_description
+                .as_ref()
+                .map(|lead| format!("Due {}", lead)),
+        ]
+        .into_iter()
+        .flatten()
+        .collect();
+
+        if lines.is_empty() {
+            None
+        } else {
+            Some(lines.join("\n"))
+        }
+    }
+}
+
+/// Payload of the `task-digest` event
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TaskDigestEvent {
+    /// "daily" or "weekly", see the `digest_frequency` setting
+    pub frequency: String,
+    /// Open tasks due today (or this week for a weekly digest), soonest first
+    pub due_tasks: Vec<Task>,
+    pub overdue_count: usize,
+}
+
+impl TaskDigestEvent {
+    /// Title of the OS notification
+    pub fn notification_title(&self) -> &str {
+        if self.frequency == "weekly" {
+            "📋 Tasks due this week"
+        } else {
+            "📋 Tasks due today"
+        }
+    }
+
+    /// Body of the OS notification: due task titles, one per line, then the overdue count
+    pub fn notification_body(&self) -> String {
+        let mut lines: Vec<String> = self
+            .due_tasks
+            .iter()
+            .take(DIGEST_LISTED_TASKS)
+            .map(|task| format!("• {}", task.title))
+            .collect();
+
+        let unlisted = self.due_tasks.len().saturating_sub(DIGEST_LISTED_TASKS);
+        if unlisted > 0 {
+            lines.push(format!("…and {} more", unlisted));
+        }
+        if lines.is_empty() {
+            lines.push("Nothing due".to_string());
+        }
+        if self.overdue_count > 0 {
+            lines.push(format!("{} overdue", self.overdue_count));
+        }
+
+        lines.join("\n")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::{ReminderDelivery, RepeatInterval};
+    use chrono::Utc;
+
+    fn triggered(description: Option<&str>, task_title: Option<&str>) -> ReminderTriggeredEvent {
+        let now = Utc::now();
+        ReminderTriggeredEvent {
+            reminder: Reminder {
+                id: "reminder-1".to_string(),
+                task_id: task_title.map(|_| "task-1".to_string()),
+                title: "Check in".to_string(),
+                description: description.map(str::to_string),
+                remind_at: now,
+                repeat_interval: RepeatInterval::none(),
+                is_active: true,
+                last_triggered_at: None,
+                created_at: now,
+                updated_at: now,
+                acknowledged_at: None,
+                escalation_minutes: None,
+                escalation_count: 0,
+                delivery: ReminderDelivery::Notification,
+            },
+            lead_description: None,
+            task_title: task_title.map(str::to_string),
+        }
+    }
+
+    #[test]
+    fn test_notification_body_with_task() {
+        let mut event = triggered(Some("Bring slides"), Some("Quarterly review"));
+        assert_eq!(
+            event.notification_body().as_deref(),
+            Some("Reminder • Quarterly review\nBring slides")
+        );
+
+        event.lead_description = Some("in 2 hours".to_string());
+        assert_eq!(
+            event.notification_body().as_deref(),
+            Some("Reminder • Quarterly review\nBring slides\nDue in 2 hours")
+        );
+    }
+
+    #[test]
+    fn test_notification_body_without_task() {
+        assert_eq!(
+            triggered(Some("Bring slides"), None)
+                .notification_body()
+                .as_deref(),
+            Some("Bring slides")
+        );
+        assert_eq!(triggered(None, None).notification_body(), None);
+    }
+
+    #[test]
+    fn test_task_changed_event_serialization() {
+        let event = TaskChangedEvent {
+            id: "task-1".to_string(),
+            kind: ChangeKind::Created,
+        };
+
+        let json = serde_json::to_value(&event).unwrap();
+        assert_eq!(
+            json,
+            serde_json::json!({ "id": "task-1", "kind": "created" })
+        );
+    }
+
+    #[test]
+    fn test_reminder_changed_event_round_trip() {
+        for (kind, name) in [
+            (ChangeKind::Created, "created"),
+            (ChangeKind::Updated, "updated"),
+            (ChangeKind::Deleted, "deleted"),
+            (ChangeKind::Triggered, "triggered"),
+        ] {
+            let event = ReminderChangedEvent {
+                id: "reminder-1".to_string(),
+                kind,
+            };
+
+            let json = serde_json::to_string(&event).unwrap();
+            assert!(json.contains(&format!("\"kind\":\"{}\"", name)));
+
+            let parsed: ReminderChangedEvent = serde_json::from_str(&json).unwrap();
+            assert_eq!(parsed, event);
+        }
+    }
+}
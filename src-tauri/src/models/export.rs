@@ -0,0 +1,98 @@
+use chrono::format::{Item, StrftimeItems};
+use chrono::{DateTime, FixedOffset, Utc};
+use serde::{Deserialize, Serialize};
+
+/// File format for task and reminder exports
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ExportFormat {
+    Json,
+    Csv,
+}
+
+/// How dates are written in exports; the default keeps RFC 3339 in UTC
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct ExportOptions {
+    /// Offset from UTC applied to every exported date
+    pub timezone_offset_minutes: i32,
+    /// chrono `strftime` pattern, e.g. "%d/%m/%Y %H:%M"; RFC 3339 when unset
+    pub date_format: Option<String>,
+}
+
+impl ExportOptions {
+    /// True when dates are written exactly as stored (RFC 3339, UTC)
+    pub fn is_default(&self) -> bool {
+        self.timezone_offset_minutes == 0 && self.date_format.is_none()
+    }
+
+    /// The configured offset, or None when it is out of range
+    pub fn offset(&self) -> Option<FixedOffset> {
+        FixedOffset::east_opt(self.timezone_offset_minutes.checked_mul(60)?)
+    }
+
+    /// Whether `date_format` (if set) only contains valid specifiers
+    pub fn has_valid_format(&self) -> bool {
+        self.date_format.as_deref().is_none_or(|format| {
+            !StrftimeItems::new(format).any(|item| matches!(item, Item::Error))
+        })
+    }
+
+    /// Format `time` in the configured offset and format
+    ///
+    /// Falls back to UTC for an out-of-range offset; check `offset` and
+    /// `has_valid_format` before exporting.
+    pub fn format(&self, time: DateTime<Utc>) -> String {
+        if self.is_default() {
+            return time.to_rfc3339();
+        }
+
+        let local = time.with_timezone(&self.offset().unwrap_or(FixedOffset::east_opt(0).unwrap()));
+        match &self.date_format {
+            Some(format) => local.format(format).to_string(),
+            None => local.to_rfc3339(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::TimeZone;
+
+    fn noon() -> DateTime<Utc> {
+        Utc.with_ymd_and_hms(2024, 3, 9, 12, 30, 0).unwrap()
+    }
+
+    #[test]
+    fn test_default_options_keep_rfc3339_utc() {
+        let options = ExportOptions::default();
+        assert_eq!(options.format(noon()), noon().to_rfc3339());
+    }
+
+    #[test]
+    fn test_offset_and_custom_format() {
+        let offset_only = ExportOptions {
+            timezone_offset_minutes: 420,
+            date_format: None,
+        };
+        assert_eq!(offset_only.format(noon()), "2024-03-09T19:30:00+07:00");
+
+        let custom = ExportOptions {
+            timezone_offset_minutes: -300,
+            date_format: Some("%d/%m/%Y %H:%M".to_string()),
+        };
+        assert!(custom.has_valid_format());
+        assert_eq!(custom.format(noon()), "09/03/2024 07:30");
+    }
+
+    #[test]
+    fn test_invalid_options_are_detected() {
+        let options = ExportOptions {
+            timezone_offset_minutes: 24 * 60,
+            date_format: Some("%Y-%Q".to_string()),
+        };
+        assert!(options.offset().is_none());
+        assert!(!options.has_valid_format());
+    }
+}
@@ -0,0 +1,44 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+/// A Pomodoro-style session on a task, alternating work and break periods
+///
+/// While active, two repeating reminders signal the transitions: one when each
+/// work period ends and one when each break ends.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct FocusSession {
+    pub id: String,
+    pub task_id: String,
+    pub work_minutes: i32,
+    pub break_minutes: i32,
+    pub break_reminder_id: Option<String>,
+    pub work_reminder_id: Option<String>,
+    pub started_at: DateTime<Utc>,
+    pub ended_at: Option<DateTime<Utc>>,
+    /// Work minutes added to the task's `actual_minutes` when the session stopped
+    pub logged_minutes: Option<i32>,
+}
+
+impl FocusSession {
+    /// Check if the session is still running
+    pub fn is_active(&self) -> bool {
+        self.ended_at.is_none()
+    }
+
+    /// Length of one work + break cycle, in minutes
+    pub fn cycle_minutes(&self) -> i32 {
+        self.work_minutes + self.break_minutes
+    }
+
+    /// Work minutes accrued from `started_at` until `until`
+    ///
+    /// Each full cycle counts its work period; a partial cycle counts at most one work period.
+    pub fn worked_minutes(&self, until: DateTime<Utc>) -> i32 {
+        let elapsed = (until - self.started_at).num_minutes().max(0);
+        let cycle = i64::from(self.cycle_minutes());
+        let work = i64::from(self.work_minutes);
+
+        let worked = elapsed / cycle * work + (elapsed % cycle).min(work);
+        i32::try_from(worked).unwrap_or(i32::MAX)
+    }
+}
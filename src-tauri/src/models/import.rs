@@ -0,0 +1,36 @@
+use serde::{Deserialize, Serialize};
+
+/// How an import treats a record whose id matches an existing row
+///
+/// Records without an id, or whose id is unknown, are always created as new rows.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum ImportConflictPolicy {
+    /// Keep the existing row and skip the record
+    #[default]
+    Skip,
+    /// Update the existing row with the record's fields
+    Overwrite,
+    /// Create the record as a new row with a suffix appended to its title
+    Rename,
+}
+
+/// Outcome of importing records from JSON or CSV
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct ImportReport {
+    pub imported: usize,
+    /// Existing rows updated under `ImportConflictPolicy::Overwrite`
+    #[serde(default)]
+    pub overwritten: usize,
+    pub skipped: usize,
+    /// One message per skipped record
+    pub errors: Vec<String>,
+}
+
+impl ImportReport {
+    /// Record a skipped entry along with why it was skipped
+    pub fn skip(&mut self, reason: String) {
+        self.skipped += 1;
+        self.errors.push(reason);
+    }
+}
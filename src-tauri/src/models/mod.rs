@@ -1,10 +1,45 @@
+pub mod attachment;
+pub mod backup;
+pub mod checklist;
+pub mod comment;
+pub mod diagnostics;
+pub mod event;
+pub mod export;
+pub mod focus_session;
+pub mod import;
 pub mod reminder;
+pub mod reminder_template;
+pub mod search;
 pub mod settings;
+pub mod status_history;
 pub mod task;
+pub mod undo;
 
-pub use reminder::{CreateReminderDto, Reminder, RepeatInterval, UpdateReminderDto};
+pub use attachment::TaskAttachment;
+pub use backup::{BackupEnvelope, BACKUP_VERSION};
+pub use checklist::ChecklistItem;
+pub use comment::TaskComment;
+pub use diagnostics::{CompactionResult, Diagnostics, IntegrityRepair, RecordCounts};
+pub use event::{ChangeKind, ReminderChangedEvent, ReminderTriggeredEvent, TaskChangedEvent};
+pub use export::{ExportFormat, ExportOptions};
+pub use focus_session::FocusSession;
+pub use import::{ImportConflictPolicy, ImportReport};
+pub use reminder::{
+    CreateReminderDto, MonthDay, Reminder, ReminderContext, ReminderDay, ReminderDelivery,
+    ReminderFilter, ReminderShiftResult, ReminderSort, ReminderSortField, RepeatInterval,
+    UpdateReminderDto,
+};
+pub use reminder_template::{
+    CreateReminderTemplateDto, ReminderTemplate, UpdateReminderTemplateDto,
+};
+pub use search::GlobalSearchResult;
 pub use settings::{AppSettings, Setting};
+pub use status_history::TaskStatusChange;
 pub use task::{
-    CreateTaskDto, EffectiveTaskStatus, PaginatedResponse, Pagination, SortDirection, Tag, Task,
-    TaskFilter, TaskPriority, TaskSort, TaskSortField, TaskStatus, UpdateTaskDto,
+    CreateTaskDto, CreateTaskResult, EditScope, EffectiveTaskStatus, EstimationEntry,
+    EstimationReport, FacetCount, FilterFacets, MarkDoneResult, PaginatedResponse, Pagination,
+    SortDirection, Tag, TagAssignment, Task, TaskDelta, TaskDetail, TaskFilter, TaskPriority,
+    TaskSort, TaskSortField, TaskStatus, TaskSummary, TaskView, TaskWithProgress, TimeSummary,
+    UpdateTaskDto, UpdateTaskResult,
 };
+pub use undo::{SnapshotTable, UndoEntityType, UndoEntry, UndoSnapshot};
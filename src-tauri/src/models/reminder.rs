@@ -1,8 +1,12 @@
-use chrono::{DateTime, Utc};
+use super::task::{SortDirection, Task};
+use chrono::{DateTime, Datelike, Months, NaiveDate, Utc, Weekday};
 use serde::{Deserialize, Serialize};
 
 /// Repeat interval - now supports custom intervals
 /// Format: "{type}_{value}_{unit}" e.g. "every_10_minutes", "after_1_hour"
+/// Weekday schedules: "weekdays_{days}" e.g. "weekdays_mon,wed,fri"
+/// Business-day schedules: "weekdays" or "every_1_businessday" (Monday to Friday)
+/// Day-of-month schedules: "monthly_day_{day}" e.g. "monthly_day_15", "monthly_day_last"
 /// Special case: "none" for no repeat
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
 pub struct RepeatInterval(pub String);
@@ -26,9 +30,10 @@ impl RepeatInterval {
     }
 
     /// Parse the interval string to extract type, value, and unit
-    /// Returns None for "none", Some((type, value, unit)) otherwise
+    /// Returns None for "none" and calendar schedules (see `weekdays` and `month_day`),
+    /// Some((type, value, unit)) otherwise
     pub fn parse(&self) -> Option<(String, i64, String)> {
-        if self.0 == "none" {
+        if self.0 == "none" || self.is_calendar() {
             return None;
         }
 
@@ -43,6 +48,152 @@ impl RepeatInterval {
 
         None
     }
+
+    /// Days of a "weekdays_mon,wed,fri" schedule, `None` for other formats or unknown day names
+    ///
+    /// Business-day schedules yield `BUSINESS_DAYS`.
+    pub fn weekdays(&self) -> Option<Vec<Weekday>> {
+        if BUSINESS_DAY_INTERVALS.contains(&self.0.as_str()) {
+            return Some(BUSINESS_DAYS.to_vec());
+        }
+
+        let days = self
+            .0
+            .strip_prefix("weekdays_")?
+            .split(',')
+            .map(|day| day.trim().parse::<Weekday>().ok())
+            .collect::<Option<Vec<Weekday>>>()?;
+
+        Some(days).filter(|days| !days.is_empty())
+    }
+
+    /// Day of a "monthly_day_15" / "monthly_day_last" schedule, `None` for other formats
+    pub fn month_day(&self) -> Option<MonthDay> {
+        match self.0.strip_prefix("monthly_day_")? {
+            "last" => Some(MonthDay::Last),
+            day => day
+                .parse::<u32>()
+                .ok()
+                .filter(|day| (1..=31).contains(day))
+                .map(MonthDay::Day),
+        }
+    }
+
+    /// Whether occurrences follow the calendar (weekdays or day of month) rather than a fixed step
+    pub fn is_calendar(&self) -> bool {
+        self.weekdays().is_some() || self.month_day().is_some()
+    }
+
+    /// Whether the string is a format the scheduler understands
+    ///
+    /// A typo such as "every_10_minutess" would otherwise be stored and never fire.
+    pub fn is_valid(&self) -> bool {
+        if self.0 == "none" || self.is_calendar() {
+            return true;
+        }
+
+        match self.parse() {
+            Some((interval_type, value, unit)) => {
+                matches!(interval_type.as_str(), "every" | "after")
+                    && value > 0
+                    && REPEAT_UNITS.contains(&unit.as_str())
+                    && self.step().is_some()
+            }
+            None => false,
+        }
+    }
+
+    /// Length of one "{type}_{value}_{unit}" interval
+    ///
+    /// `None` for other formats and for values too large to represent as a `Duration`.
+    /// Months count as 30 days and years as 365.
+    pub fn step(&self) -> Option<chrono::Duration> {
+        use chrono::Duration;
+
+        let (_, value, unit) = self.parse()?;
+        match unit.as_str() {
+            "seconds" | "second" => Duration::try_seconds(value),
+            "minutes" | "minute" => Duration::try_minutes(value),
+            "hours" | "hour" => Duration::try_hours(value),
+            "days" | "day" => Duration::try_days(value),
+            "weeks" | "week" => Duration::try_weeks(value),
+            "months" | "month" => Duration::try_days(value.checked_mul(30)?),
+            "years" | "year" => Duration::try_days(value.checked_mul(365)?),
+            _ => None,
+        }
+    }
+}
+
+/// Units accepted in "{type}_{value}_{unit}" intervals, singular and plural
+const REPEAT_UNITS: &[&str] = &[
+    "second", "seconds", "minute", "minutes", "hour", "hours", "day", "days", "week", "weeks",
+    "month", "months", "year", "years",
+];
+
+/// Interval strings for a Monday-to-Friday schedule
+const BUSINESS_DAY_INTERVALS: &[&str] = &["weekdays", "every_1_businessday"];
+
+/// Days a business-day schedule fires on; weekends are Saturday and Sunday for now
+const BUSINESS_DAYS: [Weekday; 5] = [
+    Weekday::Mon,
+    Weekday::Tue,
+    Weekday::Wed,
+    Weekday::Thu,
+    Weekday::Fri,
+];
+
+/// Day targeted by a day-of-month schedule
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum MonthDay {
+    /// Clamped to the month's length, so the 31st falls on the 30th or 28th/29th
+    Day(u32),
+    Last,
+}
+
+impl MonthDay {
+    /// The concrete day in `year`/`month`
+    fn in_month(self, year: i32, month: u32) -> Option<u32> {
+        let first = NaiveDate::from_ymd_opt(year, month, 1)?;
+        let last = (first + Months::new(1)).pred_opt()?.day();
+        Some(match self {
+            MonthDay::Day(day) => day.min(last),
+            MonthDay::Last => last,
+        })
+    }
+}
+
+/// Follow-up notifications sent per trigger while the linked task stays incomplete
+pub const MAX_ESCALATIONS: u32 = 3;
+
+/// Longest gap between follow-up notifications (one week)
+pub const MAX_ESCALATION_MINUTES: i64 = 10_080;
+
+/// How a fired reminder reaches the user
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ReminderDelivery {
+    /// OS notification as soon as the reminder fires
+    #[default]
+    Notification,
+    /// No interruption; surfaced the next time the app window gains focus
+    InApp,
+}
+
+impl ReminderDelivery {
+    pub fn as_str(&self) -> &str {
+        match self {
+            ReminderDelivery::Notification => "notification",
+            ReminderDelivery::InApp => "in_app",
+        }
+    }
+
+    pub fn parse(s: &str) -> Option<Self> {
+        match s {
+            "notification" => Some(ReminderDelivery::Notification),
+            "in_app" => Some(ReminderDelivery::InApp),
+            _ => None,
+        }
+    }
 }
 
 /// Reminder entity
@@ -58,9 +209,63 @@ pub struct Reminder {
     pub last_triggered_at: Option<DateTime<Utc>>,
     pub created_at: DateTime<Utc>,
     pub updated_at: DateTime<Utc>,
+    /// When the user last saw the fired notification; `None` after each trigger
+    #[serde(default)]
+    pub acknowledged_at: Option<DateTime<Utc>>,
+    /// Minutes between follow-up notifications while the linked task is incomplete
+    #[serde(default)]
+    pub escalation_minutes: Option<i64>,
+    /// Follow-ups sent since the last trigger; reset on each trigger
+    #[serde(default)]
+    pub escalation_count: u32,
+    #[serde(default)]
+    pub delivery: ReminderDelivery,
 }
 
 impl Reminder {
+    /// When the next follow-up notification is due, if escalation applies
+    ///
+    /// Follow-ups are spaced `escalation_minutes` apart from the last trigger and
+    /// stop after `MAX_ESCALATIONS`. Whether the linked task is still incomplete is
+    /// checked by the caller. `None` when the follow-up time would overflow.
+    pub fn next_escalation_at(&self) -> Option<DateTime<Utc>> {
+        let minutes = self.escalation_minutes?;
+        let last_triggered = self.last_triggered_at?;
+        if !self.is_active || self.escalation_count >= MAX_ESCALATIONS {
+            return None;
+        }
+
+        let delay = minutes.checked_mul(i64::from(self.escalation_count + 1))?;
+        last_triggered.checked_add_signed(chrono::Duration::try_minutes(delay)?)
+    }
+
+    /// Human-readable time from this reminder until the linked task is due, e.g. "in 2 hours"
+    ///
+    /// Measured from `remind_at`, or from `now` when the reminder fires late. A due date
+    /// already passed reads as "2 hours ago". `None` when the task has no due date.
+    pub fn lead_description(
+        &self,
+        task_due: Option<DateTime<Utc>>,
+        now: DateTime<Utc>,
+    ) -> Option<String> {
+        let lead_minutes = (task_due? - self.remind_at.max(now)).num_minutes();
+        let minutes = lead_minutes.abs();
+
+        let (value, unit) = match minutes {
+            0 => return Some("now".to_string()),
+            1..=59 => (minutes, "minute"),
+            60..=1439 => (minutes / 60, "hour"),
+            _ => (minutes / 1440, "day"),
+        };
+        let span = format!("{} {}{}", value, unit, if value == 1 { "" } else { "s" });
+
+        Some(if lead_minutes > 0 {
+            format!("in {}", span)
+        } else {
+            format!("{} ago", span)
+        })
+    }
+
     /// Check if reminder is due based on current time
     pub fn is_due(&self) -> bool {
         if !self.is_active {
@@ -74,6 +279,14 @@ impl Reminder {
             return false;
         }
 
+        // A calendar schedule starts at its first slot on or after remind_at
+        if self.repeat_interval.is_calendar() {
+            match self.calendar_slot(self.remind_at, true) {
+                Some(first) if first <= now => {}
+                _ => return false,
+            }
+        }
+
         // For non-repeating reminders, check if it hasn't been triggered yet
         if !self.repeat_interval.is_repeating() {
             return self.last_triggered_at.is_none();
@@ -90,31 +303,15 @@ impl Reminder {
 
     /// Calculate if a repeating reminder should trigger again
     fn should_repeat_now(&self, last_triggered: DateTime<Utc>, now: DateTime<Utc>) -> bool {
-        use chrono::Duration;
-
-        // Parse the interval string
-        if let Some((interval_type, value, unit)) = self.repeat_interval.parse() {
-            // For "after" type, it should only trigger once
-            if interval_type == "after" {
-                return false;
-            }
-
-            // For "every" type, calculate duration based on unit
-            let duration = match unit.as_str() {
-                "seconds" | "second" => Duration::seconds(value),
-                "minutes" | "minute" => Duration::minutes(value),
-                "hours" | "hour" => Duration::hours(value),
-                "days" | "day" => Duration::days(value),
-                "weeks" | "week" => Duration::weeks(value),
-                "months" | "month" => Duration::days(value * 30), // Approximate
-                "years" | "year" => Duration::days(value * 365),  // Approximate
-                _ => return false,                                // Unknown unit
-            };
-
-            (now - last_triggered) >= duration
-        } else {
-            false
+        if self.repeat_interval.is_calendar() {
+            return self
+                .calendar_slot(last_triggered, false)
+                .is_some_and(|slot| slot <= now);
         }
+
+        // "after" intervals trigger once; unknown or out-of-range ones never repeat
+        self.repeat_step()
+            .is_some_and(|step| now.signed_duration_since(last_triggered) >= step)
     }
 
     /// Check if reminder is overdue (past remind_at time)
@@ -153,42 +350,304 @@ impl Reminder {
         }
     }
 
+    /// When the reminder fires next, as shown to the user
+    ///
+    /// An untriggered reminder fires at `remind_at` (or its first calendar slot);
+    /// after that only repeating reminders have a next time. `None` when inactive.
+    pub fn upcoming_trigger(&self) -> Option<DateTime<Utc>> {
+        if !self.is_active {
+            return None;
+        }
+
+        if self.last_triggered_at.is_none() {
+            if self.repeat_interval.is_calendar() {
+                return self.calendar_slot(self.remind_at, true);
+            }
+            return Some(self.remind_at);
+        }
+
+        self.next_trigger_time()
+    }
+
     /// Get next trigger time for repeating reminders
     pub fn next_trigger_time(&self) -> Option<DateTime<Utc>> {
         if !self.is_active || !self.repeat_interval.is_repeating() {
             return None;
         }
 
-        use chrono::Duration;
-
         let base_time = self.last_triggered_at.unwrap_or(self.remind_at);
+        if self.repeat_interval.is_calendar() {
+            return self.calendar_slot(base_time, false);
+        }
+        Some(base_time + self.repeat_step()?)
+    }
 
-        // Parse the interval to get type, value, and unit
-        if let Some((interval_type, value, unit)) = self.repeat_interval.parse() {
-            // For "after" type, don't calculate next trigger (one-time only)
-            if interval_type == "after" {
-                return None;
+    /// First slot of the repeat schedule strictly after `now`, anchored on `remind_at`
+    ///
+    /// Computed in one step, so a reminder that missed many slots while the app
+    /// was closed skips straight to the next future one instead of firing once
+    /// per missed slot.
+    pub fn next_trigger_after(&self, now: DateTime<Utc>) -> Option<DateTime<Utc>> {
+        if !self.is_active || !self.repeat_interval.is_repeating() {
+            return None;
+        }
+
+        if self.repeat_interval.is_calendar() {
+            let first = self.calendar_slot(self.remind_at, true)?;
+            if first > now {
+                return Some(first);
             }
+            return self.calendar_slot(now, false);
+        }
+
+        let step = self.repeat_step()?.num_seconds();
+        if step <= 0 {
+            return None;
+        }
+        if self.remind_at > now {
+            return Some(self.remind_at);
+        }
+
+        let missed = (now - self.remind_at).num_seconds() / step + 1;
+        Some(self.remind_at + chrono::Duration::seconds(step * missed))
+    }
+
+    /// Trigger times falling in `[start, end)`, at most `limit` of them
+    ///
+    /// One-off reminders yield `remind_at` when it is in range; repeating ones are
+    /// expanded slot by slot along their schedule.
+    pub fn occurrences_between(
+        &self,
+        start: DateTime<Utc>,
+        end: DateTime<Utc>,
+        limit: usize,
+    ) -> Vec<DateTime<Utc>> {
+        let mut occurrences = Vec::new();
+        let mut cursor = start - chrono::Duration::seconds(1);
+        while occurrences.len() < limit {
+            match self.next_trigger_after(cursor) {
+                Some(next) if next < end => {
+                    occurrences.push(next);
+                    cursor = next;
+                }
+                _ => break,
+            }
+        }
+
+        if occurrences.is_empty()
+            && limit > 0
+            && !self.repeat_interval.is_calendar()
+            && self.remind_at >= start
+            && self.remind_at < end
+        {
+            occurrences.push(self.remind_at);
+        }
 
-            // For "every" type, calculate next time based on unit
-            let duration = match unit.as_str() {
-                "seconds" | "second" => Duration::seconds(value),
-                "minutes" | "minute" => Duration::minutes(value),
-                "hours" | "hour" => Duration::hours(value),
-                "days" | "day" => Duration::days(value),
-                "weeks" | "week" => Duration::weeks(value),
-                "months" | "month" => Duration::days(value * 30),
-                "years" | "year" => Duration::days(value * 365),
-                _ => return None,
-            };
-
-            Some(base_time + duration)
+        occurrences
+    }
+
+    /// First slot of a calendar schedule after `from` (or at it, when `inclusive`)
+    ///
+    /// Slots fall at `remind_at`'s time of day; days and times are taken in UTC.
+    fn calendar_slot(&self, from: DateTime<Utc>, inclusive: bool) -> Option<DateTime<Utc>> {
+        if let Some(days) = self.repeat_interval.weekdays() {
+            self.weekday_slot(&days, from, inclusive)
         } else {
-            None
+            self.month_day_slot(self.repeat_interval.month_day()?, from, inclusive)
+        }
+    }
+
+    /// First listed weekday at `remind_at`'s time of day after `from` (or at it, when `inclusive`)
+    fn weekday_slot(
+        &self,
+        days: &[Weekday],
+        from: DateTime<Utc>,
+        inclusive: bool,
+    ) -> Option<DateTime<Utc>> {
+        let time_of_day = self.remind_at.time();
+
+        (0..=7)
+            .map(|offset| {
+                (from.date_naive() + chrono::Duration::days(offset))
+                    .and_time(time_of_day)
+                    .and_utc()
+            })
+            .find(|slot| {
+                days.contains(&slot.weekday()) && (*slot > from || (inclusive && *slot == from))
+            })
+    }
+
+    /// Target day of `from`'s month or the next one at `remind_at`'s time of day, after `from`
+    /// (or at it, when `inclusive`)
+    fn month_day_slot(
+        &self,
+        day: MonthDay,
+        from: DateTime<Utc>,
+        inclusive: bool,
+    ) -> Option<DateTime<Utc>> {
+        let time_of_day = self.remind_at.time();
+        let month_start = from.date_naive().with_day(1)?;
+
+        (0..=1)
+            .filter_map(|offset| {
+                let month = month_start + Months::new(offset);
+                let date = month.with_day(day.in_month(month.year(), month.month())?)?;
+                Some(date.and_time(time_of_day).and_utc())
+            })
+            .find(|slot| *slot > from || (inclusive && *slot == from))
+    }
+
+    /// Time between occurrences, `None` for non-repeating, "after" and calendar intervals
+    fn repeat_step(&self) -> Option<chrono::Duration> {
+        // Parse the interval to get type, value, and unit
+        let (interval_type, _, _) = self.repeat_interval.parse()?;
+
+        // For "after" type, don't calculate next trigger (one-time only)
+        if interval_type == "after" {
+            return None;
+        }
+
+        self.repeat_interval.step()
+    }
+}
+
+/// Reminder query filters
+#[derive(Debug, Clone, Serialize, Deserialize, Default)]
+pub struct ReminderFilter {
+    pub is_active: Option<bool>,
+    /// Only reminders due strictly before this instant (exclusive)
+    pub due_before: Option<DateTime<Utc>>,
+    /// Only reminders due at or after this instant (inclusive)
+    pub due_after: Option<DateTime<Utc>>,
+    pub task_id: Option<String>,
+}
+
+/// Reminder sorting options
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ReminderSort {
+    pub field: ReminderSortField,
+    pub direction: SortDirection,
+}
+
+/// Reminder sort field enumeration
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ReminderSortField {
+    RemindAt,
+    CreatedAt,
+    UpdatedAt,
+    Title,
+}
+
+impl ReminderSortField {
+    pub fn as_str(&self) -> &str {
+        match self {
+            ReminderSortField::RemindAt => "remind_at",
+            ReminderSortField::CreatedAt => "created_at",
+            ReminderSortField::UpdatedAt => "updated_at",
+            ReminderSortField::Title => "title",
         }
     }
 }
 
+/// Outcome of shifting reminders in bulk
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ReminderShiftResult {
+    /// Reminders whose `remind_at` moved, including clamped ones
+    pub shifted_ids: Vec<String>,
+    /// Reminders left untouched because the shift would put them in the past
+    pub skipped: usize,
+}
+
+/// A reminder with its linked task and the task's other reminders
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ReminderContext {
+    pub reminder: Reminder,
+    /// `None` for standalone reminders and when the task has been deleted
+    pub task: Option<Task>,
+    /// Other reminders on the same task, ordered by `remind_at`
+    pub siblings: Vec<Reminder>,
+}
+
+/// Notification title and body for `reminder`, with task placeholders filled in
+///
+/// `{task.title}`, `{task.description}`, `{task.due_date}`, `{task.priority}` and
+/// `{task.status}` are replaced from `task`, rendering as empty text when there is no
+/// task or the field is unset. Unknown placeholders are kept literally, and `{{` / `}}`
+/// produce literal braces.
+pub fn render_template(reminder: &Reminder, task: Option<&Task>) -> (String, Option<String>) {
+    (
+        interpolate(&reminder.title, task),
+        reminder
+            .description
+            .as_deref()
+            .map(|description| interpolate(description, task)),
+    )
+}
+
+/// Value of a known `task.*` placeholder, `None` for unknown names
+fn placeholder_value(name: &str, task: Option<&Task>) -> Option<String> {
+    let value = match name {
+        "task.title" => task.map(|t| t.title.clone()),
+        "task.description" => task.and_then(|t| t.description.clone()),
+        "task.due_date" => task
+            .and_then(|t| t.due_date)
+            .map(|due| due.format("%Y-%m-%d %H:%M UTC").to_string()),
+        "task.priority" => task.map(|t| t.priority.as_str().to_string()),
+        "task.status" => task.map(|t| t.status.as_str().to_string()),
+        _ => return None,
+    };
+    Some(value.unwrap_or_default())
+}
+
+fn interpolate(template: &str, task: Option<&Task>) -> String {
+    let mut out = String::with_capacity(template.len());
+    let mut rest = template;
+
+    while let Some(pos) = rest.find(['{', '}']) {
+        out.push_str(&rest[..pos]);
+        let brace = &rest[pos..pos + 1];
+        rest = &rest[pos + 1..];
+
+        // Doubled braces are escapes for a literal brace
+        if rest.starts_with(brace) {
+            out.push_str(brace);
+            rest = &rest[1..];
+            continue;
+        }
+        if brace == "}" {
+            out.push('}');
+            continue;
+        }
+
+        match rest.find('}') {
+            Some(end) => {
+                let name = &rest[..end];
+                match placeholder_value(name, task) {
+                    Some(value) => out.push_str(&value),
+                    None => {
+                        out.push('{');
+                        out.push_str(name);
+                        out.push('}');
+                    }
+                }
+                rest = &rest[end + 1..];
+            }
+            None => out.push('{'),
+        }
+    }
+
+    out.push_str(rest);
+    out
+}
+
+/// Reminders falling on one local calendar day
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ReminderDay {
+    pub date: NaiveDate,
+    pub reminders: Vec<Reminder>,
+}
+
 /// Create reminder DTO
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct CreateReminderDto {
@@ -197,14 +656,496 @@ pub struct CreateReminderDto {
     pub description: Option<String>,
     pub remind_at: DateTime<Utc>,
     pub repeat_interval: RepeatInterval,
+    /// Re-notify every this many minutes after a trigger until the linked task is done
+    #[serde(default)]
+    pub escalation_minutes: Option<i64>,
+    /// Defaults to an OS notification
+    #[serde(default)]
+    pub delivery: Option<ReminderDelivery>,
 }
 
 /// Update reminder DTO
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct UpdateReminderDto {
+    /// Re-link the reminder to another task
+    #[serde(default)]
+    pub task_id: Option<String>,
     pub title: Option<String>,
     pub description: Option<String>,
     pub remind_at: Option<DateTime<Utc>>,
     pub repeat_interval: Option<RepeatInterval>,
     pub is_active: Option<bool>,
+    #[serde(default)]
+    pub delivery: Option<ReminderDelivery>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::{Duration, TimeZone};
+
+    fn hourly(remind_at: DateTime<Utc>, last_triggered_at: Option<DateTime<Utc>>) -> Reminder {
+        Reminder {
+            id: "r1".to_string(),
+            task_id: None,
+            title: "Hourly".to_string(),
+            description: None,
+            remind_at,
+            repeat_interval: RepeatInterval::from_str("every_1_hour"),
+            is_active: true,
+            last_triggered_at,
+            created_at: remind_at,
+            updated_at: remind_at,
+            acknowledged_at: None,
+            escalation_minutes: None,
+            escalation_count: 0,
+            delivery: ReminderDelivery::Notification,
+        }
+    }
+
+    #[test]
+    fn test_repeat_interval_is_valid() {
+        for valid in [
+            "none",
+            "every_10_minutes",
+            "every_1_hour",
+            "after_2_days",
+            "every_1_year",
+            "weekdays_mon,wed,fri",
+            "monthly_day_last",
+        ] {
+            assert!(RepeatInterval::from_str(valid).is_valid(), "{}", valid);
+        }
+
+        for invalid in [
+            "",
+            "daily",
+            "every_10_minutess",
+            "every_0_hours",
+            "every_-1_days",
+            "sometimes_1_day",
+            "every_ten_minutes",
+            "every_10",
+            "every_9999999999_years",
+            "every_9223372036854775807_seconds",
+            "weekdays_funday",
+            "monthly_day_32",
+        ] {
+            assert!(!RepeatInterval::from_str(invalid).is_valid(), "{}", invalid);
+        }
+    }
+
+    #[test]
+    fn test_lead_description_picks_unit() {
+        let remind_at = Utc.with_ymd_and_hms(2024, 6, 1, 9, 0, 0).unwrap();
+        let reminder = hourly(remind_at, None);
+        let lead = |due: DateTime<Utc>| reminder.lead_description(Some(due), remind_at);
+
+        assert_eq!(
+            lead(remind_at + Duration::minutes(1)).as_deref(),
+            Some("in 1 minute")
+        );
+        assert_eq!(
+            lead(remind_at + Duration::minutes(45)).as_deref(),
+            Some("in 45 minutes")
+        );
+        assert_eq!(
+            lead(remind_at + Duration::minutes(150)).as_deref(),
+            Some("in 2 hours")
+        );
+        assert_eq!(
+            lead(remind_at + Duration::hours(24)).as_deref(),
+            Some("in 1 day")
+        );
+        assert_eq!(
+            lead(remind_at + Duration::days(3)).as_deref(),
+            Some("in 3 days")
+        );
+        assert_eq!(lead(remind_at).as_deref(), Some("now"));
+        assert_eq!(
+            lead(remind_at - Duration::hours(2)).as_deref(),
+            Some("2 hours ago")
+        );
+    }
+
+    #[test]
+    fn test_lead_description_without_due_date_or_when_late() {
+        let remind_at = Utc.with_ymd_and_hms(2024, 6, 1, 9, 0, 0).unwrap();
+        let reminder = hourly(remind_at, None);
+
+        assert_eq!(reminder.lead_description(None, remind_at), None);
+
+        // A reminder delivered late measures from now rather than its scheduled time
+        let due = remind_at + Duration::hours(3);
+        assert_eq!(
+            reminder
+                .lead_description(Some(due), remind_at + Duration::hours(2))
+                .as_deref(),
+            Some("in 1 hour")
+        );
+    }
+
+    #[test]
+    fn test_next_trigger_after_skips_missed_slots() {
+        let remind_at = Utc.with_ymd_and_hms(2024, 6, 1, 9, 0, 0).unwrap();
+        let now = remind_at + Duration::weeks(1) + Duration::minutes(30);
+        let reminder = hourly(remind_at, Some(remind_at));
+
+        // Advancing a single interval would still be a week in the past
+        assert!(reminder.next_trigger_time().unwrap() < now);
+        assert_eq!(
+            reminder.next_trigger_after(now),
+            Some(remind_at + Duration::weeks(1) + Duration::hours(1))
+        );
+    }
+
+    #[test]
+    fn test_next_trigger_after_is_strictly_in_the_future() {
+        let remind_at = Utc.with_ymd_and_hms(2024, 6, 1, 9, 0, 0).unwrap();
+        let reminder = hourly(remind_at, None);
+
+        let on_slot = remind_at + Duration::hours(3);
+        assert_eq!(
+            reminder.next_trigger_after(on_slot),
+            Some(remind_at + Duration::hours(4))
+        );
+        assert_eq!(
+            reminder.next_trigger_after(remind_at - Duration::minutes(5)),
+            Some(remind_at)
+        );
+    }
+
+    #[test]
+    fn test_should_repeat_now_uses_checked_step() {
+        let remind_at = Utc.with_ymd_and_hms(2024, 6, 1, 9, 0, 0).unwrap();
+        let mut reminder = hourly(remind_at, Some(remind_at));
+
+        assert!(!reminder.should_repeat_now(remind_at, remind_at + Duration::minutes(59)));
+        assert!(reminder.should_repeat_now(remind_at, remind_at + Duration::hours(1)));
+
+        for interval in [
+            "after_1_hour",
+            "every_9223372036854775807_seconds",
+            "every_9223372036854775807_months",
+            "every_1_fortnight",
+        ] {
+            reminder.repeat_interval = RepeatInterval::from_str(interval);
+            assert!(!reminder.should_repeat_now(remind_at, remind_at + Duration::days(400)));
+        }
+    }
+
+    #[test]
+    fn test_next_trigger_after_ignores_non_repeating() {
+        let remind_at = Utc.with_ymd_and_hms(2024, 6, 1, 9, 0, 0).unwrap();
+        let mut reminder = hourly(remind_at, None);
+        reminder.repeat_interval = RepeatInterval::none();
+
+        assert_eq!(
+            reminder.next_trigger_after(remind_at + Duration::days(1)),
+            None
+        );
+    }
+
+    #[test]
+    fn test_upcoming_trigger_by_state() {
+        let remind_at = Utc.with_ymd_and_hms(2024, 6, 1, 9, 0, 0).unwrap();
+
+        // Repeating: remind_at until the first trigger, then one step after the last
+        assert_eq!(hourly(remind_at, None).upcoming_trigger(), Some(remind_at));
+        assert_eq!(
+            hourly(remind_at, Some(remind_at)).upcoming_trigger(),
+            Some(remind_at + Duration::hours(1))
+        );
+
+        // Non-repeating: remind_at until triggered, then nothing
+        let mut once = hourly(remind_at, None);
+        once.repeat_interval = RepeatInterval::none();
+        assert_eq!(once.upcoming_trigger(), Some(remind_at));
+        once.last_triggered_at = Some(remind_at);
+        assert_eq!(once.upcoming_trigger(), None);
+
+        // Inactive: never
+        let mut inactive = hourly(remind_at, None);
+        inactive.is_active = false;
+        assert_eq!(inactive.upcoming_trigger(), None);
+
+        // Calendar: first matching slot on or after remind_at (2024-06-01 is a Saturday)
+        let mut weekly = hourly(remind_at, None);
+        weekly.repeat_interval = RepeatInterval::from_str("weekdays_mon");
+        assert_eq!(
+            weekly.upcoming_trigger(),
+            Some(Utc.with_ymd_and_hms(2024, 6, 3, 9, 0, 0).unwrap())
+        );
+    }
+
+    #[test]
+    fn test_weekdays_parsing_keeps_existing_formats() {
+        assert_eq!(
+            RepeatInterval::from_str("weekdays_mon,wed,fri").weekdays(),
+            Some(vec![Weekday::Mon, Weekday::Wed, Weekday::Fri])
+        );
+        assert_eq!(
+            RepeatInterval::from_str("weekdays_mon,funday").weekdays(),
+            None
+        );
+        assert_eq!(RepeatInterval::from_str("every_1_hour").weekdays(), None);
+        assert_eq!(
+            RepeatInterval::from_str("every_1_hour").parse(),
+            Some(("every".to_string(), 1, "hour".to_string()))
+        );
+        assert!(RepeatInterval::from_str("weekdays_mon").is_repeating());
+    }
+
+    #[test]
+    fn test_weekday_schedule_scheduled_tuesday_fires_wed_fri_mon() {
+        // 2024-06-04 is a Tuesday
+        let tuesday = Utc.with_ymd_and_hms(2024, 6, 4, 9, 0, 0).unwrap();
+        let wednesday = Utc.with_ymd_and_hms(2024, 6, 5, 9, 0, 0).unwrap();
+        let friday = Utc.with_ymd_and_hms(2024, 6, 7, 9, 0, 0).unwrap();
+        let monday = Utc.with_ymd_and_hms(2024, 6, 10, 9, 0, 0).unwrap();
+
+        let mut reminder = hourly(tuesday, None);
+        reminder.repeat_interval = RepeatInterval::from_str("weekdays_mon,wed,fri");
+
+        // Tuesday is not a listed day, so the first slot is Wednesday
+        assert_eq!(
+            reminder.next_trigger_after(tuesday - Duration::hours(1)),
+            Some(wednesday)
+        );
+
+        // Follow the notification loop: fire, then reschedule after the fired slot
+        let mut fired = Vec::new();
+        let mut now = wednesday;
+        for _ in 0..3 {
+            fired.push(now);
+            reminder.last_triggered_at = Some(now);
+            assert_eq!(
+                reminder.next_trigger_time(),
+                reminder.next_trigger_after(now)
+            );
+            reminder.remind_at = reminder.next_trigger_after(now).unwrap();
+            now = reminder.remind_at;
+        }
+        assert_eq!(fired, vec![wednesday, friday, monday]);
+        assert_eq!(reminder.remind_at.weekday(), Weekday::Wed);
+    }
+
+    #[test]
+    fn test_weekday_schedule_is_due_only_on_listed_days() {
+        let now = Utc::now();
+        let mut reminder = hourly(now - Duration::minutes(1), None);
+
+        reminder.repeat_interval =
+            RepeatInterval::from_str(&format!("weekdays_{}", now.weekday().succ()));
+        assert!(!reminder.is_due());
+
+        reminder.repeat_interval =
+            RepeatInterval::from_str(&format!("weekdays_{}", reminder.remind_at.weekday()));
+        assert!(reminder.is_due());
+
+        // Fired at this slot, so not due again until the same weekday next week
+        reminder.last_triggered_at = Some(reminder.remind_at);
+        assert!(!reminder.is_due());
+    }
+
+    #[test]
+    fn test_business_day_schedule_skips_weekend() {
+        // 2024-06-07 is a Friday
+        let friday = Utc.with_ymd_and_hms(2024, 6, 7, 9, 0, 0).unwrap();
+        let monday = Utc.with_ymd_and_hms(2024, 6, 10, 9, 0, 0).unwrap();
+
+        for interval in ["weekdays", "every_1_businessday"] {
+            let mut reminder = hourly(friday, Some(friday));
+            reminder.repeat_interval = RepeatInterval::from_str(interval);
+            assert!(reminder.repeat_interval.is_valid());
+            assert_eq!(reminder.repeat_interval.parse(), None);
+
+            assert_eq!(reminder.next_trigger_time(), Some(monday));
+            assert_eq!(reminder.next_trigger_after(friday), Some(monday));
+
+            let saturday = friday + Duration::days(1);
+            assert!(!reminder.should_repeat_now(friday, saturday));
+            assert!(!reminder.should_repeat_now(friday, saturday + Duration::days(1)));
+            assert!(reminder.should_repeat_now(friday, monday));
+        }
+    }
+
+    fn monthly(remind_at: DateTime<Utc>, interval: &str) -> Reminder {
+        let mut reminder = hourly(remind_at, None);
+        reminder.repeat_interval = RepeatInterval::from_str(interval);
+        reminder
+    }
+
+    /// Follow the notification loop from `remind_at`, collecting the next `count` slot dates
+    fn upcoming_dates(mut reminder: Reminder, count: usize) -> Vec<NaiveDate> {
+        (0..count)
+            .map(|_| {
+                let fired = reminder.remind_at;
+                reminder.last_triggered_at = Some(fired);
+                assert_eq!(
+                    reminder.next_trigger_time(),
+                    reminder.next_trigger_after(fired)
+                );
+                reminder.remind_at = reminder.next_trigger_after(fired).unwrap();
+                reminder.remind_at.date_naive()
+            })
+            .collect()
+    }
+
+    fn ymd(year: i32, month: u32, day: u32) -> NaiveDate {
+        NaiveDate::from_ymd_opt(year, month, day).unwrap()
+    }
+
+    #[test]
+    fn test_next_escalation_at_spacing_and_overflow() {
+        let fired = Utc.with_ymd_and_hms(2024, 7, 1, 9, 0, 0).unwrap();
+        let mut reminder = hourly(fired, Some(fired));
+        reminder.escalation_minutes = Some(15);
+        reminder.escalation_count = 1;
+        assert_eq!(
+            reminder.next_escalation_at(),
+            Some(fired + Duration::minutes(30))
+        );
+
+        reminder.escalation_minutes = Some(i64::MAX);
+        assert_eq!(reminder.next_escalation_at(), None);
+        reminder.escalation_minutes = Some(1 << 40);
+        assert_eq!(reminder.next_escalation_at(), None);
+    }
+
+    #[test]
+    fn test_month_day_parsing() {
+        assert_eq!(
+            RepeatInterval::from_str("monthly_day_15").month_day(),
+            Some(MonthDay::Day(15))
+        );
+        assert_eq!(
+            RepeatInterval::from_str("monthly_day_last").month_day(),
+            Some(MonthDay::Last)
+        );
+        assert_eq!(RepeatInterval::from_str("monthly_day_0").month_day(), None);
+        assert_eq!(RepeatInterval::from_str("monthly_day_32").month_day(), None);
+        assert_eq!(RepeatInterval::from_str("every_1_month").month_day(), None);
+        assert!(RepeatInterval::from_str("monthly_day_last").is_calendar());
+        assert!(!RepeatInterval::from_str("every_1_month").is_calendar());
+    }
+
+    fn linked_task() -> Task {
+        let now = Utc::now();
+        Task {
+            id: "task-1".to_string(),
+            title: "Quarterly review".to_string(),
+            description: None,
+            status: crate::models::TaskStatus::InProgress,
+            priority: crate::models::TaskPriority::High,
+            due_date: Some(Utc.with_ymd_and_hms(2024, 7, 1, 15, 30, 0).unwrap()),
+            completed_at: None,
+            image_path: None,
+            notes: None,
+            estimated_minutes: None,
+            actual_minutes: None,
+            parent_id: None,
+            sort_order: 0,
+            checklist_progress: None,
+            color: None,
+            is_pinned: false,
+            series_id: None,
+            is_deferred: false,
+            deferred_until: None,
+            completion_note: None,
+            created_at: now,
+            updated_at: now,
+            tags: vec![],
+        }
+    }
+
+    fn templated(title: &str, description: Option<&str>) -> Reminder {
+        let mut reminder = hourly(Utc::now(), None);
+        reminder.title = title.to_string();
+        reminder.description = description.map(str::to_string);
+        reminder
+    }
+
+    #[test]
+    fn test_render_template_substitutes_task_fields() {
+        let reminder = templated(
+            "Prepare {task.title}",
+            Some("Due {task.due_date} ({task.priority}, {task.status}){task.description}"),
+        );
+
+        let (title, body) = render_template(&reminder, Some(&linked_task()));
+        assert_eq!(title, "Prepare Quarterly review");
+        assert_eq!(
+            body.as_deref(),
+            Some("Due 2024-07-01 15:30 UTC (high, in_progress)")
+        );
+    }
+
+    #[test]
+    fn test_render_template_without_task_leaves_placeholders_empty() {
+        let reminder = templated("Prepare {task.title}", None);
+
+        let (title, body) = render_template(&reminder, None);
+        assert_eq!(title, "Prepare ");
+        assert_eq!(body, None);
+    }
+
+    #[test]
+    fn test_render_template_keeps_unknown_placeholders_and_escaped_braces() {
+        let reminder = templated(
+            "{{task.title}} is {task.title}",
+            Some("{task.owner} {unclosed} }} {x"),
+        );
+
+        let (title, body) = render_template(&reminder, Some(&linked_task()));
+        assert_eq!(title, "{task.title} is Quarterly review");
+        assert_eq!(body.as_deref(), Some("{task.owner} {unclosed} } {x"));
+    }
+
+    #[test]
+    fn test_month_day_31_clamps_across_february_and_short_months() {
+        let remind_at = Utc.with_ymd_and_hms(2024, 1, 31, 9, 0, 0).unwrap();
+        let reminder = monthly(remind_at, "monthly_day_31");
+
+        assert_eq!(
+            upcoming_dates(reminder, 4),
+            vec![
+                ymd(2024, 2, 29),
+                ymd(2024, 3, 31),
+                ymd(2024, 4, 30),
+                ymd(2024, 5, 31)
+            ]
+        );
+    }
+
+    #[test]
+    fn test_month_day_last_resolves_per_month() {
+        let remind_at = Utc.with_ymd_and_hms(2023, 1, 31, 18, 30, 0).unwrap();
+        let reminder = monthly(remind_at, "monthly_day_last");
+
+        let slots = upcoming_dates(reminder.clone(), 3);
+        assert_eq!(
+            slots,
+            vec![ymd(2023, 2, 28), ymd(2023, 3, 31), ymd(2023, 4, 30)]
+        );
+
+        // The time of day is kept
+        let next = reminder.next_trigger_after(remind_at).unwrap();
+        assert_eq!(next, Utc.with_ymd_and_hms(2023, 2, 28, 18, 30, 0).unwrap());
+    }
+
+    #[test]
+    fn test_month_day_scheduled_after_target_starts_next_month() {
+        let remind_at = Utc.with_ymd_and_hms(2024, 6, 20, 9, 0, 0).unwrap();
+        let reminder = monthly(remind_at, "monthly_day_15");
+
+        assert_eq!(
+            reminder.next_trigger_after(remind_at - Duration::days(1)),
+            Some(Utc.with_ymd_and_hms(2024, 7, 15, 9, 0, 0).unwrap())
+        );
+        assert_eq!(
+            reminder.next_trigger_after(Utc.with_ymd_and_hms(2024, 7, 15, 9, 0, 0).unwrap()),
+            Some(Utc.with_ymd_and_hms(2024, 8, 15, 9, 0, 0).unwrap())
+        );
+    }
 }
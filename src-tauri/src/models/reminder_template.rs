@@ -0,0 +1,37 @@
+use super::RepeatInterval;
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+/// Preset for quickly creating a reminder a fixed time from now
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ReminderTemplate {
+    pub id: String,
+    pub name: String,
+    pub title: String,
+    pub description: Option<String>,
+    pub repeat_interval: RepeatInterval,
+    /// Minutes from creation until the reminder fires
+    pub default_offset_minutes: i64,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+}
+
+/// Create reminder template DTO
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CreateReminderTemplateDto {
+    pub name: String,
+    pub title: String,
+    pub description: Option<String>,
+    pub repeat_interval: RepeatInterval,
+    pub default_offset_minutes: i64,
+}
+
+/// Update reminder template DTO
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct UpdateReminderTemplateDto {
+    pub name: Option<String>,
+    pub title: Option<String>,
+    pub description: Option<String>,
+    pub repeat_interval: Option<RepeatInterval>,
+    pub default_offset_minutes: Option<i64>,
+}
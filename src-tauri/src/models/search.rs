@@ -0,0 +1,63 @@
+use super::{Reminder, Task};
+use serde::{Deserialize, Serialize};
+use unicode_normalization::char::is_combining_mark;
+use unicode_normalization::UnicodeNormalization;
+
+/// Fold text for case- and accent-insensitive matching ("Café" -> "cafe")
+///
+/// Lowercases, decomposes and drops combining marks. `đ` has no decomposition
+/// and is mapped to `d` explicitly. Also registered as the SQL function `fold`.
+pub fn fold_for_search(text: &str) -> String {
+    text.nfd()
+        .filter(|c| !is_combining_mark(*c))
+        .flat_map(char::to_lowercase)
+        .map(|c| if c == 'đ' { 'd' } else { c })
+        .collect()
+}
+
+/// Fold `query` and wrap it in `%` for a substring `LIKE ... ESCAPE '\'` match
+///
+/// `%`, `_` and `\` in the query are escaped so they match literally.
+pub fn like_pattern(query: &str) -> String {
+    format!("%{}%", escape_like(&fold_for_search(query)))
+}
+
+/// Escape the `LIKE` wildcards in `text`, using `\` as the escape character
+fn escape_like(text: &str) -> String {
+    let mut escaped = String::with_capacity(text.len());
+    for c in text.chars() {
+        if matches!(c, '%' | '_' | '\\') {
+            escaped.push('\\');
+        }
+        escaped.push(c);
+    }
+    escaped
+}
+
+/// Matches for a single search query across tasks and reminders
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct GlobalSearchResult {
+    pub tasks: Vec<Task>,
+    pub reminders: Vec<Reminder>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_fold_for_search_strips_case_and_accents() {
+        assert_eq!(fold_for_search("Café Meeting"), "cafe meeting");
+        assert_eq!(fold_for_search("Crème BRÛLÉE"), "creme brulee");
+        assert_eq!(fold_for_search("Đặt lịch họp"), "dat lich hop");
+        assert_eq!(fold_for_search("plain"), "plain");
+    }
+
+    #[test]
+    fn test_like_pattern_escapes_wildcards() {
+        assert_eq!(like_pattern("Café"), "%cafe%");
+        assert_eq!(like_pattern("100%"), "%100\\%%");
+        assert_eq!(like_pattern("a_b"), "%a\\_b%");
+        assert_eq!(like_pattern("C:\\temp"), "%c:\\\\temp%");
+    }
+}
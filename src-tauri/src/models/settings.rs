@@ -1,5 +1,33 @@
+use chrono::{DateTime, Datelike, Duration, FixedOffset, NaiveTime, Utc, Weekday};
 use serde::{Deserialize, Serialize};
 
+/// Accepted values for the `theme` setting
+pub const SUPPORTED_THEMES: &[&str] = &["system", "light", "dark"];
+
+/// Accepted values for the `language` setting
+pub const SUPPORTED_LANGUAGES: &[&str] = &["en", "vi"];
+
+/// Accepted values for the `first_day_of_week` setting
+pub const SUPPORTED_FIRST_DAYS_OF_WEEK: &[&str] = &["monday", "sunday"];
+
+/// Accepted values for the `digest_frequency` setting
+pub const SUPPORTED_DIGEST_FREQUENCIES: &[&str] = &["off", "daily", "weekly"];
+
+/// Accepted values for the `closed_task_reminders` setting
+pub const SUPPORTED_CLOSED_TASK_REMINDER_MODES: &[&str] = &["fire", "skip", "deactivate"];
+
+/// Format of the `digest_time` setting (local time of day)
+pub const DIGEST_TIME_FORMAT: &str = "%H:%M";
+
+/// Upper bound for the `overdue_grace_minutes` setting (one day)
+pub const MAX_OVERDUE_GRACE_MINUTES: i64 = 1440;
+
+/// Upper bound for the `auto_reminder_lead_minutes` setting (30 days)
+pub const MAX_AUTO_REMINDER_LEAD_MINUTES: i64 = 43_200;
+
+/// Default for the `max_import_rows` setting
+pub const DEFAULT_MAX_IMPORT_ROWS: usize = 10_000;
+
 /// Settings key-value pair stored in the database
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Setting {
@@ -9,13 +37,55 @@ pub struct Setting {
 }
 
 /// Application settings
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct AppSettings {
     pub theme: String,
     pub language: String,
     pub notification_sound: bool,
     pub show_completed_tasks: bool,
     pub default_task_priority: String,
+    /// Minutes before a task's due date to create an automatic reminder (`None` disables it)
+    #[serde(default)]
+    pub auto_reminder_lead_minutes: Option<i64>,
+    /// Day weeks start on for "this week" ranges: "monday" or "sunday"
+    #[serde(default = "default_first_day_of_week")]
+    pub first_day_of_week: String,
+    /// Minutes past a task's due date before it counts as overdue
+    #[serde(default)]
+    pub overdue_grace_minutes: i64,
+    /// Most rows a single import may contain; larger inputs are rejected before writing
+    #[serde(default = "default_max_import_rows")]
+    pub max_import_rows: usize,
+    /// Summary of due tasks: "off", "daily", or "weekly" (sent on `first_day_of_week`)
+    #[serde(default = "default_digest_frequency")]
+    pub digest_frequency: String,
+    /// Local time of day ("HH:MM") the digest is sent at
+    #[serde(default = "default_digest_time")]
+    pub digest_time: String,
+    /// Due reminders whose task is completed or cancelled: "fire" them anyway,
+    /// "skip" them (they fire again if the task is reopened), or "deactivate" them
+    #[serde(default = "default_closed_task_reminders")]
+    pub closed_task_reminders: String,
+}
+
+fn default_first_day_of_week() -> String {
+    "monday".to_string()
+}
+
+fn default_max_import_rows() -> usize {
+    DEFAULT_MAX_IMPORT_ROWS
+}
+
+fn default_digest_frequency() -> String {
+    "off".to_string()
+}
+
+fn default_digest_time() -> String {
+    "08:00".to_string()
+}
+
+fn default_closed_task_reminders() -> String {
+    "fire".to_string()
 }
 
 impl Default for AppSettings {
@@ -26,6 +96,13 @@ impl Default for AppSettings {
             notification_sound: true,
             show_completed_tasks: false,
             default_task_priority: "medium".to_string(),
+            auto_reminder_lead_minutes: None,
+            first_day_of_week: default_first_day_of_week(),
+            overdue_grace_minutes: 0,
+            max_import_rows: default_max_import_rows(),
+            digest_frequency: default_digest_frequency(),
+            digest_time: default_digest_time(),
+            closed_task_reminders: default_closed_task_reminders(),
         }
     }
 }
@@ -48,9 +125,77 @@ impl AppSettings {
                 "default_task_priority".to_string(),
                 self.default_task_priority.clone(),
             ),
+            (
+                "auto_reminder_lead_minutes".to_string(),
+                self.auto_reminder_lead_minutes
+                    .map(|m| m.to_string())
+                    .unwrap_or_default(),
+            ),
+            (
+                "first_day_of_week".to_string(),
+                self.first_day_of_week.clone(),
+            ),
+            (
+                "overdue_grace_minutes".to_string(),
+                self.overdue_grace_minutes.to_string(),
+            ),
+            (
+                "max_import_rows".to_string(),
+                self.max_import_rows.to_string(),
+            ),
+            (
+                "digest_frequency".to_string(),
+                self.digest_frequency.clone(),
+            ),
+            ("digest_time".to_string(), self.digest_time.clone()),
+            (
+                "closed_task_reminders".to_string(),
+                self.closed_task_reminders.clone(),
+            ),
         ]
     }
 
+    /// Weekday that starts a week, per `first_day_of_week` (Monday unless set to Sunday)
+    pub fn week_start(&self) -> Weekday {
+        match self.first_day_of_week.as_str() {
+            "sunday" => Weekday::Sun,
+            _ => Weekday::Mon,
+        }
+    }
+
+    /// Grace period before a past-due task counts as overdue
+    pub fn overdue_grace(&self) -> Duration {
+        Duration::minutes(self.overdue_grace_minutes.max(0))
+    }
+
+    /// When the digest is due on `now`'s local day, `None` if none is sent that day
+    pub fn digest_slot(&self, now: DateTime<FixedOffset>) -> Option<DateTime<Utc>> {
+        match self.digest_frequency.as_str() {
+            "daily" => {}
+            "weekly" if now.weekday() == self.week_start() => {}
+            _ => return None,
+        }
+
+        let time = NaiveTime::parse_from_str(&self.digest_time, DIGEST_TIME_FORMAT).ok()?;
+        now.date_naive()
+            .and_time(time)
+            .and_local_timezone(*now.offset())
+            .single()
+            .map(|slot| slot.with_timezone(&Utc))
+    }
+
+    /// Whether the digest should go out at `now`: today's slot has passed and
+    /// nothing was sent since it, so a digest missed while the app was closed
+    /// still arrives later that day, but only once
+    pub fn should_send_digest(
+        &self,
+        now: DateTime<FixedOffset>,
+        last_sent: Option<DateTime<Utc>>,
+    ) -> bool {
+        self.digest_slot(now)
+            .is_some_and(|slot| now >= slot && last_sent.is_none_or(|sent| sent < slot))
+    }
+
     /// Create settings from key-value pairs
     pub fn from_key_value_pairs(pairs: Vec<Setting>) -> Self {
         let mut settings = Self::default();
@@ -66,6 +211,24 @@ impl AppSettings {
                     settings.show_completed_tasks = setting.value.parse().unwrap_or(false)
                 }
                 "default_task_priority" => settings.default_task_priority = setting.value,
+                "auto_reminder_lead_minutes" => {
+                    settings.auto_reminder_lead_minutes = setting.value.parse().ok()
+                }
+                "first_day_of_week" => settings.first_day_of_week = setting.value,
+                "overdue_grace_minutes" => {
+                    settings.overdue_grace_minutes = setting.value.parse().unwrap_or(0)
+                }
+                "max_import_rows" => {
+                    settings.max_import_rows = setting
+                        .value
+                        .parse()
+                        .ok()
+                        .filter(|rows| *rows > 0)
+                        .unwrap_or_else(default_max_import_rows)
+                }
+                "digest_frequency" => settings.digest_frequency = setting.value,
+                "digest_time" => settings.digest_time = setting.value,
+                "closed_task_reminders" => settings.closed_task_reminders = setting.value,
                 _ => {}
             }
         }
@@ -73,3 +236,76 @@ impl AppSettings {
         settings
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::TimeZone;
+
+    fn digest(frequency: &str, time: &str) -> AppSettings {
+        AppSettings {
+            digest_frequency: frequency.to_string(),
+            digest_time: time.to_string(),
+            ..AppSettings::default()
+        }
+    }
+
+    /// 2024-06-03 (a Monday) at `hour:minute` in UTC+7
+    fn monday_at(hour: u32, minute: u32) -> DateTime<FixedOffset> {
+        FixedOffset::east_opt(7 * 3600)
+            .unwrap()
+            .with_ymd_and_hms(2024, 6, 3, hour, minute, 0)
+            .unwrap()
+    }
+
+    #[test]
+    fn test_digest_off_never_sends() {
+        let settings = digest("off", "08:00");
+        assert!(!settings.should_send_digest(monday_at(9, 0), None));
+    }
+
+    #[test]
+    fn test_daily_digest_waits_for_its_local_time() {
+        let settings = digest("daily", "08:00");
+
+        assert!(!settings.should_send_digest(monday_at(7, 59), None));
+        assert!(settings.should_send_digest(monday_at(8, 0), None));
+        assert!(settings.should_send_digest(monday_at(23, 0), None));
+        assert_eq!(
+            settings.digest_slot(monday_at(12, 0)),
+            Some(Utc.with_ymd_and_hms(2024, 6, 3, 1, 0, 0).unwrap())
+        );
+    }
+
+    #[test]
+    fn test_daily_digest_sent_once_per_day() {
+        let settings = digest("daily", "08:00");
+        let sent = monday_at(8, 0).with_timezone(&Utc);
+
+        assert!(!settings.should_send_digest(monday_at(8, 30), Some(sent)));
+        // Yesterday's digest does not hold back today's
+        let yesterday = sent - Duration::days(1);
+        assert!(settings.should_send_digest(monday_at(8, 30), Some(yesterday)));
+        // Nor does one sent earlier today, before the slot was moved later
+        let early = monday_at(6, 0).with_timezone(&Utc);
+        assert!(settings.should_send_digest(monday_at(8, 30), Some(early)));
+    }
+
+    #[test]
+    fn test_weekly_digest_only_on_first_day_of_week() {
+        let mut settings = digest("weekly", "08:00");
+
+        assert!(settings.should_send_digest(monday_at(9, 0), None));
+        assert!(!settings.should_send_digest(monday_at(9, 0) + Duration::days(1), None));
+
+        settings.first_day_of_week = "sunday".to_string();
+        assert!(!settings.should_send_digest(monday_at(9, 0), None));
+        assert!(settings.should_send_digest(monday_at(9, 0) - Duration::days(1), None));
+    }
+
+    #[test]
+    fn test_digest_with_invalid_time_never_sends() {
+        let settings = digest("daily", "8 o'clock");
+        assert!(!settings.should_send_digest(monday_at(23, 0), None));
+    }
+}
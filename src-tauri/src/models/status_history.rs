@@ -0,0 +1,13 @@
+use super::TaskStatus;
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+/// A single recorded status transition of a task
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TaskStatusChange {
+    pub id: String,
+    pub task_id: String,
+    pub from_status: TaskStatus,
+    pub to_status: TaskStatus,
+    pub changed_at: DateTime<Utc>,
+}
@@ -1,6 +1,10 @@
-use chrono::{DateTime, Utc};
+use super::{ChecklistItem, Reminder};
+use chrono::{DateTime, Duration, Utc};
 use serde::{Deserialize, Serialize};
 
+/// Maximum length of a completion note, in characters
+pub const MAX_COMPLETION_NOTE_LENGTH: usize = 1000;
+
 /// Task status enumeration
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
 #[serde(rename_all = "snake_case")]
@@ -92,6 +96,36 @@ impl TaskPriority {
             _ => None,
         }
     }
+
+    /// Every priority, from least to most important
+    pub fn all() -> [TaskPriority; 4] {
+        [
+            TaskPriority::Low,
+            TaskPriority::Medium,
+            TaskPriority::High,
+            TaskPriority::Urgent,
+        ]
+    }
+
+    /// Numeric importance (Low=0 .. Urgent=3) for sorting and analytics
+    pub fn weight(&self) -> u8 {
+        match self {
+            TaskPriority::Low => 0,
+            TaskPriority::Medium => 1,
+            TaskPriority::High => 2,
+            TaskPriority::Urgent => 3,
+        }
+    }
+
+    /// Color shown for a task that has neither its own color nor a tag
+    pub fn default_color(&self) -> &'static str {
+        match self {
+            TaskPriority::Low => "#9ca3af",
+            TaskPriority::Medium => "#3b82f6",
+            TaskPriority::High => "#f97316",
+            TaskPriority::Urgent => "#ef4444",
+        }
+    }
 }
 
 /// Task entity
@@ -108,6 +142,33 @@ pub struct Task {
     pub notes: Option<String>,
     pub estimated_minutes: Option<i32>,
     pub actual_minutes: Option<i32>,
+    /// Parent task when this task is a subtask
+    #[serde(default)]
+    pub parent_id: Option<String>,
+    /// Manual position used for drag-and-drop ordering (lower comes first)
+    #[serde(default)]
+    pub sort_order: i64,
+    /// Share of checklist items done (0.0 - 1.0), `None` when the task has no checklist
+    #[serde(default)]
+    pub checklist_progress: Option<f32>,
+    /// Accent color as a hex string (e.g. "#3b82f6"), independent of tags
+    #[serde(default)]
+    pub color: Option<String>,
+    /// Pinned tasks are listed before all others
+    #[serde(default)]
+    pub is_pinned: bool,
+    /// Shared by the occurrences of a recurring task
+    #[serde(default)]
+    pub series_id: Option<String>,
+    /// Parked as "someday/maybe": hidden from default listings and never overdue
+    #[serde(default)]
+    pub is_deferred: bool,
+    /// When a deferred task automatically returns to the active lists
+    #[serde(default)]
+    pub deferred_until: Option<DateTime<Utc>>,
+    /// Outcome recorded when the task was marked done
+    #[serde(default)]
+    pub completion_note: Option<String>,
     pub created_at: DateTime<Utc>,
     pub updated_at: DateTime<Utc>,
     #[serde(default)]
@@ -116,10 +177,13 @@ pub struct Task {
 
 impl Task {
     /// Check if the task is overdue based on current time
-    pub fn is_overdue(&self) -> bool {
+    ///
+    /// A task only counts as overdue once `grace` has passed since its due date.
+    /// Deferred tasks are never overdue.
+    pub fn is_overdue(&self, grace: Duration) -> bool {
         if let Some(due_date) = self.due_date {
             // Task is overdue if it has a due date in the past and is not completed/cancelled
-            Utc::now() > due_date && !self.status.is_terminal()
+            Utc::now() > due_date + grace && !self.status.is_terminal() && !self.is_deferred
         } else {
             false
         }
@@ -135,11 +199,11 @@ impl Task {
         !self.status.is_terminal()
     }
 
-    /// Get the effective status considering overdue state
-    pub fn effective_status(&self) -> EffectiveTaskStatus {
+    /// Get the effective status considering overdue state, see `is_overdue`
+    pub fn effective_status(&self, overdue_grace: Duration) -> EffectiveTaskStatus {
         if self.is_completed() {
             EffectiveTaskStatus::Done
-        } else if self.is_overdue() {
+        } else if self.is_overdue(overdue_grace) {
             EffectiveTaskStatus::Overdue
         } else {
             match self.status {
@@ -215,6 +279,219 @@ pub enum EffectiveTaskStatus {
     Cancelled,
 }
 
+/// A task with everything the detail view shows alongside it
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TaskDetail {
+    pub task: Task,
+    /// Ordered by `remind_at`
+    pub reminders: Vec<Reminder>,
+    pub subtasks: Vec<Task>,
+    pub checklist: Vec<ChecklistItem>,
+}
+
+/// Task as returned to the frontend, with display fields computed on the way out
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TaskView {
+    #[serde(flatten)]
+    pub task: Task,
+    /// The task's own color, else its first tag's color, else a color for its priority
+    pub display_color: String,
+}
+
+impl From<Task> for TaskView {
+    fn from(task: Task) -> Self {
+        let display_color = task
+            .color
+            .clone()
+            .or_else(|| task.tags.first().map(|tag| tag.color.clone()))
+            .unwrap_or_else(|| task.priority.default_color().to_string());
+
+        TaskView {
+            task,
+            display_color,
+        }
+    }
+}
+
+/// Lightweight task row for list views, without tags, notes or checklist
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TaskSummary {
+    pub id: String,
+    pub title: String,
+    pub status: TaskStatus,
+    pub priority: TaskPriority,
+    pub due_date: Option<DateTime<Utc>>,
+    pub tag_count: u32,
+}
+
+/// Task enriched with its computed completion progress
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TaskWithProgress {
+    #[serde(flatten)]
+    pub task: Task,
+    /// Completion ratio between 0.0 and 1.0
+    pub progress: f32,
+}
+
+/// Total estimated and actual minutes across a set of tasks
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct TimeSummary {
+    pub estimated_minutes: i64,
+    pub actual_minutes: i64,
+}
+
+/// Estimate accuracy of one completed task
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct EstimationEntry {
+    pub id: String,
+    pub title: String,
+    pub estimated_minutes: i32,
+    pub actual_minutes: i32,
+    /// How far `actual_minutes` was off the estimate, in percent (positive means over)
+    pub variance_pct: f32,
+}
+
+impl EstimationEntry {
+    /// Build the entry, computing the variance from the two minute counts
+    pub fn new(id: String, title: String, estimated_minutes: i32, actual_minutes: i32) -> Self {
+        let variance_pct =
+            (actual_minutes - estimated_minutes) as f32 / estimated_minutes as f32 * 100.0;
+        Self {
+            id,
+            title,
+            estimated_minutes,
+            actual_minutes,
+            variance_pct,
+        }
+    }
+}
+
+/// Estimate accuracy over recently completed tasks
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct EstimationReport {
+    /// Most recently completed first
+    pub entries: Vec<EstimationEntry>,
+    /// Mean of the entries' `variance_pct`, `None` when there are no entries
+    pub average_variance_pct: Option<f32>,
+}
+
+impl EstimationReport {
+    /// Build the report, averaging the entries' variances
+    pub fn new(entries: Vec<EstimationEntry>) -> Self {
+        let average_variance_pct = (!entries.is_empty()).then(|| {
+            entries.iter().map(|entry| entry.variance_pct).sum::<f32>() / entries.len() as f32
+        });
+        Self {
+            entries,
+            average_variance_pct,
+        }
+    }
+}
+
+/// Tasks changed and deleted after a point in time, for incremental refreshes
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TaskDelta {
+    /// Created or updated tasks, least recently updated first
+    pub tasks: Vec<Task>,
+    /// Tasks deleted since then (including cascaded subtasks), as far back as the undo log goes
+    pub deleted_ids: Vec<String>,
+    /// When the delta was taken; pass it as `since` on the next request
+    pub as_of: DateTime<Utc>,
+}
+
+/// A filter value together with the number of tasks that have it
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct FacetCount<T> {
+    pub value: T,
+    pub count: u32,
+}
+
+impl<T> From<(T, u32)> for FacetCount<T> {
+    fn from((value, count): (T, u32)) -> Self {
+        FacetCount { value, count }
+    }
+}
+
+/// Values actually in use, for populating filter dropdowns
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct FilterFacets {
+    pub statuses: Vec<FacetCount<TaskStatus>>,
+    pub priorities: Vec<FacetCount<TaskPriority>>,
+    pub tags: Vec<FacetCount<Tag>>,
+}
+
+/// Completed task with a non-fatal warning when it took longer than estimated
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct MarkDoneResult {
+    #[serde(flatten)]
+    pub task: Task,
+    /// `actual_minutes / estimated_minutes`, when both are known
+    pub overrun_ratio: Option<f32>,
+    /// Set when the overrun ratio is above 1.0
+    pub warning: Option<String>,
+}
+
+impl MarkDoneResult {
+    /// Build the result, computing the overrun ratio from the task's minutes
+    pub fn new(task: Task) -> Self {
+        let overrun_ratio = match (task.actual_minutes, task.estimated_minutes) {
+            (Some(actual), Some(estimated)) if estimated > 0 => {
+                Some(actual as f32 / estimated as f32)
+            }
+            _ => None,
+        };
+        let warning = overrun_ratio
+            .filter(|ratio| *ratio > 1.0)
+            .map(|ratio| format!("Took {:.1}x longer than estimated", ratio));
+
+        Self {
+            task,
+            overrun_ratio,
+            warning,
+        }
+    }
+}
+
+/// Updated task with any reminders left scheduled after its new due date
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct UpdateTaskResult {
+    #[serde(flatten)]
+    pub task: Task,
+    /// Active reminders still scheduled after the due date
+    pub late_reminder_ids: Vec<String>,
+    /// Reminders moved back to the due date
+    pub shifted_reminder_ids: Vec<String>,
+    /// Set when reminders are left scheduled after the due date
+    pub warning: Option<String>,
+    /// Other occurrences of the task's series changed by an `EditScope::ThisAndFuture` edit
+    #[serde(default)]
+    pub updated_occurrence_ids: Vec<String>,
+    /// Requested tag ids with no matching tag; these were skipped
+    #[serde(default)]
+    pub invalid_tag_ids: Vec<String>,
+}
+
+/// Outcome of creating a task
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CreateTaskResult {
+    #[serde(flatten)]
+    pub task: Task,
+    /// Requested tag ids with no matching tag; these were skipped
+    #[serde(default)]
+    pub invalid_tag_ids: Vec<String>,
+}
+
+/// Which occurrences of a recurring task an edit applies to
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum EditScope {
+    /// Only the edited occurrence
+    #[default]
+    ThisOnly,
+    /// The edited occurrence and every other open occurrence of its series
+    ThisAndFuture,
+}
+
 /// Tag entity
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Tag {
@@ -224,6 +501,24 @@ pub struct Tag {
     pub created_at: DateTime<Utc>,
 }
 
+impl Tag {
+    /// Check that `color` is a hex color in `#rgb` or `#rrggbb` form
+    pub fn is_valid_color(color: &str) -> bool {
+        color.strip_prefix('#').is_some_and(|hex| {
+            matches!(hex.len(), 3 | 6) && hex.chars().all(|c| c.is_ascii_hexdigit())
+        })
+    }
+}
+
+/// Outcome of replacing a task's tags
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TagAssignment {
+    /// Tags now applied to the task, by name
+    pub tags: Vec<Tag>,
+    /// Requested ids with no matching tag; these were skipped
+    pub invalid_tag_ids: Vec<String>,
+}
+
 /// Create task DTO (Data Transfer Object)
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct CreateTaskDto {
@@ -235,6 +530,12 @@ pub struct CreateTaskDto {
     pub notes: Option<String>,
     pub estimated_minutes: Option<i32>,
     pub tag_ids: Vec<String>,
+    /// Create the task as a subtask of this parent
+    #[serde(default)]
+    pub parent_id: Option<String>,
+    /// Accent color as a hex string
+    #[serde(default)]
+    pub color: Option<String>,
 }
 
 /// Update task DTO
@@ -250,6 +551,32 @@ pub struct UpdateTaskDto {
     pub estimated_minutes: Option<i32>,
     pub actual_minutes: Option<i32>,
     pub tag_ids: Option<Vec<String>>,
+    #[serde(default)]
+    pub color: Option<String>,
+    /// Optional fields to set to null, since `None` above means "leave unchanged"
+    #[serde(default)]
+    pub clear_fields: Vec<String>,
+}
+
+impl UpdateTaskDto {
+    /// Whether `field` is listed in `clear_fields`
+    pub fn clears(&self, field: &str) -> bool {
+        self.clear_fields.iter().any(|f| f == field)
+    }
+
+    /// Whether a new value is given for a clearable `field`; `None` if it can't be cleared
+    pub fn sets_clearable(&self, field: &str) -> Option<bool> {
+        match field {
+            "description" => Some(self.description.is_some()),
+            "due_date" => Some(self.due_date.is_some()),
+            "image_path" => Some(self.image_path.is_some()),
+            "notes" => Some(self.notes.is_some()),
+            "estimated_minutes" => Some(self.estimated_minutes.is_some()),
+            "actual_minutes" => Some(self.actual_minutes.is_some()),
+            "color" => Some(self.color.is_some()),
+            _ => None,
+        }
+    }
 }
 
 /// Task query filters
@@ -259,8 +586,28 @@ pub struct TaskFilter {
     pub priority: Option<TaskPriority>,
     pub tag_ids: Option<Vec<String>>,
     pub search: Option<String>,
+    /// Only tasks due strictly before this instant (exclusive)
     pub due_before: Option<DateTime<Utc>>,
+    /// Only tasks due at or after this instant (inclusive)
     pub due_after: Option<DateTime<Utc>>,
+    /// Filter on the computed status (e.g. Overdue), evaluated in SQL
+    #[serde(default)]
+    pub effective_status: Option<EffectiveTaskStatus>,
+    /// When `Some(false)`, completed tasks are excluded unless a status filter is set
+    #[serde(default)]
+    pub include_completed: Option<bool>,
+    /// Only tasks without a due date (the GTD-style inbox)
+    #[serde(default)]
+    pub only_no_due_date: bool,
+    /// Minutes past the due date before `effective_status` counts a task as overdue
+    #[serde(default)]
+    pub overdue_grace_minutes: i64,
+    /// Deferred tasks are excluded unless this is set
+    #[serde(default)]
+    pub include_deferred: bool,
+    /// Only deferred tasks (the "someday/maybe" list)
+    #[serde(default)]
+    pub only_deferred: bool,
 }
 
 /// Sorting options
@@ -280,6 +627,7 @@ pub enum TaskSortField {
     DueDate,
     CreatedAt,
     UpdatedAt,
+    SortOrder,
 }
 
 impl TaskSortField {
@@ -291,6 +639,7 @@ impl TaskSortField {
             TaskSortField::DueDate => "due_date",
             TaskSortField::CreatedAt => "created_at",
             TaskSortField::UpdatedAt => "updated_at",
+            TaskSortField::SortOrder => "sort_order",
         }
     }
 }
@@ -356,3 +705,95 @@ impl<T> PaginatedResponse<T> {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_priority_weight_follows_importance() {
+        assert!(TaskPriority::Low.weight() < TaskPriority::Medium.weight());
+        assert!(TaskPriority::Medium.weight() < TaskPriority::High.weight());
+        assert!(TaskPriority::High.weight() < TaskPriority::Urgent.weight());
+        assert_eq!(TaskPriority::Low.weight(), 0);
+        assert_eq!(TaskPriority::Urgent.weight(), 3);
+    }
+
+    fn task(color: Option<&str>, tag_colors: &[&str], priority: TaskPriority) -> Task {
+        let now = Utc::now();
+        Task {
+            id: "task-1".to_string(),
+            title: "Paint fence".to_string(),
+            description: None,
+            status: TaskStatus::Pending,
+            priority,
+            due_date: None,
+            completed_at: None,
+            image_path: None,
+            notes: None,
+            estimated_minutes: None,
+            actual_minutes: None,
+            parent_id: None,
+            sort_order: 0,
+            checklist_progress: None,
+            color: color.map(str::to_string),
+            is_pinned: false,
+            series_id: None,
+            is_deferred: false,
+            deferred_until: None,
+            completion_note: None,
+            created_at: now,
+            updated_at: now,
+            tags: tag_colors
+                .iter()
+                .enumerate()
+                .map(|(i, color)| Tag {
+                    id: format!("tag-{}", i),
+                    name: format!("Tag {}", i),
+                    color: color.to_string(),
+                    created_at: now,
+                })
+                .collect(),
+        }
+    }
+
+    #[test]
+    fn test_display_color_prefers_task_color() {
+        let view = TaskView::from(task(Some("#123456"), &["#abcdef"], TaskPriority::High));
+        assert_eq!(view.display_color, "#123456");
+    }
+
+    #[test]
+    fn test_display_color_falls_back_to_first_tag() {
+        let view = TaskView::from(task(None, &["#abcdef", "#fedcba"], TaskPriority::High));
+        assert_eq!(view.display_color, "#abcdef");
+    }
+
+    #[test]
+    fn test_display_color_falls_back_to_priority() {
+        for priority in TaskPriority::all() {
+            let expected = priority.default_color();
+            let view = TaskView::from(task(None, &[], priority));
+            assert_eq!(view.display_color, expected);
+        }
+    }
+
+    #[test]
+    fn test_task_view_serializes_flat() {
+        let json =
+            serde_json::to_value(TaskView::from(task(None, &[], TaskPriority::Low))).unwrap();
+        assert_eq!(json["title"], "Paint fence");
+        assert_eq!(json["display_color"], "#9ca3af");
+    }
+
+    #[test]
+    fn test_priority_all_is_complete_and_ordered() {
+        let all = TaskPriority::all();
+
+        let weights: Vec<u8> = all.iter().map(TaskPriority::weight).collect();
+        assert_eq!(weights, vec![0, 1, 2, 3]);
+        for name in ["low", "medium", "high", "urgent"] {
+            assert!(all.contains(&TaskPriority::from_str(name).unwrap()));
+        }
+    }
+}
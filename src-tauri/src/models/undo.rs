@@ -0,0 +1,63 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use serde_json::{Map, Value};
+
+/// Kind of entity whose deletion can be undone
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum UndoEntityType {
+    Task,
+    Reminder,
+    Tag,
+}
+
+impl UndoEntityType {
+    pub fn as_str(&self) -> &str {
+        match self {
+            UndoEntityType::Task => "task",
+            UndoEntityType::Reminder => "reminder",
+            UndoEntityType::Tag => "tag",
+        }
+    }
+
+    pub fn parse(s: &str) -> Option<Self> {
+        match s {
+            "task" => Some(UndoEntityType::Task),
+            "reminder" => Some(UndoEntityType::Reminder),
+            "tag" => Some(UndoEntityType::Tag),
+            _ => None,
+        }
+    }
+}
+
+/// Rows of one table captured before a delete, keyed by column name
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SnapshotTable {
+    pub table: String,
+    pub rows: Vec<Map<String, Value>>,
+}
+
+/// Every row a delete removes, in the order the rows must be re-inserted
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct UndoSnapshot {
+    pub tables: Vec<SnapshotTable>,
+}
+
+impl UndoSnapshot {
+    /// Total number of rows captured across all tables
+    pub fn row_count(&self) -> usize {
+        self.tables.iter().map(|t| t.rows.len()).sum()
+    }
+}
+
+/// A deletion that was undone
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct UndoEntry {
+    pub id: i64,
+    pub entity_type: UndoEntityType,
+    pub entity_id: String,
+    pub deleted_at: DateTime<Utc>,
+    /// Newer deletions dropped from the log because they could no longer be restored
+    #[serde(default)]
+    pub dropped: Vec<String>,
+}
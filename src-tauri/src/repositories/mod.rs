@@ -1,5 +1,13 @@
 pub mod reminder_repository;
+pub mod reminder_template_repository;
+pub mod settings_repository;
+pub mod tag_repository;
 pub mod task_repository;
+pub mod undo_repository;
 
 pub use reminder_repository::ReminderRepository;
+pub use reminder_template_repository::ReminderTemplateRepository;
+pub use settings_repository::SettingsRepository;
+pub use tag_repository::TagRepository;
 pub use task_repository::TaskRepository;
+pub use undo_repository::UndoRepository;
@@ -1,9 +1,38 @@
 use crate::db::Database;
-use crate::models::{CreateReminderDto, Reminder, RepeatInterval, UpdateReminderDto};
-use chrono::{DateTime, Utc};
-use rusqlite::{params, Result, Row};
+use crate::models::search::like_pattern;
+use crate::models::{
+    reminder::MAX_ESCALATIONS, CreateReminderDto, PaginatedResponse, Pagination, Reminder,
+    ReminderDelivery, ReminderFilter, ReminderSort, RepeatInterval, UpdateReminderDto,
+};
+use chrono::{DateTime, Duration, Utc};
+use rusqlite::{params, Result, Row, ToSql};
 use uuid::Uuid;
 
+/// SQL side of the due check shared by `find_due_reminders` and `count_due`; `?1` is now
+const DUE_CONDITIONS: &str = "is_active = 1
+       AND remind_at <= ?1
+       AND (last_triggered_at IS NULL OR acknowledged_at IS NOT NULL)
+       AND NOT EXISTS (
+           SELECT 1 FROM reminder_trigger_log log
+           WHERE log.reminder_id = reminders.id AND log.scheduled_at = reminders.remind_at
+       )";
+
+/// Due condition added when reminders of completed or cancelled tasks are held back
+const OPEN_TASK_CONDITION: &str = "
+       AND NOT EXISTS (
+           SELECT 1 FROM tasks
+           WHERE tasks.id = reminders.task_id AND tasks.status IN ('completed', 'cancelled')
+       )";
+
+/// `DUE_CONDITIONS`, optionally leaving out reminders whose linked task is closed
+fn due_conditions(skip_closed_tasks: bool) -> String {
+    if skip_closed_tasks {
+        format!("{}{}", DUE_CONDITIONS, OPEN_TASK_CONDITION)
+    } else {
+        DUE_CONDITIONS.to_string()
+    }
+}
+
 /// Repository for reminder data access
 pub struct ReminderRepository<'a> {
     db: &'a Database,
@@ -25,8 +54,8 @@ impl<'a> ReminderRepository<'a> {
         conn.execute(
             "INSERT INTO reminders (
                 id, task_id, title, description, remind_at, 
-                repeat_interval, is_active, created_at, updated_at
-            ) VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, ?9)",
+                repeat_interval, is_active, created_at, updated_at, escalation_minutes, delivery
+            ) VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, ?9, ?10, ?11)",
             params![
                 id,
                 dto.task_id,
@@ -37,6 +66,8 @@ impl<'a> ReminderRepository<'a> {
                 1, // is_active = true by default
                 now.to_rfc3339(),
                 now.to_rfc3339(),
+                dto.escalation_minutes,
+                dto.delivery.unwrap_or_default().as_str(),
             ],
         )?;
 
@@ -52,7 +83,8 @@ impl<'a> ReminderRepository<'a> {
         let mut stmt = conn.prepare(
             "SELECT id, task_id, title, description, remind_at, 
                     repeat_interval, is_active, last_triggered_at, 
-                    created_at, updated_at
+                    created_at, updated_at, acknowledged_at,
+                    escalation_minutes, escalation_count, delivery
              FROM reminders 
              WHERE id = ?1",
         )?;
@@ -74,6 +106,10 @@ impl<'a> ReminderRepository<'a> {
         let mut updates = Vec::new();
         let mut params: Vec<Box<dyn rusqlite::ToSql>> = Vec::new();
 
+        if let Some(task_id) = &dto.task_id {
+            updates.push("task_id = ?");
+            params.push(Box::new(task_id.clone()));
+        }
         if let Some(title) = &dto.title {
             updates.push("title = ?");
             params.push(Box::new(title.clone()));
@@ -94,6 +130,10 @@ impl<'a> ReminderRepository<'a> {
             updates.push("is_active = ?");
             params.push(Box::new(if is_active { 1 } else { 0 }));
         }
+        if let Some(delivery) = dto.delivery {
+            updates.push("delivery = ?");
+            params.push(Box::new(delivery.as_str().to_string()));
+        }
 
         if updates.is_empty() {
             return self
@@ -101,6 +141,9 @@ impl<'a> ReminderRepository<'a> {
                 .ok_or(rusqlite::Error::QueryReturnedNoRows);
         }
 
+        updates.push("updated_at = ?");
+        params.push(Box::new(Utc::now().to_rfc3339()));
+
         let query = format!("UPDATE reminders SET {} WHERE id = ?", updates.join(", "));
         params.push(Box::new(id.to_string()));
 
@@ -127,7 +170,8 @@ impl<'a> ReminderRepository<'a> {
         let mut stmt = conn.prepare(
             "SELECT id, task_id, title, description, remind_at, 
                     repeat_interval, is_active, last_triggered_at, 
-                    created_at, updated_at
+                    created_at, updated_at, acknowledged_at,
+                    escalation_minutes, escalation_count, delivery
              FROM reminders 
              ORDER BY remind_at ASC",
         )?;
@@ -139,6 +183,123 @@ impl<'a> ReminderRepository<'a> {
         Ok(reminders)
     }
 
+    /// Find reminders matching a filter, ordered by `sort` (reminder time by default)
+    pub fn find_filtered(
+        &self,
+        filter: &ReminderFilter,
+        sort: Option<Vec<ReminderSort>>,
+    ) -> Result<Vec<Reminder>> {
+        let conn = self.db.connection();
+
+        let (where_clause, where_params) = self.build_where_clause(filter);
+        let order_by = self.build_order_by(&sort);
+
+        let mut stmt = conn.prepare(&format!(
+            "SELECT id, task_id, title, description, remind_at, 
+                    repeat_interval, is_active, last_triggered_at, 
+                    created_at, updated_at, acknowledged_at,
+                    escalation_minutes, escalation_count, delivery
+             FROM reminders 
+             {}
+             {}",
+            where_clause, order_by
+        ))?;
+
+        let reminders = stmt
+            .query_map(rusqlite::params_from_iter(where_params.iter()), |row| {
+                self.map_row_to_reminder(row)
+            })?
+            .collect::<Result<Vec<Reminder>>>()?;
+
+        Ok(reminders)
+    }
+
+    /// Find one page of reminders matching an optional filter, ordered by `sort`
+    pub fn find_paginated(
+        &self,
+        filter: Option<ReminderFilter>,
+        sort: Option<Vec<ReminderSort>>,
+        pagination: Pagination,
+    ) -> Result<PaginatedResponse<Reminder>> {
+        let conn = self.db.connection();
+
+        let (where_clause, where_params) = self.build_where_clause(&filter.unwrap_or_default());
+        let order_by = self.build_order_by(&sort);
+
+        let count_query = format!("SELECT COUNT(*) FROM reminders {}", where_clause);
+        let total: u32 = conn.query_row(
+            &count_query,
+            rusqlite::params_from_iter(where_params.iter()),
+            |row| row.get(0),
+        )?;
+
+        let query = format!(
+            "SELECT id, task_id, title, description, remind_at, 
+                    repeat_interval, is_active, last_triggered_at, 
+                    created_at, updated_at, acknowledged_at,
+                    escalation_minutes, escalation_count, delivery
+             FROM reminders 
+             {}
+             {}
+             LIMIT ?{} OFFSET ?{}",
+            where_clause,
+            order_by,
+            where_params.len() + 1,
+            where_params.len() + 2,
+        );
+
+        let mut stmt = conn.prepare(&query)?;
+
+        let mut all_params: Vec<Box<dyn ToSql>> = where_params;
+        all_params.push(Box::new(pagination.page_size));
+        all_params.push(Box::new(pagination.offset()));
+
+        let reminders = stmt
+            .query_map(rusqlite::params_from_iter(all_params.iter()), |row| {
+                self.map_row_to_reminder(row)
+            })?
+            .collect::<Result<Vec<Reminder>>>()?;
+
+        Ok(PaginatedResponse::new(reminders, total, pagination))
+    }
+
+    /// Find active reminders due between now and `within` from now
+    pub fn find_upcoming(&self, within: Duration) -> Result<Vec<Reminder>> {
+        let now = Utc::now();
+        self.find_filtered(
+            &ReminderFilter {
+                is_active: Some(true),
+                due_after: Some(now),
+                due_before: Some(now + within),
+                task_id: None,
+            },
+            None,
+        )
+    }
+
+    /// Find reminders whose title or description contains `query` (case-insensitive)
+    pub fn search(&self, query: &str, limit: u32) -> Result<Vec<Reminder>> {
+        let conn = self.db.connection();
+        let pattern = like_pattern(query);
+
+        let mut stmt = conn.prepare(
+            "SELECT id, task_id, title, description, remind_at, 
+                    repeat_interval, is_active, last_triggered_at, 
+                    created_at, updated_at, acknowledged_at,
+                    escalation_minutes, escalation_count, delivery
+             FROM reminders 
+             WHERE fold(title) LIKE ?1 ESCAPE '\\' OR fold(description) LIKE ?1 ESCAPE '\\'
+             ORDER BY remind_at ASC
+             LIMIT ?2",
+        )?;
+
+        let reminders = stmt
+            .query_map(params![pattern, limit], |row| self.map_row_to_reminder(row))?
+            .collect::<Result<Vec<Reminder>>>()?;
+
+        Ok(reminders)
+    }
+
     /// Find reminders by task ID
     pub fn find_by_task_id(&self, task_id: &str) -> Result<Vec<Reminder>> {
         let conn = self.db.connection();
@@ -146,7 +307,8 @@ impl<'a> ReminderRepository<'a> {
         let mut stmt = conn.prepare(
             "SELECT id, task_id, title, description, remind_at, 
                     repeat_interval, is_active, last_triggered_at, 
-                    created_at, updated_at
+                    created_at, updated_at, acknowledged_at,
+                    escalation_minutes, escalation_count, delivery
              FROM reminders 
              WHERE task_id = ?1
              ORDER BY remind_at ASC",
@@ -159,13 +321,28 @@ impl<'a> ReminderRepository<'a> {
         Ok(reminders)
     }
 
+    /// Delete all reminders linked to a task, returning how many were removed
+    pub fn delete_by_task_id(&self, task_id: &str) -> Result<usize> {
+        let conn = self.db.connection();
+        conn.execute("DELETE FROM reminders WHERE task_id = ?1", params![task_id])
+    }
+
+    /// Deactivate all active reminders linked to a task, returning how many were changed
+    pub fn deactivate_by_task_id(&self, task_id: &str) -> Result<usize> {
+        let conn = self.db.connection();
+        conn.execute(
+            "UPDATE reminders SET is_active = 0, updated_at = ?1 WHERE task_id = ?2 AND is_active = 1",
+            params![Utc::now().to_rfc3339(), task_id],
+        )
+    }
+
     /// Deactivate a reminder (set is_active to false)
     pub fn deactivate(&self, id: &str) -> Result<Reminder> {
         let conn = self.db.connection();
 
         conn.execute(
-            "UPDATE reminders SET is_active = 0 WHERE id = ?1",
-            params![id],
+            "UPDATE reminders SET is_active = 0, updated_at = ?1 WHERE id = ?2",
+            params![Utc::now().to_rfc3339(), id],
         )?;
 
         self.find_by_id(id)?
@@ -177,8 +354,8 @@ impl<'a> ReminderRepository<'a> {
         let conn = self.db.connection();
 
         conn.execute(
-            "UPDATE reminders SET is_active = 1 WHERE id = ?1",
-            params![id],
+            "UPDATE reminders SET is_active = 1, updated_at = ?1 WHERE id = ?2",
+            params![Utc::now().to_rfc3339(), id],
         )?;
 
         self.find_by_id(id)?
@@ -197,21 +374,24 @@ impl<'a> ReminderRepository<'a> {
     }
 
     /// Find reminders that are due to be triggered
-    pub fn find_due_reminders(&self) -> Result<Vec<Reminder>> {
+    ///
+    /// A reminder whose last notification is still unacknowledged is held back
+    /// until the user has seen it, and with `skip_closed_tasks` so is one whose
+    /// linked task is completed or cancelled.
+    pub fn find_due_reminders(&self, skip_closed_tasks: bool) -> Result<Vec<Reminder>> {
         let conn = self.db.connection();
         let now = Utc::now().to_rfc3339();
 
-        let mut stmt = conn.prepare(
+        let mut stmt = conn.prepare(&format!(
             "SELECT id, task_id, title, description, remind_at, 
                     repeat_interval, is_active, last_triggered_at, 
-                    created_at, updated_at
+                    created_at, updated_at, acknowledged_at,
+                    escalation_minutes, escalation_count, delivery
              FROM reminders
-             WHERE is_active = 1 
-               AND remind_at <= ?1
-               AND (last_triggered_at IS NULL 
-                    OR last_triggered_at < datetime('now', '-1 minute'))
+             WHERE {}
              ORDER BY remind_at ASC",
-        )?;
+            due_conditions(skip_closed_tasks)
+        ))?;
 
         let reminder_iter = stmt.query_map(params![now], |row| self.map_row_to_reminder(row))?;
 
@@ -228,19 +408,257 @@ impl<'a> ReminderRepository<'a> {
         Ok(reminders)
     }
 
+    /// Count due reminders without loading them, e.g. for a badge
+    ///
+    /// Uses only the SQL side of the due check, so it can overcount `find_due_reminders`
+    /// for repeating reminders whose `remind_at` has passed but whose interval since the
+    /// last trigger has not (only possible when rescheduling after a trigger failed), and
+    /// for calendar schedules whose first slot after `remind_at` is still ahead.
+    pub fn count_due(&self, skip_closed_tasks: bool) -> Result<u32> {
+        let conn = self.db.connection();
+        let count: u32 = conn.query_row(
+            &format!(
+                "SELECT COUNT(*) FROM reminders WHERE {}",
+                due_conditions(skip_closed_tasks)
+            ),
+            params![Utc::now().to_rfc3339()],
+            |row| row.get(0),
+        )?;
+        Ok(count)
+    }
+
     /// Mark reminder as triggered (update last_triggered_at)
     pub fn mark_as_triggered(&self, id: &str) -> Result<()> {
         let conn = self.db.connection();
         let now = Utc::now().to_rfc3339();
 
         conn.execute(
-            "UPDATE reminders SET last_triggered_at = ?1 WHERE id = ?2",
+            "UPDATE reminders
+             SET last_triggered_at = ?1, acknowledged_at = NULL, escalation_count = 0
+             WHERE id = ?2",
             params![now, id],
         )?;
 
         Ok(())
     }
 
+    /// Log that `reminder` fired for its current `remind_at` slot and mark it triggered
+    ///
+    /// The log insert and the `last_triggered_at` update share one transaction.
+    /// Returns false, changing nothing, when the slot was already logged.
+    pub fn record_trigger(&self, reminder: &Reminder) -> Result<bool> {
+        let now = Utc::now().to_rfc3339();
+
+        self.db.transaction(|conn| {
+            let inserted = conn.execute(
+                "INSERT OR IGNORE INTO reminder_trigger_log (reminder_id, scheduled_at, fired_at)
+                 VALUES (?1, ?2, ?3)",
+                params![reminder.id, reminder.remind_at.to_rfc3339(), now],
+            )?;
+            if inserted == 0 {
+                return Ok(false);
+            }
+
+            conn.execute(
+                "UPDATE reminders
+                 SET last_triggered_at = ?1, acknowledged_at = NULL, escalation_count = 0
+                 WHERE id = ?2",
+                params![now, reminder.id],
+            )?;
+            Ok(true)
+        })
+    }
+
+    /// Undo `record_trigger` after a delivery failure so the slot is retried
+    pub fn release_trigger(&self, reminder: &Reminder) -> Result<()> {
+        self.db.transaction(|conn| {
+            conn.execute(
+                "DELETE FROM reminder_trigger_log WHERE reminder_id = ?1 AND scheduled_at = ?2",
+                params![reminder.id, reminder.remind_at.to_rfc3339()],
+            )?;
+            conn.execute(
+                "UPDATE reminders
+                 SET last_triggered_at = ?1, acknowledged_at = ?2, escalation_count = ?3
+                 WHERE id = ?4",
+                params![
+                    reminder.last_triggered_at.map(|d| d.to_rfc3339()),
+                    reminder.acknowledged_at.map(|d| d.to_rfc3339()),
+                    reminder.escalation_count,
+                    reminder.id
+                ],
+            )?;
+            Ok(())
+        })
+    }
+
+    /// Record that the user has seen the reminder's last notification
+    pub fn acknowledge(&self, id: &str) -> Result<Reminder> {
+        let conn = self.db.connection();
+
+        conn.execute(
+            "UPDATE reminders SET acknowledged_at = ?1 WHERE id = ?2",
+            params![Utc::now().to_rfc3339(), id],
+        )?;
+
+        self.find_by_id(id)?
+            .ok_or(rusqlite::Error::QueryReturnedNoRows)
+    }
+
+    /// Find active reminders that have fired but not been acknowledged yet
+    pub fn find_unacknowledged(&self) -> Result<Vec<Reminder>> {
+        let conn = self.db.connection();
+
+        let mut stmt = conn.prepare(
+            "SELECT id, task_id, title, description, remind_at, 
+                    repeat_interval, is_active, last_triggered_at, 
+                    created_at, updated_at, acknowledged_at,
+                    escalation_minutes, escalation_count, delivery
+             FROM reminders
+             WHERE is_active = 1
+               AND last_triggered_at IS NOT NULL
+               AND acknowledged_at IS NULL
+             ORDER BY last_triggered_at DESC",
+        )?;
+
+        let reminders = stmt
+            .query_map([], |row| self.map_row_to_reminder(row))?
+            .collect::<Result<Vec<Reminder>>>()?;
+
+        Ok(reminders)
+    }
+
+    /// Find in-app reminders that have fired but not been shown yet, oldest first
+    pub fn find_pending_in_app(&self) -> Result<Vec<Reminder>> {
+        let conn = self.db.connection();
+
+        let mut stmt = conn.prepare(
+            "SELECT id, task_id, title, description, remind_at, 
+                    repeat_interval, is_active, last_triggered_at, 
+                    created_at, updated_at, acknowledged_at,
+                    escalation_minutes, escalation_count, delivery
+             FROM reminders
+             WHERE is_active = 1
+               AND delivery = ?1
+               AND last_triggered_at IS NOT NULL
+               AND acknowledged_at IS NULL
+             ORDER BY last_triggered_at ASC",
+        )?;
+
+        let reminders = stmt
+            .query_map(params![ReminderDelivery::InApp.as_str()], |row| {
+                self.map_row_to_reminder(row)
+            })?
+            .collect::<Result<Vec<Reminder>>>()?;
+
+        Ok(reminders)
+    }
+
+    /// Acknowledge several reminders at `at` in a single transaction
+    pub fn acknowledge_all(&self, ids: &[String], at: DateTime<Utc>) -> Result<usize> {
+        let at = at.to_rfc3339();
+
+        self.db.transaction(|conn| {
+            let mut updated = 0;
+            for id in ids {
+                updated += conn.execute(
+                    "UPDATE reminders SET acknowledged_at = ?1 WHERE id = ?2",
+                    params![at, id],
+                )?;
+            }
+            Ok(updated)
+        })
+    }
+
+    /// Find fired reminders whose next follow-up is due and whose linked task is still open
+    pub fn find_escalations_due(&self) -> Result<Vec<Reminder>> {
+        let conn = self.db.connection();
+
+        let mut stmt = conn.prepare(
+            "SELECT r.id, r.task_id, r.title, r.description, r.remind_at,
+                    r.repeat_interval, r.is_active, r.last_triggered_at,
+                    r.created_at, r.updated_at, r.acknowledged_at,
+                    r.escalation_minutes, r.escalation_count, r.delivery
+             FROM reminders r
+             JOIN tasks t ON t.id = r.task_id
+             WHERE r.is_active = 1
+               AND r.escalation_minutes IS NOT NULL
+               AND r.last_triggered_at IS NOT NULL
+               AND r.escalation_count < ?1
+               AND r.delivery = 'notification'
+               AND t.status NOT IN ('completed', 'cancelled')
+             ORDER BY r.last_triggered_at ASC",
+        )?;
+
+        let now = Utc::now();
+        let reminders = stmt
+            .query_map(params![MAX_ESCALATIONS], |row| {
+                self.map_row_to_reminder(row)
+            })?
+            .collect::<Result<Vec<Reminder>>>()?
+            .into_iter()
+            .filter(|reminder| reminder.next_escalation_at().is_some_and(|at| at <= now))
+            .collect();
+
+        Ok(reminders)
+    }
+
+    /// Claim the reminder's next follow-up, returning false if it was already sent
+    pub fn record_escalation(&self, reminder: &Reminder) -> Result<bool> {
+        let rows_affected = self.db.connection().execute(
+            "UPDATE reminders SET escalation_count = escalation_count + 1
+             WHERE id = ?1 AND escalation_count = ?2",
+            params![reminder.id, reminder.escalation_count],
+        )?;
+        Ok(rows_affected > 0)
+    }
+
+    /// Undo `record_escalation` after a delivery failure so the follow-up is retried
+    pub fn release_escalation(&self, reminder: &Reminder) -> Result<()> {
+        self.db.connection().execute(
+            "UPDATE reminders SET escalation_count = ?1 WHERE id = ?2",
+            params![reminder.escalation_count, reminder.id],
+        )?;
+        Ok(())
+    }
+
+    /// Set or clear (`None`) the follow-up interval of a reminder
+    pub fn set_escalation(&self, id: &str, escalation_minutes: Option<i64>) -> Result<Reminder> {
+        self.db.connection().execute(
+            "UPDATE reminders SET escalation_minutes = ?1, updated_at = ?2 WHERE id = ?3",
+            params![escalation_minutes, Utc::now().to_rfc3339(), id],
+        )?;
+
+        self.find_by_id(id)?
+            .ok_or(rusqlite::Error::QueryReturnedNoRows)
+    }
+
+    /// Link a reminder to another task, or detach it with `None`
+    pub fn set_task_id(&self, id: &str, task_id: Option<&str>) -> Result<Reminder> {
+        self.db.connection().execute(
+            "UPDATE reminders SET task_id = ?1, updated_at = ?2 WHERE id = ?3",
+            params![task_id, Utc::now().to_rfc3339(), id],
+        )?;
+
+        self.find_by_id(id)?
+            .ok_or(rusqlite::Error::QueryReturnedNoRows)
+    }
+
+    /// Set `remind_at` for each `(id, remind_at)` pair in a single transaction
+    pub fn set_remind_times(&self, remind_times: &[(String, DateTime<Utc>)]) -> Result<usize> {
+        let now = Utc::now().to_rfc3339();
+
+        self.db.transaction(|conn| {
+            let mut updated = 0;
+            for (id, remind_at) in remind_times {
+                updated += conn.execute(
+                    "UPDATE reminders SET remind_at = ?1, updated_at = ?2 WHERE id = ?3",
+                    params![remind_at.to_rfc3339(), now, id],
+                )?;
+            }
+            Ok(updated)
+        })
+    }
+
     /// Update next trigger time for repeating reminders
     pub fn update_next_trigger_time(&self, id: &str, next_trigger: &DateTime<Utc>) -> Result<()> {
         let conn = self.db.connection();
@@ -258,6 +676,56 @@ impl<'a> ReminderRepository<'a> {
     // Private helper methods
     // ========================================================================
 
+    /// Build WHERE clause from filter
+    fn build_where_clause(&self, filter: &ReminderFilter) -> (String, Vec<Box<dyn ToSql>>) {
+        let mut conditions = Vec::new();
+        let mut params: Vec<Box<dyn ToSql>> = Vec::new();
+
+        if let Some(is_active) = filter.is_active {
+            conditions.push("is_active = ?");
+            params.push(Box::new(is_active as i32));
+        }
+
+        if let Some(due_before) = &filter.due_before {
+            conditions.push("remind_at < ?");
+            params.push(Box::new(due_before.to_rfc3339()));
+        }
+
+        if let Some(due_after) = &filter.due_after {
+            conditions.push("remind_at >= ?");
+            params.push(Box::new(due_after.to_rfc3339()));
+        }
+
+        if let Some(task_id) = &filter.task_id {
+            conditions.push("task_id = ?");
+            params.push(Box::new(task_id.clone()));
+        }
+
+        let where_clause = if conditions.is_empty() {
+            String::new()
+        } else {
+            format!("WHERE {}", conditions.join(" AND "))
+        };
+
+        (where_clause, params)
+    }
+
+    /// Build ORDER BY clause from sort options
+    ///
+    /// Ties fall back to insertion order so pages stay stable.
+    fn build_order_by(&self, sort: &Option<Vec<ReminderSort>>) -> String {
+        if let Some(sorts) = sort {
+            if !sorts.is_empty() {
+                let order_parts: Vec<String> = sorts
+                    .iter()
+                    .map(|s| format!("{} {}", s.field.as_str(), s.direction.as_str()))
+                    .collect();
+                return format!("ORDER BY {}, rowid ASC", order_parts.join(", "));
+            }
+        }
+        "ORDER BY remind_at ASC, rowid ASC".to_string()
+    }
+
     /// Map database row to Reminder struct
     fn map_row_to_reminder(&self, row: &Row) -> Result<Reminder> {
         let repeat_interval_str: String = row.get(5)?;
@@ -267,6 +735,8 @@ impl<'a> ReminderRepository<'a> {
         let last_triggered_at: Option<String> = row.get(7)?;
         let created_at: String = row.get(8)?;
         let updated_at: String = row.get(9)?;
+        let acknowledged_at: Option<String> = row.get(10)?;
+        let delivery: String = row.get(13)?;
 
         Ok(Reminder {
             id: row.get(0)?,
@@ -287,6 +757,12 @@ impl<'a> ReminderRepository<'a> {
             updated_at: DateTime::parse_from_rfc3339(&updated_at)
                 .map(|d| d.with_timezone(&Utc))
                 .unwrap_or_else(|_| Utc::now()),
+            acknowledged_at: acknowledged_at
+                .and_then(|d| DateTime::parse_from_rfc3339(&d).ok())
+                .map(|d| d.with_timezone(&Utc)),
+            escalation_minutes: row.get(11)?,
+            escalation_count: row.get(12)?,
+            delivery: ReminderDelivery::parse(&delivery).unwrap_or_default(),
         })
     }
 }
@@ -294,7 +770,9 @@ impl<'a> ReminderRepository<'a> {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::models::{CreateReminderDto, RepeatInterval, UpdateReminderDto};
+    use crate::models::{
+        CreateReminderDto, ReminderSortField, RepeatInterval, SortDirection, UpdateReminderDto,
+    };
     use rusqlite::Connection;
 
     fn setup_test_db() -> Database {
@@ -312,7 +790,22 @@ mod tests {
                 is_active INTEGER NOT NULL DEFAULT 1,
                 last_triggered_at TEXT,
                 created_at TEXT NOT NULL,
-                updated_at TEXT NOT NULL
+                updated_at TEXT NOT NULL,
+                acknowledged_at TEXT,
+                escalation_minutes INTEGER,
+                escalation_count INTEGER NOT NULL DEFAULT 0,
+                delivery TEXT NOT NULL DEFAULT 'notification'
+            )",
+            [],
+        )
+        .unwrap();
+
+        conn.execute(
+            "CREATE TABLE reminder_trigger_log (
+                reminder_id TEXT NOT NULL,
+                scheduled_at TEXT NOT NULL,
+                fired_at TEXT NOT NULL,
+                PRIMARY KEY (reminder_id, scheduled_at)
             )",
             [],
         )
@@ -333,6 +826,8 @@ mod tests {
             description: Some("Test Description".to_string()),
             remind_at,
             repeat_interval: RepeatInterval::none(),
+            escalation_minutes: None,
+            delivery: None,
         };
 
         let reminder = repo.create(dto).unwrap();
@@ -357,6 +852,8 @@ mod tests {
             description: None,
             remind_at,
             repeat_interval: RepeatInterval::from_str("daily"),
+            escalation_minutes: None,
+            delivery: None,
         };
 
         let reminder = repo.create(dto).unwrap();
@@ -378,6 +875,8 @@ mod tests {
             description: Some("Test".to_string()),
             remind_at,
             repeat_interval: RepeatInterval::from_str("weekly"),
+            escalation_minutes: None,
+            delivery: None,
         };
 
         let created = repo.create(dto).unwrap();
@@ -411,22 +910,67 @@ mod tests {
             description: None,
             remind_at,
             repeat_interval: RepeatInterval::none(),
+            escalation_minutes: None,
+            delivery: None,
         };
 
         let created = repo.create(dto).unwrap();
 
         let update = UpdateReminderDto {
+            task_id: None,
             title: Some("Updated Title".to_string()),
             description: None,
             remind_at: None,
             repeat_interval: None,
             is_active: None,
+            delivery: None,
         };
 
         let updated = repo.update(&created.id, update).unwrap();
         assert_eq!(updated.title, "Updated Title");
     }
 
+    #[test]
+    fn test_update_and_deactivate_refresh_updated_at() {
+        let db = setup_test_db();
+        let repo = ReminderRepository::new(&db);
+
+        let created = create_at(
+            &repo,
+            "Reminder",
+            None,
+            Utc::now() + chrono::Duration::hours(1),
+        );
+        let an_hour_ago = Utc::now() - chrono::Duration::hours(1);
+        let backdate = || {
+            db.connection()
+                .execute(
+                    "UPDATE reminders SET created_at = ?1, updated_at = ?1 WHERE id = ?2",
+                    params![an_hour_ago.to_rfc3339(), created.id],
+                )
+                .unwrap();
+        };
+
+        backdate();
+        let update = UpdateReminderDto {
+            task_id: None,
+            title: Some("Renamed".to_string()),
+            description: None,
+            remind_at: None,
+            repeat_interval: None,
+            is_active: None,
+            delivery: None,
+        };
+        let updated = repo.update(&created.id, update).unwrap();
+        assert_eq!(updated.created_at, an_hour_ago);
+        assert!(updated.updated_at > an_hour_ago);
+
+        backdate();
+        let deactivated = repo.deactivate(&created.id).unwrap();
+        assert_eq!(deactivated.created_at, an_hour_ago);
+        assert!(deactivated.updated_at > an_hour_ago);
+    }
+
     #[test]
     fn test_update_reminder_repeat_interval() {
         let db = setup_test_db();
@@ -439,17 +983,21 @@ mod tests {
             description: None,
             remind_at,
             repeat_interval: RepeatInterval::none(),
+            escalation_minutes: None,
+            delivery: None,
         };
 
         let created = repo.create(dto).unwrap();
         assert_eq!(created.repeat_interval, RepeatInterval::none());
 
         let update = UpdateReminderDto {
+            task_id: None,
             title: None,
             description: None,
             remind_at: None,
             repeat_interval: Some(RepeatInterval::from_str("monthly")),
             is_active: None,
+            delivery: None,
         };
 
         let updated = repo.update(&created.id, update).unwrap();
@@ -468,6 +1016,8 @@ mod tests {
             description: None,
             remind_at,
             repeat_interval: RepeatInterval::none(),
+            escalation_minutes: None,
+            delivery: None,
         };
 
         let created = repo.create(dto).unwrap();
@@ -502,6 +1052,8 @@ mod tests {
                 description: None,
                 remind_at,
                 repeat_interval: RepeatInterval::none(),
+                escalation_minutes: None,
+                delivery: None,
             };
             repo.create(dto).unwrap();
         }
@@ -531,6 +1083,8 @@ mod tests {
                 description: None,
                 remind_at,
                 repeat_interval: RepeatInterval::none(),
+                escalation_minutes: None,
+                delivery: None,
             };
             repo.create(dto).unwrap();
         }
@@ -543,6 +1097,8 @@ mod tests {
             description: None,
             remind_at,
             repeat_interval: RepeatInterval::none(),
+            escalation_minutes: None,
+            delivery: None,
         };
         repo.create(dto).unwrap();
 
@@ -553,6 +1109,29 @@ mod tests {
             .all(|r| r.task_id == Some(task_id.to_string())));
     }
 
+    #[test]
+    fn test_delete_by_task_id() {
+        let db = setup_test_db();
+        let repo = ReminderRepository::new(&db);
+
+        for task_id in ["task-a", "task-a", "task-b"] {
+            let dto = CreateReminderDto {
+                task_id: Some(task_id.to_string()),
+                title: "Reminder".to_string(),
+                description: None,
+                remind_at: Utc::now() + chrono::Duration::hours(1),
+                repeat_interval: RepeatInterval::none(),
+                escalation_minutes: None,
+                delivery: None,
+            };
+            repo.create(dto).unwrap();
+        }
+
+        assert_eq!(repo.delete_by_task_id("task-a").unwrap(), 2);
+        assert!(repo.find_by_task_id("task-a").unwrap().is_empty());
+        assert_eq!(repo.find_by_task_id("task-b").unwrap().len(), 1);
+    }
+
     #[test]
     fn test_deactivate_reminder() {
         let db = setup_test_db();
@@ -565,6 +1144,8 @@ mod tests {
             description: None,
             remind_at,
             repeat_interval: RepeatInterval::none(),
+            escalation_minutes: None,
+            delivery: None,
         };
 
         let created = repo.create(dto).unwrap();
@@ -586,6 +1167,8 @@ mod tests {
             description: None,
             remind_at,
             repeat_interval: RepeatInterval::none(),
+            escalation_minutes: None,
+            delivery: None,
         };
 
         let created = repo.create(dto).unwrap();
@@ -611,6 +1194,8 @@ mod tests {
                 description: None,
                 remind_at,
                 repeat_interval: RepeatInterval::none(),
+                escalation_minutes: None,
+                delivery: None,
             };
             repo.create(dto).unwrap();
         }
@@ -638,6 +1223,8 @@ mod tests {
             description: None,
             remind_at,
             repeat_interval: RepeatInterval::none(),
+            escalation_minutes: None,
+            delivery: None,
         };
 
         let created = repo.create(dto).unwrap();
@@ -649,6 +1236,34 @@ mod tests {
         assert!(updated.last_triggered_at.is_some());
     }
 
+    #[test]
+    fn test_record_trigger_claims_slot_once() {
+        let db = setup_test_db();
+        let repo = ReminderRepository::new(&db);
+
+        let reminder = repo
+            .create(CreateReminderDto {
+                task_id: None,
+                title: "Slot".to_string(),
+                description: None,
+                remind_at: Utc::now() - Duration::minutes(1),
+                repeat_interval: RepeatInterval::none(),
+                escalation_minutes: None,
+                delivery: None,
+            })
+            .unwrap();
+
+        assert!(repo.record_trigger(&reminder).unwrap());
+        assert!(!repo.record_trigger(&reminder).unwrap());
+        assert!(repo.find_due_reminders(false).unwrap().is_empty());
+
+        // Releasing restores the previous state so the slot can be retried
+        repo.release_trigger(&reminder).unwrap();
+        let restored = repo.find_by_id(&reminder.id).unwrap().unwrap();
+        assert!(restored.last_triggered_at.is_none());
+        assert!(repo.record_trigger(&reminder).unwrap());
+    }
+
     #[test]
     fn test_find_due_reminders() {
         let db = setup_test_db();
@@ -662,6 +1277,8 @@ mod tests {
             description: None,
             remind_at: past,
             repeat_interval: RepeatInterval::none(),
+            escalation_minutes: None,
+            delivery: None,
         };
         repo.create(dto1).unwrap();
 
@@ -673,10 +1290,12 @@ mod tests {
             description: None,
             remind_at: future,
             repeat_interval: RepeatInterval::none(),
+            escalation_minutes: None,
+            delivery: None,
         };
         repo.create(dto2).unwrap();
 
-        let due_reminders = repo.find_due_reminders().unwrap();
+        let due_reminders = repo.find_due_reminders(false).unwrap();
 
         // Should only get the past reminder
         assert_eq!(due_reminders.len(), 1);
@@ -696,13 +1315,300 @@ mod tests {
             description: None,
             remind_at: past,
             repeat_interval: RepeatInterval::none(),
+            escalation_minutes: None,
+            delivery: None,
         };
         let created = repo.create(dto).unwrap();
         repo.deactivate(&created.id).unwrap();
 
-        let due_reminders = repo.find_due_reminders().unwrap();
+        let due_reminders = repo.find_due_reminders(false).unwrap();
 
         // Should not include inactive reminders
         assert_eq!(due_reminders.len(), 0);
     }
+
+    #[test]
+    fn test_count_due_matches_find_due_reminders() {
+        let db = setup_test_db();
+        let repo = ReminderRepository::new(&db);
+
+        let assert_counts_match = |expected: u32| {
+            assert_eq!(repo.count_due(false).unwrap(), expected);
+            assert_eq!(
+                repo.find_due_reminders(false).unwrap().len() as u32,
+                expected
+            );
+        };
+        assert_counts_match(0);
+
+        let create = |title: &str, remind_at, interval: &str| {
+            repo.create(CreateReminderDto {
+                task_id: None,
+                title: title.to_string(),
+                description: None,
+                remind_at,
+                repeat_interval: RepeatInterval::from_str(interval),
+                escalation_minutes: None,
+                delivery: None,
+            })
+            .unwrap()
+        };
+        let once = create("Once", Utc::now() - Duration::minutes(5), "none");
+        create("Hourly", Utc::now() - Duration::minutes(5), "every_1_hour");
+        create("Future", Utc::now() + Duration::hours(1), "none");
+        let inactive = create("Inactive", Utc::now() - Duration::minutes(5), "none");
+        repo.deactivate(&inactive.id).unwrap();
+        assert_counts_match(2);
+
+        // A fired, unacknowledged reminder is held back
+        assert!(repo.record_trigger(&once).unwrap());
+        assert_counts_match(1);
+    }
+
+    fn create_at(
+        repo: &ReminderRepository,
+        title: &str,
+        task_id: Option<&str>,
+        remind_at: DateTime<Utc>,
+    ) -> Reminder {
+        repo.create(CreateReminderDto {
+            task_id: task_id.map(str::to_string),
+            title: title.to_string(),
+            description: None,
+            remind_at,
+            repeat_interval: RepeatInterval::none(),
+            escalation_minutes: None,
+            delivery: None,
+        })
+        .unwrap()
+    }
+
+    #[test]
+    fn test_find_filtered_active_only() {
+        let db = setup_test_db();
+        let repo = ReminderRepository::new(&db);
+
+        let soon = Utc::now() + chrono::Duration::hours(1);
+        create_at(&repo, "Active", None, soon);
+        let inactive = create_at(&repo, "Inactive", None, soon);
+        repo.deactivate(&inactive.id).unwrap();
+
+        let filter = ReminderFilter {
+            is_active: Some(true),
+            ..Default::default()
+        };
+        let reminders = repo.find_filtered(&filter, None).unwrap();
+
+        assert_eq!(reminders.len(), 1);
+        assert_eq!(reminders[0].title, "Active");
+    }
+
+    #[test]
+    fn test_find_upcoming_window() {
+        let db = setup_test_db();
+        let repo = ReminderRepository::new(&db);
+
+        let now = Utc::now();
+        create_at(&repo, "Past", None, now - chrono::Duration::hours(1));
+        create_at(
+            &repo,
+            "In two hours",
+            None,
+            now + chrono::Duration::hours(2),
+        );
+        create_at(
+            &repo,
+            "In a minute",
+            None,
+            now + chrono::Duration::minutes(1),
+        );
+        create_at(&repo, "Next week", None, now + chrono::Duration::days(7));
+
+        let reminders = repo.find_upcoming(chrono::Duration::hours(3)).unwrap();
+        let titles: Vec<&str> = reminders.iter().map(|r| r.title.as_str()).collect();
+
+        assert_eq!(titles, vec!["In a minute", "In two hours"]);
+    }
+
+    #[test]
+    fn test_find_filtered_by_task() {
+        let db = setup_test_db();
+        let repo = ReminderRepository::new(&db);
+
+        let soon = Utc::now() + chrono::Duration::hours(1);
+        create_at(&repo, "Task A reminder", Some("task-a"), soon);
+        create_at(&repo, "Task B reminder", Some("task-b"), soon);
+        create_at(&repo, "Standalone", None, soon);
+
+        let filter = ReminderFilter {
+            task_id: Some("task-a".to_string()),
+            ..Default::default()
+        };
+        let reminders = repo.find_filtered(&filter, None).unwrap();
+
+        assert_eq!(reminders.len(), 1);
+        assert_eq!(reminders[0].title, "Task A reminder");
+    }
+
+    #[test]
+    fn test_find_paginated() {
+        let db = setup_test_db();
+        let repo = ReminderRepository::new(&db);
+
+        // Create 10 reminders
+        let base = Utc::now() + chrono::Duration::hours(1);
+        for i in 1..=10 {
+            create_at(
+                &repo,
+                &format!("Reminder {}", i),
+                None,
+                base + chrono::Duration::minutes(i),
+            );
+        }
+
+        // Get first page (4 items)
+        let result1 = repo
+            .find_paginated(
+                None,
+                None,
+                Pagination {
+                    page: 1,
+                    page_size: 4,
+                },
+            )
+            .unwrap();
+
+        assert_eq!(result1.items.len(), 4);
+        assert_eq!(result1.total, 10);
+        assert_eq!(result1.page, 1);
+        assert_eq!(result1.page_size, 4);
+        assert_eq!(result1.total_pages, 3);
+        assert_eq!(result1.items[0].title, "Reminder 1");
+
+        // Last page is partial
+        let result3 = repo
+            .find_paginated(
+                None,
+                None,
+                Pagination {
+                    page: 3,
+                    page_size: 4,
+                },
+            )
+            .unwrap();
+
+        assert_eq!(result3.items.len(), 2);
+        assert_eq!(result3.total, 10);
+        assert_eq!(result3.items[1].title, "Reminder 10");
+
+        // Ensure different reminders on different pages
+        let ids1: Vec<_> = result1.items.iter().map(|r| &r.id).collect();
+        let ids3: Vec<_> = result3.items.iter().map(|r| &r.id).collect();
+        assert!(ids1.iter().all(|id| !ids3.contains(id)));
+    }
+
+    #[test]
+    fn test_find_paginated_counts_filtered_total() {
+        let db = setup_test_db();
+        let repo = ReminderRepository::new(&db);
+
+        let soon = Utc::now() + chrono::Duration::hours(1);
+        for i in 1..=3 {
+            create_at(&repo, &format!("Task reminder {}", i), Some("task-a"), soon);
+        }
+        create_at(&repo, "Other", Some("task-b"), soon);
+
+        let filter = ReminderFilter {
+            task_id: Some("task-a".to_string()),
+            ..Default::default()
+        };
+        let result = repo
+            .find_paginated(
+                Some(filter),
+                None,
+                Pagination {
+                    page: 1,
+                    page_size: 2,
+                },
+            )
+            .unwrap();
+
+        assert_eq!(result.items.len(), 2);
+        assert_eq!(result.total, 3);
+        assert_eq!(result.total_pages, 2);
+    }
+
+    fn sort_by(field: ReminderSortField, direction: SortDirection) -> Option<Vec<ReminderSort>> {
+        Some(vec![ReminderSort { field, direction }])
+    }
+
+    fn titles(reminders: &[Reminder]) -> Vec<&str> {
+        reminders.iter().map(|r| r.title.as_str()).collect()
+    }
+
+    #[test]
+    fn test_find_filtered_sorted_by_remind_at() {
+        let db = setup_test_db();
+        let repo = ReminderRepository::new(&db);
+
+        let now = Utc::now();
+        create_at(&repo, "Second", None, now + chrono::Duration::hours(2));
+        create_at(&repo, "Third", None, now + chrono::Duration::hours(3));
+        create_at(&repo, "First", None, now + chrono::Duration::hours(1));
+
+        let filter = ReminderFilter::default();
+        let asc = repo
+            .find_filtered(
+                &filter,
+                sort_by(ReminderSortField::RemindAt, SortDirection::Asc),
+            )
+            .unwrap();
+        assert_eq!(titles(&asc), vec!["First", "Second", "Third"]);
+
+        let desc = repo
+            .find_filtered(
+                &filter,
+                sort_by(ReminderSortField::RemindAt, SortDirection::Desc),
+            )
+            .unwrap();
+        assert_eq!(titles(&desc), vec!["Third", "Second", "First"]);
+    }
+
+    #[test]
+    fn test_find_filtered_sorted_by_created_at() {
+        let db = setup_test_db();
+        let repo = ReminderRepository::new(&db);
+
+        let soon = Utc::now() + chrono::Duration::hours(1);
+        let base = Utc::now() - chrono::Duration::days(3);
+        for (i, title) in ["Oldest", "Middle", "Newest"].iter().enumerate() {
+            let reminder = create_at(&repo, title, None, soon);
+            db.connection()
+                .execute(
+                    "UPDATE reminders SET created_at = ?1 WHERE id = ?2",
+                    params![
+                        (base + chrono::Duration::days(i as i64)).to_rfc3339(),
+                        reminder.id
+                    ],
+                )
+                .unwrap();
+        }
+
+        let filter = ReminderFilter::default();
+        let asc = repo
+            .find_filtered(
+                &filter,
+                sort_by(ReminderSortField::CreatedAt, SortDirection::Asc),
+            )
+            .unwrap();
+        assert_eq!(titles(&asc), vec!["Oldest", "Middle", "Newest"]);
+
+        let desc = repo
+            .find_filtered(
+                &filter,
+                sort_by(ReminderSortField::CreatedAt, SortDirection::Desc),
+            )
+            .unwrap();
+        assert_eq!(titles(&desc), vec!["Newest", "Middle", "Oldest"]);
+    }
 }
@@ -0,0 +1,257 @@
+use crate::db::Database;
+use crate::models::{
+    CreateReminderTemplateDto, ReminderTemplate, RepeatInterval, UpdateReminderTemplateDto,
+};
+use chrono::{DateTime, Utc};
+use rusqlite::{params, OptionalExtension, Result, Row};
+use uuid::Uuid;
+
+/// Columns selected for every template query, in the order expected by `map_row_to_template`
+const TEMPLATE_COLUMNS: &str = "id, name, title, description, repeat_interval, \
+     default_offset_minutes, created_at, updated_at";
+
+/// Repository for reminder template data access
+pub struct ReminderTemplateRepository<'a> {
+    db: &'a Database,
+}
+
+impl<'a> ReminderTemplateRepository<'a> {
+    /// Create a new ReminderTemplateRepository instance
+    pub fn new(db: &'a Database) -> Self {
+        Self { db }
+    }
+
+    /// Create a new reminder template
+    pub fn create(&self, dto: CreateReminderTemplateDto) -> Result<ReminderTemplate> {
+        let id = Uuid::new_v4().to_string();
+        let now = Utc::now().to_rfc3339();
+
+        self.db.connection().execute(
+            "INSERT INTO reminder_templates (
+                id, name, title, description, repeat_interval,
+                default_offset_minutes, created_at, updated_at
+            ) VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8)",
+            params![
+                id,
+                dto.name,
+                dto.title,
+                dto.description,
+                dto.repeat_interval.as_str(),
+                dto.default_offset_minutes,
+                now,
+                now,
+            ],
+        )?;
+
+        self.find_by_id(&id)?
+            .ok_or(rusqlite::Error::QueryReturnedNoRows)
+    }
+
+    /// Find a template by ID
+    pub fn find_by_id(&self, id: &str) -> Result<Option<ReminderTemplate>> {
+        self.db
+            .connection()
+            .query_row(
+                &format!(
+                    "SELECT {} FROM reminder_templates WHERE id = ?1",
+                    TEMPLATE_COLUMNS
+                ),
+                params![id],
+                Self::map_row_to_template,
+            )
+            .optional()
+    }
+
+    /// Get all templates ordered by name
+    pub fn find_all(&self) -> Result<Vec<ReminderTemplate>> {
+        let conn = self.db.connection();
+        let mut stmt = conn.prepare(&format!(
+            "SELECT {} FROM reminder_templates ORDER BY name COLLATE NOCASE",
+            TEMPLATE_COLUMNS
+        ))?;
+
+        let templates = stmt
+            .query_map([], Self::map_row_to_template)?
+            .collect::<Result<Vec<ReminderTemplate>>>()?;
+
+        Ok(templates)
+    }
+
+    /// Update the provided fields of a template
+    pub fn update(&self, id: &str, dto: UpdateReminderTemplateDto) -> Result<ReminderTemplate> {
+        let mut updates = Vec::new();
+        let mut params: Vec<Box<dyn rusqlite::ToSql>> = Vec::new();
+
+        if let Some(name) = &dto.name {
+            updates.push("name = ?");
+            params.push(Box::new(name.clone()));
+        }
+        if let Some(title) = &dto.title {
+            updates.push("title = ?");
+            params.push(Box::new(title.clone()));
+        }
+        if let Some(description) = &dto.description {
+            // An empty description clears it
+            updates.push("description = ?");
+            params.push(Box::new(
+                Some(description.clone()).filter(|d| !d.trim().is_empty()),
+            ));
+        }
+        if let Some(repeat_interval) = &dto.repeat_interval {
+            updates.push("repeat_interval = ?");
+            params.push(Box::new(repeat_interval.as_str().to_string()));
+        }
+        if let Some(offset) = dto.default_offset_minutes {
+            updates.push("default_offset_minutes = ?");
+            params.push(Box::new(offset));
+        }
+
+        if !updates.is_empty() {
+            updates.push("updated_at = ?");
+            params.push(Box::new(Utc::now().to_rfc3339()));
+
+            let query = format!(
+                "UPDATE reminder_templates SET {} WHERE id = ?",
+                updates.join(", ")
+            );
+            params.push(Box::new(id.to_string()));
+
+            let param_refs: Vec<&dyn rusqlite::ToSql> = params.iter().map(|p| p.as_ref()).collect();
+            self.db
+                .connection()
+                .execute(&query, param_refs.as_slice())?;
+        }
+
+        self.find_by_id(id)?
+            .ok_or(rusqlite::Error::QueryReturnedNoRows)
+    }
+
+    /// Delete a template
+    pub fn delete(&self, id: &str) -> Result<bool> {
+        let rows_affected = self
+            .db
+            .connection()
+            .execute("DELETE FROM reminder_templates WHERE id = ?1", params![id])?;
+        Ok(rows_affected > 0)
+    }
+
+    fn map_row_to_template(row: &Row) -> Result<ReminderTemplate> {
+        let repeat_interval: String = row.get(4)?;
+        let created_at: String = row.get(6)?;
+        let updated_at: String = row.get(7)?;
+
+        Ok(ReminderTemplate {
+            id: row.get(0)?,
+            name: row.get(1)?,
+            title: row.get(2)?,
+            description: row.get(3)?,
+            repeat_interval: RepeatInterval::from_str(&repeat_interval),
+            default_offset_minutes: row.get(5)?,
+            created_at: DateTime::parse_from_rfc3339(&created_at)
+                .map(|d| d.with_timezone(&Utc))
+                .unwrap_or_else(|_| Utc::now()),
+            updated_at: DateTime::parse_from_rfc3339(&updated_at)
+                .map(|d| d.with_timezone(&Utc))
+                .unwrap_or_else(|_| Utc::now()),
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rusqlite::Connection;
+
+    fn setup_test_db() -> Database {
+        let conn = Connection::open_in_memory().unwrap();
+
+        conn.execute(
+            "CREATE TABLE reminder_templates (
+                id TEXT PRIMARY KEY,
+                name TEXT NOT NULL,
+                title TEXT NOT NULL,
+                description TEXT,
+                repeat_interval TEXT NOT NULL,
+                default_offset_minutes INTEGER NOT NULL,
+                created_at TEXT NOT NULL,
+                updated_at TEXT NOT NULL
+            )",
+            [],
+        )
+        .unwrap();
+
+        Database::new_from_connection(conn)
+    }
+
+    fn pomodoro() -> CreateReminderTemplateDto {
+        CreateReminderTemplateDto {
+            name: "Pomodoro".to_string(),
+            title: "Take a break".to_string(),
+            description: Some("Stand up and stretch".to_string()),
+            repeat_interval: RepeatInterval::none(),
+            default_offset_minutes: 25,
+        }
+    }
+
+    #[test]
+    fn test_create_and_find_template() {
+        let db = setup_test_db();
+        let repo = ReminderTemplateRepository::new(&db);
+
+        let created = repo.create(pomodoro()).unwrap();
+        assert_eq!(created.name, "Pomodoro");
+        assert_eq!(created.default_offset_minutes, 25);
+
+        let found = repo.find_by_id(&created.id).unwrap().unwrap();
+        assert_eq!(found.title, "Take a break");
+        assert_eq!(found.description.as_deref(), Some("Stand up and stretch"));
+        assert!(repo.find_by_id("missing").unwrap().is_none());
+    }
+
+    #[test]
+    fn test_find_all_orders_by_name() {
+        let db = setup_test_db();
+        let repo = ReminderTemplateRepository::new(&db);
+
+        for name in ["water", "Pomodoro", "Eyes"] {
+            repo.create(CreateReminderTemplateDto {
+                name: name.to_string(),
+                ..pomodoro()
+            })
+            .unwrap();
+        }
+
+        let names: Vec<String> = repo
+            .find_all()
+            .unwrap()
+            .into_iter()
+            .map(|t| t.name)
+            .collect();
+        assert_eq!(names, vec!["Eyes", "Pomodoro", "water"]);
+    }
+
+    #[test]
+    fn test_update_and_delete_template() {
+        let db = setup_test_db();
+        let repo = ReminderTemplateRepository::new(&db);
+        let created = repo.create(pomodoro()).unwrap();
+
+        let updated = repo
+            .update(
+                &created.id,
+                UpdateReminderTemplateDto {
+                    default_offset_minutes: Some(50),
+                    description: Some(String::new()),
+                    ..Default::default()
+                },
+            )
+            .unwrap();
+        assert_eq!(updated.default_offset_minutes, 50);
+        assert_eq!(updated.description, None);
+        assert_eq!(updated.title, "Take a break");
+
+        assert!(repo.delete(&created.id).unwrap());
+        assert!(!repo.delete(&created.id).unwrap());
+        assert!(repo.find_all().unwrap().is_empty());
+    }
+}
@@ -0,0 +1,159 @@
+use crate::db::Database;
+use crate::models::{AppSettings, Setting};
+use rusqlite::{params, OptionalExtension, Result};
+use std::collections::HashMap;
+
+/// Repository for settings data access
+pub struct SettingsRepository<'a> {
+    db: &'a Database,
+}
+
+impl<'a> SettingsRepository<'a> {
+    /// Create a new SettingsRepository instance
+    pub fn new(db: &'a Database) -> Self {
+        Self { db }
+    }
+
+    /// Get all stored settings rows
+    pub fn find_all(&self) -> Result<Vec<Setting>> {
+        let conn = self.db.connection();
+
+        let mut stmt = conn.prepare("SELECT key, value, updated_at FROM settings")?;
+
+        let settings = stmt
+            .query_map([], |row| {
+                Ok(Setting {
+                    key: row.get(0)?,
+                    value: row.get(1)?,
+                    updated_at: row.get(2)?,
+                })
+            })?
+            .collect::<Result<Vec<Setting>>>()?;
+
+        Ok(settings)
+    }
+
+    /// Get a single setting value by key
+    pub fn get(&self, key: &str) -> Result<Option<String>> {
+        let conn = self.db.connection();
+        conn.query_row(
+            "SELECT value FROM settings WHERE key = ?1",
+            params![key],
+            |row| row.get(0),
+        )
+        .optional()
+    }
+
+    /// Insert or overwrite a setting value
+    pub fn set(&self, key: &str, value: &str) -> Result<()> {
+        let conn = self.db.connection();
+        conn.execute(
+            "INSERT OR REPLACE INTO settings (key, value, updated_at) VALUES (?1, ?2, datetime('now'))",
+            params![key, value],
+        )?;
+        Ok(())
+    }
+
+    /// Get all settings as a key-value map
+    pub fn get_all(&self) -> Result<HashMap<String, String>> {
+        Ok(self
+            .find_all()?
+            .into_iter()
+            .map(|setting| (setting.key, setting.value))
+            .collect())
+    }
+
+    /// Load the typed application settings, falling back to defaults for missing keys
+    pub fn get_app_settings(&self) -> Result<AppSettings> {
+        Ok(AppSettings::from_key_value_pairs(self.find_all()?))
+    }
+
+    /// Store the default value of every application setting not stored yet,
+    /// returning the number of rows inserted
+    pub fn seed_defaults(&self) -> Result<usize> {
+        self.db.transaction(|conn| {
+            let mut inserted = 0;
+            for (key, value) in AppSettings::default().to_key_value_pairs() {
+                inserted += conn.execute(
+                    "INSERT OR IGNORE INTO settings (key, value, updated_at) VALUES (?1, ?2, datetime('now'))",
+                    params![key, value],
+                )?;
+            }
+            Ok(inserted)
+        })
+    }
+
+    /// Overwrite every application setting with its default value
+    ///
+    /// Keys outside `AppSettings` (e.g. frontend view preferences) are left alone.
+    pub fn reset_app_settings(&self) -> Result<AppSettings> {
+        let defaults = AppSettings::default();
+        self.db.transaction(|conn| {
+            for (key, value) in defaults.to_key_value_pairs() {
+                conn.execute(
+                    "INSERT OR REPLACE INTO settings (key, value, updated_at) VALUES (?1, ?2, datetime('now'))",
+                    params![key, value],
+                )?;
+            }
+            Ok(())
+        })?;
+        Ok(defaults)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn setup_test_db() -> Database {
+        let conn = rusqlite::Connection::open_in_memory().unwrap();
+
+        conn.execute(
+            "CREATE TABLE settings (
+                key TEXT PRIMARY KEY,
+                value TEXT NOT NULL,
+                updated_at TEXT NOT NULL DEFAULT (datetime('now'))
+            )",
+            [],
+        )
+        .unwrap();
+
+        Database::new_from_connection(conn)
+    }
+
+    #[test]
+    fn test_get_set_overwrite() {
+        let db = setup_test_db();
+        let repo = SettingsRepository::new(&db);
+
+        assert_eq!(repo.get("theme").unwrap(), None);
+
+        repo.set("theme", "dark").unwrap();
+        assert_eq!(repo.get("theme").unwrap(), Some("dark".to_string()));
+
+        repo.set("theme", "light").unwrap();
+        assert_eq!(repo.get("theme").unwrap(), Some("light".to_string()));
+        assert_eq!(repo.get_all().unwrap().len(), 1);
+    }
+
+    #[test]
+    fn test_unknown_keys_round_trip() {
+        let db = setup_test_db();
+        let repo = SettingsRepository::new(&db);
+
+        repo.set("view.sort_field", "due_date").unwrap();
+        repo.set("view.filters", r#"{"status":"pending"}"#).unwrap();
+        repo.set("theme", "dark").unwrap();
+
+        let all = repo.get_all().unwrap();
+        assert_eq!(all.get("view.sort_field"), Some(&"due_date".to_string()));
+        assert_eq!(
+            all.get("view.filters"),
+            Some(&r#"{"status":"pending"}"#.to_string())
+        );
+
+        // The typed view picks up known keys and ignores the rest
+        let settings = repo.get_app_settings().unwrap();
+        assert_eq!(settings.theme, "dark");
+    }
+}
@@ -0,0 +1,83 @@
+use crate::db::Database;
+use crate::models::Tag;
+use chrono::{DateTime, Utc};
+use rusqlite::{params, OptionalExtension, Result, Row};
+
+/// Repository for tag data access
+pub struct TagRepository<'a> {
+    db: &'a Database,
+}
+
+impl<'a> TagRepository<'a> {
+    /// Create a new TagRepository instance
+    pub fn new(db: &'a Database) -> Self {
+        Self { db }
+    }
+
+    /// Get all tags ordered by name
+    pub fn find_all(&self) -> Result<Vec<Tag>> {
+        let conn = self.db.connection();
+
+        let mut stmt =
+            conn.prepare("SELECT id, name, color, created_at FROM tags ORDER BY name")?;
+
+        let tags = stmt
+            .query_map([], Self::map_row_to_tag)?
+            .collect::<Result<Vec<Tag>>>()?;
+
+        Ok(tags)
+    }
+
+    /// Find a tag by ID
+    pub fn find_by_id(&self, id: &str) -> Result<Option<Tag>> {
+        let conn = self.db.connection();
+        conn.query_row(
+            "SELECT id, name, color, created_at FROM tags WHERE id = ?1",
+            params![id],
+            Self::map_row_to_tag,
+        )
+        .optional()
+    }
+
+    /// Find a tag by its (unique) name
+    pub fn find_by_name(&self, name: &str) -> Result<Option<Tag>> {
+        let conn = self.db.connection();
+        conn.query_row(
+            "SELECT id, name, color, created_at FROM tags WHERE name = ?1",
+            params![name],
+            Self::map_row_to_tag,
+        )
+        .optional()
+    }
+
+    /// Insert a tag keeping its id; returns false when the id or name is already taken
+    pub fn insert_if_absent(&self, tag: &Tag) -> Result<bool> {
+        let conn = self.db.connection();
+        let inserted = conn.execute(
+            "INSERT OR IGNORE INTO tags (id, name, color, created_at) VALUES (?1, ?2, ?3, ?4)",
+            params![tag.id, tag.name, tag.color, tag.created_at.to_rfc3339()],
+        )?;
+        Ok(inserted > 0)
+    }
+
+    /// Delete a tag and its task associations in one transaction; returns false if it didn't exist
+    pub fn delete(&self, id: &str) -> Result<bool> {
+        self.db.transaction(|conn| {
+            conn.execute("DELETE FROM task_tags WHERE tag_id = ?1", params![id])?;
+            let deleted = conn.execute("DELETE FROM tags WHERE id = ?1", params![id])?;
+            Ok(deleted > 0)
+        })
+    }
+
+    fn map_row_to_tag(row: &Row) -> Result<Tag> {
+        let created_at: String = row.get(3)?;
+        Ok(Tag {
+            id: row.get(0)?,
+            name: row.get(1)?,
+            color: row.get(2)?,
+            created_at: DateTime::parse_from_rfc3339(&created_at)
+                .map(|d| d.with_timezone(&Utc))
+                .unwrap_or_else(|_| Utc::now()),
+        })
+    }
+}
@@ -1,12 +1,43 @@
 use crate::db::Database;
+use crate::models::search::like_pattern;
 use crate::models::{
-    CreateTaskDto, PaginatedResponse, Pagination, Tag, Task, TaskFilter, TaskPriority, TaskSort,
-    TaskSortField, TaskStatus, UpdateTaskDto,
+    ChecklistItem, CreateTaskDto, EffectiveTaskStatus, EstimationEntry, FocusSession,
+    PaginatedResponse, Pagination, Tag, Task, TaskAttachment, TaskComment, TaskFilter,
+    TaskPriority, TaskSort, TaskSortField, TaskStatus, TaskStatusChange, TaskSummary,
+    UpdateTaskDto,
 };
-use chrono::{DateTime, Utc};
-use rusqlite::{params, Result, Row, ToSql};
+use chrono::{DateTime, Duration, Utc};
+use rusqlite::{params, Connection, Result, Row, ToSql};
+use std::collections::HashMap;
 use uuid::Uuid;
 
+/// SQL for `TaskPriority::weight` so priority sorts by importance rather than alphabetically
+fn priority_rank() -> String {
+    let cases: Vec<String> = TaskPriority::all()
+        .iter()
+        .map(|p| format!("WHEN '{}' THEN {}", p.as_str(), p.weight()))
+        .collect();
+    format!("CASE priority {} ELSE 0 END", cases.join(" "))
+}
+
+/// Workflow position of a status (Pending=0 .. Cancelled=3) so status sorts logically
+const STATUS_RANK: &str = "CASE status \
+     WHEN 'pending' THEN 0 WHEN 'in_progress' THEN 1 WHEN 'completed' THEN 2 ELSE 3 END";
+
+/// Columns selected for every task query, in the order expected by `map_row_to_task`
+const TASK_COLUMNS: &str = "id, title, description, status, priority, \
+     due_date, completed_at, image_path, notes, \
+     estimated_minutes, actual_minutes, created_at, updated_at, parent_id, sort_order, \
+     (SELECT CAST(SUM(is_done) AS REAL) / COUNT(*) FROM task_checklist_items \
+      WHERE task_checklist_items.task_id = tasks.id), \
+     color, is_pinned, series_id, is_deferred, deferred_until, completion_note";
+
+/// Gap between consecutive sort positions, leaving room for inserts without renumbering
+pub const SORT_ORDER_GAP: i64 = 1024;
+
+/// Most task ids bound by a single tag lookup in `load_tags_for_tasks`
+const TAG_LOAD_CHUNK_SIZE: usize = 500;
+
 /// Repository for task data access
 pub struct TaskRepository<'a> {
     db: &'a Database,
@@ -24,34 +55,41 @@ impl<'a> TaskRepository<'a> {
         let now = Utc::now();
         let status = TaskStatus::Pending;
 
-        let conn = self.db.connection();
+        // New tasks go to the end of the manual ordering
+        let sort_order = self.max_sort_order()? + SORT_ORDER_GAP;
 
-        // Insert task
-        conn.execute(
-            "INSERT INTO tasks (
+        // Insert the task and its tags atomically so a failed tag association leaves no orphan
+        self.db.transaction(|conn| {
+            conn.execute(
+                "INSERT INTO tasks (
                 id, title, description, status, priority, 
                 due_date, image_path, notes, estimated_minutes,
-                created_at, updated_at
-            ) VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, ?9, ?10, ?11)",
-            params![
-                id,
-                dto.title,
-                dto.description,
-                status.as_str(),
-                dto.priority.as_str(),
-                dto.due_date.map(|d| d.to_rfc3339()),
-                dto.image_path,
-                dto.notes,
-                dto.estimated_minutes,
-                now.to_rfc3339(),
-                now.to_rfc3339(),
-            ],
-        )?;
+                created_at, updated_at, parent_id, sort_order, color
+            ) VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, ?9, ?10, ?11, ?12, ?13, ?14)",
+                params![
+                    id,
+                    dto.title,
+                    dto.description,
+                    status.as_str(),
+                    dto.priority.as_str(),
+                    dto.due_date.map(|d| d.to_rfc3339()),
+                    dto.image_path,
+                    dto.notes,
+                    dto.estimated_minutes,
+                    now.to_rfc3339(),
+                    now.to_rfc3339(),
+                    dto.parent_id,
+                    sort_order,
+                    dto.color,
+                ],
+            )?;
 
-        // Associate tags
-        if !dto.tag_ids.is_empty() {
-            self.associate_tags(&id, &dto.tag_ids)?;
-        }
+            if !dto.tag_ids.is_empty() {
+                Self::associate_tags(conn, &id, &dto.tag_ids)?;
+            }
+
+            Ok(())
+        })?;
 
         // Fetch and return the created task
         self.find_by_id(&id)
@@ -62,13 +100,8 @@ impl<'a> TaskRepository<'a> {
     pub fn find_by_id(&self, id: &str) -> Result<Option<Task>> {
         let conn = self.db.connection();
 
-        let mut stmt = conn.prepare(
-            "SELECT id, title, description, status, priority, 
-                    due_date, completed_at, image_path, notes, 
-                    estimated_minutes, actual_minutes, created_at, updated_at
-             FROM tasks 
-             WHERE id = ?1",
-        )?;
+        let mut stmt =
+            conn.prepare(&format!("SELECT {} FROM tasks WHERE id = ?1", TASK_COLUMNS))?;
 
         let task_result = stmt.query_row(params![id], |row| self.map_row_to_task(row));
 
@@ -85,8 +118,19 @@ impl<'a> TaskRepository<'a> {
 
     /// Update a task
     pub fn update(&self, id: &str, dto: UpdateTaskDto) -> Result<Task> {
-        let conn = self.db.connection();
+        self.update_with_completion_note(id, dto, None)
+    }
 
+    /// Update a task, storing `completion_note` if the update completes it
+    ///
+    /// The note is written by the same statement as the status; moving the task
+    /// out of Completed clears it.
+    pub fn update_with_completion_note(
+        &self,
+        id: &str,
+        dto: UpdateTaskDto,
+        completion_note: Option<&str>,
+    ) -> Result<Task> {
         // Build dynamic UPDATE query based on provided fields
         let mut updates = Vec::new();
         let mut params: Vec<Box<dyn rusqlite::ToSql>> = Vec::new();
@@ -98,10 +142,31 @@ impl<'a> TaskRepository<'a> {
         if let Some(description) = &dto.description {
             updates.push("description = ?");
             params.push(Box::new(description.clone()));
+        } else if dto.clears("description") {
+            updates.push("description = NULL");
         }
+        // Remember the current status so an actual change can be recorded in the history
+        let previous_status = match &dto.status {
+            Some(_) => self.find_by_id(id)?.map(|task| task.status),
+            None => None,
+        };
+
         if let Some(status) = &dto.status {
             updates.push("status = ?");
             params.push(Box::new(status.as_str().to_string()));
+
+            // Stamp completion on the way in and drop it when the task is reopened
+            if *status == TaskStatus::Completed {
+                if previous_status.as_ref() != Some(&TaskStatus::Completed) {
+                    updates.push("completed_at = ?");
+                    params.push(Box::new(Utc::now().to_rfc3339()));
+                    updates.push("completion_note = ?");
+                    params.push(Box::new(completion_note.map(str::to_string)));
+                }
+            } else {
+                updates.push("completed_at = NULL");
+                updates.push("completion_note = NULL");
+            }
         }
         if let Some(priority) = &dto.priority {
             updates.push("priority = ?");
@@ -110,22 +175,44 @@ impl<'a> TaskRepository<'a> {
         if let Some(due_date) = &dto.due_date {
             updates.push("due_date = ?");
             params.push(Box::new(due_date.to_rfc3339()));
+            // A rescheduled task may become overdue (and be notified) again
+            updates.push("overdue_notified_at = NULL");
+        } else if dto.clears("due_date") {
+            updates.push("due_date = NULL");
+            updates.push("overdue_notified_at = NULL");
         }
         if let Some(image_path) = &dto.image_path {
+            // An empty path clears the image
             updates.push("image_path = ?");
-            params.push(Box::new(image_path.clone()));
+            params.push(Box::new(
+                Some(image_path.clone()).filter(|p| !p.trim().is_empty()),
+            ));
+        } else if dto.clears("image_path") {
+            updates.push("image_path = NULL");
         }
         if let Some(notes) = &dto.notes {
             updates.push("notes = ?");
             params.push(Box::new(notes.clone()));
+        } else if dto.clears("notes") {
+            updates.push("notes = NULL");
         }
         if let Some(estimated_minutes) = dto.estimated_minutes {
             updates.push("estimated_minutes = ?");
             params.push(Box::new(estimated_minutes));
+        } else if dto.clears("estimated_minutes") {
+            updates.push("estimated_minutes = NULL");
         }
         if let Some(actual_minutes) = dto.actual_minutes {
             updates.push("actual_minutes = ?");
             params.push(Box::new(actual_minutes));
+        } else if dto.clears("actual_minutes") {
+            updates.push("actual_minutes = NULL");
+        }
+        if let Some(color) = &dto.color {
+            updates.push("color = ?");
+            params.push(Box::new(color.clone()));
+        } else if dto.clears("color") {
+            updates.push("color = NULL");
         }
 
         if updates.is_empty() && dto.tag_ids.is_none() {
@@ -135,25 +222,31 @@ impl<'a> TaskRepository<'a> {
                 .ok_or(rusqlite::Error::QueryReturnedNoRows);
         }
 
-        // Execute update if there are field changes
-        if !updates.is_empty() {
-            let query = format!("UPDATE tasks SET {} WHERE id = ?", updates.join(", "));
-            params.push(Box::new(id.to_string()));
+        // Any change, including tags alone, refreshes updated_at
+        updates.push("updated_at = ?");
+        params.push(Box::new(Utc::now().to_rfc3339()));
 
-            let param_refs: Vec<&dyn rusqlite::ToSql> = params.iter().map(|p| p.as_ref()).collect();
+        let query = format!("UPDATE tasks SET {} WHERE id = ?", updates.join(", "));
+        params.push(Box::new(id.to_string()));
 
+        let param_refs: Vec<&dyn rusqlite::ToSql> = params.iter().map(|p| p.as_ref()).collect();
+
+        // The row, its status history and its tags change together
+        self.db.transaction(|conn| {
             conn.execute(&query, param_refs.as_slice())?;
-        }
 
-        // Update tags if provided
-        if let Some(tag_ids) = &dto.tag_ids {
-            // Remove existing tags
-            conn.execute("DELETE FROM task_tags WHERE task_id = ?1", params![id])?;
-            // Add new tags
-            if !tag_ids.is_empty() {
-                self.associate_tags(id, tag_ids)?;
+            if let (Some(from), Some(to)) = (&previous_status, &dto.status) {
+                if from != to {
+                    self.record_status_change(id, from, to)?;
+                }
             }
-        }
+
+            // Update tags if provided
+            if let Some(tag_ids) = &dto.tag_ids {
+                Self::replace_tags(conn, id, tag_ids)?;
+            }
+            Ok(())
+        })?;
 
         // Fetch and return updated task
         self.find_by_id(id)?
@@ -162,11 +255,90 @@ impl<'a> TaskRepository<'a> {
 
     /// Delete a task
     pub fn delete(&self, id: &str) -> Result<bool> {
-        let conn = self.db.connection();
+        Self::delete_with(self.db.connection(), id)
+    }
+
+    /// Delete a task and its dependent rows on `conn`, so callers can batch deletes in a transaction
+    fn delete_with(conn: &Connection, id: &str) -> Result<bool> {
+        // Remove dependency links in both directions
+        conn.execute(
+            "DELETE FROM task_dependencies WHERE task_id = ?1 OR depends_on_id = ?1",
+            params![id],
+        )?;
+
+        // Remove status history
+        conn.execute(
+            "DELETE FROM task_status_history WHERE task_id = ?1",
+            params![id],
+        )?;
+
+        // Remove comments
+        conn.execute("DELETE FROM task_comments WHERE task_id = ?1", params![id])?;
+
+        // Remove checklist items
+        conn.execute(
+            "DELETE FROM task_checklist_items WHERE task_id = ?1",
+            params![id],
+        )?;
+
+        // Remove attachment references
+        conn.execute(
+            "DELETE FROM task_attachments WHERE task_id = ?1",
+            params![id],
+        )?;
+
         let rows_affected = conn.execute("DELETE FROM tasks WHERE id = ?1", params![id])?;
         Ok(rows_affected > 0)
     }
 
+    /// Ids of finished tasks last touched before `cutoff` (or all of them when `None`)
+    ///
+    /// Completed tasks are matched on `completed_at`, falling back to `updated_at`.
+    /// Tasks with unfinished subtasks are left out, since deleting them would
+    /// cascade to the subtasks.
+    pub fn find_finished_ids_before(
+        &self,
+        cutoff: Option<DateTime<Utc>>,
+        include_cancelled: bool,
+    ) -> Result<Vec<String>> {
+        let conn = self.db.connection();
+
+        let statuses = if include_cancelled {
+            "'completed', 'cancelled'"
+        } else {
+            "'completed'"
+        };
+        let mut stmt = conn.prepare(&format!(
+            "SELECT id FROM tasks
+             WHERE status IN ({statuses})
+               AND (?1 IS NULL OR COALESCE(completed_at, updated_at) < ?1)
+               AND NOT EXISTS (
+                   SELECT 1 FROM tasks child
+                   WHERE child.parent_id = tasks.id AND child.status NOT IN ({statuses})
+               )"
+        ))?;
+
+        let ids = stmt
+            .query_map(params![cutoff.map(|c| c.to_rfc3339())], |row| row.get(0))?
+            .collect::<Result<Vec<String>>>()?;
+
+        Ok(ids)
+    }
+
+    /// Delete several tasks and their reminders in a single transaction, returning how many were removed
+    pub fn delete_many(&self, ids: &[String]) -> Result<usize> {
+        self.db.transaction(|conn| {
+            let mut deleted = 0;
+            for id in ids {
+                conn.execute("DELETE FROM reminders WHERE task_id = ?1", params![id])?;
+                if Self::delete_with(conn, id)? {
+                    deleted += 1;
+                }
+            }
+            Ok(deleted)
+        })
+    }
+
     /// Find all tasks with filtering, sorting, and pagination
     pub fn find_all(
         &self,
@@ -192,13 +364,12 @@ impl<'a> TaskRepository<'a> {
 
         // Get paginated results
         let query = format!(
-            "SELECT id, title, description, status, priority, 
-                    due_date, completed_at, image_path, notes, 
-                    estimated_minutes, actual_minutes, created_at, updated_at
+            "SELECT {}
              FROM tasks 
              {} 
              {} 
              LIMIT ?{} OFFSET ?{}",
+            TASK_COLUMNS,
             where_clause,
             order_by,
             where_params.len() + 1,
@@ -214,25 +385,149 @@ impl<'a> TaskRepository<'a> {
 
         let param_refs: Vec<&dyn rusqlite::ToSql> = all_params.iter().map(|p| p.as_ref()).collect();
 
-        let tasks = stmt
+        let mut tasks = stmt
             .query_map(param_refs.as_slice(), |row| self.map_row_to_task(row))?
             .collect::<Result<Vec<Task>>>()?;
+        self.load_tags_for_tasks(&mut tasks)?;
 
-        // Load tags for each task
-        let mut tasks_with_tags = Vec::new();
-        for mut task in tasks {
-            task.tags = self.load_tags_for_task(&task.id)?;
-            tasks_with_tags.push(task);
-        }
+        Ok(PaginatedResponse::new(tasks, total, pagination))
+    }
+
+    /// Same filtering, sorting and paging as `find_all`, returning only summary fields
+    ///
+    /// Tag counts come from a grouped join in the same query, so unlike `find_all`
+    /// no further query is run per task.
+    pub fn find_all_summaries(
+        &self,
+        filter: Option<TaskFilter>,
+        sort: Option<Vec<TaskSort>>,
+        pagination: Pagination,
+    ) -> Result<PaginatedResponse<TaskSummary>> {
+        let conn = self.db.connection();
+
+        let (where_clause, where_params) = self.build_where_clause(&filter);
+        let order_by = self.build_order_by(&sort);
+
+        let count_query = format!("SELECT COUNT(*) FROM tasks {}", where_clause);
+        let total: u32 = conn.query_row(
+            &count_query,
+            rusqlite::params_from_iter(where_params.iter()),
+            |row| row.get(0),
+        )?;
+
+        let query = format!(
+            "SELECT id, title, status, priority, due_date, COALESCE(tag_counts.tag_count, 0)
+             FROM tasks
+             LEFT JOIN (SELECT task_id, COUNT(*) AS tag_count FROM task_tags GROUP BY task_id)
+                 AS tag_counts ON tag_counts.task_id = tasks.id
+             {}
+             {}
+             LIMIT ?{} OFFSET ?{}",
+            where_clause,
+            order_by,
+            where_params.len() + 1,
+            where_params.len() + 2,
+        );
+
+        let mut stmt = conn.prepare(&query)?;
+
+        let mut all_params: Vec<Box<dyn ToSql>> = where_params;
+        all_params.push(Box::new(pagination.page_size));
+        all_params.push(Box::new(pagination.offset()));
+
+        let param_refs: Vec<&dyn rusqlite::ToSql> = all_params.iter().map(|p| p.as_ref()).collect();
+
+        let summaries = stmt
+            .query_map(param_refs.as_slice(), |row| {
+                let status: String = row.get(2)?;
+                let priority: String = row.get(3)?;
+                let due_date: Option<String> = row.get(4)?;
+                Ok(TaskSummary {
+                    id: row.get(0)?,
+                    title: row.get(1)?,
+                    status: TaskStatus::from_str(&status).unwrap_or(TaskStatus::Pending),
+                    priority: TaskPriority::from_str(&priority).unwrap_or(TaskPriority::Medium),
+                    due_date: due_date
+                        .and_then(|d| DateTime::parse_from_rfc3339(&d).ok())
+                        .map(|d| d.with_timezone(&Utc)),
+                    tag_count: row.get(5)?,
+                })
+            })?
+            .collect::<Result<Vec<TaskSummary>>>()?;
+
+        Ok(PaginatedResponse::new(summaries, total, pagination))
+    }
+
+    /// Find tasks whose title, description or notes contain `query` (case- and accent-insensitive)
+    pub fn search(&self, query: &str, limit: u32) -> Result<Vec<Task>> {
+        let conn = self.db.connection();
+        let pattern = like_pattern(query);
+
+        let mut stmt = conn.prepare(&format!(
+            "SELECT {}
+             FROM tasks
+             WHERE fold(title) LIKE ?1 ESCAPE '\\'
+                OR fold(description) LIKE ?1 ESCAPE '\\'
+                OR fold(notes) LIKE ?1 ESCAPE '\\'
+             ORDER BY updated_at DESC
+             LIMIT ?2",
+            TASK_COLUMNS
+        ))?;
+
+        let mut tasks = stmt
+            .query_map(params![pattern, limit], |row| self.map_row_to_task(row))?
+            .collect::<Result<Vec<Task>>>()?;
+
+        self.load_tags_for_tasks(&mut tasks)?;
+        Ok(tasks)
+    }
+
+    /// Search tasks ranked by where the query matches, best first, at most `limit`
+    ///
+    /// The best-matching field wins: title beats description beats notes, and within
+    /// a field a match at the start of a word beats a plain substring. Ties go to the
+    /// most recently updated task.
+    pub fn search_ranked(&self, query: &str, limit: u32) -> Result<Vec<Task>> {
+        let conn = self.db.connection();
+        let pattern = like_pattern(query);
+        let word_start = format!("% {}", &pattern[1..]);
+
+        let score = |column: &str, weight: u8| {
+            format!(
+                "CASE WHEN fold({column}) LIKE ?1 ESCAPE '\\'
+                      THEN {weight} + (' ' || replace(replace(fold({column}), char(10), ' '), char(9), ' ') LIKE ?2 ESCAPE '\\')
+                      ELSE 0 END"
+            )
+        };
+        let mut stmt = conn.prepare(&format!(
+            "SELECT {columns}
+             FROM (SELECT *, MAX({title}, {description}, {notes}) AS score FROM tasks) AS tasks
+             WHERE score > 0
+             ORDER BY score DESC, julianday(updated_at) DESC
+             LIMIT ?3",
+            columns = TASK_COLUMNS,
+            title = score("title", 6),
+            description = score("description", 4),
+            notes = score("notes", 2),
+        ))?;
+
+        let mut tasks = stmt
+            .query_map(params![pattern, word_start, limit], |row| {
+                self.map_row_to_task(row)
+            })?
+            .collect::<Result<Vec<Task>>>()?;
 
-        Ok(PaginatedResponse::new(tasks_with_tags, total, pagination))
+        self.load_tags_for_tasks(&mut tasks)?;
+        Ok(tasks)
     }
 
-    /// Count tasks by status
+    /// Count tasks by status, in workflow order
     pub fn count_by_status(&self) -> Result<Vec<(TaskStatus, u32)>> {
         let conn = self.db.connection();
-        let mut stmt =
-            conn.prepare("SELECT status, COUNT(*) as count FROM tasks GROUP BY status")?;
+        let mut stmt = conn.prepare(&format!(
+            "SELECT status, COUNT(*) as count FROM tasks GROUP BY status ORDER BY {}",
+            STATUS_RANK
+        ))?;
 
         let results = stmt
             .query_map([], |row| {
@@ -248,410 +543,2694 @@ impl<'a> TaskRepository<'a> {
         Ok(results)
     }
 
-    /// Get overdue tasks
-    pub fn find_overdue(&self) -> Result<Vec<Task>> {
+    /// Count tasks by priority, most urgent first
+    pub fn count_by_priority(&self) -> Result<Vec<(TaskPriority, u32)>> {
         let conn = self.db.connection();
-        let now = Utc::now().to_rfc3339();
+        let mut stmt = conn.prepare(&format!(
+            "SELECT priority, COUNT(*) as count FROM tasks GROUP BY priority ORDER BY {} DESC",
+            priority_rank()
+        ))?;
+
+        let results = stmt
+            .query_map([], |row| {
+                let priority_str: String = row.get(0)?;
+                let count: u32 = row.get(1)?;
+                Ok((
+                    TaskPriority::from_str(&priority_str).unwrap_or(TaskPriority::Medium),
+                    count,
+                ))
+            })?
+            .collect::<Result<Vec<_>>>()?;
+
+        Ok(results)
+    }
 
+    /// Count tasks per tag, by tag name; tags not applied to any task are left out
+    pub fn count_by_tag(&self) -> Result<Vec<(Tag, u32)>> {
+        let conn = self.db.connection();
         let mut stmt = conn.prepare(
-            "SELECT id, title, description, status, priority, 
-                    due_date, completed_at, image_path, notes, 
-                    estimated_minutes, actual_minutes, created_at, updated_at
-             FROM tasks 
-             WHERE due_date < ?1 
-               AND status NOT IN ('completed', 'cancelled')
-             ORDER BY due_date ASC",
+            "SELECT t.id, t.name, t.color, t.created_at, COUNT(*) as count
+             FROM tags t
+             INNER JOIN task_tags tt ON t.id = tt.tag_id
+             GROUP BY t.id
+             ORDER BY t.name",
         )?;
 
-        let tasks = stmt
-            .query_map(params![now], |row| self.map_row_to_task(row))?
-            .collect::<Result<Vec<Task>>>()?;
+        let results = stmt
+            .query_map([], |row| {
+                let created_at: String = row.get(3)?;
+                let count: u32 = row.get(4)?;
+                Ok((
+                    Tag {
+                        id: row.get(0)?,
+                        name: row.get(1)?,
+                        color: row.get(2)?,
+                        created_at: DateTime::parse_from_rfc3339(&created_at)
+                            .map(|d| d.with_timezone(&Utc))
+                            .unwrap_or_else(|_| Utc::now()),
+                    },
+                    count,
+                ))
+            })?
+            .collect::<Result<Vec<_>>>()?;
 
-        // Load tags for each task
-        let mut tasks_with_tags = Vec::new();
-        for mut task in tasks {
-            task.tags = self.load_tags_for_task(&task.id)?;
-            tasks_with_tags.push(task);
-        }
+        Ok(results)
+    }
+
+    /// Sum `(estimated_minutes, actual_minutes)` over tasks matching `filter`; missing values count as zero
+    pub fn sum_minutes(&self, filter: Option<TaskFilter>) -> Result<(i64, i64)> {
+        let conn = self.db.connection();
+        let (where_clause, where_params) = self.build_where_clause(&filter);
 
-        Ok(tasks_with_tags)
+        let query = format!(
+            "SELECT COALESCE(SUM(estimated_minutes), 0), COALESCE(SUM(actual_minutes), 0)
+             FROM tasks {}",
+            where_clause
+        );
+        conn.query_row(
+            &query,
+            rusqlite::params_from_iter(where_params.iter()),
+            |row| Ok((row.get(0)?, row.get(1)?)),
+        )
     }
 
-    // ========================================================================
-    // Private helper methods
-    // ========================================================================
+    /// Completed tasks finished since `since` that have both an estimate and actual minutes,
+    /// most recently completed first
+    pub fn find_estimation_pairs(&self, since: DateTime<Utc>) -> Result<Vec<EstimationEntry>> {
+        let conn = self.db.connection();
 
-    /// Map database row to Task struct
-    fn map_row_to_task(&self, row: &Row) -> Result<Task> {
-        let status_str: String = row.get(3)?;
-        let priority_str: String = row.get(4)?;
+        let mut stmt = conn.prepare(
+            "SELECT id, title, estimated_minutes, actual_minutes
+             FROM tasks
+             WHERE status = 'completed'
+               AND completed_at >= ?1
+               AND estimated_minutes > 0
+               AND actual_minutes IS NOT NULL
+             ORDER BY completed_at DESC",
+        )?;
 
-        let due_date: Option<String> = row.get(5)?;
-        let completed_at: Option<String> = row.get(6)?;
-        let created_at: String = row.get(11)?;
-        let updated_at: String = row.get(12)?;
+        let entries = stmt
+            .query_map(params![since.to_rfc3339()], |row| {
+                Ok(EstimationEntry::new(
+                    row.get(0)?,
+                    row.get(1)?,
+                    row.get(2)?,
+                    row.get(3)?,
+                ))
+            })?
+            .collect::<Result<Vec<EstimationEntry>>>()?;
 
-        Ok(Task {
-            id: row.get(0)?,
-            title: row.get(1)?,
-            description: row.get(2)?,
-            status: TaskStatus::from_str(&status_str).unwrap_or(TaskStatus::Pending),
-            priority: TaskPriority::from_str(&priority_str).unwrap_or(TaskPriority::Medium),
-            due_date: due_date
-                .and_then(|d| DateTime::parse_from_rfc3339(&d).ok())
-                .map(|d| d.with_timezone(&Utc)),
-            completed_at: completed_at
-                .and_then(|d| DateTime::parse_from_rfc3339(&d).ok())
-                .map(|d| d.with_timezone(&Utc)),
-            image_path: row.get(7)?,
-            notes: row.get(8)?,
-            estimated_minutes: row.get(9)?,
-            actual_minutes: row.get(10)?,
-            created_at: DateTime::parse_from_rfc3339(&created_at)
-                .map(|d| d.with_timezone(&Utc))
-                .unwrap_or_else(|_| Utc::now()),
-            updated_at: DateTime::parse_from_rfc3339(&updated_at)
-                .map(|d| d.with_timezone(&Utc))
-                .unwrap_or_else(|_| Utc::now()),
-            tags: Vec::new(), // Tags loaded separately
-        })
+        Ok(entries)
     }
 
-    /// Build WHERE clause from filter
-    fn build_where_clause(
-        &self,
-        filter: &Option<TaskFilter>,
-    ) -> (String, Vec<Box<dyn rusqlite::ToSql>>) {
-        let mut conditions = Vec::new();
-        let mut params: Vec<Box<dyn rusqlite::ToSql>> = Vec::new();
+    /// Get tasks created or updated after `since`, least recently updated first
+    ///
+    /// `updated_at` values are RFC 3339 strings whose fractional precision and offset
+    /// may differ, so both sides are compared as Julian days rather than as text.
+    pub fn find_modified_since(&self, since: DateTime<Utc>) -> Result<Vec<Task>> {
+        let conn = self.db.connection();
 
-        if let Some(f) = filter {
-            if let Some(status) = &f.status {
-                conditions.push("status = ?".to_string());
-                params.push(Box::new(status.as_str().to_string()));
-            }
+        let mut stmt = conn.prepare(&format!(
+            "SELECT {}
+             FROM tasks
+             WHERE julianday(updated_at) > julianday(?1)
+             ORDER BY julianday(updated_at) ASC",
+            TASK_COLUMNS
+        ))?;
 
-            if let Some(priority) = &f.priority {
-                conditions.push("priority = ?".to_string());
-                params.push(Box::new(priority.as_str().to_string()));
-            }
+        let mut tasks = stmt
+            .query_map(params![since.to_rfc3339()], |row| self.map_row_to_task(row))?
+            .collect::<Result<Vec<Task>>>()?;
+        self.load_tags_for_tasks(&mut tasks)?;
 
-            if let Some(search) = &f.search {
-                conditions.push("(title LIKE ? OR description LIKE ?)".to_string());
-                let search_pattern = format!("%{}%", search);
-                params.push(Box::new(search_pattern.clone()));
-                params.push(Box::new(search_pattern));
-            }
+        Ok(tasks)
+    }
 
-            if let Some(due_before) = &f.due_before {
-                conditions.push("due_date < ?".to_string());
-                params.push(Box::new(due_before.to_rfc3339()));
-            }
+    /// Get tasks more than `grace` past their due date
+    pub fn find_overdue(&self, grace: Duration) -> Result<Vec<Task>> {
+        let conn = self.db.connection();
+        let cutoff = (Utc::now() - grace).to_rfc3339();
 
-            if let Some(due_after) = &f.due_after {
-                conditions.push("due_date > ?".to_string());
-                params.push(Box::new(due_after.to_rfc3339()));
-            }
+        let mut stmt = conn.prepare(&format!(
+            "SELECT {}
+             FROM tasks 
+             WHERE due_date < ?1 
+               AND status NOT IN ('completed', 'cancelled')
+               AND is_deferred = 0
+             ORDER BY due_date ASC",
+            TASK_COLUMNS
+        ))?;
 
-            if let Some(tag_ids) = &f.tag_ids {
-                if !tag_ids.is_empty() {
-                    let placeholders = vec!["?"; tag_ids.len()].join(",");
-                    conditions.push(format!(
-                        "id IN (SELECT task_id FROM task_tags WHERE tag_id IN ({}))",
-                        placeholders
-                    ));
-                    for tag_id in tag_ids {
-                        params.push(Box::new(tag_id.clone()));
-                    }
-                }
-            }
-        }
+        let mut tasks = stmt
+            .query_map(params![cutoff], |row| self.map_row_to_task(row))?
+            .collect::<Result<Vec<Task>>>()?;
+        self.load_tags_for_tasks(&mut tasks)?;
 
-        let where_clause = if conditions.is_empty() {
-            String::new()
-        } else {
-            format!("WHERE {}", conditions.join(" AND "))
-        };
+        Ok(tasks)
+    }
 
-        (where_clause, params)
+    /// Tags applied to a task, by name
+    pub fn find_tags(&self, task_id: &str) -> Result<Vec<Tag>> {
+        self.load_tags_for_task(task_id)
     }
 
-    /// Build ORDER BY clause from sort options
-    fn build_order_by(&self, sort: &Option<Vec<TaskSort>>) -> String {
-        if let Some(sorts) = sort {
-            if !sorts.is_empty() {
-                let order_parts: Vec<String> = sorts
-                    .iter()
-                    .map(|s| format!("{} {}", s.field.as_str(), s.direction.as_str()))
-                    .collect();
-                return format!("ORDER BY {}", order_parts.join(", "));
-            }
+    /// Replace a task's tags with `tag_ids` (empty clears them) and refresh `updated_at`
+    pub fn set_tags(&self, task_id: &str, tag_ids: &[String]) -> Result<Vec<Tag>> {
+        self.db.transaction(|conn| {
+            Self::replace_tags(conn, task_id, tag_ids)?;
+            conn.execute(
+                "UPDATE tasks SET updated_at = ?1 WHERE id = ?2",
+                params![Utc::now().to_rfc3339(), task_id],
+            )?;
+            Ok(())
+        })?;
+
+        self.load_tags_for_task(task_id)
+    }
+
+    /// Find the direct subtasks of a task in manual order
+    pub fn find_children(&self, parent_id: &str) -> Result<Vec<Task>> {
+        let conn = self.db.connection();
+
+        let mut stmt = conn.prepare(&format!(
+            "SELECT {}
+             FROM tasks
+             WHERE parent_id = ?1
+             ORDER BY sort_order ASC, created_at ASC",
+            TASK_COLUMNS
+        ))?;
+
+        let mut tasks = stmt
+            .query_map(params![parent_id], |row| self.map_row_to_task(row))?
+            .collect::<Result<Vec<Task>>>()?;
+        self.load_tags_for_tasks(&mut tasks)?;
+
+        Ok(tasks)
+    }
+
+    /// Find the occurrences of a recurring task series, earliest due first
+    pub fn find_by_series(&self, series_id: &str) -> Result<Vec<Task>> {
+        let conn = self.db.connection();
+
+        let mut stmt = conn.prepare(&format!(
+            "SELECT {}
+             FROM tasks
+             WHERE series_id = ?1
+             ORDER BY due_date ASC, created_at ASC",
+            TASK_COLUMNS
+        ))?;
+
+        let mut tasks = stmt
+            .query_map(params![series_id], |row| self.map_row_to_task(row))?
+            .collect::<Result<Vec<Task>>>()?;
+        self.load_tags_for_tasks(&mut tasks)?;
+
+        Ok(tasks)
+    }
+
+    /// Find tasks by tags, matching ANY of the tags or, with `match_all`, ALL of them
+    pub fn find_by_tags(&self, tag_ids: &[String], match_all: bool) -> Result<Vec<Task>> {
+        let mut tag_ids: Vec<&String> = tag_ids.iter().collect();
+        tag_ids.sort();
+        tag_ids.dedup();
+
+        if tag_ids.is_empty() {
+            return Ok(Vec::new());
         }
-        "ORDER BY created_at DESC".to_string()
+
+        let conn = self.db.connection();
+        let placeholders = vec!["?"; tag_ids.len()].join(",");
+
+        let tag_condition = if match_all {
+            format!(
+                "id IN (SELECT task_id FROM task_tags
+                        WHERE tag_id IN ({})
+                        GROUP BY task_id
+                        HAVING COUNT(DISTINCT tag_id) = {})",
+                placeholders,
+                tag_ids.len()
+            )
+        } else {
+            format!(
+                "id IN (SELECT task_id FROM task_tags WHERE tag_id IN ({}))",
+                placeholders
+            )
+        };
+
+        let mut stmt = conn.prepare(&format!(
+            "SELECT {}
+             FROM tasks
+             WHERE {}
+             ORDER BY created_at DESC",
+            TASK_COLUMNS, tag_condition
+        ))?;
+
+        let mut tasks = stmt
+            .query_map(rusqlite::params_from_iter(tag_ids.iter()), |row| {
+                self.map_row_to_task(row)
+            })?
+            .collect::<Result<Vec<Task>>>()?;
+        self.load_tags_for_tasks(&mut tasks)?;
+
+        Ok(tasks)
     }
 
-    /// Associate tags with a task
-    fn associate_tags(&self, task_id: &str, tag_ids: &[String]) -> Result<()> {
+    /// Get tasks more than `grace` past their due date that haven't had their overdue notification yet
+    pub fn find_overdue_unnotified(&self, grace: Duration) -> Result<Vec<Task>> {
+        let conn = self.db.connection();
+        let cutoff = (Utc::now() - grace).to_rfc3339();
+
+        let mut stmt = conn.prepare(&format!(
+            "SELECT {}
+             FROM tasks
+             WHERE due_date < ?1
+               AND status NOT IN ('completed', 'cancelled')
+               AND is_deferred = 0
+               AND overdue_notified_at IS NULL
+             ORDER BY due_date ASC",
+            TASK_COLUMNS
+        ))?;
+
+        let tasks = stmt
+            .query_map(params![cutoff], |row| self.map_row_to_task(row))?
+            .collect::<Result<Vec<Task>>>()?;
+
+        Ok(tasks)
+    }
+
+    /// Record that the overdue notification for a task was sent
+    pub fn mark_overdue_notified(&self, id: &str) -> Result<()> {
         let conn = self.db.connection();
+        conn.execute(
+            "UPDATE tasks SET overdue_notified_at = ?1 WHERE id = ?2",
+            params![Utc::now().to_rfc3339(), id],
+        )?;
+        Ok(())
+    }
+
+    /// Pin or unpin a task, returning whether it exists
+    pub fn set_pinned(&self, id: &str, pinned: bool) -> Result<bool> {
+        let rows_affected = self.db.connection().execute(
+            "UPDATE tasks SET is_pinned = ?1, updated_at = ?2 WHERE id = ?3",
+            params![pinned, Utc::now().to_rfc3339(), id],
+        )?;
+        Ok(rows_affected > 0)
+    }
+
+    /// Defer a task (optionally until a date) or bring it back, returning whether it exists
+    pub fn set_deferred(
+        &self,
+        id: &str,
+        deferred: bool,
+        until: Option<DateTime<Utc>>,
+    ) -> Result<bool> {
+        let rows_affected = self.db.connection().execute(
+            "UPDATE tasks SET is_deferred = ?1, deferred_until = ?2, updated_at = ?3 WHERE id = ?4",
+            params![
+                deferred,
+                until.filter(|_| deferred).map(|d| d.to_rfc3339()),
+                Utc::now().to_rfc3339(),
+                id
+            ],
+        )?;
+        Ok(rows_affected > 0)
+    }
+
+    /// Add a task to a recurring series, returning whether it exists
+    pub fn set_series_id(&self, id: &str, series_id: &str) -> Result<bool> {
+        let rows_affected = self.db.connection().execute(
+            "UPDATE tasks SET series_id = ?1, updated_at = ?2 WHERE id = ?3",
+            params![series_id, Utc::now().to_rfc3339(), id],
+        )?;
+        Ok(rows_affected > 0)
+    }
+
+    /// Bring back deferred tasks whose `deferred_until` is at or before `now`, returning their ids
+    pub fn undefer_expired(&self, now: DateTime<Utc>) -> Result<Vec<String>> {
+        let now = now.to_rfc3339();
+
+        self.db.transaction(|conn| {
+            let ids = conn
+                .prepare(
+                    "SELECT id FROM tasks
+                     WHERE is_deferred = 1 AND deferred_until IS NOT NULL AND deferred_until <= ?1",
+                )?
+                .query_map(params![now], |row| row.get(0))?
+                .collect::<Result<Vec<String>>>()?;
+
+            for id in &ids {
+                conn.execute(
+                    "UPDATE tasks SET is_deferred = 0, deferred_until = NULL, updated_at = ?1
+                     WHERE id = ?2",
+                    params![now, id],
+                )?;
+            }
+            Ok(ids)
+        })
+    }
+
+    /// Set new due dates for several tasks in one transaction, returning the number updated
+    ///
+    /// Clears `overdue_notified_at` so the new due date can trigger its own notification.
+    pub fn set_due_dates(&self, due_dates: &[(String, DateTime<Utc>)]) -> Result<usize> {
         let now = Utc::now().to_rfc3339();
 
-        for tag_id in tag_ids {
-            conn.execute(
-                "INSERT OR IGNORE INTO task_tags (task_id, tag_id, created_at) 
-                 VALUES (?1, ?2, ?3)",
-                params![task_id, tag_id, now],
-            )?;
-        }
+        self.db.transaction(|conn| {
+            let mut updated = 0;
+            for (id, due_date) in due_dates {
+                updated += conn.execute(
+                    "UPDATE tasks SET due_date = ?1, overdue_notified_at = NULL, updated_at = ?2
+                     WHERE id = ?3",
+                    params![due_date.to_rfc3339(), now, id],
+                )?;
+            }
+            Ok(updated)
+        })
+    }
+
+    /// Count direct subtasks of a task as `(total, completed)` in a single grouped query
+    pub fn count_children_by_completion(&self, parent_id: &str) -> Result<(u32, u32)> {
+        let conn = self.db.connection();
+        conn.query_row(
+            "SELECT COUNT(*),
+                    COALESCE(SUM(CASE WHEN status = 'completed' THEN 1 ELSE 0 END), 0)
+             FROM tasks
+             WHERE parent_id = ?1",
+            params![parent_id],
+            |row| Ok((row.get(0)?, row.get(1)?)),
+        )
+    }
+
+    /// Highest sort position in use (0 when there are no tasks)
+    pub fn max_sort_order(&self) -> Result<i64> {
+        self.db.connection().query_row(
+            "SELECT COALESCE(MAX(sort_order), 0) FROM tasks",
+            [],
+            |row| row.get(0),
+        )
+    }
+
+    /// Lowest sort position in use, ignoring one task (0 when there are no others)
+    pub fn min_sort_order_excluding(&self, exclude_id: &str) -> Result<i64> {
+        self.db.connection().query_row(
+            "SELECT COALESCE(MIN(sort_order), 0) FROM tasks WHERE id != ?1",
+            params![exclude_id],
+            |row| row.get(0),
+        )
+    }
+
+    /// Smallest sort position strictly after `position`, ignoring one task
+    pub fn next_sort_order_after(&self, position: i64, exclude_id: &str) -> Result<Option<i64>> {
+        self.db.connection().query_row(
+            "SELECT MIN(sort_order) FROM tasks WHERE sort_order > ?1 AND id != ?2",
+            params![position, exclude_id],
+            |row| row.get(0),
+        )
+    }
 
+    /// Set the sort position of a single task
+    pub fn set_sort_order(&self, id: &str, sort_order: i64) -> Result<()> {
+        self.db.connection().execute(
+            "UPDATE tasks SET sort_order = ?1 WHERE id = ?2",
+            params![sort_order, id],
+        )?;
         Ok(())
     }
 
-    /// Load tags for a specific task
-    fn load_tags_for_task(&self, task_id: &str) -> Result<Vec<Tag>> {
+    /// Rewrite all sort positions with even gaps, preserving the current order
+    pub fn renumber_sort_orders(&self) -> Result<()> {
+        self.db.transaction(|conn| {
+            let ids = conn
+                .prepare("SELECT id FROM tasks ORDER BY sort_order ASC, created_at ASC")?
+                .query_map([], |row| row.get::<_, String>(0))?
+                .collect::<Result<Vec<String>>>()?;
+
+            let mut stmt = conn.prepare("UPDATE tasks SET sort_order = ?1 WHERE id = ?2")?;
+            for (index, id) in ids.iter().enumerate() {
+                stmt.execute(params![(index as i64 + 1) * SORT_ORDER_GAP, id])?;
+            }
+
+            Ok(())
+        })
+    }
+
+    /// Get the status transitions of a task, oldest first
+    pub fn find_status_history(&self, task_id: &str) -> Result<Vec<TaskStatusChange>> {
         let conn = self.db.connection();
 
         let mut stmt = conn.prepare(
-            "SELECT t.id, t.name, t.color, t.created_at
-             FROM tags t
-             INNER JOIN task_tags tt ON t.id = tt.tag_id
-             WHERE tt.task_id = ?1
-             ORDER BY t.name",
+            "SELECT id, task_id, from_status, to_status, changed_at
+             FROM task_status_history
+             WHERE task_id = ?1
+             ORDER BY changed_at ASC, rowid ASC",
         )?;
 
-        let tags = stmt
+        let history = stmt
             .query_map(params![task_id], |row| {
-                let created_at: String = row.get(3)?;
-                Ok(Tag {
+                let from_status: String = row.get(2)?;
+                let to_status: String = row.get(3)?;
+                let changed_at: String = row.get(4)?;
+                Ok(TaskStatusChange {
                     id: row.get(0)?,
-                    name: row.get(1)?,
-                    color: row.get(2)?,
-                    created_at: DateTime::parse_from_rfc3339(&created_at)
+                    task_id: row.get(1)?,
+                    from_status: TaskStatus::from_str(&from_status).unwrap_or(TaskStatus::Pending),
+                    to_status: TaskStatus::from_str(&to_status).unwrap_or(TaskStatus::Pending),
+                    changed_at: DateTime::parse_from_rfc3339(&changed_at)
                         .map(|d| d.with_timezone(&Utc))
                         .unwrap_or_else(|_| Utc::now()),
                 })
             })?
-            .collect::<Result<Vec<Tag>>>()?;
+            .collect::<Result<Vec<TaskStatusChange>>>()?;
 
-        Ok(tags)
+        Ok(history)
     }
-}
 
-#[cfg(test)]
-mod tests {
-    use super::*;
-    use crate::models::{
-        CreateTaskDto, Pagination, SortDirection, TaskFilter, TaskPriority, TaskSort,
-        TaskSortField, TaskStatus, UpdateTaskDto,
-    };
-    use rusqlite::Connection;
+    /// Record the start of a focus session
+    pub fn add_focus_session(&self, session: &FocusSession) -> Result<()> {
+        self.db.connection().execute(
+            "INSERT INTO task_focus_sessions (
+                id, task_id, work_minutes, break_minutes, break_reminder_id, work_reminder_id,
+                started_at, ended_at, logged_minutes
+            ) VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, ?9)",
+            params![
+                session.id,
+                session.task_id,
+                session.work_minutes,
+                session.break_minutes,
+                session.break_reminder_id,
+                session.work_reminder_id,
+                session.started_at.to_rfc3339(),
+                session.ended_at.map(|d| d.to_rfc3339()),
+                session.logged_minutes,
+            ],
+        )?;
+        Ok(())
+    }
 
-    fn setup_test_db() -> Database {
-        let conn = Connection::open_in_memory().unwrap();
+    /// Find the running focus session of a task, if any
+    pub fn find_active_focus_session(&self, task_id: &str) -> Result<Option<FocusSession>> {
+        let conn = self.db.connection();
 
-        // Create tables
-        conn.execute(
-            "CREATE TABLE tasks (
-                id TEXT PRIMARY KEY,
-                title TEXT NOT NULL,
-                description TEXT,
-                status TEXT NOT NULL,
-                priority TEXT NOT NULL,
-                due_date TEXT,
-                completed_at TEXT,
-                notes TEXT,
-                estimated_minutes INTEGER,
-                actual_minutes INTEGER,
-                image_path TEXT,
-                created_at TEXT NOT NULL,
-                updated_at TEXT NOT NULL
-            )",
-            [],
-        )
-        .unwrap();
+        let mut stmt = conn.prepare(
+            "SELECT id, task_id, work_minutes, break_minutes, break_reminder_id,
+                    work_reminder_id, started_at, ended_at, logged_minutes
+             FROM task_focus_sessions
+             WHERE task_id = ?1 AND ended_at IS NULL
+             ORDER BY started_at DESC
+             LIMIT 1",
+        )?;
 
-        conn.execute(
-            "CREATE TABLE task_tags (
-                task_id TEXT NOT NULL,
-                tag_id TEXT NOT NULL,
-                created_at TEXT NOT NULL,
-                PRIMARY KEY (task_id, tag_id)
-            )",
-            [],
-        )
-        .unwrap();
+        let mut sessions = stmt
+            .query_map(params![task_id], |row| self.map_row_to_focus_session(row))?
+            .collect::<Result<Vec<FocusSession>>>()?;
 
-        conn.execute(
-            "CREATE TABLE tags (
-                id TEXT PRIMARY KEY,
-                name TEXT NOT NULL,
-                color TEXT NOT NULL,
-                created_at TEXT NOT NULL
-            )",
-            [],
-        )
-        .unwrap();
+        Ok(sessions.pop())
+    }
 
-        Database::new_from_connection(conn)
+    /// End a focus session: stamp it, add `logged_minutes` to the task's actual minutes
+    /// and delete its transition reminders, all in one transaction
+    pub fn finish_focus_session(
+        &self,
+        session: &FocusSession,
+        ended_at: DateTime<Utc>,
+        logged_minutes: i32,
+    ) -> Result<()> {
+        self.db.transaction(|conn| {
+            conn.execute(
+                "UPDATE task_focus_sessions SET ended_at = ?1, logged_minutes = ?2 WHERE id = ?3",
+                params![ended_at.to_rfc3339(), logged_minutes, session.id],
+            )?;
+            conn.execute(
+                "UPDATE tasks
+                 SET actual_minutes = COALESCE(actual_minutes, 0) + ?1, updated_at = ?2
+                 WHERE id = ?3",
+                params![logged_minutes, Utc::now().to_rfc3339(), session.task_id],
+            )?;
+            for reminder_id in [&session.break_reminder_id, &session.work_reminder_id]
+                .into_iter()
+                .flatten()
+            {
+                conn.execute("DELETE FROM reminders WHERE id = ?1", params![reminder_id])?;
+            }
+            Ok(())
+        })
     }
 
-    fn create_test_tag(db: &Database, name: &str) -> String {
-        let tag_id = uuid::Uuid::new_v4().to_string();
-        let now = Utc::now().to_rfc3339();
+    /// Add a comment to a task
+    pub fn add_comment(&self, task_id: &str, body: &str) -> Result<TaskComment> {
+        let conn = self.db.connection();
+        let comment = TaskComment {
+            id: Uuid::new_v4().to_string(),
+            task_id: task_id.to_string(),
+            body: body.to_string(),
+            created_at: Utc::now(),
+        };
 
-        db.connection()
-            .execute(
-                "INSERT INTO tags (id, name, color, created_at) VALUES (?1, ?2, ?3, ?4)",
-                params![tag_id, name, "#FF0000", now],
-            )
-            .unwrap();
+        conn.execute(
+            "INSERT INTO task_comments (id, task_id, body, created_at)
+             VALUES (?1, ?2, ?3, ?4)",
+            params![
+                comment.id,
+                comment.task_id,
+                comment.body,
+                comment.created_at.to_rfc3339(),
+            ],
+        )?;
 
-        tag_id
+        Ok(comment)
     }
 
-    #[test]
-    fn test_create_task() {
-        let db = setup_test_db();
-        let repo = TaskRepository::new(&db);
+    /// List comments of a task, oldest first
+    pub fn list_comments(&self, task_id: &str) -> Result<Vec<TaskComment>> {
+        let conn = self.db.connection();
 
-        let dto = CreateTaskDto {
-            title: "Test Task".to_string(),
-            description: Some("Test Description".to_string()),
-            priority: TaskPriority::Medium,
-            due_date: None,
-            image_path: None,
-            notes: None,
-            estimated_minutes: Some(60),
-            tag_ids: vec![],
-        };
+        let mut stmt = conn.prepare(
+            "SELECT id, task_id, body, created_at
+             FROM task_comments
+             WHERE task_id = ?1
+             ORDER BY created_at ASC, rowid ASC",
+        )?;
 
-        let task = repo.create(dto).unwrap();
+        let comments = stmt
+            .query_map(params![task_id], |row| {
+                let created_at: String = row.get(3)?;
+                Ok(TaskComment {
+                    id: row.get(0)?,
+                    task_id: row.get(1)?,
+                    body: row.get(2)?,
+                    created_at: DateTime::parse_from_rfc3339(&created_at)
+                        .map(|d| d.with_timezone(&Utc))
+                        .unwrap_or_else(|_| Utc::now()),
+                })
+            })?
+            .collect::<Result<Vec<TaskComment>>>()?;
 
-        assert_eq!(task.title, "Test Task");
-        assert_eq!(task.description, Some("Test Description".to_string()));
-        assert_eq!(task.status, TaskStatus::Pending);
-        assert_eq!(task.priority, TaskPriority::Medium);
-        assert_eq!(task.estimated_minutes, Some(60));
-        assert!(task.tags.is_empty());
+        Ok(comments)
     }
 
-    #[test]
-    fn test_create_task_with_tags() {
-        let db = setup_test_db();
-        let repo = TaskRepository::new(&db);
+    /// Delete a comment, returning whether it existed
+    pub fn delete_comment(&self, comment_id: &str) -> Result<bool> {
+        let conn = self.db.connection();
+        let rows_affected = conn.execute(
+            "DELETE FROM task_comments WHERE id = ?1",
+            params![comment_id],
+        )?;
+        Ok(rows_affected > 0)
+    }
 
-        let tag1_id = create_test_tag(&db, "Work");
-        let tag2_id = create_test_tag(&db, "Urgent");
+    /// Append a checklist item to the end of a task's checklist
+    pub fn add_checklist_item(&self, task_id: &str, text: &str) -> Result<ChecklistItem> {
+        let conn = self.db.connection();
 
-        let dto = CreateTaskDto {
-            title: "Tagged Task".to_string(),
-            description: None,
-            priority: TaskPriority::High,
-            due_date: None,
-            image_path: None,
-            notes: None,
-            estimated_minutes: None,
-            tag_ids: vec![tag1_id.clone(), tag2_id.clone()],
+        let position: i64 = conn.query_row(
+            "SELECT COALESCE(MAX(position), -1) + 1 FROM task_checklist_items WHERE task_id = ?1",
+            params![task_id],
+            |row| row.get(0),
+        )?;
+
+        let item = ChecklistItem {
+            id: Uuid::new_v4().to_string(),
+            task_id: task_id.to_string(),
+            text: text.to_string(),
+            is_done: false,
+            position,
         };
 
+        conn.execute(
+            "INSERT INTO task_checklist_items (id, task_id, text, is_done, position)
+             VALUES (?1, ?2, ?3, 0, ?4)",
+            params![item.id, item.task_id, item.text, item.position],
+        )?;
+
+        Ok(item)
+    }
+
+    /// Find a checklist item by ID
+    pub fn find_checklist_item(&self, id: &str) -> Result<Option<ChecklistItem>> {
+        let conn = self.db.connection();
+
+        let mut stmt = conn.prepare(
+            "SELECT id, task_id, text, is_done, position
+             FROM task_checklist_items
+             WHERE id = ?1",
+        )?;
+
+        let mut items = stmt.query_map(params![id], |row| self.map_row_to_checklist_item(row))?;
+        items.next().transpose()
+    }
+
+    /// List a task's checklist items in position order
+    pub fn list_checklist_items(&self, task_id: &str) -> Result<Vec<ChecklistItem>> {
+        let conn = self.db.connection();
+
+        let mut stmt = conn.prepare(
+            "SELECT id, task_id, text, is_done, position
+             FROM task_checklist_items
+             WHERE task_id = ?1
+             ORDER BY position ASC",
+        )?;
+
+        let items = stmt
+            .query_map(params![task_id], |row| self.map_row_to_checklist_item(row))?
+            .collect::<Result<Vec<ChecklistItem>>>()?;
+
+        Ok(items)
+    }
+
+    /// Flip the done flag of a checklist item, returning whether it existed
+    pub fn toggle_checklist_item(&self, id: &str) -> Result<bool> {
+        let conn = self.db.connection();
+        let rows_affected = conn.execute(
+            "UPDATE task_checklist_items SET is_done = 1 - is_done WHERE id = ?1",
+            params![id],
+        )?;
+        Ok(rows_affected > 0)
+    }
+
+    /// Delete a checklist item, returning whether it existed
+    pub fn delete_checklist_item(&self, id: &str) -> Result<bool> {
+        let conn = self.db.connection();
+        let rows_affected = conn.execute(
+            "DELETE FROM task_checklist_items WHERE id = ?1",
+            params![id],
+        )?;
+        Ok(rows_affected > 0)
+    }
+
+    /// Attach a file reference to a task
+    pub fn add_attachment(
+        &self,
+        task_id: &str,
+        file_path: &str,
+        mime_type: Option<String>,
+    ) -> Result<TaskAttachment> {
+        let conn = self.db.connection();
+        let attachment = TaskAttachment {
+            id: Uuid::new_v4().to_string(),
+            task_id: task_id.to_string(),
+            file_path: file_path.to_string(),
+            mime_type,
+            created_at: Utc::now(),
+        };
+
+        conn.execute(
+            "INSERT INTO task_attachments (id, task_id, file_path, mime_type, created_at)
+             VALUES (?1, ?2, ?3, ?4, ?5)",
+            params![
+                attachment.id,
+                attachment.task_id,
+                attachment.file_path,
+                attachment.mime_type,
+                attachment.created_at.to_rfc3339(),
+            ],
+        )?;
+
+        Ok(attachment)
+    }
+
+    /// List attachments of a task, oldest first
+    pub fn list_attachments(&self, task_id: &str) -> Result<Vec<TaskAttachment>> {
+        let conn = self.db.connection();
+
+        let mut stmt = conn.prepare(
+            "SELECT id, task_id, file_path, mime_type, created_at
+             FROM task_attachments
+             WHERE task_id = ?1
+             ORDER BY created_at ASC",
+        )?;
+
+        let attachments = stmt
+            .query_map(params![task_id], |row| {
+                let created_at: String = row.get(4)?;
+                Ok(TaskAttachment {
+                    id: row.get(0)?,
+                    task_id: row.get(1)?,
+                    file_path: row.get(2)?,
+                    mime_type: row.get(3)?,
+                    created_at: DateTime::parse_from_rfc3339(&created_at)
+                        .map(|d| d.with_timezone(&Utc))
+                        .unwrap_or_else(|_| Utc::now()),
+                })
+            })?
+            .collect::<Result<Vec<TaskAttachment>>>()?;
+
+        Ok(attachments)
+    }
+
+    /// Remove an attachment, returning whether it existed
+    pub fn remove_attachment(&self, attachment_id: &str) -> Result<bool> {
+        let conn = self.db.connection();
+        let rows_affected = conn.execute(
+            "DELETE FROM task_attachments WHERE id = ?1",
+            params![attachment_id],
+        )?;
+        Ok(rows_affected > 0)
+    }
+
+    /// Record that `task_id` is blocked by `depends_on_id`
+    pub fn add_dependency(&self, task_id: &str, depends_on_id: &str) -> Result<()> {
+        let conn = self.db.connection();
+        let now = Utc::now().to_rfc3339();
+
+        conn.execute(
+            "INSERT OR IGNORE INTO task_dependencies (task_id, depends_on_id, created_at)
+             VALUES (?1, ?2, ?3)",
+            params![task_id, depends_on_id, now],
+        )?;
+
+        Ok(())
+    }
+
+    /// Remove a dependency link, returning whether one existed
+    pub fn remove_dependency(&self, task_id: &str, depends_on_id: &str) -> Result<bool> {
+        let conn = self.db.connection();
+        let rows_affected = conn.execute(
+            "DELETE FROM task_dependencies WHERE task_id = ?1 AND depends_on_id = ?2",
+            params![task_id, depends_on_id],
+        )?;
+        Ok(rows_affected > 0)
+    }
+
+    /// Find the tasks that directly block the given task
+    pub fn find_blockers(&self, task_id: &str) -> Result<Vec<Task>> {
+        let conn = self.db.connection();
+
+        let mut stmt = conn.prepare(&format!(
+            "SELECT {}
+             FROM tasks
+             WHERE id IN (SELECT depends_on_id FROM task_dependencies WHERE task_id = ?1)
+             ORDER BY created_at ASC",
+            TASK_COLUMNS
+        ))?;
+
+        let mut tasks = stmt
+            .query_map(params![task_id], |row| self.map_row_to_task(row))?
+            .collect::<Result<Vec<Task>>>()?;
+        self.load_tags_for_tasks(&mut tasks)?;
+
+        Ok(tasks)
+    }
+
+    /// Check whether `task_id` depends on `other_id`, directly or transitively
+    pub fn depends_on_transitively(&self, task_id: &str, other_id: &str) -> Result<bool> {
+        let conn = self.db.connection();
+
+        let found: i64 = conn.query_row(
+            "WITH RECURSIVE chain(id) AS (
+                 SELECT depends_on_id FROM task_dependencies WHERE task_id = ?1
+                 UNION
+                 SELECT d.depends_on_id FROM task_dependencies d
+                 INNER JOIN chain c ON d.task_id = c.id
+             )
+             SELECT COUNT(*) FROM chain WHERE id = ?2",
+            params![task_id, other_id],
+            |row| row.get(0),
+        )?;
+
+        Ok(found > 0)
+    }
+
+    // ========================================================================
+    // Private helper methods
+    // ========================================================================
+
+    /// Append a status transition to the task's history
+    fn record_status_change(
+        &self,
+        task_id: &str,
+        from: &TaskStatus,
+        to: &TaskStatus,
+    ) -> Result<()> {
+        let conn = self.db.connection();
+        conn.execute(
+            "INSERT INTO task_status_history (id, task_id, from_status, to_status, changed_at)
+             VALUES (?1, ?2, ?3, ?4, ?5)",
+            params![
+                Uuid::new_v4().to_string(),
+                task_id,
+                from.as_str(),
+                to.as_str(),
+                Utc::now().to_rfc3339(),
+            ],
+        )?;
+        Ok(())
+    }
+
+    /// Map database row to ChecklistItem struct
+    fn map_row_to_checklist_item(&self, row: &Row) -> Result<ChecklistItem> {
+        let is_done: i64 = row.get(3)?;
+
+        Ok(ChecklistItem {
+            id: row.get(0)?,
+            task_id: row.get(1)?,
+            text: row.get(2)?,
+            is_done: is_done != 0,
+            position: row.get(4)?,
+        })
+    }
+
+    /// Map database row to FocusSession struct
+    fn map_row_to_focus_session(&self, row: &Row) -> Result<FocusSession> {
+        let started_at: String = row.get(6)?;
+        let ended_at: Option<String> = row.get(7)?;
+
+        Ok(FocusSession {
+            id: row.get(0)?,
+            task_id: row.get(1)?,
+            work_minutes: row.get(2)?,
+            break_minutes: row.get(3)?,
+            break_reminder_id: row.get(4)?,
+            work_reminder_id: row.get(5)?,
+            started_at: DateTime::parse_from_rfc3339(&started_at)
+                .map(|d| d.with_timezone(&Utc))
+                .unwrap_or_else(|_| Utc::now()),
+            ended_at: ended_at.and_then(|s| {
+                DateTime::parse_from_rfc3339(&s)
+                    .ok()
+                    .map(|d| d.with_timezone(&Utc))
+            }),
+            logged_minutes: row.get(8)?,
+        })
+    }
+
+    /// Map database row to Task struct
+    fn map_row_to_task(&self, row: &Row) -> Result<Task> {
+        let status_str: String = row.get(3)?;
+        let priority_str: String = row.get(4)?;
+
+        let due_date: Option<String> = row.get(5)?;
+        let completed_at: Option<String> = row.get(6)?;
+        let created_at: String = row.get(11)?;
+        let updated_at: String = row.get(12)?;
+
+        Ok(Task {
+            id: row.get(0)?,
+            title: row.get(1)?,
+            description: row.get(2)?,
+            status: TaskStatus::from_str(&status_str).unwrap_or(TaskStatus::Pending),
+            priority: TaskPriority::from_str(&priority_str).unwrap_or(TaskPriority::Medium),
+            due_date: due_date
+                .and_then(|d| DateTime::parse_from_rfc3339(&d).ok())
+                .map(|d| d.with_timezone(&Utc)),
+            completed_at: completed_at
+                .and_then(|d| DateTime::parse_from_rfc3339(&d).ok())
+                .map(|d| d.with_timezone(&Utc)),
+            image_path: row.get(7)?,
+            notes: row.get(8)?,
+            estimated_minutes: row.get(9)?,
+            actual_minutes: row.get(10)?,
+            parent_id: row.get(13)?,
+            sort_order: row.get(14)?,
+            checklist_progress: row.get::<_, Option<f64>>(15)?.map(|p| p as f32),
+            color: row.get(16)?,
+            is_pinned: row.get::<_, i64>(17)? != 0,
+            series_id: row.get(18)?,
+            is_deferred: row.get::<_, i64>(19)? != 0,
+            deferred_until: row
+                .get::<_, Option<String>>(20)?
+                .and_then(|d| DateTime::parse_from_rfc3339(&d).ok())
+                .map(|d| d.with_timezone(&Utc)),
+            completion_note: row.get(21)?,
+            created_at: DateTime::parse_from_rfc3339(&created_at)
+                .map(|d| d.with_timezone(&Utc))
+                .unwrap_or_else(|_| Utc::now()),
+            updated_at: DateTime::parse_from_rfc3339(&updated_at)
+                .map(|d| d.with_timezone(&Utc))
+                .unwrap_or_else(|_| Utc::now()),
+            tags: Vec::new(), // Tags loaded separately
+        })
+    }
+
+    /// Build WHERE clause from filter
+    fn build_where_clause(
+        &self,
+        filter: &Option<TaskFilter>,
+    ) -> (String, Vec<Box<dyn rusqlite::ToSql>>) {
+        let mut conditions = Vec::new();
+        let mut params: Vec<Box<dyn rusqlite::ToSql>> = Vec::new();
+
+        if let Some(f) = filter {
+            if let Some(status) = &f.status {
+                conditions.push("status = ?".to_string());
+                params.push(Box::new(status.as_str().to_string()));
+            }
+
+            if let Some(priority) = &f.priority {
+                conditions.push("priority = ?".to_string());
+                params.push(Box::new(priority.as_str().to_string()));
+            }
+
+            if let Some(search) = &f.search {
+                conditions.push(
+                    "(fold(title) LIKE ? ESCAPE '\\' OR fold(description) LIKE ? ESCAPE '\\')"
+                        .to_string(),
+                );
+                let search_pattern = like_pattern(search);
+                params.push(Box::new(search_pattern.clone()));
+                params.push(Box::new(search_pattern));
+            }
+
+            if let Some(due_before) = &f.due_before {
+                conditions.push("due_date < ?".to_string());
+                params.push(Box::new(due_before.to_rfc3339()));
+            }
+
+            if let Some(due_after) = &f.due_after {
+                conditions.push("due_date >= ?".to_string());
+                params.push(Box::new(due_after.to_rfc3339()));
+            }
+
+            if f.only_no_due_date {
+                conditions.push("due_date IS NULL".to_string());
+            }
+
+            if f.only_deferred {
+                conditions.push("is_deferred = 1".to_string());
+            } else if !f.include_deferred {
+                conditions.push("is_deferred = 0".to_string());
+            }
+
+            if let Some(effective_status) = &f.effective_status {
+                let (condition, needs_cutoff) = Self::effective_status_condition(effective_status);
+                conditions.push(condition.to_string());
+                if needs_cutoff {
+                    let cutoff = Utc::now() - Duration::minutes(f.overdue_grace_minutes.max(0));
+                    params.push(Box::new(cutoff.to_rfc3339()));
+                }
+            }
+
+            // An explicit status filter takes precedence over hiding completed tasks
+            if f.include_completed == Some(false)
+                && f.status.is_none()
+                && f.effective_status.is_none()
+            {
+                conditions.push("status != 'completed'".to_string());
+            }
+
+            if let Some(tag_ids) = &f.tag_ids {
+                if !tag_ids.is_empty() {
+                    let placeholders = vec!["?"; tag_ids.len()].join(",");
+                    conditions.push(format!(
+                        "id IN (SELECT task_id FROM task_tags WHERE tag_id IN ({}))",
+                        placeholders
+                    ));
+                    for tag_id in tag_ids {
+                        params.push(Box::new(tag_id.clone()));
+                    }
+                }
+            }
+        }
+
+        let where_clause = if conditions.is_empty() {
+            String::new()
+        } else {
+            format!("WHERE {}", conditions.join(" AND "))
+        };
+
+        (where_clause, params)
+    }
+
+    /// Translate a computed effective status into a SQL condition.
+    ///
+    /// Returns the condition and whether it expects the overdue cutoff (now minus the
+    /// grace period) as a parameter. Overdue mirrors `Task::is_overdue`: past the cutoff,
+    /// not deferred and not completed/cancelled.
+    fn effective_status_condition(status: &EffectiveTaskStatus) -> (&'static str, bool) {
+        match status {
+            EffectiveTaskStatus::Overdue => (
+                "(due_date IS NOT NULL AND due_date < ? AND is_deferred = 0 \
+                 AND status NOT IN ('completed', 'cancelled'))",
+                true,
+            ),
+            EffectiveTaskStatus::Done => ("status = 'completed'", false),
+            EffectiveTaskStatus::Cancelled => ("status = 'cancelled'", false),
+            EffectiveTaskStatus::Pending => (
+                "(status = 'pending' AND (due_date IS NULL OR due_date >= ? OR is_deferred = 1))",
+                true,
+            ),
+            EffectiveTaskStatus::InProgress => (
+                "(status = 'in_progress' AND (due_date IS NULL OR due_date >= ? OR is_deferred = 1))",
+                true,
+            ),
+        }
+    }
+
+    /// Build ORDER BY clause from sort options
+    ///
+    /// Pinned tasks always come first; the sort options order within each group.
+    fn build_order_by(&self, sort: &Option<Vec<TaskSort>>) -> String {
+        if let Some(sorts) = sort {
+            if !sorts.is_empty() {
+                let order_parts: Vec<String> = sorts
+                    .iter()
+                    .map(|s| {
+                        let column = match s.field {
+                            TaskSortField::Priority => priority_rank(),
+                            TaskSortField::Status => STATUS_RANK.to_string(),
+                            _ => s.field.as_str().to_string(),
+                        };
+                        format!("{} {}", column, s.direction.as_str())
+                    })
+                    .collect();
+                return format!("ORDER BY is_pinned DESC, {}", order_parts.join(", "));
+            }
+        }
+        "ORDER BY is_pinned DESC, created_at DESC".to_string()
+    }
+
+    /// Associate tags with a task
+    fn associate_tags(conn: &Connection, task_id: &str, tag_ids: &[String]) -> Result<()> {
+        let now = Utc::now().to_rfc3339();
+
+        for tag_id in tag_ids {
+            conn.execute(
+                "INSERT OR IGNORE INTO task_tags (task_id, tag_id, created_at) 
+                 VALUES (?1, ?2, ?3)",
+                params![task_id, tag_id, now],
+            )?;
+        }
+
+        Ok(())
+    }
+
+    /// Remove a task's tags and associate `tag_ids` instead
+    fn replace_tags(conn: &Connection, task_id: &str, tag_ids: &[String]) -> Result<()> {
+        conn.execute("DELETE FROM task_tags WHERE task_id = ?1", params![task_id])?;
+        if !tag_ids.is_empty() {
+            Self::associate_tags(conn, task_id, tag_ids)?;
+        }
+        Ok(())
+    }
+
+    /// Load tags for a specific task
+    fn load_tags_for_task(&self, task_id: &str) -> Result<Vec<Tag>> {
+        let conn = self.db.connection();
+
+        let mut stmt = conn.prepare(
+            "SELECT t.id, t.name, t.color, t.created_at
+             FROM tags t
+             INNER JOIN task_tags tt ON t.id = tt.tag_id
+             WHERE tt.task_id = ?1
+             ORDER BY t.name",
+        )?;
+
+        let tags = stmt
+            .query_map(params![task_id], |row| {
+                let created_at: String = row.get(3)?;
+                Ok(Tag {
+                    id: row.get(0)?,
+                    name: row.get(1)?,
+                    color: row.get(2)?,
+                    created_at: DateTime::parse_from_rfc3339(&created_at)
+                        .map(|d| d.with_timezone(&Utc))
+                        .unwrap_or_else(|_| Utc::now()),
+                })
+            })?
+            .collect::<Result<Vec<Tag>>>()?;
+
+        Ok(tags)
+    }
+
+    /// Load the tags of all `tasks` in one query, keeping each task's tags ordered by name
+    fn load_tags_for_tasks(&self, tasks: &mut [Task]) -> Result<()> {
+        if tasks.is_empty() {
+            return Ok(());
+        }
+        let conn = self.db.connection();
+
+        // One query per chunk keeps the bound ids under SQLite's variable limit
+        let mut tags_by_task: HashMap<String, Vec<Tag>> = HashMap::new();
+        for chunk in tasks.chunks(TAG_LOAD_CHUNK_SIZE) {
+            let placeholders = vec!["?"; chunk.len()].join(",");
+            let mut stmt = conn.prepare(&format!(
+                "SELECT tt.task_id, t.id, t.name, t.color, t.created_at
+                 FROM tags t
+                 INNER JOIN task_tags tt ON t.id = tt.tag_id
+                 WHERE tt.task_id IN ({})
+                 ORDER BY t.name",
+                placeholders
+            ))?;
+
+            let rows = stmt.query_map(
+                rusqlite::params_from_iter(chunk.iter().map(|task| task.id.as_str())),
+                |row| {
+                    let created_at: String = row.get(4)?;
+                    let tag = Tag {
+                        id: row.get(1)?,
+                        name: row.get(2)?,
+                        color: row.get(3)?,
+                        created_at: DateTime::parse_from_rfc3339(&created_at)
+                            .map(|d| d.with_timezone(&Utc))
+                            .unwrap_or_else(|_| Utc::now()),
+                    };
+                    Ok((row.get::<_, String>(0)?, tag))
+                },
+            )?;
+
+            for row in rows {
+                let (task_id, tag) = row?;
+                tags_by_task.entry(task_id).or_default().push(tag);
+            }
+        }
+        for task in tasks {
+            task.tags = tags_by_task.remove(&task.id).unwrap_or_default();
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::{
+        CreateTaskDto, EffectiveTaskStatus, Pagination, SortDirection, TaskFilter, TaskPriority,
+        TaskSort, TaskSortField, TaskStatus, UpdateTaskDto,
+    };
+    use rusqlite::functions::FunctionFlags;
+    use rusqlite::Connection;
+    use std::sync::atomic::{AtomicUsize, Ordering};
+    use std::sync::Arc;
+
+    /// Count the statements that read `tags`
+    ///
+    /// A temp view shadows the table and evaluates its uncorrelated subquery once
+    /// per statement, bumping the returned counter. Statements that match no
+    /// `task_tags` rows never reach the view, so tests tag every task.
+    fn count_tag_queries(db: &Database) -> Arc<AtomicUsize> {
+        let count = Arc::new(AtomicUsize::new(0));
+        let counter = Arc::clone(&count);
+        let conn = db.connection();
+        conn.create_scalar_function(
+            "count_tag_query",
+            0,
+            FunctionFlags::SQLITE_UTF8,
+            move |_| {
+                counter.fetch_add(1, Ordering::SeqCst);
+                Ok(1)
+            },
+        )
+        .unwrap();
+        conn.execute_batch(
+            "CREATE TEMP VIEW tags AS
+             SELECT * FROM main.tags WHERE (SELECT count_tag_query()) = 1",
+        )
+        .unwrap();
+        count
+    }
+
+    fn setup_test_db() -> Database {
+        let conn = Connection::open_in_memory().unwrap();
+
+        // Create tables
+        conn.execute(
+            "CREATE TABLE tasks (
+                id TEXT PRIMARY KEY,
+                title TEXT NOT NULL,
+                description TEXT,
+                status TEXT NOT NULL,
+                priority TEXT NOT NULL,
+                due_date TEXT,
+                completed_at TEXT,
+                notes TEXT,
+                estimated_minutes INTEGER,
+                actual_minutes INTEGER,
+                color TEXT,
+                is_pinned INTEGER NOT NULL DEFAULT 0,
+                series_id TEXT,
+                is_deferred INTEGER NOT NULL DEFAULT 0,
+                deferred_until TEXT,
+                completion_note TEXT,
+                image_path TEXT,
+                parent_id TEXT,
+                sort_order INTEGER NOT NULL DEFAULT 0,
+                overdue_notified_at TEXT,
+                created_at TEXT NOT NULL,
+                updated_at TEXT NOT NULL
+            )",
+            [],
+        )
+        .unwrap();
+
+        conn.execute(
+            "CREATE TABLE task_tags (
+                task_id TEXT NOT NULL,
+                tag_id TEXT NOT NULL,
+                created_at TEXT NOT NULL,
+                PRIMARY KEY (task_id, tag_id)
+            )",
+            [],
+        )
+        .unwrap();
+
+        conn.execute(
+            "CREATE TABLE tags (
+                id TEXT PRIMARY KEY,
+                name TEXT NOT NULL,
+                color TEXT NOT NULL,
+                created_at TEXT NOT NULL
+            )",
+            [],
+        )
+        .unwrap();
+
+        conn.execute(
+            "CREATE TABLE task_dependencies (
+                task_id TEXT NOT NULL,
+                depends_on_id TEXT NOT NULL,
+                created_at TEXT NOT NULL,
+                PRIMARY KEY (task_id, depends_on_id)
+            )",
+            [],
+        )
+        .unwrap();
+
+        conn.execute(
+            "CREATE TABLE task_checklist_items (
+                id TEXT PRIMARY KEY,
+                task_id TEXT NOT NULL,
+                text TEXT NOT NULL,
+                is_done INTEGER NOT NULL DEFAULT 0,
+                position INTEGER NOT NULL DEFAULT 0
+            )",
+            [],
+        )
+        .unwrap();
+
+        conn.execute(
+            "CREATE TABLE task_comments (
+                id TEXT PRIMARY KEY,
+                task_id TEXT NOT NULL,
+                body TEXT NOT NULL,
+                created_at TEXT NOT NULL
+            )",
+            [],
+        )
+        .unwrap();
+
+        conn.execute(
+            "CREATE TABLE task_status_history (
+                id TEXT PRIMARY KEY,
+                task_id TEXT NOT NULL,
+                from_status TEXT NOT NULL,
+                to_status TEXT NOT NULL,
+                changed_at TEXT NOT NULL
+            )",
+            [],
+        )
+        .unwrap();
+
+        conn.execute(
+            "CREATE TABLE task_attachments (
+                id TEXT PRIMARY KEY,
+                task_id TEXT NOT NULL,
+                file_path TEXT NOT NULL,
+                mime_type TEXT,
+                created_at TEXT NOT NULL
+            )",
+            [],
+        )
+        .unwrap();
+
+        Database::new_from_connection(conn)
+    }
+
+    fn create_test_tag(db: &Database, name: &str) -> String {
+        let tag_id = uuid::Uuid::new_v4().to_string();
+        let now = Utc::now().to_rfc3339();
+
+        db.connection()
+            .execute(
+                "INSERT INTO tags (id, name, color, created_at) VALUES (?1, ?2, ?3, ?4)",
+                params![tag_id, name, "#FF0000", now],
+            )
+            .unwrap();
+
+        tag_id
+    }
+
+    #[test]
+    fn test_create_task() {
+        let db = setup_test_db();
+        let repo = TaskRepository::new(&db);
+
+        let dto = CreateTaskDto {
+            title: "Test Task".to_string(),
+            description: Some("Test Description".to_string()),
+            priority: TaskPriority::Medium,
+            due_date: None,
+            image_path: None,
+            notes: None,
+            estimated_minutes: Some(60),
+            tag_ids: vec![],
+            parent_id: None,
+            color: None,
+        };
+
+        let task = repo.create(dto).unwrap();
+
+        assert_eq!(task.title, "Test Task");
+        assert_eq!(task.description, Some("Test Description".to_string()));
+        assert_eq!(task.status, TaskStatus::Pending);
+        assert_eq!(task.priority, TaskPriority::Medium);
+        assert_eq!(task.estimated_minutes, Some(60));
+        assert!(task.tags.is_empty());
+    }
+
+    #[test]
+    fn test_create_task_with_tags() {
+        let db = setup_test_db();
+        let repo = TaskRepository::new(&db);
+
+        let tag1_id = create_test_tag(&db, "Work");
+        let tag2_id = create_test_tag(&db, "Urgent");
+
+        let dto = CreateTaskDto {
+            title: "Tagged Task".to_string(),
+            description: None,
+            priority: TaskPriority::High,
+            due_date: None,
+            image_path: None,
+            notes: None,
+            estimated_minutes: None,
+            tag_ids: vec![tag1_id.clone(), tag2_id.clone()],
+            parent_id: None,
+            color: None,
+        };
+
+        let task = repo.create(dto).unwrap();
+
+        assert_eq!(task.tags.len(), 2);
+        assert!(task.tags.iter().any(|t| t.id == tag1_id));
+        assert!(task.tags.iter().any(|t| t.id == tag2_id));
+    }
+
+    #[test]
+    fn test_find_by_id() {
+        let db = setup_test_db();
+        let repo = TaskRepository::new(&db);
+
+        let dto = CreateTaskDto {
+            title: "Find Me".to_string(),
+            description: Some("Test".to_string()),
+            priority: TaskPriority::Low,
+            due_date: None,
+            image_path: None,
+            notes: None,
+            estimated_minutes: None,
+            tag_ids: vec![],
+            parent_id: None,
+            color: None,
+        };
+
+        let created = repo.create(dto).unwrap();
+        let found = repo.find_by_id(&created.id).unwrap();
+
+        assert!(found.is_some());
+        let task = found.unwrap();
+        assert_eq!(task.id, created.id);
+        assert_eq!(task.title, "Find Me");
+    }
+
+    #[test]
+    fn test_find_by_id_not_found() {
+        let db = setup_test_db();
+        let repo = TaskRepository::new(&db);
+
+        let result = repo.find_by_id("non-existent-id").unwrap();
+        assert!(result.is_none());
+    }
+
+    #[test]
+    fn test_update_task_title() {
+        let db = setup_test_db();
+        let repo = TaskRepository::new(&db);
+
+        let dto = CreateTaskDto {
+            title: "Original Title".to_string(),
+            description: None,
+            priority: TaskPriority::Medium,
+            due_date: None,
+            image_path: None,
+            notes: None,
+            estimated_minutes: None,
+            tag_ids: vec![],
+            parent_id: None,
+            color: None,
+        };
+
+        let created = repo.create(dto).unwrap();
+
+        let update = UpdateTaskDto {
+            title: Some("Updated Title".to_string()),
+            description: None,
+            status: None,
+            priority: None,
+            due_date: None,
+            image_path: None,
+            notes: None,
+            estimated_minutes: None,
+            actual_minutes: None,
+            tag_ids: None,
+            color: None,
+            clear_fields: vec![],
+        };
+
+        let updated = repo.update(&created.id, update).unwrap();
+        assert_eq!(updated.title, "Updated Title");
+    }
+
+    #[test]
+    fn test_update_task_status() {
+        let db = setup_test_db();
+        let repo = TaskRepository::new(&db);
+
+        let dto = CreateTaskDto {
+            title: "Task".to_string(),
+            description: None,
+            priority: TaskPriority::Medium,
+            due_date: None,
+            image_path: None,
+            notes: None,
+            estimated_minutes: None,
+            tag_ids: vec![],
+            parent_id: None,
+            color: None,
+        };
+
+        let created = repo.create(dto).unwrap();
+        assert_eq!(created.status, TaskStatus::Pending);
+
+        let update = UpdateTaskDto {
+            title: None,
+            description: None,
+            status: Some(TaskStatus::InProgress),
+            priority: None,
+            due_date: None,
+            image_path: None,
+            notes: None,
+            estimated_minutes: None,
+            actual_minutes: None,
+            tag_ids: None,
+            color: None,
+            clear_fields: vec![],
+        };
+
+        let updated = repo.update(&created.id, update).unwrap();
+        assert_eq!(updated.status, TaskStatus::InProgress);
+    }
+
+    #[test]
+    fn test_update_rolls_back_status_without_history_row() {
+        let db = setup_test_db();
+        let repo = TaskRepository::new(&db);
+        let created = create_task_with_tags(&repo, "Task", vec![]);
+        db.connection()
+            .execute_batch(
+                "CREATE TRIGGER reject_history BEFORE INSERT ON task_status_history
+                 BEGIN SELECT RAISE(ABORT, 'rejected'); END;",
+            )
+            .unwrap();
+
+        let update = UpdateTaskDto {
+            title: None,
+            description: None,
+            status: Some(TaskStatus::InProgress),
+            priority: None,
+            due_date: None,
+            image_path: None,
+            notes: None,
+            estimated_minutes: None,
+            actual_minutes: None,
+            tag_ids: None,
+            color: None,
+            clear_fields: vec![],
+        };
+        assert!(repo.update(&created.id, update).is_err());
+        let stored = repo.find_by_id(&created.id).unwrap().unwrap();
+        assert_eq!(stored.status, TaskStatus::Pending);
+    }
+
+    #[test]
+    fn test_completed_at_set_on_completion_and_cleared_on_reopen() {
+        let db = setup_test_db();
+        let repo = TaskRepository::new(&db);
+
+        let created = repo
+            .create(CreateTaskDto {
+                title: "Task".to_string(),
+                description: None,
+                priority: TaskPriority::Medium,
+                due_date: None,
+                image_path: None,
+                notes: None,
+                estimated_minutes: None,
+                tag_ids: vec![],
+                parent_id: None,
+                color: None,
+            })
+            .unwrap();
+        assert!(created.completed_at.is_none());
+
+        let status_update = |status: TaskStatus| UpdateTaskDto {
+            title: None,
+            description: None,
+            status: Some(status),
+            priority: None,
+            due_date: None,
+            image_path: None,
+            notes: None,
+            estimated_minutes: None,
+            actual_minutes: None,
+            tag_ids: None,
+            color: None,
+            clear_fields: vec![],
+        };
+
+        let before = Utc::now();
+        let completed = repo
+            .update(&created.id, status_update(TaskStatus::Completed))
+            .unwrap();
+        assert!(completed.completed_at.unwrap() >= before);
+        assert!(completed.validate().is_ok());
+
+        let reopened = repo
+            .update(&created.id, status_update(TaskStatus::Pending))
+            .unwrap();
+        assert_eq!(reopened.status, TaskStatus::Pending);
+        assert!(reopened.completed_at.is_none());
+    }
+
+    #[test]
+    fn test_update_task_tags() {
+        let db = setup_test_db();
+        let repo = TaskRepository::new(&db);
+
+        let tag1_id = create_test_tag(&db, "Tag1");
+        let tag2_id = create_test_tag(&db, "Tag2");
+        let tag3_id = create_test_tag(&db, "Tag3");
+
+        let dto = CreateTaskDto {
+            title: "Task".to_string(),
+            description: None,
+            priority: TaskPriority::Medium,
+            due_date: None,
+            image_path: None,
+            notes: None,
+            estimated_minutes: None,
+            tag_ids: vec![tag1_id.clone()],
+            parent_id: None,
+            color: None,
+        };
+
+        let created = repo.create(dto).unwrap();
+        assert_eq!(created.tags.len(), 1);
+
+        // Update tags
+        let update = UpdateTaskDto {
+            title: None,
+            description: None,
+            status: None,
+            priority: None,
+            due_date: None,
+            image_path: None,
+            notes: None,
+            estimated_minutes: None,
+            actual_minutes: None,
+            tag_ids: Some(vec![tag2_id.clone(), tag3_id.clone()]),
+            color: None,
+            clear_fields: vec![],
+        };
+
+        let updated = repo.update(&created.id, update).unwrap();
+        assert_eq!(updated.tags.len(), 2);
+        assert!(updated.tags.iter().any(|t| t.id == tag2_id));
+        assert!(updated.tags.iter().any(|t| t.id == tag3_id));
+        assert!(!updated.tags.iter().any(|t| t.id == tag1_id));
+    }
+
+    #[test]
+    fn test_update_refreshes_updated_at_only() {
+        let db = setup_test_db();
+        let repo = TaskRepository::new(&db);
+
+        let created = repo
+            .create(CreateTaskDto {
+                title: "Task".to_string(),
+                description: None,
+                priority: TaskPriority::Medium,
+                due_date: None,
+                image_path: None,
+                notes: None,
+                estimated_minutes: None,
+                tag_ids: vec![],
+                parent_id: None,
+                color: None,
+            })
+            .unwrap();
+        let an_hour_ago = Utc::now() - chrono::Duration::hours(1);
+        db.connection()
+            .execute(
+                "UPDATE tasks SET created_at = ?1, updated_at = ?1 WHERE id = ?2",
+                params![an_hour_ago.to_rfc3339(), created.id],
+            )
+            .unwrap();
+
+        let update = UpdateTaskDto {
+            title: Some("Renamed".to_string()),
+            description: None,
+            status: None,
+            priority: None,
+            due_date: None,
+            image_path: None,
+            notes: None,
+            estimated_minutes: None,
+            actual_minutes: None,
+            tag_ids: None,
+            color: None,
+            clear_fields: vec![],
+        };
+
+        let updated = repo.update(&created.id, update).unwrap();
+        assert_eq!(updated.created_at, an_hour_ago);
+        assert!(updated.updated_at > an_hour_ago);
+    }
+
+    #[test]
+    fn test_update_clears_due_date_and_notes() {
+        let db = setup_test_db();
+        let repo = TaskRepository::new(&db);
+
+        let created = repo
+            .create(CreateTaskDto {
+                title: "Task".to_string(),
+                description: Some("Keep me".to_string()),
+                priority: TaskPriority::High,
+                due_date: Some(Utc::now() + chrono::Duration::days(1)),
+                image_path: None,
+                notes: Some("Old notes".to_string()),
+                estimated_minutes: Some(30),
+                tag_ids: vec![],
+                parent_id: None,
+                color: None,
+            })
+            .unwrap();
+
+        let update = UpdateTaskDto {
+            title: None,
+            description: None,
+            status: None,
+            priority: None,
+            due_date: None,
+            image_path: None,
+            notes: None,
+            estimated_minutes: None,
+            actual_minutes: None,
+            tag_ids: None,
+            color: None,
+            clear_fields: vec!["due_date".to_string(), "notes".to_string()],
+        };
+
+        let updated = repo.update(&created.id, update).unwrap();
+        assert!(updated.due_date.is_none());
+        assert!(updated.notes.is_none());
+        assert_eq!(updated.description.as_deref(), Some("Keep me"));
+        assert_eq!(updated.priority, TaskPriority::High);
+        assert_eq!(updated.estimated_minutes, Some(30));
+    }
+
+    #[test]
+    fn test_delete_task() {
+        let db = setup_test_db();
+        let repo = TaskRepository::new(&db);
+
+        let dto = CreateTaskDto {
+            title: "Delete Me".to_string(),
+            description: None,
+            priority: TaskPriority::Medium,
+            due_date: None,
+            image_path: None,
+            notes: None,
+            estimated_minutes: None,
+            tag_ids: vec![],
+            parent_id: None,
+            color: None,
+        };
+
+        let created = repo.create(dto).unwrap();
+        let deleted = repo.delete(&created.id).unwrap();
+
+        assert!(deleted);
+
+        let found = repo.find_by_id(&created.id).unwrap();
+        assert!(found.is_none());
+    }
+
+    #[test]
+    fn test_delete_nonexistent_task() {
+        let db = setup_test_db();
+        let repo = TaskRepository::new(&db);
+
+        let deleted = repo.delete("non-existent-id").unwrap();
+        assert!(!deleted);
+    }
+
+    #[test]
+    fn test_find_all_no_filter() {
+        let db = setup_test_db();
+        let repo = TaskRepository::new(&db);
+
+        // Create multiple tasks
+        for i in 1..=5 {
+            let dto = CreateTaskDto {
+                title: format!("Task {}", i),
+                description: None,
+                priority: TaskPriority::Medium,
+                due_date: None,
+                image_path: None,
+                notes: None,
+                estimated_minutes: None,
+                tag_ids: vec![],
+                parent_id: None,
+                color: None,
+            };
+            repo.create(dto).unwrap();
+        }
+
+        let pagination = Pagination {
+            page: 1,
+            page_size: 10,
+        };
+        let result = repo.find_all(None, None, pagination).unwrap();
+
+        assert_eq!(result.items.len(), 5);
+        assert_eq!(result.total, 5);
+    }
+
+    fn create_task_with_tags(repo: &TaskRepository, title: &str, tag_ids: Vec<String>) -> Task {
+        repo.create(CreateTaskDto {
+            title: title.to_string(),
+            description: None,
+            priority: TaskPriority::Medium,
+            due_date: None,
+            image_path: None,
+            notes: None,
+            estimated_minutes: None,
+            tag_ids,
+            parent_id: None,
+            color: None,
+        })
+        .unwrap()
+    }
+
+    #[test]
+    fn test_find_all_summaries_counts_tags() {
+        let db = setup_test_db();
+        let repo = TaskRepository::new(&db);
+        let work = create_test_tag(&db, "Work");
+        let home = create_test_tag(&db, "Home");
+
+        let none = create_task_with_tags(&repo, "No tags", vec![]);
+        let one = create_task_with_tags(&repo, "One tag", vec![work.clone()]);
+        let two = create_task_with_tags(&repo, "Two tags", vec![work.clone(), home]);
+
+        let result = repo
+            .find_all_summaries(None, None, Pagination::default())
+            .unwrap();
+        assert_eq!(result.total, 3);
+        let tag_count = |id: &str| {
+            result
+                .items
+                .iter()
+                .find(|s| s.id == id)
+                .map(|s| s.tag_count)
+                .unwrap()
+        };
+        assert_eq!(tag_count(&none.id), 0);
+        assert_eq!(tag_count(&one.id), 1);
+        assert_eq!(tag_count(&two.id), 2);
+
+        // Filtering by a tag still counts every tag on the matching tasks
+        let filter = TaskFilter {
+            tag_ids: Some(vec![work]),
+            ..Default::default()
+        };
+        let filtered = repo
+            .find_all_summaries(Some(filter), None, Pagination::default())
+            .unwrap();
+        let mut counts: Vec<u32> = filtered.items.iter().map(|s| s.tag_count).collect();
+        counts.sort();
+        assert_eq!(counts, vec![1, 2]);
+    }
+
+    #[test]
+    fn test_find_all_summaries_skips_per_task_tag_queries() {
+        let db = setup_test_db();
+        let repo = TaskRepository::new(&db);
+        let tag = create_test_tag(&db, "Work");
+        create_task_with_tags(&repo, "Tagged", vec![tag]);
+
+        // `find_all` looks up each task's tags in `tags`; summaries only read `task_tags`
+        db.connection().execute("DROP TABLE tags", []).unwrap();
+
+        assert!(repo.find_all(None, None, Pagination::default()).is_err());
+        let summaries = repo
+            .find_all_summaries(None, None, Pagination::default())
+            .unwrap();
+        assert_eq!(summaries.items.len(), 1);
+        assert_eq!(summaries.items[0].tag_count, 1);
+    }
+
+    #[test]
+    fn test_tags_loaded_in_one_query_per_call() {
+        let db = setup_test_db();
+        let repo = TaskRepository::new(&db);
+        let work = create_test_tag(&db, "Work");
+        let admin = create_test_tag(&db, "Admin");
+        let home = create_test_tag(&db, "Home");
+
+        let both = create_task_with_tags(&repo, "Both", vec![work.clone(), admin.clone()]);
+        let single = create_task_with_tags(&repo, "Single", vec![home]);
+        let untagged = create_task_with_tags(&repo, "Untagged", vec![]);
+        for task in [&both, &single, &untagged] {
+            db.connection()
+                .execute(
+                    "UPDATE tasks SET due_date = ?1 WHERE id = ?2",
+                    params![(Utc::now() - Duration::days(1)).to_rfc3339(), task.id],
+                )
+                .unwrap();
+        }
+
+        let tag_queries = count_tag_queries(&db);
+        let page = repo.find_all(None, None, Pagination::default()).unwrap();
+        assert_eq!(tag_queries.swap(0, Ordering::SeqCst), 1);
+
+        let overdue = repo.find_overdue(Duration::zero()).unwrap();
+        assert_eq!(tag_queries.swap(0, Ordering::SeqCst), 1);
+
+        let modified = repo
+            .find_modified_since(Utc::now() - Duration::days(1))
+            .unwrap();
+        assert_eq!(tag_queries.swap(0, Ordering::SeqCst), 1);
+
+        for tasks in [&page.items, &overdue, &modified] {
+            assert_eq!(tasks.len(), 3);
+            let tag_names = |id: &str| -> Vec<String> {
+                let task = tasks.iter().find(|t| t.id == id).unwrap();
+                task.tags.iter().map(|tag| tag.name.clone()).collect()
+            };
+            assert_eq!(tag_names(&both.id), vec!["Admin", "Work"]);
+            assert_eq!(tag_names(&single.id), vec!["Home"]);
+            assert!(tag_names(&untagged.id).is_empty());
+        }
+    }
+
+    #[test]
+    fn test_related_task_lookups_load_tags_in_one_query() {
+        let db = setup_test_db();
+        let repo = TaskRepository::new(&db);
+        let work = create_test_tag(&db, "Work");
+        let admin = create_test_tag(&db, "Admin");
+        let home = create_test_tag(&db, "Home");
+
+        let parent = create_task_with_tags(&repo, "Parent", vec![]);
+        let both = create_task_with_tags(&repo, "Both", vec![work.clone(), admin.clone()]);
+        let single = create_task_with_tags(&repo, "Single", vec![home.clone()]);
+        for task in [&both, &single] {
+            db.connection()
+                .execute(
+                    "UPDATE tasks SET parent_id = ?1 WHERE id = ?2",
+                    params![parent.id, task.id],
+                )
+                .unwrap();
+            repo.set_series_id(&task.id, "series-1").unwrap();
+            repo.add_dependency(&parent.id, &task.id).unwrap();
+        }
+
+        let tag_queries = count_tag_queries(&db);
+        let children = repo.find_children(&parent.id).unwrap();
+        assert_eq!(tag_queries.swap(0, Ordering::SeqCst), 1);
+
+        let series = repo.find_by_series("series-1").unwrap();
+        assert_eq!(tag_queries.swap(0, Ordering::SeqCst), 1);
+
+        let tagged = repo.find_by_tags(&[work, home], false).unwrap();
+        assert_eq!(tag_queries.swap(0, Ordering::SeqCst), 1);
+
+        let blockers = repo.find_blockers(&parent.id).unwrap();
+        assert_eq!(tag_queries.swap(0, Ordering::SeqCst), 1);
+
+        for tasks in [&children, &series, &tagged, &blockers] {
+            assert_eq!(tasks.len(), 2);
+            let tag_names = |id: &str| -> Vec<String> {
+                let task = tasks.iter().find(|t| t.id == id).unwrap();
+                task.tags.iter().map(|tag| tag.name.clone()).collect()
+            };
+            assert_eq!(tag_names(&both.id), vec!["Admin", "Work"]);
+            assert_eq!(tag_names(&single.id), vec!["Home"]);
+        }
+    }
+
+    #[test]
+    fn test_tags_loaded_in_chunks_of_task_ids() {
+        let db = setup_test_db();
+        let repo = TaskRepository::new(&db);
+        let work = create_test_tag(&db, "Work");
+
+        for i in 0..=TAG_LOAD_CHUNK_SIZE {
+            create_task_with_tags(&repo, &format!("Task {}", i), vec![work.clone()]);
+        }
+        db.connection()
+            .execute(
+                "UPDATE tasks SET due_date = ?1",
+                params![(Utc::now() - Duration::days(1)).to_rfc3339()],
+            )
+            .unwrap();
+
+        let tag_queries = count_tag_queries(&db);
+        let overdue = repo.find_overdue(Duration::zero()).unwrap();
+        assert_eq!(overdue.len(), TAG_LOAD_CHUNK_SIZE + 1);
+        assert_eq!(tag_queries.load(Ordering::SeqCst), 2);
+
+        assert!(overdue
+            .iter()
+            .all(|task| task.tags.len() == 1 && task.tags[0].name == "Work"));
+    }
+
+    #[test]
+    fn test_find_all_with_status_filter() {
+        let db = setup_test_db();
+        let repo = TaskRepository::new(&db);
+
+        // Create tasks with different statuses
+        for i in 1..=3 {
+            let dto = CreateTaskDto {
+                title: format!("Pending Task {}", i),
+                description: None,
+                priority: TaskPriority::Medium,
+                due_date: None,
+                image_path: None,
+                notes: None,
+                estimated_minutes: None,
+                tag_ids: vec![],
+                parent_id: None,
+                color: None,
+            };
+            repo.create(dto).unwrap();
+        }
+
+        // Create and update to InProgress
+        let dto = CreateTaskDto {
+            title: "InProgress Task".to_string(),
+            description: None,
+            priority: TaskPriority::Medium,
+            due_date: None,
+            image_path: None,
+            notes: None,
+            estimated_minutes: None,
+            tag_ids: vec![],
+            parent_id: None,
+            color: None,
+        };
         let task = repo.create(dto).unwrap();
+        let update = UpdateTaskDto {
+            title: None,
+            description: None,
+            status: Some(TaskStatus::InProgress),
+            priority: None,
+            due_date: None,
+            image_path: None,
+            notes: None,
+            estimated_minutes: None,
+            actual_minutes: None,
+            tag_ids: None,
+            color: None,
+            clear_fields: vec![],
+        };
+        repo.update(&task.id, update).unwrap();
+
+        // Filter by Pending status
+        let filter = TaskFilter {
+            status: Some(TaskStatus::Pending),
+            priority: None,
+            tag_ids: None,
+            search: None,
+            due_before: None,
+            due_after: None,
+            effective_status: None,
+            include_completed: None,
+            only_no_due_date: false,
+            overdue_grace_minutes: 0,
+            include_deferred: false,
+            only_deferred: false,
+        };
+
+        let pagination = Pagination {
+            page: 1,
+            page_size: 10,
+        };
+        let result = repo.find_all(Some(filter), None, pagination).unwrap();
+
+        assert_eq!(result.items.len(), 3);
+        assert!(result.items.iter().all(|t| t.status == TaskStatus::Pending));
+    }
+
+    #[test]
+    fn test_find_all_with_priority_filter() {
+        let db = setup_test_db();
+        let repo = TaskRepository::new(&db);
+
+        // Create tasks with different priorities
+        let dto1 = CreateTaskDto {
+            title: "High Priority".to_string(),
+            description: None,
+            priority: TaskPriority::High,
+            due_date: None,
+            image_path: None,
+            notes: None,
+            estimated_minutes: None,
+            tag_ids: vec![],
+            parent_id: None,
+            color: None,
+        };
+        repo.create(dto1).unwrap();
+
+        let dto2 = CreateTaskDto {
+            title: "Low Priority".to_string(),
+            description: None,
+            priority: TaskPriority::Low,
+            due_date: None,
+            image_path: None,
+            notes: None,
+            estimated_minutes: None,
+            tag_ids: vec![],
+            parent_id: None,
+            color: None,
+        };
+        repo.create(dto2).unwrap();
+
+        // Filter by High priority
+        let filter = TaskFilter {
+            status: None,
+            priority: Some(TaskPriority::High),
+            tag_ids: None,
+            search: None,
+            due_before: None,
+            due_after: None,
+            effective_status: None,
+            include_completed: None,
+            only_no_due_date: false,
+            overdue_grace_minutes: 0,
+            include_deferred: false,
+            only_deferred: false,
+        };
+
+        let pagination = Pagination {
+            page: 1,
+            page_size: 10,
+        };
+        let result = repo.find_all(Some(filter), None, pagination).unwrap();
+
+        assert_eq!(result.items.len(), 1);
+        assert_eq!(result.items[0].priority, TaskPriority::High);
+    }
+
+    #[test]
+    fn test_find_all_with_search() {
+        let db = setup_test_db();
+        let repo = TaskRepository::new(&db);
 
-        assert_eq!(task.tags.len(), 2);
-        assert!(task.tags.iter().any(|t| t.id == tag1_id));
-        assert!(task.tags.iter().any(|t| t.id == tag2_id));
+        let dto1 = CreateTaskDto {
+            title: "Important Meeting".to_string(),
+            description: Some("Discuss project".to_string()),
+            priority: TaskPriority::Medium,
+            due_date: None,
+            image_path: None,
+            notes: None,
+            estimated_minutes: None,
+            tag_ids: vec![],
+            parent_id: None,
+            color: None,
+        };
+        repo.create(dto1).unwrap();
+
+        let dto2 = CreateTaskDto {
+            title: "Buy Groceries".to_string(),
+            description: None,
+            priority: TaskPriority::Medium,
+            due_date: None,
+            image_path: None,
+            notes: None,
+            estimated_minutes: None,
+            tag_ids: vec![],
+            parent_id: None,
+            color: None,
+        };
+        repo.create(dto2).unwrap();
+
+        // Search for "meeting"
+        let filter = TaskFilter {
+            status: None,
+            priority: None,
+            tag_ids: None,
+            search: Some("meeting".to_string()),
+            due_before: None,
+            due_after: None,
+            effective_status: None,
+            include_completed: None,
+            only_no_due_date: false,
+            overdue_grace_minutes: 0,
+            include_deferred: false,
+            only_deferred: false,
+        };
+
+        let pagination = Pagination {
+            page: 1,
+            page_size: 10,
+        };
+        let result = repo.find_all(Some(filter), None, pagination).unwrap();
+
+        assert_eq!(result.items.len(), 1);
+        assert!(result.items[0].title.to_lowercase().contains("meeting"));
     }
 
     #[test]
-    fn test_find_by_id() {
+    fn test_search_matches_wildcards_literally() {
         let db = setup_test_db();
         let repo = TaskRepository::new(&db);
+        create_task_with_tags(&repo, "Reach 100% coverage", vec![]);
+        create_task_with_tags(&repo, "Order 1000 labels", vec![]);
+        create_task_with_tags(&repo, "Rename file_name", vec![]);
+        create_task_with_tags(&repo, "Rename filename", vec![]);
+
+        let titles = |tasks: Vec<Task>| -> Vec<String> {
+            let mut titles: Vec<String> = tasks.into_iter().map(|t| t.title).collect();
+            titles.sort();
+            titles
+        };
+        assert_eq!(
+            titles(repo.search("100%", 10).unwrap()),
+            vec!["Reach 100% coverage"]
+        );
+        assert_eq!(
+            titles(repo.search_ranked("file_", 10).unwrap()),
+            vec!["Rename file_name"]
+        );
 
-        let dto = CreateTaskDto {
-            title: "Find Me".to_string(),
-            description: Some("Test".to_string()),
+        let filter = TaskFilter {
+            search: Some("0%".to_string()),
+            ..Default::default()
+        };
+        let page = repo
+            .find_all(Some(filter), None, Pagination::default())
+            .unwrap();
+        assert_eq!(titles(page.items), vec!["Reach 100% coverage"]);
+    }
+
+    #[test]
+    fn test_find_all_with_sorting() {
+        let db = setup_test_db();
+        let repo = TaskRepository::new(&db);
+
+        // Create tasks with different priorities
+        let dto1 = CreateTaskDto {
+            title: "Task A".to_string(),
+            description: None,
             priority: TaskPriority::Low,
             due_date: None,
             image_path: None,
             notes: None,
             estimated_minutes: None,
             tag_ids: vec![],
+            parent_id: None,
+            color: None,
+        };
+        repo.create(dto1).unwrap();
+
+        let dto2 = CreateTaskDto {
+            title: "Task B".to_string(),
+            description: None,
+            priority: TaskPriority::High,
+            due_date: None,
+            image_path: None,
+            notes: None,
+            estimated_minutes: None,
+            tag_ids: vec![],
+            parent_id: None,
+            color: None,
+        };
+        repo.create(dto2).unwrap();
+
+        let dto3 = CreateTaskDto {
+            title: "Task C".to_string(),
+            description: None,
+            priority: TaskPriority::Medium,
+            due_date: None,
+            image_path: None,
+            notes: None,
+            estimated_minutes: None,
+            tag_ids: vec![],
+            parent_id: None,
+            color: None,
+        };
+        repo.create(dto3).unwrap();
+
+        // Sort by priority descending
+        let sort = vec![TaskSort {
+            field: TaskSortField::Priority,
+            direction: SortDirection::Desc,
+        }];
+
+        let pagination = Pagination {
+            page: 1,
+            page_size: 10,
+        };
+        let result = repo.find_all(None, Some(sort), pagination).unwrap();
+
+        assert_eq!(result.items.len(), 3);
+        // Priority sorts by rank, so descending is High, Medium, Low
+        assert_eq!(result.items[0].priority, TaskPriority::High);
+        assert_eq!(result.items[1].priority, TaskPriority::Medium);
+        assert_eq!(result.items[2].priority, TaskPriority::Low);
+    }
+
+    #[test]
+    fn test_sort_by_priority_desc_uses_rank() {
+        let db = setup_test_db();
+        let repo = TaskRepository::new(&db);
+
+        for priority in [
+            TaskPriority::Medium,
+            TaskPriority::Low,
+            TaskPriority::Urgent,
+            TaskPriority::High,
+        ] {
+            repo.create(CreateTaskDto {
+                title: priority.as_str().to_string(),
+                description: None,
+                priority,
+                due_date: None,
+                image_path: None,
+                notes: None,
+                estimated_minutes: None,
+                tag_ids: vec![],
+                parent_id: None,
+                color: None,
+            })
+            .unwrap();
+        }
+
+        let sort = |direction| {
+            Some(vec![TaskSort {
+                field: TaskSortField::Priority,
+                direction,
+            }])
+        };
+        let priorities = |sort| -> Vec<TaskPriority> {
+            repo.find_all(None, sort, Pagination::default())
+                .unwrap()
+                .items
+                .into_iter()
+                .map(|t| t.priority)
+                .collect()
+        };
+
+        assert_eq!(
+            priorities(sort(SortDirection::Desc)),
+            vec![
+                TaskPriority::Urgent,
+                TaskPriority::High,
+                TaskPriority::Medium,
+                TaskPriority::Low
+            ]
+        );
+        assert_eq!(
+            priorities(sort(SortDirection::Asc)),
+            vec![
+                TaskPriority::Low,
+                TaskPriority::Medium,
+                TaskPriority::High,
+                TaskPriority::Urgent
+            ]
+        );
+    }
+
+    #[test]
+    fn test_sort_by_status_uses_workflow_order() {
+        let db = setup_test_db();
+        let repo = TaskRepository::new(&db);
+
+        for status in [
+            TaskStatus::Cancelled,
+            TaskStatus::Completed,
+            TaskStatus::Pending,
+            TaskStatus::InProgress,
+        ] {
+            let task = repo
+                .create(CreateTaskDto {
+                    title: status.as_str().to_string(),
+                    description: None,
+                    priority: TaskPriority::Medium,
+                    due_date: None,
+                    image_path: None,
+                    notes: None,
+                    estimated_minutes: None,
+                    tag_ids: vec![],
+                    parent_id: None,
+                    color: None,
+                })
+                .unwrap();
+            db.connection()
+                .execute(
+                    "UPDATE tasks SET status = ?1 WHERE id = ?2",
+                    params![status.as_str(), task.id],
+                )
+                .unwrap();
+        }
+
+        let sort = vec![TaskSort {
+            field: TaskSortField::Status,
+            direction: SortDirection::Asc,
+        }];
+        let statuses: Vec<TaskStatus> = repo
+            .find_all(None, Some(sort), Pagination::default())
+            .unwrap()
+            .items
+            .into_iter()
+            .map(|t| t.status)
+            .collect();
+
+        // Alphabetical order would put "cancelled" first and "pending" last
+        assert_eq!(
+            statuses,
+            vec![
+                TaskStatus::Pending,
+                TaskStatus::InProgress,
+                TaskStatus::Completed,
+                TaskStatus::Cancelled
+            ]
+        );
+    }
+
+    #[test]
+    fn test_pinned_tasks_sort_first() {
+        let db = setup_test_db();
+        let repo = TaskRepository::new(&db);
+
+        let mut ids = Vec::new();
+        for priority in [
+            TaskPriority::Medium,
+            TaskPriority::Low,
+            TaskPriority::Urgent,
+            TaskPriority::High,
+        ] {
+            let task = repo
+                .create(CreateTaskDto {
+                    title: priority.as_str().to_string(),
+                    description: None,
+                    priority,
+                    due_date: None,
+                    image_path: None,
+                    notes: None,
+                    estimated_minutes: None,
+                    tag_ids: vec![],
+                    parent_id: None,
+                    color: None,
+                })
+                .unwrap();
+            ids.push(task.id);
+        }
+
+        // Pin the low priority task
+        assert!(repo.set_pinned(&ids[1], true).unwrap());
+        assert!(!repo.set_pinned("missing", true).unwrap());
+
+        let titles = |sort| -> Vec<String> {
+            repo.find_all(None, sort, Pagination::default())
+                .unwrap()
+                .items
+                .into_iter()
+                .map(|t| t.title)
+                .collect()
+        };
+        let by = |field, direction| Some(vec![TaskSort { field, direction }]);
+
+        assert_eq!(
+            titles(by(TaskSortField::Priority, SortDirection::Desc)),
+            vec!["low", "urgent", "high", "medium"]
+        );
+        assert_eq!(
+            titles(by(TaskSortField::Priority, SortDirection::Asc)),
+            vec!["low", "medium", "high", "urgent"]
+        );
+        assert_eq!(
+            titles(by(TaskSortField::Title, SortDirection::Desc)),
+            vec!["low", "urgent", "medium", "high"]
+        );
+        assert_eq!(titles(None)[0], "low");
+
+        // Filters still apply to pinned tasks
+        let filter = TaskFilter {
+            priority: Some(TaskPriority::High),
+            ..Default::default()
+        };
+        let filtered = repo
+            .find_all(Some(filter), None, Pagination::default())
+            .unwrap();
+        assert_eq!(filtered.items.len(), 1);
+        assert!(!filtered.items[0].is_pinned);
+
+        assert!(repo.set_pinned(&ids[1], false).unwrap());
+        assert_eq!(
+            titles(by(TaskSortField::Priority, SortDirection::Desc)),
+            vec!["urgent", "high", "medium", "low"]
+        );
+    }
+
+    #[test]
+    fn test_find_all_with_pagination() {
+        let db = setup_test_db();
+        let repo = TaskRepository::new(&db);
+
+        // Create 10 tasks
+        for i in 1..=10 {
+            let dto = CreateTaskDto {
+                title: format!("Task {}", i),
+                description: None,
+                priority: TaskPriority::Medium,
+                due_date: None,
+                image_path: None,
+                notes: None,
+                estimated_minutes: None,
+                tag_ids: vec![],
+                parent_id: None,
+                color: None,
+            };
+            repo.create(dto).unwrap();
+        }
+
+        // Get first page (5 items)
+        let pagination1 = Pagination {
+            page: 1,
+            page_size: 5,
+        };
+        let result1 = repo.find_all(None, None, pagination1).unwrap();
+
+        assert_eq!(result1.items.len(), 5);
+        assert_eq!(result1.total, 10);
+        assert_eq!(result1.page, 1);
+        assert_eq!(result1.page_size, 5);
+        assert_eq!(result1.total_pages, 2);
+
+        // Get second page
+        let pagination2 = Pagination {
+            page: 2,
+            page_size: 5,
+        };
+        let result2 = repo.find_all(None, None, pagination2).unwrap();
+
+        assert_eq!(result2.items.len(), 5);
+        assert_eq!(result2.total, 10);
+        assert_eq!(result2.page, 2);
+
+        // Ensure different tasks on different pages
+        let ids1: Vec<_> = result1.items.iter().map(|t| &t.id).collect();
+        let ids2: Vec<_> = result2.items.iter().map(|t| &t.id).collect();
+        assert!(ids1.iter().all(|id| !ids2.contains(id)));
+    }
+
+    #[test]
+    fn test_sum_minutes_treats_missing_values_as_zero() {
+        let db = setup_test_db();
+        let repo = TaskRepository::new(&db);
+
+        let create = |priority: TaskPriority, estimated: Option<i32>, actual: Option<i32>| {
+            let task = repo
+                .create(CreateTaskDto {
+                    title: "Task".to_string(),
+                    description: None,
+                    priority,
+                    due_date: None,
+                    image_path: None,
+                    notes: None,
+                    estimated_minutes: estimated,
+                    tag_ids: vec![],
+                    parent_id: None,
+                    color: None,
+                })
+                .unwrap();
+            if actual.is_some() {
+                repo.update(
+                    &task.id,
+                    UpdateTaskDto {
+                        title: None,
+                        description: None,
+                        status: None,
+                        priority: None,
+                        due_date: None,
+                        image_path: None,
+                        notes: None,
+                        estimated_minutes: None,
+                        actual_minutes: actual,
+                        tag_ids: None,
+                        color: None,
+                        clear_fields: vec![],
+                    },
+                )
+                .unwrap();
+            }
+        };
+
+        create(TaskPriority::High, Some(30), Some(45));
+        create(TaskPriority::High, None, Some(10));
+        create(TaskPriority::Low, Some(60), None);
+        create(TaskPriority::Low, None, None);
+
+        assert_eq!(repo.sum_minutes(None).unwrap(), (90, 55));
+
+        let high_only = TaskFilter {
+            priority: Some(TaskPriority::High),
+            ..Default::default()
+        };
+        assert_eq!(repo.sum_minutes(Some(high_only)).unwrap(), (30, 55));
+
+        let nothing = TaskFilter {
+            search: Some("no such task".to_string()),
+            ..Default::default()
         };
+        assert_eq!(repo.sum_minutes(Some(nothing)).unwrap(), (0, 0));
+    }
+
+    #[test]
+    fn test_count_by_status() {
+        let db = setup_test_db();
+        let repo = TaskRepository::new(&db);
+
+        // Create tasks with different statuses
+        for _ in 0..3 {
+            let dto = CreateTaskDto {
+                title: "Pending Task".to_string(),
+                description: None,
+                priority: TaskPriority::Medium,
+                due_date: None,
+                image_path: None,
+                notes: None,
+                estimated_minutes: None,
+                tag_ids: vec![],
+                parent_id: None,
+                color: None,
+            };
+            repo.create(dto).unwrap();
+        }
+
+        for _ in 0..2 {
+            let dto = CreateTaskDto {
+                title: "Task".to_string(),
+                description: None,
+                priority: TaskPriority::Medium,
+                due_date: None,
+                image_path: None,
+                notes: None,
+                estimated_minutes: None,
+                tag_ids: vec![],
+                parent_id: None,
+                color: None,
+            };
+            let task = repo.create(dto).unwrap();
 
-        let created = repo.create(dto).unwrap();
-        let found = repo.find_by_id(&created.id).unwrap();
+            let update = UpdateTaskDto {
+                title: None,
+                description: None,
+                status: Some(TaskStatus::InProgress),
+                priority: None,
+                due_date: None,
+                image_path: None,
+                notes: None,
+                estimated_minutes: None,
+                actual_minutes: None,
+                tag_ids: None,
+                color: None,
+                clear_fields: vec![],
+            };
+            repo.update(&task.id, update).unwrap();
+        }
 
-        assert!(found.is_some());
-        let task = found.unwrap();
-        assert_eq!(task.id, created.id);
-        assert_eq!(task.title, "Find Me");
-    }
+        let counts = repo.count_by_status().unwrap();
 
-    #[test]
-    fn test_find_by_id_not_found() {
-        let db = setup_test_db();
-        let repo = TaskRepository::new(&db);
+        let pending_count = counts
+            .iter()
+            .find(|(status, _)| *status == TaskStatus::Pending)
+            .map(|(_, count)| *count)
+            .unwrap_or(0);
 
-        let result = repo.find_by_id("non-existent-id").unwrap();
-        assert!(result.is_none());
+        let in_progress_count = counts
+            .iter()
+            .find(|(status, _)| *status == TaskStatus::InProgress)
+            .map(|(_, count)| *count)
+            .unwrap_or(0);
+
+        assert_eq!(pending_count, 3);
+        assert_eq!(in_progress_count, 2);
     }
 
     #[test]
-    fn test_update_task_title() {
+    fn test_find_all_with_overdue_filter() {
         let db = setup_test_db();
         let repo = TaskRepository::new(&db);
 
-        let dto = CreateTaskDto {
-            title: "Original Title".to_string(),
+        // Past-due pending task (overdue)
+        let overdue_dto = CreateTaskDto {
+            title: "Overdue Task".to_string(),
             description: None,
-            priority: TaskPriority::Medium,
-            due_date: None,
+            priority: TaskPriority::High,
+            due_date: Some(Utc::now() - chrono::Duration::days(1)),
             image_path: None,
             notes: None,
             estimated_minutes: None,
             tag_ids: vec![],
+            parent_id: None,
+            color: None,
         };
+        let overdue = repo.create(overdue_dto).unwrap();
 
-        let created = repo.create(dto).unwrap();
-
-        let update = UpdateTaskDto {
-            title: Some("Updated Title".to_string()),
-            description: None,
-            status: None,
-            priority: None,
-            due_date: None,
-            image_path: None,
-            notes: None,
-            estimated_minutes: None,
-            actual_minutes: None,
-            tag_ids: None,
-        };
-
-        let updated = repo.update(&created.id, update).unwrap();
-        assert_eq!(updated.title, "Updated Title");
-    }
-
-    #[test]
-    fn test_update_task_status() {
-        let db = setup_test_db();
-        let repo = TaskRepository::new(&db);
-
-        let dto = CreateTaskDto {
-            title: "Task".to_string(),
+        // Past-due but completed task (not overdue)
+        let done_dto = CreateTaskDto {
+            title: "Done Task".to_string(),
             description: None,
-            priority: TaskPriority::Medium,
-            due_date: None,
+            priority: TaskPriority::High,
+            due_date: Some(Utc::now() - chrono::Duration::days(2)),
             image_path: None,
             notes: None,
             estimated_minutes: None,
             tag_ids: vec![],
+            parent_id: None,
+            color: None,
         };
-
-        let created = repo.create(dto).unwrap();
-        assert_eq!(created.status, TaskStatus::Pending);
-
+        let done = repo.create(done_dto).unwrap();
         let update = UpdateTaskDto {
             title: None,
             description: None,
-            status: Some(TaskStatus::InProgress),
+            status: Some(TaskStatus::Completed),
             priority: None,
             due_date: None,
             image_path: None,
@@ -659,129 +3238,110 @@ mod tests {
             estimated_minutes: None,
             actual_minutes: None,
             tag_ids: None,
+            color: None,
+            clear_fields: vec![],
         };
+        repo.update(&done.id, update).unwrap();
 
-        let updated = repo.update(&created.id, update).unwrap();
-        assert_eq!(updated.status, TaskStatus::InProgress);
-    }
-
-    #[test]
-    fn test_update_task_tags() {
-        let db = setup_test_db();
-        let repo = TaskRepository::new(&db);
-
-        let tag1_id = create_test_tag(&db, "Tag1");
-        let tag2_id = create_test_tag(&db, "Tag2");
-        let tag3_id = create_test_tag(&db, "Tag3");
-
-        let dto = CreateTaskDto {
-            title: "Task".to_string(),
-            description: None,
-            priority: TaskPriority::Medium,
-            due_date: None,
-            image_path: None,
-            notes: None,
-            estimated_minutes: None,
-            tag_ids: vec![tag1_id.clone()],
-        };
-
-        let created = repo.create(dto).unwrap();
-        assert_eq!(created.tags.len(), 1);
-
-        // Update tags
-        let update = UpdateTaskDto {
-            title: None,
+        // Future task and task without due date (not overdue)
+        let future_dto = CreateTaskDto {
+            title: "Future Task".to_string(),
             description: None,
-            status: None,
-            priority: None,
-            due_date: None,
+            priority: TaskPriority::High,
+            due_date: Some(Utc::now() + chrono::Duration::days(1)),
             image_path: None,
             notes: None,
             estimated_minutes: None,
-            actual_minutes: None,
-            tag_ids: Some(vec![tag2_id.clone(), tag3_id.clone()]),
+            tag_ids: vec![],
+            parent_id: None,
+            color: None,
         };
+        repo.create(future_dto).unwrap();
 
-        let updated = repo.update(&created.id, update).unwrap();
-        assert_eq!(updated.tags.len(), 2);
-        assert!(updated.tags.iter().any(|t| t.id == tag2_id));
-        assert!(updated.tags.iter().any(|t| t.id == tag3_id));
-        assert!(!updated.tags.iter().any(|t| t.id == tag1_id));
-    }
-
-    #[test]
-    fn test_delete_task() {
-        let db = setup_test_db();
-        let repo = TaskRepository::new(&db);
-
-        let dto = CreateTaskDto {
-            title: "Delete Me".to_string(),
+        let no_due_dto = CreateTaskDto {
+            title: "No Due Date".to_string(),
             description: None,
-            priority: TaskPriority::Medium,
+            priority: TaskPriority::High,
             due_date: None,
             image_path: None,
             notes: None,
             estimated_minutes: None,
             tag_ids: vec![],
+            parent_id: None,
+            color: None,
         };
+        repo.create(no_due_dto).unwrap();
 
-        let created = repo.create(dto).unwrap();
-        let deleted = repo.delete(&created.id).unwrap();
-
-        assert!(deleted);
+        let filter = TaskFilter {
+            effective_status: Some(EffectiveTaskStatus::Overdue),
+            ..Default::default()
+        };
 
-        let found = repo.find_by_id(&created.id).unwrap();
-        assert!(found.is_none());
-    }
+        let pagination = Pagination {
+            page: 1,
+            page_size: 10,
+        };
+        let result = repo.find_all(Some(filter), None, pagination).unwrap();
 
-    #[test]
-    fn test_delete_nonexistent_task() {
-        let db = setup_test_db();
-        let repo = TaskRepository::new(&db);
+        assert_eq!(result.total, 1);
+        assert_eq!(result.items[0].id, overdue.id);
+        assert!(result.items.iter().all(|t| t.is_overdue(Duration::zero())));
 
-        let deleted = repo.delete("non-existent-id").unwrap();
-        assert!(!deleted);
+        // Pending excludes the overdue task
+        let filter = TaskFilter {
+            effective_status: Some(EffectiveTaskStatus::Pending),
+            ..Default::default()
+        };
+        let result = repo
+            .find_all(Some(filter), None, Pagination::default())
+            .unwrap();
+        assert_eq!(result.total, 2);
+        assert!(result.items.iter().all(|t| t.id != overdue.id));
     }
 
     #[test]
-    fn test_find_all_no_filter() {
+    fn test_overdue_filter_composes_with_other_filters() {
         let db = setup_test_db();
         let repo = TaskRepository::new(&db);
 
-        // Create multiple tasks
-        for i in 1..=5 {
+        for priority in [TaskPriority::Low, TaskPriority::Urgent] {
             let dto = CreateTaskDto {
-                title: format!("Task {}", i),
+                title: format!("Overdue {}", priority.as_str()),
                 description: None,
-                priority: TaskPriority::Medium,
-                due_date: None,
+                priority,
+                due_date: Some(Utc::now() - chrono::Duration::hours(3)),
                 image_path: None,
                 notes: None,
                 estimated_minutes: None,
                 tag_ids: vec![],
+                parent_id: None,
+                color: None,
             };
             repo.create(dto).unwrap();
         }
 
-        let pagination = Pagination {
-            page: 1,
-            page_size: 10,
+        let filter = TaskFilter {
+            priority: Some(TaskPriority::Urgent),
+            effective_status: Some(EffectiveTaskStatus::Overdue),
+            ..Default::default()
         };
-        let result = repo.find_all(None, None, pagination).unwrap();
 
-        assert_eq!(result.items.len(), 5);
-        assert_eq!(result.total, 5);
+        let result = repo
+            .find_all(Some(filter), None, Pagination::default())
+            .unwrap();
+
+        assert_eq!(result.total, 1);
+        assert_eq!(result.items[0].priority, TaskPriority::Urgent);
     }
 
     #[test]
-    fn test_find_all_with_status_filter() {
+    fn test_include_completed_filter() {
         let db = setup_test_db();
         let repo = TaskRepository::new(&db);
 
-        // Create tasks with different statuses
-        for i in 1..=3 {
+        for title in ["Open", "Finished"] {
             let dto = CreateTaskDto {
-                title: format!("Pending Task {}", i),
+                title: title.to_string(),
                 description: None,
                 priority: TaskPriority::Medium,
                 due_date: None,
@@ -789,125 +3349,337 @@ mod tests {
                 notes: None,
                 estimated_minutes: None,
                 tag_ids: vec![],
+                parent_id: None,
+                color: None,
             };
-            repo.create(dto).unwrap();
+            let task = repo.create(dto).unwrap();
+            if title == "Finished" {
+                let update = UpdateTaskDto {
+                    title: None,
+                    description: None,
+                    status: Some(TaskStatus::Completed),
+                    priority: None,
+                    due_date: None,
+                    image_path: None,
+                    notes: None,
+                    estimated_minutes: None,
+                    actual_minutes: None,
+                    tag_ids: None,
+                    color: None,
+                    clear_fields: vec![],
+                };
+                repo.update(&task.id, update).unwrap();
+            }
         }
 
-        // Create and update to InProgress
+        let count = |filter: TaskFilter| {
+            repo.find_all(Some(filter), None, Pagination::default())
+                .unwrap()
+                .total
+        };
+
+        // Setting on: completed tasks are listed
+        assert_eq!(
+            count(TaskFilter {
+                include_completed: Some(true),
+                ..Default::default()
+            }),
+            2
+        );
+
+        // Setting off: completed tasks are hidden
+        let hidden = repo
+            .find_all(
+                Some(TaskFilter {
+                    include_completed: Some(false),
+                    ..Default::default()
+                }),
+                None,
+                Pagination::default(),
+            )
+            .unwrap();
+        assert_eq!(hidden.total, 1);
+        assert_eq!(hidden.items[0].title, "Open");
+
+        // An explicit status filter overrides the setting
+        assert_eq!(
+            count(TaskFilter {
+                status: Some(TaskStatus::Completed),
+                include_completed: Some(false),
+                ..Default::default()
+            }),
+            1
+        );
+    }
+
+    #[test]
+    fn test_only_no_due_date_filter_composes_with_status() {
+        let db = setup_test_db();
+        let repo = TaskRepository::new(&db);
+
+        for (title, due_date, status) in [
+            ("Inbox", None, TaskStatus::Pending),
+            ("Inbox started", None, TaskStatus::InProgress),
+            (
+                "Scheduled",
+                Some(Utc::now() + chrono::Duration::days(1)),
+                TaskStatus::Pending,
+            ),
+        ] {
+            let task = repo
+                .create(CreateTaskDto {
+                    title: title.to_string(),
+                    description: None,
+                    priority: TaskPriority::Medium,
+                    due_date,
+                    image_path: None,
+                    notes: None,
+                    estimated_minutes: None,
+                    tag_ids: vec![],
+                    parent_id: None,
+                    color: None,
+                })
+                .unwrap();
+            if status != TaskStatus::Pending {
+                repo.update(
+                    &task.id,
+                    UpdateTaskDto {
+                        title: None,
+                        description: None,
+                        status: Some(status),
+                        priority: None,
+                        due_date: None,
+                        image_path: None,
+                        notes: None,
+                        estimated_minutes: None,
+                        actual_minutes: None,
+                        tag_ids: None,
+                        color: None,
+                        clear_fields: vec![],
+                    },
+                )
+                .unwrap();
+            }
+        }
+
+        let titles = |filter: TaskFilter| {
+            let mut titles: Vec<String> = repo
+                .find_all(Some(filter), None, Pagination::default())
+                .unwrap()
+                .items
+                .into_iter()
+                .map(|t| t.title)
+                .collect();
+            titles.sort();
+            titles
+        };
+
+        assert_eq!(
+            titles(TaskFilter {
+                only_no_due_date: true,
+                ..Default::default()
+            }),
+            vec!["Inbox", "Inbox started"]
+        );
+        assert_eq!(
+            titles(TaskFilter {
+                only_no_due_date: true,
+                status: Some(TaskStatus::Pending),
+                ..Default::default()
+            }),
+            vec!["Inbox"]
+        );
+        assert_eq!(titles(TaskFilter::default()).len(), 3);
+    }
+
+    #[test]
+    fn test_create_rolls_back_task_when_tag_association_fails() {
+        let db = setup_test_db();
+        let repo = TaskRepository::new(&db);
+
+        db.connection()
+            .execute_batch(
+                "CREATE TRIGGER fail_tag_association BEFORE INSERT ON task_tags
+                 BEGIN SELECT RAISE(ABORT, 'tag association failed'); END;",
+            )
+            .unwrap();
+
         let dto = CreateTaskDto {
-            title: "InProgress Task".to_string(),
+            title: "Tagged".to_string(),
             description: None,
             priority: TaskPriority::Medium,
             due_date: None,
             image_path: None,
             notes: None,
             estimated_minutes: None,
-            tag_ids: vec![],
-        };
-        let task = repo.create(dto).unwrap();
-        let update = UpdateTaskDto {
-            title: None,
-            description: None,
-            status: Some(TaskStatus::InProgress),
-            priority: None,
-            due_date: None,
-            image_path: None,
-            notes: None,
-            estimated_minutes: None,
-            actual_minutes: None,
-            tag_ids: None,
+            tag_ids: vec!["tag-1".to_string()],
+            parent_id: None,
+            color: None,
         };
-        repo.update(&task.id, update).unwrap();
 
-        // Filter by Pending status
-        let filter = TaskFilter {
-            status: Some(TaskStatus::Pending),
-            priority: None,
-            tag_ids: None,
-            search: None,
-            due_before: None,
-            due_after: None,
-        };
+        assert!(repo.create(dto).is_err());
+
+        let orphans: i64 = db
+            .connection()
+            .query_row("SELECT COUNT(*) FROM tasks", [], |row| row.get(0))
+            .unwrap();
+        assert_eq!(orphans, 0);
+    }
+
+    #[test]
+    fn test_add_and_remove_dependency() {
+        let db = setup_test_db();
+        let repo = TaskRepository::new(&db);
+
+        let mut ids = Vec::new();
+        for title in ["Blocked", "Blocker A", "Blocker B"] {
+            let dto = CreateTaskDto {
+                title: title.to_string(),
+                description: None,
+                priority: TaskPriority::Medium,
+                due_date: None,
+                image_path: None,
+                notes: None,
+                estimated_minutes: None,
+                tag_ids: vec![],
+                parent_id: None,
+                color: None,
+            };
+            ids.push(repo.create(dto).unwrap().id);
+        }
 
-        let pagination = Pagination {
-            page: 1,
-            page_size: 10,
-        };
-        let result = repo.find_all(Some(filter), None, pagination).unwrap();
+        repo.add_dependency(&ids[0], &ids[1]).unwrap();
+        repo.add_dependency(&ids[1], &ids[2]).unwrap();
 
-        assert_eq!(result.items.len(), 3);
-        assert!(result.items.iter().all(|t| t.status == TaskStatus::Pending));
+        let blockers = repo.find_blockers(&ids[0]).unwrap();
+        assert_eq!(blockers.len(), 1);
+        assert_eq!(blockers[0].id, ids[1]);
+
+        // Transitive lookup follows the chain
+        assert!(repo.depends_on_transitively(&ids[0], &ids[2]).unwrap());
+        assert!(!repo.depends_on_transitively(&ids[2], &ids[0]).unwrap());
+
+        assert!(repo.remove_dependency(&ids[0], &ids[1]).unwrap());
+        assert!(repo.find_blockers(&ids[0]).unwrap().is_empty());
+        assert!(!repo.remove_dependency(&ids[0], &ids[1]).unwrap());
     }
 
     #[test]
-    fn test_find_all_with_priority_filter() {
+    fn test_add_list_remove_attachments() {
         let db = setup_test_db();
         let repo = TaskRepository::new(&db);
 
-        // Create tasks with different priorities
-        let dto1 = CreateTaskDto {
-            title: "High Priority".to_string(),
+        let dto = CreateTaskDto {
+            title: "With files".to_string(),
             description: None,
-            priority: TaskPriority::High,
+            priority: TaskPriority::Medium,
             due_date: None,
             image_path: None,
             notes: None,
             estimated_minutes: None,
             tag_ids: vec![],
+            parent_id: None,
+            color: None,
         };
-        repo.create(dto1).unwrap();
+        let task = repo.create(dto).unwrap();
 
-        let dto2 = CreateTaskDto {
-            title: "Low Priority".to_string(),
+        let first = repo
+            .add_attachment(&task.id, "/tmp/a.png", Some("image/png".to_string()))
+            .unwrap();
+        repo.add_attachment(&task.id, "/tmp/b.pdf", None).unwrap();
+
+        let attachments = repo.list_attachments(&task.id).unwrap();
+        assert_eq!(attachments.len(), 2);
+        assert_eq!(attachments[0].file_path, "/tmp/a.png");
+        assert_eq!(attachments[0].mime_type, Some("image/png".to_string()));
+
+        assert!(repo.remove_attachment(&first.id).unwrap());
+        assert!(!repo.remove_attachment(&first.id).unwrap());
+        assert_eq!(repo.list_attachments(&task.id).unwrap().len(), 1);
+    }
+
+    #[test]
+    fn test_delete_task_removes_attachments() {
+        let db = setup_test_db();
+        let repo = TaskRepository::new(&db);
+
+        let dto = CreateTaskDto {
+            title: "Doomed".to_string(),
             description: None,
-            priority: TaskPriority::Low,
+            priority: TaskPriority::Medium,
             due_date: None,
             image_path: None,
             notes: None,
             estimated_minutes: None,
             tag_ids: vec![],
+            parent_id: None,
+            color: None,
         };
-        repo.create(dto2).unwrap();
-
-        // Filter by High priority
-        let filter = TaskFilter {
-            status: None,
-            priority: Some(TaskPriority::High),
-            tag_ids: None,
-            search: None,
-            due_before: None,
-            due_after: None,
-        };
+        let task = repo.create(dto).unwrap();
+        repo.add_attachment(&task.id, "/tmp/a.png", None).unwrap();
 
-        let pagination = Pagination {
-            page: 1,
-            page_size: 10,
-        };
-        let result = repo.find_all(Some(filter), None, pagination).unwrap();
+        repo.delete(&task.id).unwrap();
 
-        assert_eq!(result.items.len(), 1);
-        assert_eq!(result.items[0].priority, TaskPriority::High);
+        assert!(repo.list_attachments(&task.id).unwrap().is_empty());
     }
 
     #[test]
-    fn test_find_all_with_search() {
+    fn test_checklist_items_toggle_and_order() {
         let db = setup_test_db();
         let repo = TaskRepository::new(&db);
 
-        let dto1 = CreateTaskDto {
-            title: "Important Meeting".to_string(),
-            description: Some("Discuss project".to_string()),
+        let dto = CreateTaskDto {
+            title: "Pack".to_string(),
+            description: None,
             priority: TaskPriority::Medium,
             due_date: None,
             image_path: None,
             notes: None,
             estimated_minutes: None,
             tag_ids: vec![],
+            parent_id: None,
+            color: None,
         };
-        repo.create(dto1).unwrap();
+        let task = repo.create(dto).unwrap();
+        assert_eq!(task.checklist_progress, None);
+
+        let first = repo.add_checklist_item(&task.id, "Passport").unwrap();
+        repo.add_checklist_item(&task.id, "Charger").unwrap();
+
+        let items = repo.list_checklist_items(&task.id).unwrap();
+        let texts: Vec<&str> = items.iter().map(|i| i.text.as_str()).collect();
+        assert_eq!(texts, vec!["Passport", "Charger"]);
+        assert!(items[0].position < items[1].position);
+
+        assert!(repo.toggle_checklist_item(&first.id).unwrap());
+        assert!(
+            repo.find_checklist_item(&first.id)
+                .unwrap()
+                .unwrap()
+                .is_done
+        );
 
-        let dto2 = CreateTaskDto {
-            title: "Buy Groceries".to_string(),
+        let task = repo.find_by_id(&task.id).unwrap().unwrap();
+        assert_eq!(task.checklist_progress, Some(0.5));
+
+        assert!(repo.toggle_checklist_item(&first.id).unwrap());
+        assert!(
+            !repo
+                .find_checklist_item(&first.id)
+                .unwrap()
+                .unwrap()
+                .is_done
+        );
+        assert!(!repo.toggle_checklist_item("missing").unwrap());
+    }
+
+    #[test]
+    fn test_delete_task_removes_checklist_items() {
+        let db = setup_test_db();
+        let repo = TaskRepository::new(&db);
+
+        let dto = CreateTaskDto {
+            title: "Doomed".to_string(),
             description: None,
             priority: TaskPriority::Medium,
             due_date: None,
@@ -915,205 +3687,276 @@ mod tests {
             notes: None,
             estimated_minutes: None,
             tag_ids: vec![],
+            parent_id: None,
+            color: None,
         };
-        repo.create(dto2).unwrap();
-
-        // Search for "meeting"
-        let filter = TaskFilter {
-            status: None,
-            priority: None,
-            tag_ids: None,
-            search: Some("meeting".to_string()),
-            due_before: None,
-            due_after: None,
-        };
+        let task = repo.create(dto).unwrap();
+        let item = repo.add_checklist_item(&task.id, "Step").unwrap();
 
-        let pagination = Pagination {
-            page: 1,
-            page_size: 10,
-        };
-        let result = repo.find_all(Some(filter), None, pagination).unwrap();
+        repo.delete(&task.id).unwrap();
 
-        assert_eq!(result.items.len(), 1);
-        assert!(result.items[0].title.to_lowercase().contains("meeting"));
+        assert!(repo.list_checklist_items(&task.id).unwrap().is_empty());
+        assert!(repo.find_checklist_item(&item.id).unwrap().is_none());
     }
 
     #[test]
-    fn test_find_all_with_sorting() {
+    fn test_comments_ordered_and_removed_with_task() {
         let db = setup_test_db();
         let repo = TaskRepository::new(&db);
 
-        // Create tasks with different priorities
-        let dto1 = CreateTaskDto {
-            title: "Task A".to_string(),
+        let dto = CreateTaskDto {
+            title: "Discuss".to_string(),
             description: None,
-            priority: TaskPriority::Low,
+            priority: TaskPriority::Medium,
             due_date: None,
             image_path: None,
             notes: None,
             estimated_minutes: None,
             tag_ids: vec![],
+            parent_id: None,
+            color: None,
         };
-        repo.create(dto1).unwrap();
+        let task = repo.create(dto).unwrap();
 
-        let dto2 = CreateTaskDto {
-            title: "Task B".to_string(),
+        let first = repo.add_comment(&task.id, "First").unwrap();
+        repo.add_comment(&task.id, "Second").unwrap();
+        repo.add_comment(&task.id, "Third").unwrap();
+
+        let bodies: Vec<String> = repo
+            .list_comments(&task.id)
+            .unwrap()
+            .into_iter()
+            .map(|c| c.body)
+            .collect();
+        assert_eq!(bodies, vec!["First", "Second", "Third"]);
+
+        assert!(repo.delete_comment(&first.id).unwrap());
+        assert_eq!(repo.list_comments(&task.id).unwrap().len(), 2);
+
+        repo.delete(&task.id).unwrap();
+        assert!(repo.list_comments(&task.id).unwrap().is_empty());
+    }
+
+    #[test]
+    fn test_update_records_status_changes_only() {
+        let db = setup_test_db();
+        let repo = TaskRepository::new(&db);
+
+        let dto = CreateTaskDto {
+            title: "Tracked".to_string(),
             description: None,
-            priority: TaskPriority::High,
+            priority: TaskPriority::Medium,
             due_date: None,
             image_path: None,
             notes: None,
             estimated_minutes: None,
             tag_ids: vec![],
+            parent_id: None,
+            color: None,
         };
-        repo.create(dto2).unwrap();
+        let task = repo.create(dto).unwrap();
 
-        let dto3 = CreateTaskDto {
-            title: "Task C".to_string(),
+        let status_update = |status: TaskStatus| UpdateTaskDto {
+            title: None,
             description: None,
-            priority: TaskPriority::Medium,
+            status: Some(status),
+            priority: None,
             due_date: None,
             image_path: None,
             notes: None,
             estimated_minutes: None,
-            tag_ids: vec![],
+            actual_minutes: None,
+            tag_ids: None,
+            color: None,
+            clear_fields: vec![],
         };
-        repo.create(dto3).unwrap();
 
-        // Sort by priority descending
-        let sort = vec![TaskSort {
-            field: TaskSortField::Priority,
-            direction: SortDirection::Desc,
-        }];
+        // Same status is a no-op for the history
+        repo.update(&task.id, status_update(TaskStatus::Pending))
+            .unwrap();
+        assert!(repo.find_status_history(&task.id).unwrap().is_empty());
 
-        let pagination = Pagination {
-            page: 1,
-            page_size: 10,
-        };
-        let result = repo.find_all(None, Some(sort), pagination).unwrap();
+        repo.update(&task.id, status_update(TaskStatus::InProgress))
+            .unwrap();
+        let history = repo.find_status_history(&task.id).unwrap();
+        assert_eq!(history.len(), 1);
+        assert_eq!(history[0].from_status, TaskStatus::Pending);
+        assert_eq!(history[0].to_status, TaskStatus::InProgress);
 
-        assert_eq!(result.items.len(), 3);
-        // SQLite sorts priority text alphabetically: "high" < "low" < "medium"
-        // Descending: "urgent" > "medium" > "low" > "high"
-        // Since we don't have urgent, DESC order is: medium, low, high
-        assert_eq!(result.items[0].priority, TaskPriority::Medium);
-        assert_eq!(result.items[1].priority, TaskPriority::Low);
-        assert_eq!(result.items[2].priority, TaskPriority::High);
+        repo.delete(&task.id).unwrap();
+        assert!(repo.find_status_history(&task.id).unwrap().is_empty());
     }
 
     #[test]
-    fn test_find_all_with_pagination() {
+    fn test_find_by_tags_all_and_any() {
         let db = setup_test_db();
         let repo = TaskRepository::new(&db);
 
-        // Create 10 tasks
-        for i in 1..=10 {
-            let dto = CreateTaskDto {
-                title: format!("Task {}", i),
+        let work = create_test_tag(&db, "Work");
+        let urgent = create_test_tag(&db, "Urgent");
+        let home = create_test_tag(&db, "Home");
+
+        let create = |title: &str, tag_ids: Vec<String>| {
+            repo.create(CreateTaskDto {
+                title: title.to_string(),
                 description: None,
                 priority: TaskPriority::Medium,
                 due_date: None,
                 image_path: None,
                 notes: None,
                 estimated_minutes: None,
-                tag_ids: vec![],
-            };
-            repo.create(dto).unwrap();
-        }
-
-        // Get first page (5 items)
-        let pagination1 = Pagination {
-            page: 1,
-            page_size: 5,
-        };
-        let result1 = repo.find_all(None, None, pagination1).unwrap();
-
-        assert_eq!(result1.items.len(), 5);
-        assert_eq!(result1.total, 10);
-        assert_eq!(result1.page, 1);
-        assert_eq!(result1.page_size, 5);
-        assert_eq!(result1.total_pages, 2);
-
-        // Get second page
-        let pagination2 = Pagination {
-            page: 2,
-            page_size: 5,
+                tag_ids,
+                parent_id: None,
+                color: None,
+            })
+            .unwrap()
         };
-        let result2 = repo.find_all(None, None, pagination2).unwrap();
+        let both = create("Work and urgent", vec![work.clone(), urgent.clone()]);
+        let home_only = create("Home only", vec![home.clone()]);
+        create("Untagged", vec![]);
 
-        assert_eq!(result2.items.len(), 5);
-        assert_eq!(result2.total, 10);
-        assert_eq!(result2.page, 2);
+        // ALL of two tags matches the task carrying both
+        let tasks = repo
+            .find_by_tags(&[work.clone(), urgent.clone()], true)
+            .unwrap();
+        assert_eq!(tasks.len(), 1);
+        assert_eq!(tasks[0].id, both.id);
 
-        // Ensure different tasks on different pages
-        let ids1: Vec<_> = result1.items.iter().map(|t| &t.id).collect();
-        let ids2: Vec<_> = result2.items.iter().map(|t| &t.id).collect();
-        assert!(ids1.iter().all(|id| !ids2.contains(id)));
+        // ALL of three tags matches nothing
+        let tasks = repo
+            .find_by_tags(&[work.clone(), urgent.clone(), home.clone()], true)
+            .unwrap();
+        assert!(tasks.is_empty());
+
+        // ANY of the tags matches both tagged tasks
+        let mut ids: Vec<String> = repo
+            .find_by_tags(&[urgent.clone(), home.clone()], false)
+            .unwrap()
+            .into_iter()
+            .map(|t| t.id)
+            .collect();
+        ids.sort();
+        let mut expected = vec![both.id.clone(), home_only.id.clone()];
+        expected.sort();
+        assert_eq!(ids, expected);
+
+        // Duplicate IDs don't inflate the ALL count
+        let tasks = repo.find_by_tags(&[work.clone(), work], true).unwrap();
+        assert_eq!(tasks.len(), 1);
+
+        assert!(repo.find_by_tags(&[], false).unwrap().is_empty());
     }
 
     #[test]
-    fn test_count_by_status() {
+    fn test_overdue_respects_grace_period() {
         let db = setup_test_db();
         let repo = TaskRepository::new(&db);
 
-        // Create tasks with different statuses
-        for _ in 0..3 {
-            let dto = CreateTaskDto {
-                title: "Pending Task".to_string(),
+        for (title, minutes_late) in [("Within grace", 10), ("Past grace", 20)] {
+            repo.create(CreateTaskDto {
+                title: title.to_string(),
                 description: None,
                 priority: TaskPriority::Medium,
-                due_date: None,
+                due_date: Some(Utc::now() - Duration::minutes(minutes_late)),
                 image_path: None,
                 notes: None,
                 estimated_minutes: None,
                 tag_ids: vec![],
-            };
-            repo.create(dto).unwrap();
+                parent_id: None,
+                color: None,
+            })
+            .unwrap();
+        }
+        let grace = Duration::minutes(15);
+
+        let overdue = repo.find_overdue(grace).unwrap();
+        assert_eq!(overdue.len(), 1);
+        assert_eq!(overdue[0].title, "Past grace");
+        assert_eq!(repo.find_overdue_unnotified(grace).unwrap().len(), 1);
+        assert_eq!(repo.find_overdue(Duration::zero()).unwrap().len(), 2);
+
+        let tasks = repo
+            .find_all(None, None, Pagination::default())
+            .unwrap()
+            .items;
+        for task in &tasks {
+            assert_eq!(task.is_overdue(grace), task.title == "Past grace");
+            assert!(task.is_overdue(Duration::zero()));
         }
 
-        for _ in 0..2 {
-            let dto = CreateTaskDto {
-                title: "Task".to_string(),
-                description: None,
-                priority: TaskPriority::Medium,
-                due_date: None,
-                image_path: None,
-                notes: None,
-                estimated_minutes: None,
-                tag_ids: vec![],
-            };
-            let task = repo.create(dto).unwrap();
+        // The effective status filter applies the same grace period
+        let filter = |effective_status| TaskFilter {
+            effective_status: Some(effective_status),
+            overdue_grace_minutes: 15,
+            ..Default::default()
+        };
+        let titles = |effective_status| -> Vec<String> {
+            repo.find_all(Some(filter(effective_status)), None, Pagination::default())
+                .unwrap()
+                .items
+                .into_iter()
+                .map(|t| t.title)
+                .collect()
+        };
+        assert_eq!(titles(EffectiveTaskStatus::Overdue), vec!["Past grace"]);
+        assert_eq!(titles(EffectiveTaskStatus::Pending), vec!["Within grace"]);
+    }
 
-            let update = UpdateTaskDto {
-                title: None,
-                description: None,
-                status: Some(TaskStatus::InProgress),
-                priority: None,
-                due_date: None,
-                image_path: None,
-                notes: None,
-                estimated_minutes: None,
-                actual_minutes: None,
-                tag_ids: None,
-            };
-            repo.update(&task.id, update).unwrap();
-        }
+    #[test]
+    fn test_overdue_unnotified_until_rescheduled() {
+        let db = setup_test_db();
+        let repo = TaskRepository::new(&db);
 
-        let counts = repo.count_by_status().unwrap();
+        let dto = CreateTaskDto {
+            title: "Late".to_string(),
+            description: None,
+            priority: TaskPriority::Medium,
+            due_date: Some(Utc::now() - chrono::Duration::hours(1)),
+            image_path: None,
+            notes: None,
+            estimated_minutes: None,
+            tag_ids: vec![],
+            parent_id: None,
+            color: None,
+        };
+        let task = repo.create(dto).unwrap();
 
-        let pending_count = counts
-            .iter()
-            .find(|(status, _)| *status == TaskStatus::Pending)
-            .map(|(_, count)| *count)
-            .unwrap_or(0);
+        assert_eq!(
+            repo.find_overdue_unnotified(Duration::zero())
+                .unwrap()
+                .len(),
+            1
+        );
 
-        let in_progress_count = counts
-            .iter()
-            .find(|(status, _)| *status == TaskStatus::InProgress)
-            .map(|(_, count)| *count)
-            .unwrap_or(0);
+        repo.mark_overdue_notified(&task.id).unwrap();
+        assert!(repo
+            .find_overdue_unnotified(Duration::zero())
+            .unwrap()
+            .is_empty());
+        // Still overdue, just already notified
+        assert_eq!(repo.find_overdue(Duration::zero()).unwrap().len(), 1);
 
-        assert_eq!(pending_count, 3);
-        assert_eq!(in_progress_count, 2);
+        // Moving the due date re-arms the notification
+        let update = UpdateTaskDto {
+            title: None,
+            description: None,
+            status: None,
+            priority: None,
+            due_date: Some(Utc::now() - chrono::Duration::minutes(5)),
+            image_path: None,
+            notes: None,
+            estimated_minutes: None,
+            actual_minutes: None,
+            tag_ids: None,
+            color: None,
+            clear_fields: vec![],
+        };
+        repo.update(&task.id, update).unwrap();
+        assert_eq!(
+            repo.find_overdue_unnotified(Duration::zero())
+                .unwrap()
+                .len(),
+            1
+        );
     }
 }
@@ -0,0 +1,360 @@
+use crate::db::Database;
+use crate::models::{SnapshotTable, UndoEntityType, UndoEntry, UndoSnapshot};
+use chrono::{DateTime, Utc};
+use rusqlite::types::{Type, Value as SqlValue, ValueRef};
+use rusqlite::{params, params_from_iter, Connection, OptionalExtension, Result};
+use serde_json::{Map, Number, Value};
+
+/// Number of deletions kept in the undo log; older entries are dropped
+pub const MAX_UNDO_ENTRIES: i64 = 20;
+
+/// Tables a snapshot may write back into
+const RESTORABLE_TABLES: &[&str] = &[
+    "tasks",
+    "tags",
+    "task_tags",
+    "task_dependencies",
+    "task_attachments",
+    "task_checklist_items",
+    "task_comments",
+    "task_status_history",
+    "reminders",
+    "reminder_trigger_log",
+    "task_focus_sessions",
+];
+
+/// Tables holding rows owned by a task through `task_id`, in restore order
+const TASK_CHILD_TABLES: &[&str] = &[
+    "task_tags",
+    "task_attachments",
+    "task_checklist_items",
+    "task_comments",
+    "task_status_history",
+    "reminders",
+];
+
+/// Ids of a task and all of its descendants, with their depth below it
+const TASK_SUBTREE: &str = "WITH RECURSIVE subtree(id, depth) AS (
+    SELECT ?1, 0
+    UNION ALL
+    SELECT tasks.id, subtree.depth + 1 FROM tasks JOIN subtree ON tasks.parent_id = subtree.id
+)";
+
+/// An undo log row: id, entity type, entity id, snapshot JSON and deletion time
+type UndoLogRow = (i64, String, String, String, String);
+
+/// Repository for the undo log of deleted rows
+pub struct UndoRepository<'a> {
+    db: &'a Database,
+}
+
+impl<'a> UndoRepository<'a> {
+    /// Create a new UndoRepository instance
+    pub fn new(db: &'a Database) -> Self {
+        Self { db }
+    }
+
+    /// Capture every row that deleting the entity removes
+    ///
+    /// A task snapshot covers its subtasks and everything cascading from them;
+    /// parents come before children so the rows can be re-inserted in order.
+    pub fn snapshot(&self, entity_type: UndoEntityType, id: &str) -> Result<UndoSnapshot> {
+        let queries: Vec<(&str, String)> = match entity_type {
+            UndoEntityType::Task => {
+                let mut queries = vec![(
+                    "tasks",
+                    format!(
+                        "{TASK_SUBTREE} SELECT tasks.* FROM tasks
+                         JOIN subtree ON tasks.id = subtree.id ORDER BY subtree.depth"
+                    ),
+                )];
+                for table in TASK_CHILD_TABLES {
+                    queries.push((
+                        table,
+                        format!(
+                            "{TASK_SUBTREE} SELECT * FROM {table}
+                             WHERE task_id IN (SELECT id FROM subtree)"
+                        ),
+                    ));
+                }
+                queries.push((
+                    "reminder_trigger_log",
+                    format!(
+                        "{TASK_SUBTREE} SELECT * FROM reminder_trigger_log WHERE reminder_id IN
+                         (SELECT id FROM reminders WHERE task_id IN (SELECT id FROM subtree))"
+                    ),
+                ));
+                queries.push((
+                    "task_focus_sessions",
+                    format!(
+                        "{TASK_SUBTREE} SELECT * FROM task_focus_sessions
+                         WHERE task_id IN (SELECT id FROM subtree)"
+                    ),
+                ));
+                queries.push((
+                    "task_dependencies",
+                    format!(
+                        "{TASK_SUBTREE} SELECT * FROM task_dependencies
+                         WHERE task_id IN (SELECT id FROM subtree)
+                            OR depends_on_id IN (SELECT id FROM subtree)"
+                    ),
+                ));
+                queries
+            }
+            UndoEntityType::Reminder => vec![
+                (
+                    "reminders",
+                    "SELECT * FROM reminders WHERE id = ?1".to_string(),
+                ),
+                (
+                    "reminder_trigger_log",
+                    "SELECT * FROM reminder_trigger_log WHERE reminder_id = ?1".to_string(),
+                ),
+            ],
+            UndoEntityType::Tag => vec![
+                ("tags", "SELECT * FROM tags WHERE id = ?1".to_string()),
+                (
+                    "task_tags",
+                    "SELECT * FROM task_tags WHERE tag_id = ?1".to_string(),
+                ),
+            ],
+        };
+
+        let conn = self.db.connection();
+        let mut snapshot = UndoSnapshot::default();
+        for (table, sql) in queries {
+            let rows = Self::read_rows(conn, &sql, id)?;
+            if !rows.is_empty() {
+                snapshot.tables.push(SnapshotTable {
+                    table: table.to_string(),
+                    rows,
+                });
+            }
+        }
+
+        Ok(snapshot)
+    }
+
+    /// Record a deletion, dropping the oldest entries beyond `MAX_UNDO_ENTRIES`
+    pub fn push(
+        &self,
+        entity_type: UndoEntityType,
+        entity_id: &str,
+        snapshot: &UndoSnapshot,
+    ) -> Result<()> {
+        let payload = serde_json::to_string(snapshot)
+            .map_err(|e| rusqlite::Error::ToSqlConversionFailure(Box::new(e)))?;
+
+        self.db.transaction(|conn| {
+            conn.execute(
+                "INSERT INTO undo_log (entity_type, entity_id, snapshot, deleted_at)
+                 VALUES (?1, ?2, ?3, ?4)",
+                params![
+                    entity_type.as_str(),
+                    entity_id,
+                    payload,
+                    Utc::now().to_rfc3339()
+                ],
+            )?;
+            conn.execute(
+                "DELETE FROM undo_log WHERE id NOT IN
+                 (SELECT id FROM undo_log ORDER BY id DESC LIMIT ?1)",
+                params![MAX_UNDO_ENTRIES],
+            )?;
+            Ok(())
+        })
+    }
+
+    /// Re-insert the rows of the most recent deletion and remove it from the log
+    ///
+    /// An entry that can no longer be restored (e.g. a tag name taken in the meantime)
+    /// is dropped and the next one tried; the reasons are returned alongside the
+    /// restored entry, which is None when no restorable entry is left.
+    pub fn restore_latest(&self) -> Result<(Option<UndoEntry>, Vec<String>)> {
+        self.db.transaction(|conn| {
+            let mut dropped = Vec::new();
+            loop {
+                let Some((id, entity_type, entity_id, snapshot, deleted_at)) =
+                    Self::latest_entry(conn)?
+                else {
+                    return Ok((None, dropped));
+                };
+
+                // Restore in a savepoint so a failed entry leaves none of its rows behind
+                let restored = self
+                    .db
+                    .transaction(|conn| Self::restore_snapshot(conn, &entity_type, &snapshot));
+                conn.execute("DELETE FROM undo_log WHERE id = ?1", params![id])?;
+
+                match restored {
+                    Ok(entity_type) => {
+                        let entry = UndoEntry {
+                            id,
+                            entity_type,
+                            entity_id,
+                            deleted_at: DateTime::parse_from_rfc3339(&deleted_at)
+                                .map(|dt| dt.with_timezone(&Utc))
+                                .unwrap_or_else(|_| Utc::now()),
+                            dropped: vec![],
+                        };
+                        return Ok((Some(entry), dropped));
+                    }
+                    Err(e) => dropped.push(format!(
+                        "{} {} deleted at {}: {}",
+                        entity_type, entity_id, deleted_at, e
+                    )),
+                }
+            }
+        })
+    }
+
+    /// Newest undo log entry
+    fn latest_entry(conn: &Connection) -> Result<Option<UndoLogRow>> {
+        conn.query_row(
+            "SELECT id, entity_type, entity_id, snapshot, deleted_at
+                     FROM undo_log ORDER BY id DESC LIMIT 1",
+            [],
+            |row| {
+                Ok((
+                    row.get::<_, i64>(0)?,
+                    row.get::<_, String>(1)?,
+                    row.get::<_, String>(2)?,
+                    row.get::<_, String>(3)?,
+                    row.get::<_, String>(4)?,
+                ))
+            },
+        )
+        .optional()
+    }
+
+    /// Re-insert the rows of a stored snapshot, returning its entity type
+    fn restore_snapshot(
+        conn: &Connection,
+        entity_type: &str,
+        snapshot: &str,
+    ) -> Result<UndoEntityType> {
+        let entity_type = UndoEntityType::parse(entity_type).ok_or_else(|| {
+            rusqlite::Error::FromSqlConversionFailure(
+                1,
+                Type::Text,
+                format!("Unknown undo entity type: {}", entity_type).into(),
+            )
+        })?;
+        let snapshot: UndoSnapshot = serde_json::from_str(snapshot)
+            .map_err(|e| rusqlite::Error::FromSqlConversionFailure(3, Type::Text, Box::new(e)))?;
+
+        for table in &snapshot.tables {
+            Self::insert_rows(conn, table)?;
+        }
+
+        Ok(entity_type)
+    }
+
+    /// Ids of tasks deleted after `since` that are still in the log, subtasks included
+    pub fn deleted_task_ids_since(&self, since: DateTime<Utc>) -> Result<Vec<String>> {
+        let conn = self.db.connection();
+        let mut stmt = conn.prepare(
+            "SELECT snapshot FROM undo_log
+             WHERE entity_type = ?1 AND julianday(deleted_at) > julianday(?2)
+             ORDER BY id ASC",
+        )?;
+
+        let snapshots = stmt
+            .query_map(
+                params![UndoEntityType::Task.as_str(), since.to_rfc3339()],
+                |row| row.get::<_, String>(0),
+            )?
+            .collect::<Result<Vec<String>>>()?;
+
+        let mut ids = Vec::new();
+        for snapshot in snapshots {
+            let snapshot: UndoSnapshot = serde_json::from_str(&snapshot).map_err(|e| {
+                rusqlite::Error::FromSqlConversionFailure(0, Type::Text, Box::new(e))
+            })?;
+            let task_rows = snapshot
+                .tables
+                .iter()
+                .filter(|table| table.table == "tasks")
+                .flat_map(|table| &table.rows);
+            ids.extend(
+                task_rows.filter_map(|row| row.get("id").and_then(Value::as_str).map(String::from)),
+            );
+        }
+
+        Ok(ids)
+    }
+
+    /// Number of deletions currently in the log
+    pub fn count(&self) -> Result<i64> {
+        self.db
+            .connection()
+            .query_row("SELECT COUNT(*) FROM undo_log", [], |row| row.get(0))
+    }
+
+    fn read_rows(conn: &Connection, sql: &str, id: &str) -> Result<Vec<Map<String, Value>>> {
+        let mut stmt = conn.prepare(sql)?;
+        let columns: Vec<String> = stmt.column_names().into_iter().map(String::from).collect();
+
+        let rows = stmt
+            .query_map(params![id], |row| {
+                let mut fields = Map::new();
+                for (i, column) in columns.iter().enumerate() {
+                    fields.insert(column.clone(), Self::to_json(row.get_ref(i)?));
+                }
+                Ok(fields)
+            })?
+            .collect::<Result<Vec<_>>>()?;
+
+        Ok(rows)
+    }
+
+    fn insert_rows(conn: &Connection, table: &SnapshotTable) -> Result<()> {
+        // Table and column names are interpolated, so only known tables are accepted
+        if !RESTORABLE_TABLES.contains(&table.table.as_str()) {
+            return Err(rusqlite::Error::InvalidParameterName(table.table.clone()));
+        }
+
+        for row in &table.rows {
+            let columns: Vec<String> = row.keys().map(|c| format!("\"{}\"", c)).collect();
+            let placeholders: Vec<String> = (1..=row.len()).map(|i| format!("?{}", i)).collect();
+            let sql = format!(
+                "INSERT INTO {} ({}) VALUES ({})",
+                table.table,
+                columns.join(", "),
+                placeholders.join(", ")
+            );
+            conn.execute(&sql, params_from_iter(row.values().map(Self::to_sql)))?;
+        }
+
+        Ok(())
+    }
+
+    fn to_json(value: ValueRef<'_>) -> Value {
+        match value {
+            ValueRef::Null => Value::Null,
+            ValueRef::Integer(i) => Value::from(i),
+            ValueRef::Real(f) => Number::from_f64(f).map_or(Value::Null, Value::Number),
+            ValueRef::Text(t) => Value::String(String::from_utf8_lossy(t).into_owned()),
+            ValueRef::Blob(b) => Value::Array(b.iter().map(|&byte| Value::from(byte)).collect()),
+        }
+    }
+
+    fn to_sql(value: &Value) -> SqlValue {
+        match value {
+            Value::Null => SqlValue::Null,
+            Value::Bool(b) => SqlValue::Integer(i64::from(*b)),
+            Value::Number(n) => match n.as_i64() {
+                Some(i) => SqlValue::Integer(i),
+                None => SqlValue::Real(n.as_f64().unwrap_or_default()),
+            },
+            Value::String(s) => SqlValue::Text(s.clone()),
+            Value::Array(bytes) => SqlValue::Blob(
+                bytes
+                    .iter()
+                    .filter_map(|b| b.as_u64().and_then(|b| u8::try_from(b).ok()))
+                    .collect(),
+            ),
+            Value::Object(_) => SqlValue::Text(value.to_string()),
+        }
+    }
+}
@@ -0,0 +1,389 @@
+use crate::db::Database;
+use crate::error::{DomainError, DomainResult};
+use crate::models::{BackupEnvelope, CreateReminderDto, CreateTaskDto, Pagination};
+use crate::repositories::{ReminderRepository, TagRepository, TaskRepository};
+use crate::services::{ReminderService, TaskService};
+use std::collections::HashMap;
+
+/// Service for full-data backup and restore
+pub struct BackupService<'a> {
+    db: &'a Database,
+}
+
+impl<'a> BackupService<'a> {
+    /// Create a new BackupService instance
+    pub fn new(db: &'a Database) -> Self {
+        Self { db }
+    }
+
+    /// Collect all tasks, reminders and tags into a versioned backup
+    pub fn backup(&self) -> DomainResult<BackupEnvelope> {
+        let pagination = Pagination {
+            page: 1,
+            page_size: 10000,
+        };
+        let tasks = TaskRepository::new(self.db)
+            .find_all(None, None, pagination)
+            .map_err(|e| DomainError::BusinessRuleViolation(format!("Failed to get tasks: {}", e)))?
+            .items;
+
+        let reminders = ReminderRepository::new(self.db).find_all().map_err(|e| {
+            DomainError::BusinessRuleViolation(format!("Failed to get reminders: {}", e))
+        })?;
+
+        let tags = TagRepository::new(self.db).find_all().map_err(|e| {
+            DomainError::BusinessRuleViolation(format!("Failed to get tags: {}", e))
+        })?;
+
+        Ok(BackupEnvelope::new(tasks, reminders, tags))
+    }
+
+    /// Restore tasks and reminders from a backup, returning `(tasks, reminders)` restored
+    ///
+    /// Fails up front on unparseable or unsupported backups. Individual records that
+    /// fail validation are logged and skipped. Tags are restored first with their
+    /// original ids; restored tasks get new ids, and reminders are re-linked to them.
+    pub fn restore(&self, backup_json: &str) -> DomainResult<(usize, usize)> {
+        let backup = BackupEnvelope::parse(backup_json).map_err(DomainError::InvalidInput)?;
+
+        let tag_ids = self.restore_tags(&backup)?;
+
+        let task_service = TaskService::new(self.db);
+        let reminder_service = ReminderService::new(self.db);
+
+        // Old task id -> restored task id
+        let mut task_ids: HashMap<String, String> = HashMap::new();
+        for task in backup.tasks {
+            let dto = CreateTaskDto {
+                title: task.title,
+                description: task.description,
+                priority: task.priority,
+                due_date: task.due_date,
+                // Images that no longer exist on this machine are dropped
+                image_path: task
+                    .image_path
+                    .filter(|p| std::path::Path::new(p).is_file()),
+                notes: task.notes,
+                estimated_minutes: task.estimated_minutes,
+                tag_ids: task
+                    .tags
+                    .iter()
+                    .filter_map(|tag| tag_ids.get(&tag.id).cloned())
+                    .collect(),
+                parent_id: task.parent_id.and_then(|id| task_ids.get(&id).cloned()),
+                color: task.color,
+            };
+
+            match task_service.create_task_without_auto_reminder(dto) {
+                Ok(created) => {
+                    if task.is_pinned {
+                        if let Err(e) = task_service.pin_task(&created.id) {
+                            eprintln!("Failed to restore task pin: {}", e);
+                        }
+                    }
+                    if task.is_deferred {
+                        if let Err(e) = task_service.defer_task(&created.id, task.deferred_until) {
+                            eprintln!("Failed to restore task deferral: {}", e);
+                        }
+                    }
+                    task_ids.insert(task.id, created.id);
+                }
+                Err(e) => eprintln!("Failed to restore task: {}", e),
+            }
+        }
+
+        let mut reminder_count = 0;
+        for reminder in backup.reminders {
+            let dto = CreateReminderDto {
+                task_id: reminder.task_id.and_then(|id| task_ids.get(&id).cloned()),
+                title: reminder.title,
+                description: reminder.description,
+                remind_at: reminder.remind_at,
+                repeat_interval: reminder.repeat_interval,
+                escalation_minutes: reminder.escalation_minutes,
+                delivery: Some(reminder.delivery),
+            };
+
+            match reminder_service.create_reminder(dto) {
+                Ok(_) => reminder_count += 1,
+                Err(e) => eprintln!("Failed to restore reminder: {}", e),
+            }
+        }
+
+        Ok((task_ids.len(), reminder_count))
+    }
+
+    /// Recreate the backup's tags, returning a map of backup tag id -> tag id in the database
+    ///
+    /// Tags embedded in tasks are included so backups without a tags array still
+    /// restore associations. An existing tag with the same id is kept as-is; one
+    /// with the same name but a different id is reused.
+    fn restore_tags(&self, backup: &BackupEnvelope) -> DomainResult<HashMap<String, String>> {
+        let repo = TagRepository::new(self.db);
+        let db_error = |e: rusqlite::Error| {
+            DomainError::BusinessRuleViolation(format!("Failed to restore tags: {}", e))
+        };
+
+        let embedded = backup.tasks.iter().flat_map(|task| task.tags.iter());
+
+        let mut tag_ids: HashMap<String, String> = HashMap::new();
+        for tag in backup.tags.iter().chain(embedded) {
+            if tag_ids.contains_key(&tag.id) {
+                continue;
+            }
+
+            let id = if repo.insert_if_absent(tag).map_err(db_error)?
+                || repo.find_by_id(&tag.id).map_err(db_error)?.is_some()
+            {
+                tag.id.clone()
+            } else {
+                // The name is taken by another tag; attach tasks to that one instead
+                match repo.find_by_name(&tag.name).map_err(db_error)? {
+                    Some(existing) => existing.id,
+                    None => continue,
+                }
+            };
+            tag_ids.insert(tag.id.clone(), id);
+        }
+
+        Ok(tag_ids)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::{Tag, Task, TaskPriority};
+    use chrono::{Duration, Utc};
+    use rusqlite::Connection;
+
+    fn setup_test_db() -> Database {
+        let conn = Connection::open_in_memory().unwrap();
+
+        conn.execute(
+            "CREATE TABLE tasks (
+                id TEXT PRIMARY KEY,
+                title TEXT NOT NULL,
+                description TEXT,
+                status TEXT NOT NULL,
+                priority TEXT NOT NULL,
+                due_date TEXT,
+                completed_at TEXT,
+                image_path TEXT,
+                notes TEXT,
+                estimated_minutes INTEGER,
+                actual_minutes INTEGER,
+                color TEXT,
+                is_pinned INTEGER NOT NULL DEFAULT 0,
+                series_id TEXT,
+                is_deferred INTEGER NOT NULL DEFAULT 0,
+                deferred_until TEXT,
+                completion_note TEXT,
+                parent_id TEXT,
+                sort_order INTEGER NOT NULL DEFAULT 0,
+                overdue_notified_at TEXT,
+                created_at TEXT NOT NULL,
+                updated_at TEXT NOT NULL
+            )",
+            [],
+        )
+        .unwrap();
+
+        conn.execute(
+            "CREATE TABLE task_tags (
+                task_id TEXT NOT NULL,
+                tag_id TEXT NOT NULL,
+                created_at TEXT NOT NULL,
+                PRIMARY KEY (task_id, tag_id)
+            )",
+            [],
+        )
+        .unwrap();
+
+        conn.execute(
+            "CREATE TABLE tags (
+                id TEXT PRIMARY KEY,
+                name TEXT NOT NULL UNIQUE,
+                color TEXT NOT NULL,
+                created_at TEXT NOT NULL
+            )",
+            [],
+        )
+        .unwrap();
+
+        conn.execute(
+            "CREATE TABLE task_checklist_items (
+                id TEXT PRIMARY KEY,
+                task_id TEXT NOT NULL,
+                text TEXT NOT NULL,
+                is_done INTEGER NOT NULL DEFAULT 0,
+                position INTEGER NOT NULL DEFAULT 0
+            )",
+            [],
+        )
+        .unwrap();
+
+        conn.execute(
+            "CREATE TABLE reminders (
+                id TEXT PRIMARY KEY,
+                task_id TEXT,
+                title TEXT NOT NULL,
+                description TEXT,
+                remind_at TEXT NOT NULL,
+                repeat_interval TEXT NOT NULL,
+                is_active INTEGER NOT NULL DEFAULT 1,
+                last_triggered_at TEXT,
+                created_at TEXT NOT NULL,
+                updated_at TEXT NOT NULL,
+                acknowledged_at TEXT,
+                escalation_minutes INTEGER,
+                escalation_count INTEGER NOT NULL DEFAULT 0,
+                delivery TEXT NOT NULL DEFAULT 'notification'
+            )",
+            [],
+        )
+        .unwrap();
+
+        conn.execute(
+            "CREATE TABLE settings (
+                key TEXT PRIMARY KEY,
+                value TEXT NOT NULL,
+                updated_at TEXT NOT NULL DEFAULT (datetime('now'))
+            )",
+            [],
+        )
+        .unwrap();
+
+        Database::new_from_connection(conn)
+    }
+
+    #[test]
+    fn test_restore_v1_envelope() {
+        let db = setup_test_db();
+        let service = BackupService::new(&db);
+
+        let due = (Utc::now() + Duration::days(2)).to_rfc3339();
+        let remind_at = (Utc::now() + Duration::days(1)).to_rfc3339();
+        let json = format!(
+            r#"{{
+                "version": "1.0",
+                "timestamp": "2024-01-01T00:00:00Z",
+                "tasks": [{{
+                    "id": "old-task",
+                    "title": "Restored task",
+                    "description": null,
+                    "status": "pending",
+                    "priority": "high",
+                    "due_date": "{due}",
+                    "completed_at": null,
+                    "image_path": null,
+                    "notes": null,
+                    "estimated_minutes": null,
+                    "actual_minutes": null,
+                    "created_at": "2024-01-01T00:00:00Z",
+                    "updated_at": "2024-01-01T00:00:00Z"
+                }}],
+                "reminders": [{{
+                    "id": "old-reminder",
+                    "task_id": "old-task",
+                    "title": "Restored reminder",
+                    "description": null,
+                    "remind_at": "{remind_at}",
+                    "repeat_interval": "none",
+                    "is_active": true,
+                    "last_triggered_at": null,
+                    "created_at": "2024-01-01T00:00:00Z",
+                    "updated_at": "2024-01-01T00:00:00Z"
+                }}]
+            }}"#
+        );
+
+        assert_eq!(service.restore(&json).unwrap(), (1, 1));
+
+        let tasks = service.backup().unwrap().tasks;
+        assert_eq!(tasks.len(), 1);
+        assert_eq!(tasks[0].title, "Restored task");
+
+        let reminders = ReminderRepository::new(&db).find_all().unwrap();
+        assert_eq!(reminders[0].task_id.as_deref(), Some(tasks[0].id.as_str()));
+    }
+
+    #[test]
+    fn test_restore_rejects_unknown_version() {
+        let db = setup_test_db();
+        let service = BackupService::new(&db);
+
+        let json = r#"{"version": "0.1-bogus", "timestamp": "2024-01-01T00:00:00Z",
+                       "tasks": [], "reminders": []}"#;
+
+        let result = service.restore(json);
+        assert!(matches!(result, Err(DomainError::InvalidInput(_))));
+    }
+
+    fn create_tagged_task(db: &Database, tag: &Tag) -> Task {
+        TagRepository::new(db).insert_if_absent(tag).unwrap();
+        TaskService::new(db)
+            .create_task(CreateTaskDto {
+                title: "Tagged task".to_string(),
+                description: None,
+                priority: TaskPriority::Medium,
+                due_date: None,
+                image_path: None,
+                notes: None,
+                estimated_minutes: None,
+                tag_ids: vec![tag.id.clone()],
+                parent_id: None,
+                color: None,
+            })
+            .unwrap()
+    }
+
+    fn work_tag() -> Tag {
+        Tag {
+            id: "tag-work".to_string(),
+            name: "Work".to_string(),
+            color: "#ff0000".to_string(),
+            created_at: Utc::now(),
+        }
+    }
+
+    #[test]
+    fn test_backup_restore_round_trip_keeps_tag_associations() {
+        let source = setup_test_db();
+        create_tagged_task(&source, &work_tag());
+
+        let backup = BackupService::new(&source).backup().unwrap();
+        assert_eq!(backup.tags.len(), 1);
+        let json = serde_json::to_string(&backup).unwrap();
+
+        let target = setup_test_db();
+        let service = BackupService::new(&target);
+        assert_eq!(service.restore(&json).unwrap(), (1, 0));
+
+        let tags = TagRepository::new(&target).find_all().unwrap();
+        assert_eq!(tags.len(), 1);
+        assert_eq!(tags[0].id, "tag-work");
+
+        let tasks = service.backup().unwrap().tasks;
+        let task_tag_ids: Vec<&str> = tasks[0].tags.iter().map(|t| t.id.as_str()).collect();
+        assert_eq!(task_tag_ids, vec!["tag-work"]);
+    }
+
+    #[test]
+    fn test_restore_skips_existing_tag_id() {
+        let db = setup_test_db();
+        create_tagged_task(&db, &work_tag());
+
+        let service = BackupService::new(&db);
+        let json = serde_json::to_string(&service.backup().unwrap()).unwrap();
+
+        assert_eq!(service.restore(&json).unwrap(), (1, 0));
+
+        assert_eq!(TagRepository::new(&db).find_all().unwrap().len(), 1);
+        let tasks = service.backup().unwrap().tasks;
+        assert_eq!(tasks.len(), 2);
+        assert!(tasks
+            .iter()
+            .all(|t| t.tags.len() == 1 && t.tags[0].id == "tag-work"));
+    }
+}
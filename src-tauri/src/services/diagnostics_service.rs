@@ -0,0 +1,228 @@
+use crate::db::Database;
+use crate::error::{DomainError, DomainResult};
+use crate::models::{CompactionResult, Diagnostics, IntegrityRepair, RecordCounts};
+
+/// Service collecting health information about the app and its database
+pub struct DiagnosticsService<'a> {
+    db: &'a Database,
+}
+
+impl<'a> DiagnosticsService<'a> {
+    /// Create a new DiagnosticsService instance
+    pub fn new(db: &'a Database) -> Self {
+        Self { db }
+    }
+
+    /// Count stored tasks, reminders and tags
+    pub fn counts(&self) -> DomainResult<RecordCounts> {
+        self.db
+            .connection()
+            .query_row(
+                "SELECT (SELECT COUNT(*) FROM tasks),
+                        (SELECT COUNT(*) FROM reminders),
+                        (SELECT COUNT(*) FROM tags)",
+                [],
+                |row| {
+                    Ok(RecordCounts {
+                        tasks: row.get(0)?,
+                        reminders: row.get(1)?,
+                        tags: row.get(2)?,
+                    })
+                },
+            )
+            .map_err(|e| {
+                DomainError::BusinessRuleViolation(format!("Failed to count records: {}", e))
+            })
+    }
+
+    /// Collect a full diagnostics snapshot, including a database integrity check
+    pub fn collect(&self, notification_thread_running: bool) -> DomainResult<Diagnostics> {
+        let db_error = |e: rusqlite::Error| {
+            DomainError::BusinessRuleViolation(format!("Failed to collect diagnostics: {}", e))
+        };
+
+        let journal_mode = self.db.journal_mode().map_err(db_error)?;
+
+        Ok(Diagnostics {
+            app_version: env!("CARGO_PKG_VERSION").to_string(),
+            sqlite_version: rusqlite::version().to_string(),
+            schema_version: self.db.schema_version().map_err(db_error)?,
+            counts: self.counts()?,
+            database_size_bytes: self.db.file_size(),
+            wal_enabled: journal_mode.eq_ignore_ascii_case("wal"),
+            journal_mode,
+            notification_thread_running,
+            integrity_check: self.db.integrity_check().map_err(db_error)?,
+        })
+    }
+
+    /// Run `VACUUM` (after a WAL checkpoint when enabled) and report the size change
+    pub fn compact(&self) -> DomainResult<CompactionResult> {
+        let (size_before_bytes, size_after_bytes) = self.db.compact().map_err(|e| {
+            DomainError::BusinessRuleViolation(format!("Failed to compact database: {}", e))
+        })?;
+
+        Ok(CompactionResult {
+            size_before_bytes,
+            size_after_bytes,
+        })
+    }
+
+    /// Remove `task_tags` rows left behind by deleted tasks or tags and unlink
+    /// reminders from tasks that no longer exist
+    ///
+    /// Foreign keys were not always enforced, so these can predate the current schema.
+    pub fn repair_integrity(&self) -> DomainResult<IntegrityRepair> {
+        self.db
+            .transaction(|conn| {
+                let orphaned_task_tags_removed = conn.execute(
+                    "DELETE FROM task_tags
+                     WHERE NOT EXISTS (SELECT 1 FROM tasks WHERE tasks.id = task_tags.task_id)
+                        OR NOT EXISTS (SELECT 1 FROM tags WHERE tags.id = task_tags.tag_id)",
+                    [],
+                )?;
+                let dangling_reminder_links_cleared = conn.execute(
+                    "UPDATE reminders SET task_id = NULL
+                     WHERE task_id IS NOT NULL
+                       AND NOT EXISTS (SELECT 1 FROM tasks WHERE tasks.id = reminders.task_id)",
+                    [],
+                )?;
+
+                Ok(IntegrityRepair {
+                    orphaned_task_tags_removed,
+                    dangling_reminder_links_cleared,
+                })
+            })
+            .map_err(|e| {
+                DomainError::BusinessRuleViolation(format!("Failed to repair integrity: {}", e))
+            })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rusqlite::Connection;
+
+    fn setup_test_db() -> Database {
+        let conn = Connection::open_in_memory().unwrap();
+
+        conn.execute_batch(
+            "CREATE TABLE tasks (id TEXT PRIMARY KEY, title TEXT NOT NULL);
+             CREATE TABLE reminders (id TEXT PRIMARY KEY, title TEXT NOT NULL, task_id TEXT);
+             CREATE TABLE tags (id TEXT PRIMARY KEY, name TEXT NOT NULL UNIQUE);
+             CREATE TABLE task_tags (
+                 task_id TEXT NOT NULL,
+                 tag_id TEXT NOT NULL,
+                 PRIMARY KEY (task_id, tag_id)
+             );
+             CREATE TABLE schema_version (version INTEGER PRIMARY KEY);",
+        )
+        .unwrap();
+
+        Database::new_from_connection(conn)
+    }
+
+    fn seed(db: &Database) {
+        db.connection()
+            .execute_batch(
+                "INSERT INTO tasks VALUES ('t1', 'One'), ('t2', 'Two'), ('t3', 'Three');
+                 INSERT INTO reminders (id, title) VALUES ('r1', 'Reminder');
+                 INSERT INTO tags VALUES ('g1', 'Work'), ('g2', 'Home');
+                 INSERT INTO schema_version VALUES (1), (2), (3), (4);",
+            )
+            .unwrap();
+    }
+
+    #[test]
+    fn test_counts_empty_database() {
+        let db = setup_test_db();
+
+        let counts = DiagnosticsService::new(&db).counts().unwrap();
+        assert_eq!(
+            counts,
+            RecordCounts {
+                tasks: 0,
+                reminders: 0,
+                tags: 0
+            }
+        );
+    }
+
+    #[test]
+    fn test_counts_seeded_database() {
+        let db = setup_test_db();
+        seed(&db);
+
+        let counts = DiagnosticsService::new(&db).counts().unwrap();
+        assert_eq!(
+            counts,
+            RecordCounts {
+                tasks: 3,
+                reminders: 1,
+                tags: 2
+            }
+        );
+    }
+
+    #[test]
+    fn test_collect_reports_database_state() {
+        let db = setup_test_db();
+        seed(&db);
+
+        let diagnostics = DiagnosticsService::new(&db).collect(true).unwrap();
+        assert_eq!(diagnostics.schema_version, 4);
+        assert_eq!(diagnostics.counts.tasks, 3);
+        assert_eq!(diagnostics.integrity_check, "ok");
+        assert_eq!(diagnostics.database_size_bytes, None);
+        assert!(!diagnostics.wal_enabled);
+        assert!(diagnostics.notification_thread_running);
+    }
+
+    #[test]
+    fn test_repair_integrity_removes_orphans() {
+        let db = setup_test_db();
+        seed(&db);
+        db.connection()
+            .execute_batch(
+                "INSERT INTO task_tags VALUES ('t1', 'g1'), ('t2', 'g2'),
+                     ('deleted-task', 'g1'), ('t3', 'deleted-tag');
+                 INSERT INTO reminders (id, title, task_id) VALUES
+                     ('r2', 'Linked', 't1'), ('r3', 'Dangling', 'deleted-task');",
+            )
+            .unwrap();
+        let service = DiagnosticsService::new(&db);
+
+        let repair = service.repair_integrity().unwrap();
+        assert_eq!(
+            repair,
+            IntegrityRepair {
+                orphaned_task_tags_removed: 2,
+                dangling_reminder_links_cleared: 1,
+            }
+        );
+
+        let conn = db.connection();
+        let task_tags: i64 = conn
+            .query_row("SELECT COUNT(*) FROM task_tags", [], |row| row.get(0))
+            .unwrap();
+        assert_eq!(task_tags, 2);
+        let linked: Option<String> = conn
+            .query_row("SELECT task_id FROM reminders WHERE id = 'r2'", [], |row| {
+                row.get(0)
+            })
+            .unwrap();
+        assert_eq!(linked.as_deref(), Some("t1"));
+        let dangling: Option<String> = conn
+            .query_row("SELECT task_id FROM reminders WHERE id = 'r3'", [], |row| {
+                row.get(0)
+            })
+            .unwrap();
+        assert_eq!(dangling, None);
+
+        // A second run has nothing left to fix
+        let again = service.repair_integrity().unwrap();
+        assert_eq!(again.orphaned_task_tags_removed, 0);
+        assert_eq!(again.dangling_reminder_links_cleared, 0);
+    }
+}
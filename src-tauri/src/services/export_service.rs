@@ -0,0 +1,753 @@
+use crate::db::Database;
+use crate::error::{DomainError, DomainResult};
+use crate::models::{ExportFormat, ExportOptions, Pagination, Reminder, ReminderFilter, Task};
+use crate::repositories::{ReminderRepository, TaskRepository};
+use chrono::{DateTime, Utc};
+use std::fs::File;
+use std::io::{BufWriter, Write};
+use std::path::Path;
+
+/// Cap on events emitted per reminder in a calendar feed
+const MAX_FEED_OCCURRENCES: usize = 1000;
+
+/// Service serializing tasks and reminders to JSON, CSV or iCalendar
+pub struct ExportService<'a> {
+    db: &'a Database,
+}
+
+impl<'a> ExportService<'a> {
+    /// Create a new ExportService instance
+    pub fn new(db: &'a Database) -> Self {
+        Self { db }
+    }
+
+    /// Export all tasks as a string
+    pub fn export_tasks(
+        &self,
+        format: ExportFormat,
+        options: &ExportOptions,
+    ) -> DomainResult<String> {
+        let mut buffer = Vec::new();
+        self.write_tasks(format, options, &mut buffer)?;
+        Ok(String::from_utf8_lossy(&buffer).into_owned())
+    }
+
+    /// Export all reminders as a string
+    pub fn export_reminders(
+        &self,
+        format: ExportFormat,
+        options: &ExportOptions,
+    ) -> DomainResult<String> {
+        let mut buffer = Vec::new();
+        self.write_reminders(format, options, &mut buffer)?;
+        Ok(String::from_utf8_lossy(&buffer).into_owned())
+    }
+
+    /// Write all tasks to `path`, returning the number of bytes written
+    pub fn export_tasks_to_file(
+        &self,
+        path: &Path,
+        format: ExportFormat,
+        options: &ExportOptions,
+    ) -> DomainResult<u64> {
+        validate_options(options)?;
+        Self::write_file(path, |writer| self.write_tasks(format, options, writer))
+    }
+
+    /// Write all reminders to `path`, returning the number of bytes written
+    pub fn export_reminders_to_file(
+        &self,
+        path: &Path,
+        format: ExportFormat,
+        options: &ExportOptions,
+    ) -> DomainResult<u64> {
+        validate_options(options)?;
+        Self::write_file(path, |writer| self.write_reminders(format, options, writer))
+    }
+
+    /// Build an iCalendar feed of active reminders triggering in `[start, end)`
+    ///
+    /// Repeating reminders get one event per occurrence in range, each with a
+    /// display alarm at the trigger time.
+    pub fn generate_ics_feed(
+        &self,
+        start: DateTime<Utc>,
+        end: DateTime<Utc>,
+    ) -> DomainResult<String> {
+        let mut buffer = Vec::new();
+        self.write_ics_feed(start, end, &mut buffer)?;
+        Ok(String::from_utf8_lossy(&buffer).into_owned())
+    }
+
+    /// Write the iCalendar feed for `[start, end)` to `path`, returning the number of bytes written
+    pub fn export_ics_feed_to_file(
+        &self,
+        path: &Path,
+        start: DateTime<Utc>,
+        end: DateTime<Utc>,
+    ) -> DomainResult<u64> {
+        Self::write_file(path, |writer| self.write_ics_feed(start, end, writer))
+    }
+
+    fn write_tasks(
+        &self,
+        format: ExportFormat,
+        options: &ExportOptions,
+        writer: &mut impl Write,
+    ) -> DomainResult<()> {
+        validate_options(options)?;
+        let pagination = Pagination {
+            page: 1,
+            page_size: 10000, // Large enough to get all tasks
+        };
+        let tasks = TaskRepository::new(self.db)
+            .find_all(None, None, pagination)
+            .map_err(|e| DomainError::BusinessRuleViolation(format!("Failed to get tasks: {}", e)))?
+            .items;
+
+        match format {
+            ExportFormat::Json => write_json(&tasks, options, writer).map_err(|e| {
+                DomainError::BusinessRuleViolation(format!("Failed to serialize tasks: {}", e))
+            }),
+            ExportFormat::Csv => write_tasks_csv(&tasks, options, writer).map_err(write_error),
+        }
+    }
+
+    fn write_reminders(
+        &self,
+        format: ExportFormat,
+        options: &ExportOptions,
+        writer: &mut impl Write,
+    ) -> DomainResult<()> {
+        validate_options(options)?;
+        let reminders = ReminderRepository::new(self.db).find_all().map_err(|e| {
+            DomainError::BusinessRuleViolation(format!("Failed to get reminders: {}", e))
+        })?;
+
+        match format {
+            ExportFormat::Json => write_json(&reminders, options, writer).map_err(|e| {
+                DomainError::BusinessRuleViolation(format!("Failed to serialize reminders: {}", e))
+            }),
+            ExportFormat::Csv => {
+                write_reminders_csv(&reminders, options, writer).map_err(write_error)
+            }
+        }
+    }
+
+    fn write_ics_feed(
+        &self,
+        start: DateTime<Utc>,
+        end: DateTime<Utc>,
+        writer: &mut impl Write,
+    ) -> DomainResult<()> {
+        if end <= start {
+            return Err(DomainError::field("end", "End must be after start"));
+        }
+
+        let reminders = ReminderRepository::new(self.db)
+            .find_filtered(
+                &ReminderFilter {
+                    is_active: Some(true),
+                    ..Default::default()
+                },
+                None,
+            )
+            .map_err(|e| {
+                DomainError::BusinessRuleViolation(format!("Failed to get reminders: {}", e))
+            })?;
+
+        let mut events: Vec<(&Reminder, DateTime<Utc>)> = reminders
+            .iter()
+            .flat_map(|reminder| {
+                reminder
+                    .occurrences_between(start, end, MAX_FEED_OCCURRENCES)
+                    .into_iter()
+                    .map(move |at| (reminder, at))
+            })
+            .collect();
+        events.sort_by_key(|(_, at)| *at);
+
+        write_ics(&events, Utc::now(), writer).map_err(write_error)
+    }
+
+    /// Stream an export into a new file at `path` after checking its directory is writable
+    fn write_file<F>(path: &Path, write: F) -> DomainResult<u64>
+    where
+        F: FnOnce(&mut BufWriter<File>) -> DomainResult<()>,
+    {
+        let parent = match path.parent() {
+            Some(parent) if !parent.as_os_str().is_empty() => parent,
+            _ => Path::new("."),
+        };
+        let metadata = std::fs::metadata(parent)
+            .map_err(|_| DomainError::field("path", "Directory does not exist"))?;
+        if !metadata.is_dir() {
+            return Err(DomainError::field("path", "Parent is not a directory"));
+        }
+        if metadata.permissions().readonly() {
+            return Err(DomainError::field("path", "Directory is not writable"));
+        }
+
+        let file = File::create(path).map_err(write_error)?;
+        let mut writer = BufWriter::new(file);
+        write(&mut writer)?;
+        writer.flush().map_err(write_error)?;
+
+        let bytes = std::fs::metadata(path).map_err(write_error)?.len();
+        Ok(bytes)
+    }
+}
+
+fn write_error(e: std::io::Error) -> DomainError {
+    DomainError::BusinessRuleViolation(format!("Failed to write export: {}", e))
+}
+
+fn validate_options(options: &ExportOptions) -> DomainResult<()> {
+    if options.offset().is_none() {
+        return Err(DomainError::field(
+            "timezone_offset_minutes",
+            "Offset must be less than 24 hours",
+        ));
+    }
+    if !options.has_valid_format() {
+        return Err(DomainError::field("date_format", "Invalid date format"));
+    }
+    Ok(())
+}
+
+/// Serialize `items` as pretty JSON, rewriting date fields per `options`
+fn write_json<T: serde::Serialize>(
+    items: &[T],
+    options: &ExportOptions,
+    writer: &mut impl Write,
+) -> serde_json::Result<()> {
+    if options.is_default() {
+        return serde_json::to_writer_pretty(writer, items);
+    }
+
+    let mut value = serde_json::to_value(items)?;
+    reformat_dates(&mut value, options);
+    serde_json::to_writer_pretty(writer, &value)
+}
+
+/// Reformat every RFC 3339 string stored under a date-like key (`*_at`, `*_date`, `*_until`)
+fn reformat_dates(value: &mut serde_json::Value, options: &ExportOptions) {
+    match value {
+        serde_json::Value::Array(items) => {
+            for item in items {
+                reformat_dates(item, options);
+            }
+        }
+        serde_json::Value::Object(fields) => {
+            for (key, field) in fields.iter_mut() {
+                let is_date_key =
+                    key.ends_with("_at") || key.ends_with("_date") || key.ends_with("_until");
+                let parsed = field
+                    .as_str()
+                    .filter(|_| is_date_key)
+                    .and_then(|text| DateTime::parse_from_rfc3339(text).ok());
+                match parsed {
+                    Some(time) => {
+                        *field = serde_json::Value::String(options.format(time.with_timezone(&Utc)))
+                    }
+                    None => reformat_dates(field, options),
+                }
+            }
+        }
+        _ => {}
+    }
+}
+
+fn write_tasks_csv(
+    tasks: &[Task],
+    options: &ExportOptions,
+    writer: &mut impl Write,
+) -> std::io::Result<()> {
+    writeln!(
+        writer,
+        "id,title,description,status,priority,due_date,tags,created_at,updated_at,color,completion_note"
+    )?;
+
+    for task in tasks {
+        let description = task.description.as_deref().unwrap_or_default();
+        let completion_note = task.completion_note.as_deref().unwrap_or_default();
+        let due_date = task.due_date.map(|d| options.format(d)).unwrap_or_default();
+        let tag_names: Vec<&str> = task.tags.iter().map(|t| t.name.as_str()).collect();
+
+        writeln!(
+            writer,
+            "\"{}\",\"{}\",\"{}\",\"{}\",\"{}\",\"{}\",\"{}\",\"{}\",\"{}\",\"{}\",\"{}\"",
+            task.id,
+            task.title.replace('"', "\"\""),
+            description.replace('"', "\"\""),
+            task.status.as_str(),
+            task.priority.as_str(),
+            due_date,
+            tag_names.join("|"),
+            options.format(task.created_at),
+            options.format(task.updated_at),
+            task.color.as_deref().unwrap_or_default(),
+            completion_note.replace('"', "\"\"")
+        )?;
+    }
+
+    Ok(())
+}
+
+fn write_reminders_csv(
+    reminders: &[Reminder],
+    options: &ExportOptions,
+    writer: &mut impl Write,
+) -> std::io::Result<()> {
+    writeln!(
+        writer,
+        "id,task_id,title,remind_at,is_active,created_at,updated_at"
+    )?;
+
+    for reminder in reminders {
+        writeln!(
+            writer,
+            "\"{}\",\"{}\",\"{}\",\"{}\",{},\"{}\",\"{}\"",
+            reminder.id,
+            reminder.task_id.as_deref().unwrap_or_default(),
+            reminder.title.replace('"', "\"\""),
+            options.format(reminder.remind_at),
+            reminder.is_active,
+            options.format(reminder.created_at),
+            options.format(reminder.updated_at)
+        )?;
+    }
+
+    Ok(())
+}
+
+fn write_ics(
+    events: &[(&Reminder, DateTime<Utc>)],
+    stamp: DateTime<Utc>,
+    writer: &mut impl Write,
+) -> std::io::Result<()> {
+    let stamp = ics_time(stamp);
+
+    write_ics_line(writer, "BEGIN:VCALENDAR")?;
+    write_ics_line(writer, "VERSION:2.0")?;
+    write_ics_line(writer, "PRODID:-//Task Reminder//Reminders//EN")?;
+    write_ics_line(writer, "CALSCALE:GREGORIAN")?;
+    write_ics_line(writer, "X-WR-CALNAME:Task Reminder")?;
+
+    for (reminder, at) in events {
+        let start = ics_time(*at);
+        let summary = ics_escape(&reminder.title);
+
+        write_ics_line(writer, "BEGIN:VEVENT")?;
+        write_ics_line(
+            writer,
+            &format!("UID:{}-{}@task-reminder", reminder.id, start),
+        )?;
+        write_ics_line(writer, &format!("DTSTAMP:{}", stamp))?;
+        write_ics_line(writer, &format!("DTSTART:{}", start))?;
+        write_ics_line(writer, &format!("SUMMARY:{}", summary))?;
+        if let Some(description) = &reminder.description {
+            write_ics_line(writer, &format!("DESCRIPTION:{}", ics_escape(description)))?;
+        }
+        write_ics_line(writer, "BEGIN:VALARM")?;
+        write_ics_line(writer, "ACTION:DISPLAY")?;
+        write_ics_line(writer, &format!("DESCRIPTION:{}", summary))?;
+        write_ics_line(writer, "TRIGGER:PT0S")?;
+        write_ics_line(writer, "END:VALARM")?;
+        write_ics_line(writer, "END:VEVENT")?;
+    }
+
+    write_ics_line(writer, "END:VCALENDAR")
+}
+
+fn ics_time(time: DateTime<Utc>) -> String {
+    time.format("%Y%m%dT%H%M%SZ").to_string()
+}
+
+/// Escape a TEXT value per RFC 5545
+fn ics_escape(text: &str) -> String {
+    text.replace('\\', "\\\\")
+        .replace(';', "\\;")
+        .replace(',', "\\,")
+        .replace("\r\n", "\\n")
+        .replace('\n', "\\n")
+}
+
+/// Write a content line, folding it at 75 octets and ending it with CRLF
+fn write_ics_line(writer: &mut impl Write, line: &str) -> std::io::Result<()> {
+    let mut rest = line;
+    let mut limit = 75;
+    while rest.len() > limit {
+        let mut split = limit;
+        while !rest.is_char_boundary(split) {
+            split -= 1;
+        }
+        writer.write_all(&rest.as_bytes()[..split])?;
+        writer.write_all(b"\r\n ")?;
+        rest = &rest[split..];
+        // Continuation lines start with a space, which counts towards the limit
+        limit = 74;
+    }
+    writer.write_all(rest.as_bytes())?;
+    writer.write_all(b"\r\n")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::{CreateReminderDto, CreateTaskDto, RepeatInterval, TaskPriority};
+    use crate::services::{ReminderService, TaskService};
+    use chrono::{Duration, Utc};
+    use rusqlite::Connection;
+    use std::path::PathBuf;
+    use uuid::Uuid;
+
+    fn setup_test_db() -> Database {
+        let conn = Connection::open_in_memory().unwrap();
+
+        conn.execute(
+            "CREATE TABLE tasks (
+                id TEXT PRIMARY KEY,
+                title TEXT NOT NULL,
+                description TEXT,
+                status TEXT NOT NULL,
+                priority TEXT NOT NULL,
+                due_date TEXT,
+                completed_at TEXT,
+                image_path TEXT,
+                notes TEXT,
+                estimated_minutes INTEGER,
+                actual_minutes INTEGER,
+                color TEXT,
+                is_pinned INTEGER NOT NULL DEFAULT 0,
+                series_id TEXT,
+                is_deferred INTEGER NOT NULL DEFAULT 0,
+                deferred_until TEXT,
+                completion_note TEXT,
+                parent_id TEXT,
+                sort_order INTEGER NOT NULL DEFAULT 0,
+                overdue_notified_at TEXT,
+                created_at TEXT NOT NULL,
+                updated_at TEXT NOT NULL
+            )",
+            [],
+        )
+        .unwrap();
+
+        conn.execute(
+            "CREATE TABLE task_tags (
+                task_id TEXT NOT NULL,
+                tag_id TEXT NOT NULL,
+                created_at TEXT NOT NULL,
+                PRIMARY KEY (task_id, tag_id)
+            )",
+            [],
+        )
+        .unwrap();
+
+        conn.execute(
+            "CREATE TABLE tags (
+                id TEXT PRIMARY KEY,
+                name TEXT NOT NULL UNIQUE,
+                color TEXT NOT NULL,
+                created_at TEXT NOT NULL
+            )",
+            [],
+        )
+        .unwrap();
+
+        conn.execute(
+            "CREATE TABLE task_checklist_items (
+                id TEXT PRIMARY KEY,
+                task_id TEXT NOT NULL,
+                text TEXT NOT NULL,
+                is_done INTEGER NOT NULL DEFAULT 0,
+                position INTEGER NOT NULL DEFAULT 0
+            )",
+            [],
+        )
+        .unwrap();
+
+        conn.execute(
+            "CREATE TABLE reminders (
+                id TEXT PRIMARY KEY,
+                task_id TEXT,
+                title TEXT NOT NULL,
+                description TEXT,
+                remind_at TEXT NOT NULL,
+                repeat_interval TEXT NOT NULL,
+                is_active INTEGER NOT NULL DEFAULT 1,
+                last_triggered_at TEXT,
+                created_at TEXT NOT NULL,
+                updated_at TEXT NOT NULL,
+                acknowledged_at TEXT,
+                escalation_minutes INTEGER,
+                escalation_count INTEGER NOT NULL DEFAULT 0,
+                delivery TEXT NOT NULL DEFAULT 'notification'
+            )",
+            [],
+        )
+        .unwrap();
+
+        conn.execute(
+            "CREATE TABLE settings (
+                key TEXT PRIMARY KEY,
+                value TEXT NOT NULL,
+                updated_at TEXT NOT NULL DEFAULT (datetime('now'))
+            )",
+            [],
+        )
+        .unwrap();
+
+        Database::new_from_connection(conn)
+    }
+
+    fn seed(db: &Database) {
+        let task = TaskService::new(db)
+            .create_task(CreateTaskDto {
+                title: "Write \"report\"".to_string(),
+                description: Some("Quarterly numbers".to_string()),
+                priority: TaskPriority::High,
+                due_date: Some(Utc::now() + Duration::days(1)),
+                image_path: None,
+                notes: None,
+                estimated_minutes: None,
+                tag_ids: vec![],
+                parent_id: None,
+                color: Some("#f97316".to_string()),
+            })
+            .unwrap();
+
+        ReminderService::new(db)
+            .create_reminder(CreateReminderDto {
+                task_id: Some(task.id),
+                title: "Start report".to_string(),
+                description: None,
+                remind_at: Utc::now() + Duration::hours(2),
+                repeat_interval: RepeatInterval::none(),
+                escalation_minutes: None,
+                delivery: None,
+            })
+            .unwrap();
+    }
+
+    fn temp_path(name: &str) -> PathBuf {
+        std::env::temp_dir().join(format!("{}-{}", Uuid::new_v4(), name))
+    }
+
+    #[test]
+    fn test_export_tasks_to_file_matches_in_memory_export() {
+        let db = setup_test_db();
+        seed(&db);
+        let service = ExportService::new(&db);
+
+        for (format, name) in [
+            (ExportFormat::Json, "tasks.json"),
+            (ExportFormat::Csv, "tasks.csv"),
+        ] {
+            let path = temp_path(name);
+            let bytes = service
+                .export_tasks_to_file(&path, format, &ExportOptions::default())
+                .unwrap();
+            let written = std::fs::read_to_string(&path).unwrap();
+            let _ = std::fs::remove_file(&path);
+
+            assert_eq!(
+                written,
+                service
+                    .export_tasks(format, &ExportOptions::default())
+                    .unwrap()
+            );
+            assert_eq!(bytes, written.len() as u64);
+        }
+    }
+
+    #[test]
+    fn test_tasks_csv_includes_color() {
+        let db = setup_test_db();
+        seed(&db);
+
+        let csv = ExportService::new(&db)
+            .export_tasks(ExportFormat::Csv, &ExportOptions::default())
+            .unwrap();
+        let mut lines = csv.lines();
+        assert!(lines.next().unwrap().ends_with(",color,completion_note"));
+        assert!(lines.next().unwrap().ends_with(",\"#f97316\",\"\""));
+    }
+
+    #[test]
+    fn test_export_reminders_to_file_matches_in_memory_export() {
+        let db = setup_test_db();
+        seed(&db);
+        let service = ExportService::new(&db);
+
+        let path = temp_path("reminders.csv");
+        let bytes = service
+            .export_reminders_to_file(&path, ExportFormat::Csv, &ExportOptions::default())
+            .unwrap();
+        let written = std::fs::read_to_string(&path).unwrap();
+        let _ = std::fs::remove_file(&path);
+
+        assert_eq!(
+            written,
+            service
+                .export_reminders(ExportFormat::Csv, &ExportOptions::default())
+                .unwrap()
+        );
+        assert_eq!(bytes, written.len() as u64);
+        assert!(written.contains("Start report"));
+    }
+
+    #[test]
+    fn test_export_applies_timezone_offset() {
+        let db = setup_test_db();
+        seed(&db);
+        let task = TaskRepository::new(&db)
+            .find_all(None, None, Pagination::default())
+            .unwrap()
+            .items
+            .remove(0);
+        let options = ExportOptions {
+            timezone_offset_minutes: 420,
+            date_format: None,
+        };
+        let expected = task
+            .due_date
+            .unwrap()
+            .with_timezone(&chrono::FixedOffset::east_opt(420 * 60).unwrap())
+            .to_rfc3339();
+
+        let service = ExportService::new(&db);
+        let csv = service.export_tasks(ExportFormat::Csv, &options).unwrap();
+        assert!(csv.contains(&format!("\"{}\"", expected)));
+
+        let json: serde_json::Value =
+            serde_json::from_str(&service.export_tasks(ExportFormat::Json, &options).unwrap())
+                .unwrap();
+        assert_eq!(json[0]["due_date"], expected.as_str());
+        assert!(json[0]["created_at"].as_str().unwrap().ends_with("+07:00"));
+        assert_eq!(json[0]["title"], "Write \"report\"");
+    }
+
+    #[test]
+    fn test_export_applies_custom_date_format() {
+        let db = setup_test_db();
+        seed(&db);
+        let reminder = ReminderRepository::new(&db).find_all().unwrap().remove(0);
+        let options = ExportOptions {
+            timezone_offset_minutes: 0,
+            date_format: Some("%d/%m/%Y %H:%M".to_string()),
+        };
+        let expected = reminder.remind_at.format("%d/%m/%Y %H:%M").to_string();
+
+        let service = ExportService::new(&db);
+        let csv = service
+            .export_reminders(ExportFormat::Csv, &options)
+            .unwrap();
+        assert!(csv.contains(&format!("\"{}\"", expected)));
+
+        let json: serde_json::Value = serde_json::from_str(
+            &service
+                .export_reminders(ExportFormat::Json, &options)
+                .unwrap(),
+        )
+        .unwrap();
+        assert_eq!(json[0]["remind_at"], expected.as_str());
+
+        let invalid = ExportOptions {
+            date_format: Some("%Q".to_string()),
+            ..ExportOptions::default()
+        };
+        assert!(matches!(
+            service.export_reminders(ExportFormat::Csv, &invalid),
+            Err(DomainError::FieldValidation { ref field, .. }) if field == "date_format"
+        ));
+    }
+
+    fn create_reminder(db: &Database, title: &str, remind_at: DateTime<Utc>, repeat: &str) {
+        ReminderService::new(db)
+            .create_reminder(CreateReminderDto {
+                task_id: None,
+                title: title.to_string(),
+                description: None,
+                remind_at,
+                repeat_interval: RepeatInterval::from_str(repeat),
+                escalation_minutes: None,
+                delivery: None,
+            })
+            .unwrap();
+    }
+
+    #[test]
+    fn test_ics_feed_includes_only_reminders_in_range() {
+        let db = setup_test_db();
+        let start = Utc::now() + Duration::days(1);
+        let end = start + Duration::days(3);
+        create_reminder(&db, "Inside", start + Duration::hours(1), "none");
+        create_reminder(&db, "Too late", end + Duration::hours(1), "none");
+        create_reminder(&db, "Daily", start - Duration::hours(12), "every_1_days");
+
+        let feed = ExportService::new(&db)
+            .generate_ics_feed(start, end)
+            .unwrap();
+
+        assert!(feed.starts_with("BEGIN:VCALENDAR\r\n"));
+        assert!(feed.ends_with("END:VCALENDAR\r\n"));
+        assert!(feed.contains("SUMMARY:Inside\r\n"));
+        assert!(!feed.contains("Too late"));
+        assert_eq!(feed.matches("SUMMARY:Daily\r\n").count(), 3);
+        assert_eq!(feed.matches("BEGIN:VEVENT").count(), 4);
+    }
+
+    #[test]
+    fn test_ics_feed_emits_alarm_per_event() {
+        let db = setup_test_db();
+        let remind_at = Utc::now() + Duration::hours(2);
+        create_reminder(&db, "Call Ann; bring notes, slides", remind_at, "none");
+
+        let feed = ExportService::new(&db)
+            .generate_ics_feed(
+                remind_at - Duration::hours(1),
+                remind_at + Duration::hours(1),
+            )
+            .unwrap();
+
+        assert_eq!(feed.matches("BEGIN:VALARM").count(), 1);
+        assert!(feed.contains(&format!(
+            "DTSTART:{}\r\n",
+            remind_at.format("%Y%m%dT%H%M%SZ")
+        )));
+        assert!(
+            feed.contains("ACTION:DISPLAY\r\nDESCRIPTION:Call Ann\\; bring notes\\, slides\r\n")
+        );
+        assert!(feed.contains("TRIGGER:PT0S\r\nEND:VALARM\r\n"));
+    }
+
+    #[test]
+    fn test_ics_feed_rejects_empty_range() {
+        let db = setup_test_db();
+        let now = Utc::now();
+
+        let result = ExportService::new(&db).generate_ics_feed(now, now);
+        assert!(matches!(
+            result,
+            Err(DomainError::FieldValidation { ref field, .. }) if field == "end"
+        ));
+    }
+
+    #[test]
+    fn test_export_to_missing_directory_fails() {
+        let db = setup_test_db();
+        let path = temp_path("missing").join("tasks.json");
+
+        let result = ExportService::new(&db).export_tasks_to_file(
+            &path,
+            ExportFormat::Json,
+            &ExportOptions::default(),
+        );
+        assert!(matches!(
+            result,
+            Err(DomainError::FieldValidation { ref field, .. }) if field == "path"
+        ));
+    }
+}
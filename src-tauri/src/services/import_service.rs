@@ -0,0 +1,926 @@
+use crate::db::Database;
+use crate::error::{DomainError, DomainResult};
+use crate::models::{
+    CreateReminderDto, CreateTaskDto, ExportFormat, ImportConflictPolicy, ImportReport,
+    RepeatInterval, TaskPriority, UpdateReminderDto, UpdateTaskDto,
+};
+use crate::repositories::{ReminderRepository, SettingsRepository, TaskRepository};
+use crate::services::{ReminderService, TaskService};
+use chrono::{DateTime, Utc};
+use serde::de::{self, DeserializeOwned, IgnoredAny, SeqAccess, Visitor};
+use serde::{Deserialize, Deserializer};
+use std::cell::Cell;
+use std::fmt;
+use std::fs::File;
+use std::io::{BufRead, BufReader, Read};
+use std::marker::PhantomData;
+use std::path::Path;
+
+/// Appended to a record's title when imported under `ImportConflictPolicy::Rename`
+const RENAME_SUFFIX: &str = " (imported)";
+
+/// Task in a JSON import, with the id it was exported under if any
+#[derive(Deserialize)]
+struct ImportedTask {
+    #[serde(default)]
+    id: Option<String>,
+    #[serde(flatten)]
+    task: CreateTaskDto,
+}
+
+/// Reminder in a JSON import, with the id it was exported under if any
+#[derive(Deserialize)]
+struct ImportedReminder {
+    #[serde(default)]
+    id: Option<String>,
+    #[serde(flatten)]
+    reminder: CreateReminderDto,
+}
+
+/// Service creating tasks and reminders from exported JSON or CSV
+///
+/// Records carrying the id of an existing row are handled per `ImportConflictPolicy`.
+pub struct ImportService<'a> {
+    db: &'a Database,
+}
+
+impl<'a> ImportService<'a> {
+    /// Create a new ImportService instance
+    pub fn new(db: &'a Database) -> Self {
+        Self { db }
+    }
+
+    /// Import tasks from a `.json` or `.csv` file, picking the format by extension
+    pub fn import_tasks_from_file(
+        &self,
+        path: &Path,
+        policy: ImportConflictPolicy,
+    ) -> DomainResult<ImportReport> {
+        let format = match path
+            .extension()
+            .and_then(|ext| ext.to_str())
+            .map(|ext| ext.to_ascii_lowercase())
+            .as_deref()
+        {
+            Some("json") => ExportFormat::Json,
+            Some("csv") => ExportFormat::Csv,
+            _ => {
+                return Err(DomainError::field(
+                    "path",
+                    "Unsupported file type; expected .json or .csv",
+                ))
+            }
+        };
+
+        let file = File::open(path).map_err(|e| {
+            if e.kind() == std::io::ErrorKind::NotFound {
+                DomainError::field("path", "File does not exist")
+            } else {
+                DomainError::BusinessRuleViolation(format!("Failed to read import file: {}", e))
+            }
+        })?;
+        let reader = BufReader::new(file);
+
+        match format {
+            ExportFormat::Json => self.import_tasks_json(reader, policy),
+            ExportFormat::Csv => self.import_tasks_csv(reader, policy),
+        }
+    }
+
+    /// Import tasks from a JSON array of task definitions
+    ///
+    /// Parsing stops at the first element past the `max_import_rows` limit, and
+    /// nothing is written for an input over it.
+    pub fn import_tasks_json(
+        &self,
+        reader: impl Read,
+        policy: ImportConflictPolicy,
+    ) -> DomainResult<ImportReport> {
+        let tasks: Vec<ImportedTask> = self.read_json_rows(reader)?;
+
+        let mut report = ImportReport::default();
+        for imported in tasks {
+            self.import_task(imported.id.as_deref(), imported.task, policy, &mut report);
+        }
+
+        log_skipped(&report);
+        Ok(report)
+    }
+
+    /// Import tasks from CSV in the `export_tasks_csv` layout, skipping the header row
+    ///
+    /// Every row is parsed before anything is written, so an input over the
+    /// `max_import_rows` limit is rejected without importing any of it.
+    pub fn import_tasks_csv(
+        &self,
+        reader: impl BufRead,
+        policy: ImportConflictPolicy,
+    ) -> DomainResult<ImportReport> {
+        let rows = self.read_csv_rows(reader)?;
+
+        let mut report = ImportReport::default();
+        for line in rows {
+            match parse_task_csv_line(&line) {
+                Some((id, task_data)) => self.import_task(id, task_data, policy, &mut report),
+                None => report.skip(format!("Skipping invalid CSV line: {}", line)),
+            }
+        }
+
+        log_skipped(&report);
+        Ok(report)
+    }
+
+    /// Import reminders from a JSON array of reminder definitions
+    ///
+    /// Like `import_tasks_json`, an input over the `max_import_rows` limit is
+    /// rejected once its first extra element is parsed.
+    pub fn import_reminders_json(
+        &self,
+        reader: impl Read,
+        policy: ImportConflictPolicy,
+    ) -> DomainResult<ImportReport> {
+        let reminders: Vec<ImportedReminder> = self.read_json_rows(reader)?;
+
+        let mut report = ImportReport::default();
+        for imported in reminders {
+            self.import_reminder(
+                imported.id.as_deref(),
+                imported.reminder,
+                policy,
+                &mut report,
+            );
+        }
+
+        log_skipped(&report);
+        Ok(report)
+    }
+
+    /// Import reminders from CSV in the `export_reminders_csv` layout, skipping the header row
+    ///
+    /// Only the task link, title and `remind_at` are read; imported reminders don't repeat.
+    pub fn import_reminders_csv(
+        &self,
+        reader: impl BufRead,
+        policy: ImportConflictPolicy,
+    ) -> DomainResult<ImportReport> {
+        let rows = self.read_csv_rows(reader)?;
+
+        let mut report = ImportReport::default();
+        for line in rows {
+            match parse_reminder_csv_line(&line) {
+                Ok((id, reminder_data)) => {
+                    self.import_reminder(id, reminder_data, policy, &mut report)
+                }
+                Err(reason) => report.skip(reason),
+            }
+        }
+
+        log_skipped(&report);
+        Ok(report)
+    }
+
+    /// Parse a JSON array of records, failing past the `max_import_rows` limit
+    ///
+    /// Records are counted during the parse, so an oversized array is never
+    /// collected in full.
+    fn read_json_rows<T: DeserializeOwned>(&self, reader: impl Read) -> DomainResult<Vec<T>> {
+        let limit = self.max_import_rows()?;
+        let exceeded = Cell::new(false);
+        let mut deserializer = serde_json::Deserializer::from_reader(reader);
+
+        let rows = deserializer
+            .deserialize_seq(BoundedRows {
+                limit,
+                exceeded: &exceeded,
+                rows: PhantomData,
+            })
+            .and_then(|rows| deserializer.end().map(|_| rows));
+
+        rows.map_err(|e| {
+            if exceeded.get() {
+                import_limit_error(limit)
+            } else {
+                DomainError::InvalidInput(format!("Failed to parse JSON: {}", e))
+            }
+        })
+    }
+
+    fn max_import_rows(&self) -> DomainResult<usize> {
+        SettingsRepository::new(self.db)
+            .get_app_settings()
+            .map(|settings| settings.max_import_rows)
+            .map_err(|e| {
+                DomainError::BusinessRuleViolation(format!("Failed to read settings: {}", e))
+            })
+    }
+
+    /// Read the non-empty data rows of a CSV, failing past the `max_import_rows` limit
+    fn read_csv_rows(&self, reader: impl BufRead) -> DomainResult<Vec<String>> {
+        let limit = self.max_import_rows()?;
+        let mut rows = Vec::new();
+
+        for line in reader.lines().skip(1) {
+            let line = line.map_err(|e| {
+                DomainError::BusinessRuleViolation(format!("Failed to read CSV: {}", e))
+            })?;
+            if line.trim().is_empty() {
+                continue;
+            }
+            if rows.len() == limit {
+                return Err(import_limit_error(limit));
+            }
+
+            rows.push(line);
+        }
+
+        Ok(rows)
+    }
+
+    fn import_task(
+        &self,
+        id: Option<&str>,
+        mut task_data: CreateTaskDto,
+        policy: ImportConflictPolicy,
+        report: &mut ImportReport,
+    ) {
+        let existing = match id.filter(|id| !id.is_empty()) {
+            Some(id) => match TaskRepository::new(self.db).find_by_id(id) {
+                Ok(existing) => existing,
+                Err(e) => return report.skip(format!("Failed to import task {}: {}", id, e)),
+            },
+            None => None,
+        };
+        let Some(existing) = existing else {
+            return self.create_task(task_data, report);
+        };
+
+        match policy {
+            ImportConflictPolicy::Skip => {
+                report.skip(format!("Skipping task {}: it already exists", existing.id))
+            }
+            ImportConflictPolicy::Overwrite => {
+                // Overwrite replaces the task, so fields absent from the import are cleared
+                let mut dto = UpdateTaskDto {
+                    title: Some(task_data.title),
+                    description: task_data.description,
+                    status: None,
+                    priority: Some(task_data.priority),
+                    due_date: task_data.due_date,
+                    image_path: task_data.image_path,
+                    notes: task_data.notes,
+                    estimated_minutes: task_data.estimated_minutes,
+                    actual_minutes: None,
+                    tag_ids: Some(task_data.tag_ids),
+                    color: task_data.color,
+                    clear_fields: vec![],
+                };
+                dto.clear_fields = OVERWRITE_CLEARABLE_FIELDS
+                    .iter()
+                    .filter(|field| dto.sets_clearable(field) == Some(false))
+                    .map(|field| field.to_string())
+                    .collect();
+                match TaskService::new(self.db).update_task(&existing.id, dto, false) {
+                    Ok(_) => report.overwritten += 1,
+                    Err(e) => {
+                        report.skip(format!("Failed to overwrite task {}: {}", existing.id, e))
+                    }
+                }
+            }
+            ImportConflictPolicy::Rename => {
+                task_data.title = renamed(&task_data.title);
+                self.create_task(task_data, report);
+            }
+        }
+    }
+
+    fn create_task(&self, task_data: CreateTaskDto, report: &mut ImportReport) {
+        match TaskService::new(self.db).create_task_without_auto_reminder(task_data) {
+            Ok(_) => report.imported += 1,
+            // Log error but continue with other tasks
+            Err(e) => report.skip(format!("Failed to import task: {}", e)),
+        }
+    }
+
+    fn import_reminder(
+        &self,
+        id: Option<&str>,
+        mut reminder_data: CreateReminderDto,
+        policy: ImportConflictPolicy,
+        report: &mut ImportReport,
+    ) {
+        let existing = match id.filter(|id| !id.is_empty()) {
+            Some(id) => match ReminderRepository::new(self.db).find_by_id(id) {
+                Ok(existing) => existing,
+                Err(e) => return report.skip(format!("Failed to import reminder {}: {}", id, e)),
+            },
+            None => None,
+        };
+        let Some(existing) = existing else {
+            return self.create_reminder(reminder_data, report);
+        };
+
+        match policy {
+            ImportConflictPolicy::Skip => report.skip(format!(
+                "Skipping reminder {}: it already exists",
+                existing.id
+            )),
+            ImportConflictPolicy::Overwrite => {
+                let dto = UpdateReminderDto {
+                    task_id: reminder_data.task_id,
+                    title: Some(reminder_data.title),
+                    description: reminder_data.description,
+                    remind_at: Some(reminder_data.remind_at),
+                    repeat_interval: Some(reminder_data.repeat_interval),
+                    is_active: None,
+                    delivery: reminder_data.delivery,
+                };
+                match ReminderService::new(self.db).update_reminder(&existing.id, dto) {
+                    Ok(_) => report.overwritten += 1,
+                    Err(e) => report.skip(format!(
+                        "Failed to overwrite reminder {}: {}",
+                        existing.id, e
+                    )),
+                }
+            }
+            ImportConflictPolicy::Rename => {
+                reminder_data.title = renamed(&reminder_data.title);
+                self.create_reminder(reminder_data, report);
+            }
+        }
+    }
+
+    fn create_reminder(&self, reminder_data: CreateReminderDto, report: &mut ImportReport) {
+        match ReminderService::new(self.db).create_reminder(reminder_data) {
+            Ok(_) => report.imported += 1,
+            // Log error but continue with other reminders
+            Err(e) => report.skip(format!("Failed to import reminder: {}", e)),
+        }
+    }
+}
+
+/// Task fields an overwriting import clears when the imported row leaves them out
+const OVERWRITE_CLEARABLE_FIELDS: &[&str] = &[
+    "description",
+    "due_date",
+    "image_path",
+    "notes",
+    "estimated_minutes",
+    "color",
+];
+
+/// Visitor collecting a JSON array, stopping at the first element past `limit`
+struct BoundedRows<'a, T> {
+    limit: usize,
+    exceeded: &'a Cell<bool>,
+    rows: PhantomData<T>,
+}
+
+impl<'de, T: Deserialize<'de>> Visitor<'de> for BoundedRows<'_, T> {
+    type Value = Vec<T>;
+
+    fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        write!(formatter, "an array of at most {} records", self.limit)
+    }
+
+    fn visit_seq<A: SeqAccess<'de>>(self, mut seq: A) -> Result<Vec<T>, A::Error> {
+        let mut rows = Vec::with_capacity(seq.size_hint().unwrap_or(0).min(self.limit));
+        while rows.len() < self.limit {
+            match seq.next_element()? {
+                Some(row) => rows.push(row),
+                None => return Ok(rows),
+            }
+        }
+
+        if seq.next_element::<IgnoredAny>()?.is_some() {
+            self.exceeded.set(true);
+            return Err(de::Error::custom("too many records"));
+        }
+        Ok(rows)
+    }
+}
+
+fn import_limit_error(limit: usize) -> DomainError {
+    DomainError::InvalidInput(format!(
+        "Import exceeds the limit of {} rows; raise the max_import_rows setting to import more",
+        limit
+    ))
+}
+
+/// Log why each skipped record of an import was skipped
+fn log_skipped(report: &ImportReport) {
+    for reason in &report.errors {
+        eprintln!("{}", reason);
+    }
+}
+
+/// Append `RENAME_SUFFIX`, keeping the title within the 200 character limit
+fn renamed(title: &str) -> String {
+    let mut title = title.trim().to_string();
+    while title.len() + RENAME_SUFFIX.len() > 200 {
+        title.pop();
+    }
+    title.push_str(RENAME_SUFFIX);
+    title
+}
+
+/// Parse one CSV row into its id (if any) and task (simple implementation: fields must
+/// not contain commas)
+fn parse_task_csv_line(line: &str) -> Option<(Option<&str>, CreateTaskDto)> {
+    let fields: Vec<&str> = line
+        .split(',')
+        .map(|s| s.trim_matches('"').trim())
+        .collect();
+
+    if fields.len() < 9 {
+        return None;
+    }
+
+    let due_date = if !fields[5].is_empty() {
+        DateTime::parse_from_rfc3339(fields[5])
+            .ok()
+            .map(|dt| dt.with_timezone(&Utc))
+    } else {
+        None
+    };
+
+    let tags: Vec<String> = if !fields[6].is_empty() {
+        fields[6].split('|').map(|s| s.to_string()).collect()
+    } else {
+        vec![]
+    };
+
+    let id = Some(fields[0]).filter(|id| !id.is_empty());
+    let task = CreateTaskDto {
+        title: fields[1].to_string(),
+        description: if !fields[2].is_empty() {
+            Some(fields[2].to_string())
+        } else {
+            None
+        },
+        priority: TaskPriority::from_str(fields[4]).unwrap_or(TaskPriority::Medium),
+        due_date,
+        image_path: None,
+        notes: None,
+        estimated_minutes: None,
+        tag_ids: tags,
+        parent_id: None,
+        color: fields
+            .get(9)
+            .filter(|color| !color.is_empty())
+            .map(|color| color.to_string()),
+    };
+
+    Some((id, task))
+}
+
+/// Parse one reminder CSV row into its id (if any) and reminder, or why it was rejected
+fn parse_reminder_csv_line(line: &str) -> Result<(Option<&str>, CreateReminderDto), String> {
+    let fields: Vec<&str> = line
+        .split(',')
+        .map(|s| s.trim_matches('"').trim())
+        .collect();
+
+    if fields.len() < 7 {
+        return Err(format!("Skipping invalid CSV line: {}", line));
+    }
+
+    let remind_at = DateTime::parse_from_rfc3339(fields[3])
+        .map_err(|e| format!("Failed to parse remind_at: {}", e))?
+        .with_timezone(&Utc);
+
+    let id = Some(fields[0]).filter(|id| !id.is_empty());
+    let reminder = CreateReminderDto {
+        task_id: Some(fields[1])
+            .filter(|task_id| !task_id.is_empty())
+            .map(|task_id| task_id.to_string()),
+        title: fields[2].to_string(),
+        description: None,
+        remind_at,
+        repeat_interval: RepeatInterval::none(),
+        escalation_minutes: None,
+        delivery: None,
+    };
+
+    Ok((id, reminder))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::repositories::TaskRepository;
+    use rusqlite::Connection;
+    use std::path::PathBuf;
+    use uuid::Uuid;
+
+    fn setup_test_db() -> Database {
+        let conn = Connection::open_in_memory().unwrap();
+
+        conn.execute(
+            "CREATE TABLE tasks (
+                id TEXT PRIMARY KEY,
+                title TEXT NOT NULL,
+                description TEXT,
+                status TEXT NOT NULL,
+                priority TEXT NOT NULL,
+                due_date TEXT,
+                completed_at TEXT,
+                image_path TEXT,
+                notes TEXT,
+                estimated_minutes INTEGER,
+                actual_minutes INTEGER,
+                color TEXT,
+                is_pinned INTEGER NOT NULL DEFAULT 0,
+                series_id TEXT,
+                is_deferred INTEGER NOT NULL DEFAULT 0,
+                deferred_until TEXT,
+                completion_note TEXT,
+                parent_id TEXT,
+                sort_order INTEGER NOT NULL DEFAULT 0,
+                overdue_notified_at TEXT,
+                created_at TEXT NOT NULL,
+                updated_at TEXT NOT NULL
+            )",
+            [],
+        )
+        .unwrap();
+
+        conn.execute(
+            "CREATE TABLE task_tags (
+                task_id TEXT NOT NULL,
+                tag_id TEXT NOT NULL,
+                created_at TEXT NOT NULL,
+                PRIMARY KEY (task_id, tag_id)
+            )",
+            [],
+        )
+        .unwrap();
+
+        conn.execute(
+            "CREATE TABLE tags (
+                id TEXT PRIMARY KEY,
+                name TEXT NOT NULL UNIQUE,
+                color TEXT NOT NULL,
+                created_at TEXT NOT NULL
+            )",
+            [],
+        )
+        .unwrap();
+
+        conn.execute(
+            "CREATE TABLE task_checklist_items (
+                id TEXT PRIMARY KEY,
+                task_id TEXT NOT NULL,
+                text TEXT NOT NULL,
+                is_done INTEGER NOT NULL DEFAULT 0,
+                position INTEGER NOT NULL DEFAULT 0
+            )",
+            [],
+        )
+        .unwrap();
+
+        conn.execute(
+            "CREATE TABLE reminders (
+                id TEXT PRIMARY KEY,
+                task_id TEXT,
+                title TEXT NOT NULL,
+                description TEXT,
+                remind_at TEXT NOT NULL,
+                repeat_interval TEXT NOT NULL,
+                is_active INTEGER NOT NULL DEFAULT 1,
+                last_triggered_at TEXT,
+                created_at TEXT NOT NULL,
+                updated_at TEXT NOT NULL,
+                acknowledged_at TEXT,
+                escalation_minutes INTEGER,
+                escalation_count INTEGER NOT NULL DEFAULT 0,
+                delivery TEXT NOT NULL DEFAULT 'notification'
+            )",
+            [],
+        )
+        .unwrap();
+
+        conn.execute(
+            "CREATE TABLE settings (
+                key TEXT PRIMARY KEY,
+                value TEXT NOT NULL,
+                updated_at TEXT NOT NULL DEFAULT (datetime('now'))
+            )",
+            [],
+        )
+        .unwrap();
+
+        Database::new_from_connection(conn)
+    }
+
+    fn write_temp_file(name: &str, contents: &str) -> PathBuf {
+        let path = std::env::temp_dir().join(format!("{}-{}", Uuid::new_v4(), name));
+        std::fs::write(&path, contents).unwrap();
+        path
+    }
+
+    fn task_titles(db: &Database) -> Vec<String> {
+        let mut titles: Vec<String> = TaskRepository::new(db)
+            .search("", 100)
+            .unwrap()
+            .into_iter()
+            .map(|t| t.title)
+            .collect();
+        titles.sort();
+        titles
+    }
+
+    #[test]
+    fn test_import_tasks_from_json_file() {
+        let db = setup_test_db();
+        let path = write_temp_file(
+            "tasks.JSON",
+            r#"[
+                {"title": "From JSON", "priority": "high", "tag_ids": []},
+                {"title": "", "priority": "low", "tag_ids": []}
+            ]"#,
+        );
+
+        let report = ImportService::new(&db)
+            .import_tasks_from_file(&path, ImportConflictPolicy::Skip)
+            .unwrap();
+        let _ = std::fs::remove_file(&path);
+
+        assert_eq!(report.imported, 1);
+        assert_eq!(report.skipped, 1);
+        assert_eq!(report.errors.len(), 1);
+        assert_eq!(task_titles(&db), vec!["From JSON"]);
+    }
+
+    #[test]
+    fn test_import_tasks_from_csv_file() {
+        let db = setup_test_db();
+        let path = write_temp_file(
+            "tasks.csv",
+            "id,title,description,status,priority,due_date,tags,created_at,updated_at\n\
+             \"1\",\"First\",\"\",\"pending\",\"low\",\"\",\"\",\"\",\"\"\n\
+             \"2\",\"Second\",\"Details\",\"pending\",\"high\",\"\",\"\",\"\",\"\"\n\
+             not,enough,fields\n",
+        );
+
+        let report = ImportService::new(&db)
+            .import_tasks_from_file(&path, ImportConflictPolicy::Skip)
+            .unwrap();
+        let _ = std::fs::remove_file(&path);
+
+        assert_eq!(report.imported, 2);
+        assert_eq!(report.skipped, 1);
+        assert_eq!(task_titles(&db), vec!["First", "Second"]);
+    }
+
+    fn set_max_import_rows(db: &Database, rows: usize) {
+        SettingsRepository::new(db)
+            .set("max_import_rows", &rows.to_string())
+            .unwrap();
+    }
+
+    fn tasks_csv(count: usize) -> String {
+        let mut csv = "id,title,description,status,priority,due_date,tags,created_at,updated_at\n"
+            .to_string();
+        for i in 0..count {
+            csv.push_str(&format!(
+                "\"{i}\",\"Task {i}\",\"\",\"pending\",\"low\",\"\",\"\",\"\",\"\"\n"
+            ));
+        }
+        csv
+    }
+
+    fn tasks_json(count: usize) -> String {
+        let tasks: Vec<String> = (0..count)
+            .map(|i| {
+                format!(
+                    r#"{{"title": "Task {}", "priority": "low", "tag_ids": []}}"#,
+                    i
+                )
+            })
+            .collect();
+        format!("[{}]", tasks.join(","))
+    }
+
+    #[test]
+    fn test_import_at_and_below_row_limit() {
+        for count in [2, 3] {
+            let db = setup_test_db();
+            set_max_import_rows(&db, 3);
+            let service = ImportService::new(&db);
+
+            let report = service
+                .import_tasks_csv(tasks_csv(count).as_bytes(), ImportConflictPolicy::Skip)
+                .unwrap();
+            assert_eq!(report.imported, count);
+
+            let report = service
+                .import_tasks_json(tasks_json(count).as_bytes(), ImportConflictPolicy::Skip)
+                .unwrap();
+            assert_eq!(report.imported, count);
+            assert_eq!(task_titles(&db).len(), count * 2);
+        }
+    }
+
+    #[test]
+    fn test_import_above_row_limit_writes_nothing() {
+        let db = setup_test_db();
+        set_max_import_rows(&db, 3);
+        let service = ImportService::new(&db);
+
+        let result = service.import_tasks_csv(tasks_csv(4).as_bytes(), ImportConflictPolicy::Skip);
+        assert!(
+            matches!(result, Err(DomainError::InvalidInput(ref m)) if m.contains("max_import_rows"))
+        );
+
+        let result =
+            service.import_tasks_json(tasks_json(4).as_bytes(), ImportConflictPolicy::Skip);
+        assert!(
+            matches!(result, Err(DomainError::InvalidInput(ref m)) if m.contains("max_import_rows"))
+        );
+
+        // Parsing stops at the first extra element, before the malformed tail
+        let json = tasks_json(5);
+        let truncated = format!("{}, not json", &json[..json.len() - 1]);
+        let result = service.import_tasks_json(truncated.as_bytes(), ImportConflictPolicy::Skip);
+        assert!(
+            matches!(result, Err(DomainError::InvalidInput(ref m)) if m.contains("max_import_rows"))
+        );
+
+        let reminder =
+            r#"{"title": "Ping", "remind_at": "2030-01-01T09:00:00Z", "repeat_interval": "none"}"#;
+        let reminders = format!("[{}]", [reminder; 4].join(","));
+        let result =
+            service.import_reminders_json(reminders.as_bytes(), ImportConflictPolicy::Skip);
+        assert!(
+            matches!(result, Err(DomainError::InvalidInput(ref m)) if m.contains("max_import_rows"))
+        );
+
+        assert!(task_titles(&db).is_empty());
+    }
+
+    /// An existing "Original" task and a JSON import of "Updated" under the same id
+    fn conflicting_task(db: &Database) -> (String, String) {
+        db.connection()
+            .execute(
+                "INSERT INTO tags (id, name, color, created_at) VALUES ('tag-1', 'work', '#3b82f6', '2024-01-01T00:00:00Z')",
+                [],
+            )
+            .unwrap();
+        let task = TaskService::new(db)
+            .create_task(CreateTaskDto {
+                title: "Original".to_string(),
+                description: Some("Old description".to_string()),
+                priority: TaskPriority::Low,
+                due_date: Some(Utc::now() + chrono::Duration::days(1)),
+                image_path: None,
+                notes: Some("Old notes".to_string()),
+                estimated_minutes: None,
+                tag_ids: vec!["tag-1".to_string()],
+                parent_id: None,
+                color: None,
+            })
+            .unwrap();
+        let json = format!(
+            r#"[{{"id": "{}", "title": "Updated", "priority": "high", "tag_ids": []}}]"#,
+            task.id
+        );
+        (task.id, json)
+    }
+
+    #[test]
+    fn test_import_skip_keeps_existing_task() {
+        let db = setup_test_db();
+        let (id, json) = conflicting_task(&db);
+
+        let report = ImportService::new(&db)
+            .import_tasks_json(json.as_bytes(), ImportConflictPolicy::Skip)
+            .unwrap();
+
+        assert_eq!(
+            (report.imported, report.overwritten, report.skipped),
+            (0, 0, 1)
+        );
+        assert!(report.errors[0].contains(&id));
+        assert_eq!(task_titles(&db), vec!["Original"]);
+    }
+
+    #[test]
+    fn test_import_overwrite_updates_existing_task() {
+        let db = setup_test_db();
+        let (id, json) = conflicting_task(&db);
+
+        let report = ImportService::new(&db)
+            .import_tasks_json(json.as_bytes(), ImportConflictPolicy::Overwrite)
+            .unwrap();
+
+        assert_eq!(
+            (report.imported, report.overwritten, report.skipped),
+            (0, 1, 0)
+        );
+        let task = TaskRepository::new(&db).find_by_id(&id).unwrap().unwrap();
+        assert_eq!(task.title, "Updated");
+        assert_eq!(task.priority, TaskPriority::High);
+        // Fields the import leaves out are cleared rather than merged
+        assert_eq!(task.description, None);
+        assert_eq!(task.notes, None);
+        assert_eq!(task.due_date, None);
+        assert!(task.tags.is_empty());
+        assert_eq!(task_titles(&db), vec!["Updated"]);
+    }
+
+    #[test]
+    fn test_import_rename_creates_suffixed_task() {
+        let db = setup_test_db();
+        let (_, json) = conflicting_task(&db);
+
+        let report = ImportService::new(&db)
+            .import_tasks_json(json.as_bytes(), ImportConflictPolicy::Rename)
+            .unwrap();
+
+        assert_eq!(
+            (report.imported, report.overwritten, report.skipped),
+            (1, 0, 0)
+        );
+        assert_eq!(task_titles(&db), vec!["Original", "Updated (imported)"]);
+    }
+
+    #[test]
+    fn test_reminder_import_conflict_policies() {
+        let db = setup_test_db();
+        let existing = ReminderService::new(&db)
+            .create_reminder(CreateReminderDto {
+                task_id: None,
+                title: "Call mom".to_string(),
+                description: None,
+                remind_at: Utc::now() + chrono::Duration::hours(1),
+                repeat_interval: RepeatInterval::none(),
+                escalation_minutes: None,
+                delivery: None,
+            })
+            .unwrap();
+        let csv = format!(
+            "id,task_id,title,remind_at,is_active,created_at,updated_at\n\
+             \"{}\",\"\",\"Call dad\",\"2030-01-01T09:00:00Z\",true,\"\",\"\"\n",
+            existing.id
+        );
+        let service = ImportService::new(&db);
+        let reminder_titles = || {
+            let mut titles: Vec<String> = ReminderRepository::new(&db)
+                .search("", 100)
+                .unwrap()
+                .into_iter()
+                .map(|r| r.title)
+                .collect();
+            titles.sort();
+            titles
+        };
+
+        let report = service
+            .import_reminders_csv(csv.as_bytes(), ImportConflictPolicy::Skip)
+            .unwrap();
+        assert_eq!(report.skipped, 1);
+        assert_eq!(reminder_titles(), vec!["Call mom"]);
+
+        let report = service
+            .import_reminders_csv(csv.as_bytes(), ImportConflictPolicy::Overwrite)
+            .unwrap();
+        assert_eq!(report.overwritten, 1);
+        let updated = ReminderRepository::new(&db)
+            .find_by_id(&existing.id)
+            .unwrap()
+            .unwrap();
+        assert_eq!(updated.title, "Call dad");
+        assert_eq!(updated.remind_at.to_rfc3339(), "2030-01-01T09:00:00+00:00");
+
+        let report = service
+            .import_reminders_csv(csv.as_bytes(), ImportConflictPolicy::Rename)
+            .unwrap();
+        assert_eq!(report.imported, 1);
+        assert_eq!(reminder_titles(), vec!["Call dad", "Call dad (imported)"]);
+    }
+
+    #[test]
+    fn test_import_missing_file_fails() {
+        let db = setup_test_db();
+        let path = std::env::temp_dir().join(format!("missing-{}.json", Uuid::new_v4()));
+
+        let result =
+            ImportService::new(&db).import_tasks_from_file(&path, ImportConflictPolicy::Skip);
+        assert!(matches!(
+            result,
+            Err(DomainError::FieldValidation { ref field, .. }) if field == "path"
+        ));
+    }
+
+    #[test]
+    fn test_import_rejects_unknown_extension() {
+        let db = setup_test_db();
+        let path = write_temp_file("tasks.txt", "[]");
+
+        let result =
+            ImportService::new(&db).import_tasks_from_file(&path, ImportConflictPolicy::Skip);
+        let _ = std::fs::remove_file(&path);
+
+        assert!(matches!(result, Err(DomainError::FieldValidation { .. })));
+    }
+}
@@ -1,7 +1,17 @@
+pub mod backup_service;
+pub mod diagnostics_service;
+pub mod export_service;
+pub mod import_service;
 pub mod notification_service;
 pub mod reminder_service;
 pub mod task_service;
+pub mod undo_service;
 
+pub use backup_service::BackupService;
+pub use diagnostics_service::DiagnosticsService;
+pub use export_service::ExportService;
+pub use import_service::ImportService;
 pub use notification_service::NotificationService;
 pub use reminder_service::ReminderService;
 pub use task_service::TaskService;
+pub use undo_service::UndoService;
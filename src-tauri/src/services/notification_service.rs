@@ -1,28 +1,138 @@
 use crate::db::Database;
-use crate::repositories::ReminderRepository;
+use crate::models::event::{
+    TaskDigestEvent, REMINDERS_CHANGED_EVENT, REMINDER_TRIGGERED_EVENT, TASK_DIGEST_EVENT,
+};
+use crate::models::reminder::{render_template, MAX_ESCALATIONS};
+use crate::models::{
+    ChangeKind, Reminder, ReminderChangedEvent, ReminderDelivery, ReminderTriggeredEvent, Task,
+};
+use crate::repositories::{ReminderRepository, SettingsRepository, TaskRepository};
+use crate::services::TaskService;
+use chrono::{DateTime, FixedOffset, Local, Utc};
 use std::sync::{Arc, Mutex};
 use std::thread;
 use std::time::Duration;
-use tauri::{AppHandle, Emitter, Manager};
+use tauri::{AppHandle, Emitter};
+
+/// How often the background thread checks for due reminders
+const CHECK_INTERVAL: Duration = Duration::from_secs(30);
+
+/// Settings key persisting the paused state across restarts
+const PAUSED_SETTING_KEY: &str = "notifications_paused";
+
+/// Settings key recording when the last digest was sent
+const DIGEST_LAST_SENT_KEY: &str = "digest_last_sent_at";
+
+/// Event emitted once when a task passes its due date without being completed
+pub const TASK_OVERDUE_EVENT: &str = "task-overdue";
+
+/// Delivers triggered reminders and overdue alerts to the user
+pub trait ReminderNotifier: Send + Sync {
+    fn notify(&self, event: &ReminderTriggeredEvent) -> Result<(), Box<dyn std::error::Error>>;
+
+    fn notify_overdue(&self, task: &Task) -> Result<(), Box<dyn std::error::Error>>;
+
+    fn notify_digest(&self, digest: &TaskDigestEvent) -> Result<(), Box<dyn std::error::Error>>;
+}
+
+impl ReminderNotifier for AppHandle {
+    /// Emit a `reminder-triggered` event to the frontend and show an OS notification
+    fn notify(&self, event: &ReminderTriggeredEvent) -> Result<(), Box<dyn std::error::Error>> {
+        let reminder = &event.reminder;
+        self.emit(REMINDER_TRIGGERED_EVENT, event.clone())?;
+        self.emit(
+            REMINDERS_CHANGED_EVENT,
+            ReminderChangedEvent {
+                id: reminder.id.clone(),
+                kind: ChangeKind::Triggered,
+            },
+        )?;
+
+        #[cfg(not(target_os = "linux"))]
+        {
+            use tauri_plugin_notification::NotificationExt;
+
+            let body = event.notification_body();
+            let notification = self.notification().builder().title(&reminder.title);
+            let notification = if let Some(body) = &body {
+                notification.body(body)
+            } else {
+                notification
+            };
+            notification.show()?;
+        }
+
+        Ok(())
+    }
+
+    /// Emit a `task-overdue` event to the frontend and show an OS notification
+    fn notify_overdue(&self, task: &Task) -> Result<(), Box<dyn std::error::Error>> {
+        self.emit(TASK_OVERDUE_EVENT, task.clone())?;
+
+        #[cfg(not(target_os = "linux"))]
+        {
+            use tauri_plugin_notification::NotificationExt;
+
+            self.notification()
+                .builder()
+                .title("⚠️ Task overdue")
+                .body(&task.title)
+                .show()?;
+        }
+
+        Ok(())
+    }
+
+    /// Emit a `task-digest` event to the frontend and show one summary OS notification
+    fn notify_digest(&self, digest: &TaskDigestEvent) -> Result<(), Box<dyn std::error::Error>> {
+        self.emit(TASK_DIGEST_EVENT, digest.clone())?;
+
+        #[cfg(not(target_os = "linux"))]
+        {
+            use tauri_plugin_notification::NotificationExt;
+
+            self.notification()
+                .builder()
+                .title(digest.notification_title())
+                .body(digest.notification_body())
+                .show()?;
+        }
+
+        Ok(())
+    }
+}
 
 /// Background notification service that checks for due reminders
 /// and triggers OS native notifications
 pub struct NotificationService {
-    app_handle: AppHandle,
+    notifier: Arc<dyn ReminderNotifier>,
+    db: Arc<Mutex<Database>>,
     is_running: Arc<Mutex<bool>>,
+    /// When set, the thread keeps running but due reminders are held back until resume
+    is_paused: Arc<Mutex<bool>>,
 }
 
 impl NotificationService {
-    /// Create a new NotificationService instance
-    pub fn new(app_handle: AppHandle) -> Self {
+    /// Create a new NotificationService instance, restoring the persisted pause state
+    pub fn new(notifier: impl ReminderNotifier + 'static, db: Arc<Mutex<Database>>) -> Self {
+        let paused = db
+            .lock()
+            .ok()
+            .and_then(|db| SettingsRepository::new(&db).get(PAUSED_SETTING_KEY).ok())
+            .flatten()
+            .map(|value| value == "true")
+            .unwrap_or(false);
+
         Self {
-            app_handle,
+            notifier: Arc::new(notifier),
+            db,
             is_running: Arc::new(Mutex::new(false)),
+            is_paused: Arc::new(Mutex::new(paused)),
         }
     }
 
     /// Start the background notification checker
-    /// Runs in a separate thread and checks every 60 seconds
+    /// Runs in a separate thread and checks every 30 seconds
     pub fn start(&self) {
         let mut is_running = self.is_running.lock().unwrap();
         if *is_running {
@@ -32,11 +142,13 @@ impl NotificationService {
         *is_running = true;
         drop(is_running); // Release lock before spawning thread
 
-        let app_handle = self.app_handle.clone();
+        let notifier = Arc::clone(&self.notifier);
+        let db = Arc::clone(&self.db);
         let is_running_clone = Arc::clone(&self.is_running);
+        let is_paused_clone = Arc::clone(&self.is_paused);
 
         thread::spawn(move || {
-            println!("✅ NotificationService started - checking every 60 seconds");
+            println!("🔔 NotificationService started");
 
             loop {
                 // Check if we should keep running
@@ -49,12 +161,11 @@ impl NotificationService {
                 }
 
                 // Check for due reminders and send notifications
-                if let Err(e) = Self::check_and_notify(&app_handle) {
+                if let Err(e) = Self::check_and_notify(notifier.as_ref(), &db, &is_paused_clone) {
                     eprintln!("❌ Error checking reminders: {}", e);
                 }
 
-                // Sleep for 60 seconds before next check
-                thread::sleep(Duration::from_secs(60));
+                thread::sleep(CHECK_INTERVAL);
             }
         });
     }
@@ -66,16 +177,83 @@ impl NotificationService {
         println!("🛑 NotificationService stop requested");
     }
 
-    /// Check for due reminders and send notifications
+    /// Pause delivery without stopping the background thread
+    pub fn pause(&self) -> Result<(), Box<dyn std::error::Error>> {
+        self.set_paused(true)?;
+        println!("⏸️ NotificationService paused");
+        Ok(())
+    }
+
+    /// Resume delivery; reminders that became due while paused fire right away
+    pub fn resume(&self) -> Result<(), Box<dyn std::error::Error>> {
+        self.set_paused(false)?;
+        println!("▶️ NotificationService resumed");
+        self.check_now()
+    }
+
+    /// Whether the background checker thread is running
+    pub fn is_running(&self) -> bool {
+        *self.is_running.lock().unwrap()
+    }
+
+    /// Whether delivery is currently paused
+    pub fn is_paused(&self) -> bool {
+        *self.is_paused.lock().unwrap()
+    }
+
+    /// Manually trigger a check (useful for testing or immediate checks)
+    pub fn check_now(&self) -> Result<(), Box<dyn std::error::Error>> {
+        Self::check_and_notify(self.notifier.as_ref(), &self.db, &self.is_paused)
+    }
+
+    /// Update the pause flag and persist it to settings
+    fn set_paused(&self, paused: bool) -> Result<(), Box<dyn std::error::Error>> {
+        {
+            let db = self
+                .db
+                .lock()
+                .map_err(|_| "Failed to acquire database lock")?;
+            SettingsRepository::new(&db).set(PAUSED_SETTING_KEY, &paused.to_string())?;
+        }
+        *self.is_paused.lock().unwrap() = paused;
+        Ok(())
+    }
+
+    /// Check for due reminders (rescheduling repeating ones), expired deferrals, newly overdue
+    /// tasks and the scheduled digest
     /// This is the core logic that runs periodically
-    fn check_and_notify(app_handle: &AppHandle) -> Result<(), Box<dyn std::error::Error>> {
-        // Get database from app state
-        let db = app_handle.state::<Mutex<Database>>();
-        let db = db.lock().unwrap();
+    fn check_and_notify(
+        notifier: &dyn ReminderNotifier,
+        db: &Mutex<Database>,
+        is_paused: &Mutex<bool>,
+    ) -> Result<(), Box<dyn std::error::Error>> {
+        // Leave due reminders untouched so they fire once resumed
+        if *is_paused.lock().unwrap() {
+            return Ok(());
+        }
 
-        // Get due reminders
-        let repo = ReminderRepository::new(&db);
-        let due_reminders = repo.find_due_reminders()?;
+        let db = db.lock().map_err(|_| "Failed to acquire database lock")?;
+
+        Self::notify_due_reminders(notifier, &db)?;
+        Self::notify_escalations(notifier, &db)?;
+        Self::undefer_expired_tasks(&db)?;
+        Self::notify_overdue_tasks(notifier, &db)?;
+        Self::send_digest(notifier, &db, Local::now().fixed_offset())?;
+
+        Ok(())
+    }
+
+    /// Notify due reminders, mark them triggered, and reschedule repeating ones
+    fn notify_due_reminders(
+        notifier: &dyn ReminderNotifier,
+        db: &Database,
+    ) -> Result<(), Box<dyn std::error::Error>> {
+        let repo = ReminderRepository::new(db);
+        let closed_task_mode = SettingsRepository::new(db)
+            .get_app_settings()?
+            .closed_task_reminders;
+        // "skip" leaves them out in SQL, like the due counts; "deactivate" needs to see them
+        let due_reminders = repo.find_due_reminders(closed_task_mode == "skip")?;
 
         if due_reminders.is_empty() {
             return Ok(());
@@ -83,59 +261,226 @@ impl NotificationService {
 
         println!("📬 Found {} due reminders", due_reminders.len());
 
-        // Process each due reminder
         for reminder in due_reminders {
-            // Send OS notification
-            if let Err(e) = Self::send_notification(app_handle, &reminder) {
-                eprintln!(
-                    "❌ Failed to send notification for '{}': {}",
-                    reminder.title, e
-                );
+            if closed_task_mode == "deactivate" && Self::linked_task_closed(db, &reminder) {
+                if let Err(e) = repo.deactivate(&reminder.id) {
+                    eprintln!("❌ Failed to deactivate reminder: {}", e);
+                    continue;
+                }
+                println!("🔕 Reminder deactivated, task closed: {}", reminder.title);
                 continue;
             }
 
-            // Mark as triggered
-            if let Err(e) = repo.mark_as_triggered(&reminder.id) {
-                eprintln!("❌ Failed to mark reminder as triggered: {}", e);
+            // Claim the slot before notifying so it is delivered at most once
+            let claimed = match repo.record_trigger(&reminder) {
+                Ok(claimed) => claimed,
+                Err(e) => {
+                    eprintln!("❌ Failed to mark reminder as triggered: {}", e);
+                    continue;
+                }
+            };
+
+            if claimed && reminder.delivery == ReminderDelivery::InApp {
+                // Surfaced by `get_pending_inapp_reminders` when the window gains focus
+                println!("📥 In-app reminder queued: {}", reminder.title);
+            } else if claimed {
+                if let Err(e) = notifier.notify(&Self::triggered_event(db, &reminder)) {
+                    eprintln!(
+                        "❌ Failed to send notification for '{}': {}",
+                        reminder.title, e
+                    );
+                    if let Err(e) = repo.release_trigger(&reminder) {
+                        eprintln!("❌ Failed to release reminder trigger: {}", e);
+                    }
+                    continue;
+                }
+            } else {
+                // Already delivered; only make sure a repeating reminder moves on
+                println!(
+                    "⏭️ Reminder '{}' already fired for this slot",
+                    reminder.title
+                );
+            }
+
+            // Schedule next trigger for repeating reminders
+            if reminder.repeat_interval.is_repeating() {
+                if let Some(next_time) = reminder.next_trigger_after(Utc::now()) {
+                    println!(
+                        "📅 Scheduling next trigger for '{}' at: {}",
+                        reminder.title, next_time
+                    );
+                    if let Err(e) = repo.update_next_trigger_time(&reminder.id, &next_time) {
+                        eprintln!("❌ Failed to update next trigger time: {}", e);
+                    }
+                } else {
+                    println!(
+                        "⚠️ Could not calculate next trigger time for '{}'",
+                        reminder.title
+                    );
+                }
             }
 
-            // Emit event to frontend
-            if let Err(e) = app_handle.emit("reminder-triggered", reminder.clone()) {
-                eprintln!("❌ Failed to emit reminder event: {}", e);
+            if claimed {
+                println!("✅ Reminder triggered: {}", reminder.title);
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Re-notify fired reminders whose linked task is still open, up to `MAX_ESCALATIONS` times
+    fn notify_escalations(
+        notifier: &dyn ReminderNotifier,
+        db: &Database,
+    ) -> Result<(), Box<dyn std::error::Error>> {
+        let repo = ReminderRepository::new(db);
+
+        for reminder in repo.find_escalations_due()? {
+            match repo.record_escalation(&reminder) {
+                Ok(true) => {}
+                Ok(false) => continue,
+                Err(e) => {
+                    eprintln!("❌ Failed to record reminder escalation: {}", e);
+                    continue;
+                }
+            }
+
+            if let Err(e) = notifier.notify(&Self::triggered_event(db, &reminder)) {
+                eprintln!(
+                    "❌ Failed to send follow-up notification for '{}': {}",
+                    reminder.title, e
+                );
+                if let Err(e) = repo.release_escalation(&reminder) {
+                    eprintln!("❌ Failed to release reminder escalation: {}", e);
+                }
+                continue;
             }
 
-            println!("✅ Reminder triggered: {}", reminder.title);
+            println!(
+                "🔁 Reminder escalated ({}/{}): {}",
+                reminder.escalation_count + 1,
+                MAX_ESCALATIONS,
+                reminder.title
+            );
         }
 
         Ok(())
     }
 
-    /// Send an OS native notification
-    fn send_notification(
-        app_handle: &AppHandle,
-        reminder: &crate::models::Reminder,
+    /// Whether the reminder's linked task is completed or cancelled
+    fn linked_task_closed(db: &Database, reminder: &Reminder) -> bool {
+        reminder
+            .task_id
+            .as_deref()
+            .and_then(|task_id| TaskRepository::new(db).find_by_id(task_id).ok().flatten())
+            .is_some_and(|task| task.status.is_terminal())
+    }
+
+    /// Build the `reminder-triggered` payload, looking up the linked task's title and due date
+    ///
+    /// Task placeholders in the reminder's title and description are rendered (see
+    /// `render_template`). A task that has since been deleted is treated like an
+    /// unlinked reminder.
+    fn triggered_event(db: &Database, reminder: &Reminder) -> ReminderTriggeredEvent {
+        let task = reminder
+            .task_id
+            .as_deref()
+            .and_then(|task_id| TaskRepository::new(db).find_by_id(task_id).ok().flatten());
+        let task_due = task.as_ref().and_then(|task| task.due_date);
+        let (title, description) = render_template(reminder, task.as_ref());
+
+        ReminderTriggeredEvent {
+            reminder: Reminder {
+                title,
+                description,
+                ..reminder.clone()
+            },
+            lead_description: reminder.lead_description(task_due, Utc::now()),
+            task_title: task.map(|task| task.title),
+        }
+    }
+
+    /// Send the digest of due and overdue tasks once its slot on the local day has passed
+    fn send_digest(
+        notifier: &dyn ReminderNotifier,
+        db: &Database,
+        now: DateTime<FixedOffset>,
     ) -> Result<(), Box<dyn std::error::Error>> {
-        use tauri_plugin_notification::NotificationExt;
+        let settings_repo = SettingsRepository::new(db);
+        let settings = settings_repo.get_app_settings()?;
+        let last_sent = settings_repo
+            .get(DIGEST_LAST_SENT_KEY)?
+            .and_then(|sent| DateTime::parse_from_rfc3339(&sent).ok())
+            .map(|sent| sent.with_timezone(&Utc));
 
-        // Build notification
-        let notification = app_handle.notification().builder().title(&reminder.title);
+        if !settings.should_send_digest(now, last_sent) {
+            return Ok(());
+        }
 
-        // Set body (description)
-        let notification = if let Some(description) = &reminder.description {
-            notification.body(description)
+        let service = TaskService::new(db);
+        let utc_offset_minutes = now.offset().local_minus_utc() / 60;
+        let due_tasks = if settings.digest_frequency == "weekly" {
+            service.get_tasks_due_this_week(utc_offset_minutes)?
         } else {
-            notification
+            service.get_tasks_due_today(utc_offset_minutes)?
+        };
+        let digest = TaskDigestEvent {
+            frequency: settings.digest_frequency,
+            due_tasks: due_tasks
+                .into_iter()
+                .filter(|task| !task.status.is_terminal())
+                .collect(),
+            overdue_count: service.auto_update_overdue_status()?.len(),
         };
 
-        // Send notification
-        notification.show()?;
+        // Only recorded once delivered, so a failed digest is retried on the next check
+        notifier.notify_digest(&digest)?;
+        settings_repo.set(DIGEST_LAST_SENT_KEY, &Utc::now().to_rfc3339())?;
+        println!(
+            "📋 Digest sent: {} due, {} overdue",
+            digest.due_tasks.len(),
+            digest.overdue_count
+        );
 
         Ok(())
     }
 
-    /// Manually trigger a check (useful for testing or immediate checks)
-    pub fn check_now(&self) -> Result<(), Box<dyn std::error::Error>> {
-        Self::check_and_notify(&self.app_handle)
+    /// Bring back deferred tasks whose `deferred_until` has passed
+    fn undefer_expired_tasks(db: &Database) -> Result<(), Box<dyn std::error::Error>> {
+        for id in TaskRepository::new(db).undefer_expired(Utc::now())? {
+            println!("📤 Task no longer deferred: {}", id);
+        }
+
+        Ok(())
+    }
+
+    /// Notify each task that became overdue, once per due date
+    fn notify_overdue_tasks(
+        notifier: &dyn ReminderNotifier,
+        db: &Database,
+    ) -> Result<(), Box<dyn std::error::Error>> {
+        let repo = TaskRepository::new(db);
+        let grace = SettingsRepository::new(db)
+            .get_app_settings()?
+            .overdue_grace();
+
+        for task in repo.find_overdue_unnotified(grace)? {
+            if let Err(e) = notifier.notify_overdue(&task) {
+                eprintln!(
+                    "❌ Failed to send overdue notification for '{}': {}",
+                    task.title, e
+                );
+                continue;
+            }
+
+            if let Err(e) = repo.mark_overdue_notified(&task.id) {
+                eprintln!("❌ Failed to mark task as overdue-notified: {}", e);
+            }
+
+            println!("⚠️ Task overdue: {}", task.title);
+        }
+
+        Ok(())
     }
 }
 
@@ -148,7 +493,936 @@ impl Drop for NotificationService {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::models::{CreateReminderDto, CreateTaskDto, RepeatInterval, TaskPriority};
+    use crate::services::ReminderService;
+    use chrono::{Duration as ChronoDuration, Utc};
+
+    /// Records the titles of notified reminders and tasks instead of showing them
+    #[derive(Default)]
+    struct RecordingNotifier {
+        titles: Arc<Mutex<Vec<String>>>,
+        leads: Arc<Mutex<Vec<Option<String>>>>,
+        task_titles: Arc<Mutex<Vec<Option<String>>>>,
+        overdue: Arc<Mutex<Vec<String>>>,
+        digests: Arc<Mutex<Vec<TaskDigestEvent>>>,
+    }
+
+    impl ReminderNotifier for RecordingNotifier {
+        fn notify(&self, event: &ReminderTriggeredEvent) -> Result<(), Box<dyn std::error::Error>> {
+            self.titles
+                .lock()
+                .unwrap()
+                .push(event.reminder.title.clone());
+            self.leads
+                .lock()
+                .unwrap()
+                .push(event.lead_description.clone());
+            self.task_titles
+                .lock()
+                .unwrap()
+                .push(event.task_title.clone());
+            Ok(())
+        }
+
+        fn notify_overdue(&self, task: &Task) -> Result<(), Box<dyn std::error::Error>> {
+            self.overdue.lock().unwrap().push(task.title.clone());
+            Ok(())
+        }
+
+        fn notify_digest(
+            &self,
+            digest: &TaskDigestEvent,
+        ) -> Result<(), Box<dyn std::error::Error>> {
+            self.digests.lock().unwrap().push(digest.clone());
+            Ok(())
+        }
+    }
+
+    fn setup_test_db() -> Database {
+        let conn = rusqlite::Connection::open_in_memory().unwrap();
+
+        conn.execute(
+            "CREATE TABLE tasks (
+                id TEXT PRIMARY KEY,
+                title TEXT NOT NULL,
+                description TEXT,
+                status TEXT NOT NULL,
+                priority TEXT NOT NULL,
+                due_date TEXT,
+                completed_at TEXT,
+                image_path TEXT,
+                notes TEXT,
+                estimated_minutes INTEGER,
+                actual_minutes INTEGER,
+                color TEXT,
+                is_pinned INTEGER NOT NULL DEFAULT 0,
+                series_id TEXT,
+                is_deferred INTEGER NOT NULL DEFAULT 0,
+                deferred_until TEXT,
+                completion_note TEXT,
+                parent_id TEXT,
+                sort_order INTEGER NOT NULL DEFAULT 0,
+                overdue_notified_at TEXT,
+                created_at TEXT NOT NULL,
+                updated_at TEXT NOT NULL
+            )",
+            [],
+        )
+        .unwrap();
+
+        conn.execute(
+            "CREATE TABLE task_tags (
+                task_id TEXT NOT NULL,
+                tag_id TEXT NOT NULL,
+                PRIMARY KEY (task_id, tag_id)
+            )",
+            [],
+        )
+        .unwrap();
+
+        conn.execute(
+            "CREATE TABLE tags (
+                id TEXT PRIMARY KEY,
+                name TEXT NOT NULL,
+                color TEXT NOT NULL,
+                created_at TEXT NOT NULL
+            )",
+            [],
+        )
+        .unwrap();
+
+        conn.execute(
+            "CREATE TABLE task_checklist_items (
+                id TEXT PRIMARY KEY,
+                task_id TEXT NOT NULL,
+                text TEXT NOT NULL,
+                is_done INTEGER NOT NULL DEFAULT 0,
+                position INTEGER NOT NULL DEFAULT 0
+            )",
+            [],
+        )
+        .unwrap();
+
+        conn.execute(
+            "CREATE TABLE reminders (
+                id TEXT PRIMARY KEY,
+                task_id TEXT,
+                title TEXT NOT NULL,
+                description TEXT,
+                remind_at TEXT NOT NULL,
+                repeat_interval TEXT NOT NULL,
+                is_active INTEGER NOT NULL DEFAULT 1,
+                last_triggered_at TEXT,
+                created_at TEXT NOT NULL,
+                updated_at TEXT NOT NULL,
+                acknowledged_at TEXT,
+                escalation_minutes INTEGER,
+                escalation_count INTEGER NOT NULL DEFAULT 0,
+                delivery TEXT NOT NULL DEFAULT 'notification'
+            )",
+            [],
+        )
+        .unwrap();
+
+        conn.execute(
+            "CREATE TABLE reminder_trigger_log (
+                reminder_id TEXT NOT NULL,
+                scheduled_at TEXT NOT NULL,
+                fired_at TEXT NOT NULL,
+                PRIMARY KEY (reminder_id, scheduled_at)
+            )",
+            [],
+        )
+        .unwrap();
+
+        conn.execute(
+            "CREATE TABLE settings (
+                key TEXT PRIMARY KEY,
+                value TEXT NOT NULL,
+                updated_at TEXT NOT NULL DEFAULT (datetime('now'))
+            )",
+            [],
+        )
+        .unwrap();
+
+        Database::new_from_connection(conn)
+    }
+
+    fn create_due_reminder(db: &Mutex<Database>, title: &str) {
+        let db = db.lock().unwrap();
+        ReminderRepository::new(&db)
+            .create(CreateReminderDto {
+                task_id: None,
+                title: title.to_string(),
+                description: None,
+                remind_at: Utc::now() - ChronoDuration::minutes(1),
+                repeat_interval: RepeatInterval::none(),
+                escalation_minutes: None,
+                delivery: None,
+            })
+            .unwrap();
+    }
+
+    #[test]
+    fn test_in_app_reminder_skips_notification_and_is_pending_once() {
+        let db = Arc::new(Mutex::new(setup_test_db()));
+        let in_app = {
+            let db = db.lock().unwrap();
+            ReminderRepository::new(&db)
+                .create(CreateReminderDto {
+                    task_id: None,
+                    title: "Stand up".to_string(),
+                    description: None,
+                    remind_at: Utc::now() - ChronoDuration::minutes(1),
+                    repeat_interval: RepeatInterval::none(),
+                    escalation_minutes: None,
+                    delivery: Some(ReminderDelivery::InApp),
+                })
+                .unwrap()
+        };
+        create_due_reminder(&db, "Drink water");
+
+        let notifier = RecordingNotifier::default();
+        let titles = Arc::clone(&notifier.titles);
+        let service = NotificationService::new(notifier, Arc::clone(&db));
+
+        service.check_now().unwrap();
+        assert_eq!(*titles.lock().unwrap(), vec!["Drink water"]);
+
+        let db = db.lock().unwrap();
+        let service = ReminderService::new(&db);
+        let pending = service.take_pending_in_app_reminders().unwrap();
+        assert_eq!(pending.len(), 1);
+        assert_eq!(pending[0].id, in_app.id);
+        assert_eq!(pending[0].delivery, ReminderDelivery::InApp);
+        assert!(pending[0].acknowledged_at.is_some());
+
+        assert!(service.take_pending_in_app_reminders().unwrap().is_empty());
+    }
+
+    #[test]
+    fn test_check_now_triggers_and_reschedules() {
+        let db = Arc::new(Mutex::new(setup_test_db()));
+        let remind_at = Utc::now() - ChronoDuration::minutes(10);
+
+        let (once, hourly) = {
+            let db = db.lock().unwrap();
+            let repo = ReminderRepository::new(&db);
+            let create = |title: &str, interval: &str| {
+                repo.create(CreateReminderDto {
+                    task_id: None,
+                    title: title.to_string(),
+                    description: None,
+                    remind_at,
+                    repeat_interval: RepeatInterval::from_str(interval),
+                    escalation_minutes: None,
+                    delivery: None,
+                })
+                .unwrap()
+            };
+            (create("Once", "none"), create("Hourly", "every_1_hour"))
+        };
+
+        let notifier = RecordingNotifier::default();
+        let titles = Arc::clone(&notifier.titles);
+        let service = NotificationService::new(notifier, Arc::clone(&db));
+
+        service.check_now().unwrap();
+
+        let mut notified = titles.lock().unwrap().clone();
+        notified.sort();
+        assert_eq!(notified, vec!["Hourly", "Once"]);
+
+        let db = db.lock().unwrap();
+        let repo = ReminderRepository::new(&db);
+
+        let once = repo.find_by_id(&once.id).unwrap().unwrap();
+        assert!(once.last_triggered_at.is_some());
+        assert_eq!(once.remind_at, remind_at);
+
+        let hourly = repo.find_by_id(&hourly.id).unwrap().unwrap();
+        assert!(hourly.last_triggered_at.is_some());
+        assert_eq!(hourly.remind_at, remind_at + ChronoDuration::hours(1));
+    }
+
+    #[test]
+    fn test_stale_repeating_reminder_skips_to_first_future_slot() {
+        let db = Arc::new(Mutex::new(setup_test_db()));
+        let week_ago = Utc::now() - ChronoDuration::weeks(1) - ChronoDuration::minutes(20);
+
+        let reminder = {
+            let db = db.lock().unwrap();
+            let reminder = ReminderRepository::new(&db)
+                .create(CreateReminderDto {
+                    task_id: None,
+                    title: "Hourly".to_string(),
+                    description: None,
+                    remind_at: week_ago,
+                    repeat_interval: RepeatInterval::from_str("every_1_hour"),
+                    escalation_minutes: None,
+                    delivery: None,
+                })
+                .unwrap();
+            db.connection()
+                .execute(
+                    "UPDATE reminders SET last_triggered_at = ?1, acknowledged_at = ?1 WHERE id = ?2",
+                    rusqlite::params![week_ago.to_rfc3339(), reminder.id],
+                )
+                .unwrap();
+            reminder
+        };
+
+        let notifier = RecordingNotifier::default();
+        let titles = Arc::clone(&notifier.titles);
+        let service = NotificationService::new(notifier, Arc::clone(&db));
+
+        service.check_now().unwrap();
+        service.check_now().unwrap();
+
+        // One notification for the missed run, not one per missed hour
+        assert_eq!(*titles.lock().unwrap(), vec!["Hourly"]);
+
+        let db = db.lock().unwrap();
+        let next = ReminderRepository::new(&db)
+            .find_by_id(&reminder.id)
+            .unwrap()
+            .unwrap()
+            .remind_at;
+        let now = Utc::now();
+        assert!(next > now);
+        assert!(next <= now + ChronoDuration::hours(1));
+        assert_eq!((next - week_ago).num_seconds() % 3600, 0);
+    }
+
+    #[test]
+    fn test_rapid_checks_trigger_once_per_slot() {
+        let db = Arc::new(Mutex::new(setup_test_db()));
+        create_due_reminder(&db, "Once");
+
+        let notifier = RecordingNotifier::default();
+        let titles = Arc::clone(&notifier.titles);
+        let service = NotificationService::new(notifier, Arc::clone(&db));
+
+        service.check_now().unwrap();
+        service.check_now().unwrap();
+
+        assert_eq!(*titles.lock().unwrap(), vec!["Once"]);
+
+        let db = db.lock().unwrap();
+        let logged: i64 = db
+            .connection()
+            .query_row("SELECT COUNT(*) FROM reminder_trigger_log", [], |row| {
+                row.get(0)
+            })
+            .unwrap();
+        assert_eq!(logged, 1);
+    }
+
+    #[test]
+    fn test_logged_slot_not_refired_after_restart() {
+        let db = Arc::new(Mutex::new(setup_test_db()));
+        create_due_reminder(&db, "Once");
+
+        let first = RecordingNotifier::default();
+        let first_titles = Arc::clone(&first.titles);
+        NotificationService::new(first, Arc::clone(&db))
+            .check_now()
+            .unwrap();
+        assert_eq!(*first_titles.lock().unwrap(), vec!["Once"]);
+
+        // Even if last_triggered_at were lost, the log keeps the slot from firing again
+        db.lock()
+            .unwrap()
+            .connection()
+            .execute("UPDATE reminders SET last_triggered_at = NULL", [])
+            .unwrap();
+
+        let restarted = RecordingNotifier::default();
+        let restarted_titles = Arc::clone(&restarted.titles);
+        NotificationService::new(restarted, Arc::clone(&db))
+            .check_now()
+            .unwrap();
+        assert!(restarted_titles.lock().unwrap().is_empty());
+    }
+
+    #[test]
+    fn test_pause_gates_check_and_notify() {
+        let db = Arc::new(Mutex::new(setup_test_db()));
+        let notifier = RecordingNotifier::default();
+        let titles = Arc::clone(&notifier.titles);
+        let service = NotificationService::new(notifier, Arc::clone(&db));
+
+        service.pause().unwrap();
+        assert!(service.is_paused());
+
+        create_due_reminder(&db, "Held back");
+        service.check_now().unwrap();
+        assert!(titles.lock().unwrap().is_empty());
+
+        // Accumulated reminders fire on resume
+        service.resume().unwrap();
+        assert!(!service.is_paused());
+        assert_eq!(*titles.lock().unwrap(), vec!["Held back"]);
+    }
+
+    #[test]
+    fn test_pause_state_survives_restart() {
+        let db = Arc::new(Mutex::new(setup_test_db()));
+
+        let service = NotificationService::new(RecordingNotifier::default(), Arc::clone(&db));
+        assert!(!service.is_paused());
+        service.pause().unwrap();
+        drop(service);
+
+        let restarted = NotificationService::new(RecordingNotifier::default(), Arc::clone(&db));
+        assert!(restarted.is_paused());
+    }
+
+    #[test]
+    fn test_overdue_notification_waits_for_grace_period() {
+        let db = Arc::new(Mutex::new(setup_test_db()));
+        {
+            let db = db.lock().unwrap();
+            SettingsRepository::new(&db)
+                .set("overdue_grace_minutes", "15")
+                .unwrap();
+            TaskRepository::new(&db)
+                .create(CreateTaskDto {
+                    title: "Call dentist".to_string(),
+                    description: None,
+                    priority: TaskPriority::Medium,
+                    due_date: Some(Utc::now() - ChronoDuration::minutes(10)),
+                    image_path: None,
+                    notes: None,
+                    estimated_minutes: None,
+                    tag_ids: vec![],
+                    parent_id: None,
+                    color: None,
+                })
+                .unwrap();
+        }
+
+        let notifier = RecordingNotifier::default();
+        let overdue = Arc::clone(&notifier.overdue);
+        let service = NotificationService::new(notifier, Arc::clone(&db));
+
+        service.check_now().unwrap();
+        assert!(overdue.lock().unwrap().is_empty());
+
+        SettingsRepository::new(&db.lock().unwrap())
+            .set("overdue_grace_minutes", "5")
+            .unwrap();
+        service.check_now().unwrap();
+        assert_eq!(*overdue.lock().unwrap(), vec!["Call dentist"]);
+    }
+
+    #[test]
+    fn test_expired_deferral_is_lifted() {
+        let db = Arc::new(Mutex::new(setup_test_db()));
+        let (parked, until_later) = {
+            let db = db.lock().unwrap();
+            let repo = TaskRepository::new(&db);
+            let create = |title: &str| {
+                repo.create(CreateTaskDto {
+                    title: title.to_string(),
+                    description: None,
+                    priority: TaskPriority::Low,
+                    due_date: None,
+                    image_path: None,
+                    notes: None,
+                    estimated_minutes: None,
+                    tag_ids: vec![],
+                    parent_id: None,
+                    color: None,
+                })
+                .unwrap()
+            };
+            let parked = create("Plan vacation");
+            let until_later = create("Repaint fence");
+            repo.set_deferred(
+                &parked.id,
+                true,
+                Some(Utc::now() - ChronoDuration::minutes(1)),
+            )
+            .unwrap();
+            repo.set_deferred(
+                &until_later.id,
+                true,
+                Some(Utc::now() + ChronoDuration::days(7)),
+            )
+            .unwrap();
+            (parked, until_later)
+        };
+
+        NotificationService::new(RecordingNotifier::default(), Arc::clone(&db))
+            .check_now()
+            .unwrap();
+
+        let db = db.lock().unwrap();
+        let repo = TaskRepository::new(&db);
+        let parked = repo.find_by_id(&parked.id).unwrap().unwrap();
+        assert!(!parked.is_deferred);
+        assert_eq!(parked.deferred_until, None);
+        assert!(
+            repo.find_by_id(&until_later.id)
+                .unwrap()
+                .unwrap()
+                .is_deferred
+        );
+    }
+
+    #[test]
+    fn test_overdue_task_notified_only_once() {
+        let db = Arc::new(Mutex::new(setup_test_db()));
+        {
+            let db = db.lock().unwrap();
+            TaskRepository::new(&db)
+                .create(CreateTaskDto {
+                    title: "File taxes".to_string(),
+                    description: None,
+                    priority: TaskPriority::High,
+                    due_date: Some(Utc::now() - ChronoDuration::minutes(5)),
+                    image_path: None,
+                    notes: None,
+                    estimated_minutes: None,
+                    tag_ids: vec![],
+                    parent_id: None,
+                    color: None,
+                })
+                .unwrap();
+        }
+
+        let notifier = RecordingNotifier::default();
+        let overdue = Arc::clone(&notifier.overdue);
+        let service = NotificationService::new(notifier, Arc::clone(&db));
+
+        service.check_now().unwrap();
+        service.check_now().unwrap();
 
-    // Tests would require a test Tauri app setup
-    // Placeholder for future testing
+        assert_eq!(*overdue.lock().unwrap(), vec!["File taxes"]);
+    }
+
+    #[test]
+    fn test_digest_sent_once_with_due_and_overdue_tasks() {
+        let db = Arc::new(Mutex::new(setup_test_db()));
+        let now = Utc::now().fixed_offset();
+        {
+            let db = db.lock().unwrap();
+            let settings = SettingsRepository::new(&db);
+            settings.set("digest_frequency", "daily").unwrap();
+            settings.set("digest_time", "00:00").unwrap();
+
+            let repo = TaskRepository::new(&db);
+            let create = |title: &str, due_date| {
+                repo.create(CreateTaskDto {
+                    title: title.to_string(),
+                    description: None,
+                    priority: TaskPriority::Medium,
+                    due_date: Some(due_date),
+                    image_path: None,
+                    notes: None,
+                    estimated_minutes: None,
+                    tag_ids: vec![],
+                    parent_id: None,
+                    color: None,
+                })
+                .unwrap()
+            };
+            let end_of_day = now.date_naive().and_hms_opt(23, 59, 59).unwrap().and_utc();
+            create("Pay rent", end_of_day);
+            create("Renew passport", Utc::now() - ChronoDuration::days(2));
+        }
+
+        let notifier = RecordingNotifier::default();
+        let digests = Arc::clone(&notifier.digests);
+        {
+            let db = db.lock().unwrap();
+            NotificationService::send_digest(&notifier, &db, now).unwrap();
+            NotificationService::send_digest(&notifier, &db, now).unwrap();
+        }
+
+        let digests = digests.lock().unwrap();
+        assert_eq!(digests.len(), 1);
+        let titles: Vec<&str> = digests[0]
+            .due_tasks
+            .iter()
+            .map(|t| t.title.as_str())
+            .collect();
+        assert_eq!(titles, vec!["Pay rent"]);
+        assert_eq!(digests[0].overdue_count, 1);
+        assert_eq!(
+            digests[0].notification_body(),
+            "• Pay rent\n1 overdue".to_string()
+        );
+    }
+
+    #[test]
+    fn test_unacknowledged_reminder_not_refired_until_acknowledged() {
+        let db = Arc::new(Mutex::new(setup_test_db()));
+        let reminder = {
+            let db = db.lock().unwrap();
+            ReminderRepository::new(&db)
+                .create(CreateReminderDto {
+                    task_id: None,
+                    title: "Stretch".to_string(),
+                    description: None,
+                    remind_at: Utc::now() - ChronoDuration::minutes(1),
+                    repeat_interval: RepeatInterval::from_str("every_1_minute"),
+                    escalation_minutes: None,
+                    delivery: None,
+                })
+                .unwrap()
+        };
+
+        let notifier = RecordingNotifier::default();
+        let titles = Arc::clone(&notifier.titles);
+        let service = NotificationService::new(notifier, Arc::clone(&db));
+        let make_due = || {
+            db.lock()
+                .unwrap()
+                .connection()
+                .execute(
+                    "UPDATE reminders SET remind_at = ?1, last_triggered_at = ?1 WHERE id = ?2",
+                    rusqlite::params![
+                        (Utc::now() - ChronoDuration::minutes(2)).to_rfc3339(),
+                        reminder.id
+                    ],
+                )
+                .unwrap();
+        };
+
+        service.check_now().unwrap();
+        assert_eq!(*titles.lock().unwrap(), vec!["Stretch"]);
+        {
+            let db = db.lock().unwrap();
+            let unacknowledged = ReminderService::new(&db)
+                .get_unacknowledged_reminders()
+                .unwrap();
+            assert_eq!(unacknowledged.len(), 1);
+            assert!(unacknowledged[0].acknowledged_at.is_none());
+        }
+
+        // The next slot is due, but the first notification has not been seen
+        make_due();
+        service.check_now().unwrap();
+        assert_eq!(titles.lock().unwrap().len(), 1);
+
+        {
+            let db = db.lock().unwrap();
+            let reminder_service = ReminderService::new(&db);
+            let acknowledged = reminder_service.acknowledge_reminder(&reminder.id).unwrap();
+            assert!(acknowledged.acknowledged_at.is_some());
+            assert!(reminder_service
+                .get_unacknowledged_reminders()
+                .unwrap()
+                .is_empty());
+        }
+
+        service.check_now().unwrap();
+        assert_eq!(titles.lock().unwrap().len(), 2);
+        let db = db.lock().unwrap();
+        let refired = ReminderRepository::new(&db)
+            .find_by_id(&reminder.id)
+            .unwrap()
+            .unwrap();
+        assert!(refired.acknowledged_at.is_none());
+    }
+
+    /// Create an urgent task with a fired-once reminder escalating every 10 minutes
+    fn create_escalating_reminder(db: &Mutex<Database>) -> (Task, Reminder) {
+        let db = db.lock().unwrap();
+        let task = TaskRepository::new(&db)
+            .create(CreateTaskDto {
+                title: "Renew passport".to_string(),
+                description: None,
+                priority: TaskPriority::Urgent,
+                due_date: None,
+                image_path: None,
+                notes: None,
+                estimated_minutes: None,
+                tag_ids: vec![],
+                parent_id: None,
+                color: None,
+            })
+            .unwrap();
+        let reminder = ReminderRepository::new(&db)
+            .create(CreateReminderDto {
+                task_id: Some(task.id.clone()),
+                title: "Renew passport".to_string(),
+                description: None,
+                remind_at: Utc::now() - ChronoDuration::minutes(1),
+                repeat_interval: RepeatInterval::none(),
+                escalation_minutes: Some(10),
+                delivery: None,
+            })
+            .unwrap();
+        (task, reminder)
+    }
+
+    #[test]
+    fn test_triggered_event_carries_task_title_unless_deleted() {
+        let db = Arc::new(Mutex::new(setup_test_db()));
+        create_due_reminder(&db, "Standalone");
+        {
+            let db = db.lock().unwrap();
+            let task_repo = TaskRepository::new(&db);
+            let reminder_repo = ReminderRepository::new(&db);
+            for title in ["Book flights", "Cancelled trip"] {
+                let task = task_repo
+                    .create(CreateTaskDto {
+                        title: title.to_string(),
+                        description: None,
+                        priority: TaskPriority::Medium,
+                        due_date: None,
+                        image_path: None,
+                        notes: None,
+                        estimated_minutes: None,
+                        tag_ids: vec![],
+                        parent_id: None,
+                        color: None,
+                    })
+                    .unwrap();
+                reminder_repo
+                    .create(CreateReminderDto {
+                        task_id: Some(task.id),
+                        title: title.to_string(),
+                        description: None,
+                        remind_at: Utc::now() - ChronoDuration::minutes(1),
+                        repeat_interval: RepeatInterval::none(),
+                        escalation_minutes: None,
+                        delivery: None,
+                    })
+                    .unwrap();
+            }
+            // Remove the task row only, leaving its reminder dangling
+            db.connection()
+                .execute("DELETE FROM tasks WHERE title = 'Cancelled trip'", [])
+                .unwrap();
+        }
+
+        let notifier = RecordingNotifier::default();
+        let task_titles = Arc::clone(&notifier.task_titles);
+        NotificationService::new(notifier, Arc::clone(&db))
+            .check_now()
+            .unwrap();
+
+        let mut task_titles = task_titles.lock().unwrap().clone();
+        task_titles.sort();
+        assert_eq!(
+            task_titles,
+            vec![None, None, Some("Book flights".to_string())]
+        );
+    }
+
+    #[test]
+    fn test_triggered_event_carries_lead_description() {
+        let db = Arc::new(Mutex::new(setup_test_db()));
+        create_due_reminder(&db, "Standalone");
+        {
+            let db = db.lock().unwrap();
+            let task = TaskRepository::new(&db)
+                .create(CreateTaskDto {
+                    title: "Submit report".to_string(),
+                    description: None,
+                    priority: TaskPriority::High,
+                    due_date: Some(Utc::now() + ChronoDuration::minutes(150)),
+                    image_path: None,
+                    notes: None,
+                    estimated_minutes: None,
+                    tag_ids: vec![],
+                    parent_id: None,
+                    color: None,
+                })
+                .unwrap();
+            ReminderRepository::new(&db)
+                .create(CreateReminderDto {
+                    task_id: Some(task.id),
+                    title: "Submit report".to_string(),
+                    description: None,
+                    remind_at: Utc::now() - ChronoDuration::minutes(1),
+                    repeat_interval: RepeatInterval::none(),
+                    escalation_minutes: None,
+                    delivery: None,
+                })
+                .unwrap();
+        }
+
+        let notifier = RecordingNotifier::default();
+        let leads = Arc::clone(&notifier.leads);
+        NotificationService::new(notifier, Arc::clone(&db))
+            .check_now()
+            .unwrap();
+
+        let mut leads = leads.lock().unwrap().clone();
+        leads.sort();
+        assert_eq!(leads, vec![None, Some("in 2 hours".to_string())]);
+    }
+
+    /// Pretend the reminder's last trigger happened `minutes` ago
+    fn backdate_trigger(db: &Mutex<Database>, reminder_id: &str, minutes: i64) {
+        let db = db.lock().unwrap();
+        db.connection()
+            .execute(
+                "UPDATE reminders SET last_triggered_at = ?1 WHERE id = ?2",
+                rusqlite::params![
+                    (Utc::now() - ChronoDuration::minutes(minutes)).to_rfc3339(),
+                    reminder_id
+                ],
+            )
+            .unwrap();
+    }
+
+    #[test]
+    fn test_escalation_refires_until_max_count() {
+        let db = Arc::new(Mutex::new(setup_test_db()));
+        let (_task, reminder) = create_escalating_reminder(&db);
+
+        let notifier = RecordingNotifier::default();
+        let titles = Arc::clone(&notifier.titles);
+        let service = NotificationService::new(notifier, Arc::clone(&db));
+
+        service.check_now().unwrap();
+        service.check_now().unwrap();
+        assert_eq!(titles.lock().unwrap().len(), 1);
+
+        // One follow-up per check once the interval has elapsed
+        backdate_trigger(&db, &reminder.id, 11);
+        service.check_now().unwrap();
+        service.check_now().unwrap();
+        assert_eq!(titles.lock().unwrap().len(), 2);
+
+        backdate_trigger(&db, &reminder.id, 60);
+        for _ in 0..4 {
+            service.check_now().unwrap();
+        }
+        assert_eq!(titles.lock().unwrap().len(), 1 + MAX_ESCALATIONS as usize);
+
+        let db = db.lock().unwrap();
+        let escalated = ReminderRepository::new(&db)
+            .find_by_id(&reminder.id)
+            .unwrap()
+            .unwrap();
+        assert_eq!(escalated.escalation_count, MAX_ESCALATIONS);
+        assert!(escalated.next_escalation_at().is_none());
+    }
+
+    /// Create a task with `status` and a due reminder titled after it
+    fn create_task_reminder(db: &Mutex<Database>, title: &str, status: &str) -> Reminder {
+        let db = db.lock().unwrap();
+        let task = TaskRepository::new(&db)
+            .create(CreateTaskDto {
+                title: title.to_string(),
+                description: None,
+                priority: TaskPriority::Medium,
+                due_date: None,
+                image_path: None,
+                notes: None,
+                estimated_minutes: None,
+                tag_ids: vec![],
+                parent_id: None,
+                color: None,
+            })
+            .unwrap();
+        db.connection()
+            .execute(
+                "UPDATE tasks SET status = ?1 WHERE id = ?2",
+                [status, task.id.as_str()],
+            )
+            .unwrap();
+        ReminderRepository::new(&db)
+            .create(CreateReminderDto {
+                task_id: Some(task.id),
+                title: title.to_string(),
+                description: None,
+                remind_at: Utc::now() - ChronoDuration::minutes(1),
+                repeat_interval: RepeatInterval::none(),
+                escalation_minutes: None,
+                delivery: None,
+            })
+            .unwrap()
+    }
+
+    #[test]
+    fn test_reminder_for_closed_task_is_skipped() {
+        let db = Arc::new(Mutex::new(setup_test_db()));
+        SettingsRepository::new(&db.lock().unwrap())
+            .set("closed_task_reminders", "skip")
+            .unwrap();
+        create_task_reminder(&db, "Pay rent", "pending");
+        let completed = create_task_reminder(&db, "File taxes", "completed");
+
+        let notifier = RecordingNotifier::default();
+        let titles = Arc::clone(&notifier.titles);
+        let service = NotificationService::new(notifier, Arc::clone(&db));
+
+        service.check_now().unwrap();
+        assert_eq!(*titles.lock().unwrap(), vec!["Pay rent"]);
+
+        // Skipped, not consumed: still active and untriggered
+        let db = db.lock().unwrap();
+        let stored = ReminderRepository::new(&db)
+            .find_by_id(&completed.id)
+            .unwrap()
+            .unwrap();
+        assert!(stored.is_active);
+        assert!(stored.last_triggered_at.is_none());
+    }
+
+    #[test]
+    fn test_closed_task_reminder_mode_setting() {
+        let db = Arc::new(Mutex::new(setup_test_db()));
+        let cancelled = create_task_reminder(&db, "Book venue", "cancelled");
+        SettingsRepository::new(&db.lock().unwrap())
+            .set("closed_task_reminders", "deactivate")
+            .unwrap();
+
+        let notifier = RecordingNotifier::default();
+        let titles = Arc::clone(&notifier.titles);
+        let service = NotificationService::new(notifier, Arc::clone(&db));
+
+        service.check_now().unwrap();
+        assert!(titles.lock().unwrap().is_empty());
+        {
+            let db = db.lock().unwrap();
+            let stored = ReminderRepository::new(&db)
+                .find_by_id(&cancelled.id)
+                .unwrap()
+                .unwrap();
+            assert!(!stored.is_active);
+        }
+
+        create_task_reminder(&db, "Return library books", "completed");
+        SettingsRepository::new(&db.lock().unwrap())
+            .set("closed_task_reminders", "fire")
+            .unwrap();
+        service.check_now().unwrap();
+        assert_eq!(*titles.lock().unwrap(), vec!["Return library books"]);
+    }
+
+    #[test]
+    fn test_escalation_stops_once_task_completed() {
+        let db = Arc::new(Mutex::new(setup_test_db()));
+        let (task, reminder) = create_escalating_reminder(&db);
+
+        let notifier = RecordingNotifier::default();
+        let titles = Arc::clone(&notifier.titles);
+        let service = NotificationService::new(notifier, Arc::clone(&db));
+
+        service.check_now().unwrap();
+        assert_eq!(titles.lock().unwrap().len(), 1);
+
+        {
+            let db = db.lock().unwrap();
+            db.connection()
+                .execute(
+                    "UPDATE tasks SET status = 'completed' WHERE id = ?1",
+                    [&task.id],
+                )
+                .unwrap();
+        }
+        backdate_trigger(&db, &reminder.id, 60);
+        service.check_now().unwrap();
+
+        assert_eq!(titles.lock().unwrap().len(), 1);
+    }
 }
@@ -1,8 +1,18 @@
 use crate::db::Database;
 use crate::error::{DomainError, DomainResult};
-use crate::models::{CreateReminderDto, Reminder, RepeatInterval, UpdateReminderDto};
-use crate::repositories::ReminderRepository;
-use chrono::{DateTime, Utc};
+use crate::models::reminder::MAX_ESCALATION_MINUTES;
+use crate::models::{
+    CreateReminderDto, CreateReminderTemplateDto, CreateTaskDto, PaginatedResponse, Pagination,
+    Reminder, ReminderContext, ReminderFilter, ReminderShiftResult, ReminderSort, ReminderTemplate,
+    RepeatInterval, Task, TaskPriority, UndoEntityType, UpdateReminderDto,
+    UpdateReminderTemplateDto,
+};
+use crate::repositories::{
+    ReminderRepository, ReminderTemplateRepository, SettingsRepository, TaskRepository,
+};
+use crate::services::{TaskService, UndoService};
+use chrono::{DateTime, Duration, FixedOffset, NaiveDate, Utc};
+use std::collections::BTreeMap;
 
 /// Service layer for reminder business logic
 pub struct ReminderService<'a> {
@@ -21,36 +31,50 @@ impl<'a> ReminderService<'a> {
     /// - Title must not be empty and <= 200 chars
     /// - Description <= 1000 chars
     /// - remind_at must be in the future
+    /// - Repeat interval must be a known format
+    /// - Escalation needs a linked task and a positive interval
     pub fn create_reminder(&self, mut dto: CreateReminderDto) -> DomainResult<Reminder> {
         // Validate title
         dto.title = dto.title.trim().to_string();
         if dto.title.is_empty() {
-            return Err(DomainError::ValidationError(
-                "Title cannot be empty".to_string(),
-            ));
+            return Err(DomainError::field("title", "Title cannot be empty"));
         }
         if dto.title.len() > 200 {
-            return Err(DomainError::ValidationError(
-                "Title cannot exceed 200 characters".to_string(),
+            return Err(DomainError::field(
+                "title",
+                "Title cannot exceed 200 characters",
             ));
         }
 
         // Validate description
         if let Some(desc) = &dto.description {
             if desc.len() > 1000 {
-                return Err(DomainError::ValidationError(
-                    "Description cannot exceed 1000 characters".to_string(),
+                return Err(DomainError::field(
+                    "description",
+                    "Description cannot exceed 1000 characters",
                 ));
             }
         }
 
         // Validate remind_at (must be in the future)
         if dto.remind_at <= Utc::now() {
-            return Err(DomainError::InvalidDateTime(
-                "Reminder time must be in the future".to_string(),
+            return Err(DomainError::field(
+                "remind_at",
+                "Reminder time must be in the future",
             ));
         }
 
+        Self::validate_repeat_interval(&dto.repeat_interval, dto.remind_at)?;
+
+        // Validate linked task exists
+        if let Some(task_id) = &dto.task_id {
+            self.ensure_task_exists(task_id)?;
+        }
+
+        if let Some(minutes) = dto.escalation_minutes {
+            Self::validate_escalation(minutes, dto.task_id.is_some())?;
+        }
+
         // Create reminder via repository
         let repo = ReminderRepository::new(self.db);
         repo.create(dto).map_err(|e| {
@@ -63,22 +87,21 @@ impl<'a> ReminderService<'a> {
         let repo = ReminderRepository::new(self.db);
 
         // Fetch existing reminder
-        let _existing = repo
+        let existing = repo
             .find_by_id(id)
             .map_err(|e| DomainError::BusinessRuleViolation(format!("Database error: {}", e)))?
-            .ok_or_else(|| DomainError::TaskNotFound(id.to_string()))?;
+            .ok_or_else(|| DomainError::ReminderNotFound(id.to_string()))?;
 
         // Validate title if provided
         if let Some(title) = &dto.title {
             let trimmed = title.trim();
             if trimmed.is_empty() {
-                return Err(DomainError::ValidationError(
-                    "Title cannot be empty".to_string(),
-                ));
+                return Err(DomainError::field("title", "Title cannot be empty"));
             }
             if trimmed.len() > 200 {
-                return Err(DomainError::ValidationError(
-                    "Title cannot exceed 200 characters".to_string(),
+                return Err(DomainError::field(
+                    "title",
+                    "Title cannot exceed 200 characters",
                 ));
             }
         }
@@ -86,8 +109,9 @@ impl<'a> ReminderService<'a> {
         // Validate description if provided
         if let Some(desc) = &dto.description {
             if desc.len() > 1000 {
-                return Err(DomainError::ValidationError(
-                    "Description cannot exceed 1000 characters".to_string(),
+                return Err(DomainError::field(
+                    "description",
+                    "Description cannot exceed 1000 characters",
                 ));
             }
         }
@@ -95,12 +119,22 @@ impl<'a> ReminderService<'a> {
         // Validate remind_at if provided
         if let Some(remind_at) = dto.remind_at {
             if remind_at <= Utc::now() {
-                return Err(DomainError::InvalidDateTime(
-                    "Reminder time must be in the future".to_string(),
+                return Err(DomainError::field(
+                    "remind_at",
+                    "Reminder time must be in the future",
                 ));
             }
         }
 
+        if let Some(interval) = &dto.repeat_interval {
+            Self::validate_repeat_interval(interval, dto.remind_at.unwrap_or(existing.remind_at))?;
+        }
+
+        // Validate linked task exists
+        if let Some(task_id) = &dto.task_id {
+            self.ensure_task_exists(task_id)?;
+        }
+
         // Update reminder via repository
         repo.update(id, dto).map_err(|e| {
             DomainError::BusinessRuleViolation(format!("Failed to update reminder: {}", e))
@@ -118,12 +152,23 @@ impl<'a> ReminderService<'a> {
             .is_some();
 
         if !exists {
-            return Err(DomainError::TaskNotFound(id.to_string()));
+            return Err(DomainError::ReminderNotFound(id.to_string()));
         }
 
-        // Delete reminder
-        repo.delete(id).map_err(|e| {
-            DomainError::BusinessRuleViolation(format!("Failed to delete reminder: {}", e))
+        // Snapshot, delete and undo entry commit together
+        self.db.try_transaction(|_| {
+            let undo = UndoService::new(self.db);
+            let snapshot = undo.snapshot(UndoEntityType::Reminder, id)?;
+
+            // Delete reminder
+            let deleted = repo.delete(id).map_err(|e| {
+                DomainError::BusinessRuleViolation(format!("Failed to delete reminder: {}", e))
+            })?;
+
+            if deleted {
+                undo.record(UndoEntityType::Reminder, id, &snapshot)?;
+            }
+            Ok(deleted)
         })
     }
 
@@ -132,7 +177,12 @@ impl<'a> ReminderService<'a> {
         let repo = ReminderRepository::new(self.db);
         repo.find_by_id(id)
             .map_err(|e| DomainError::BusinessRuleViolation(format!("Database error: {}", e)))?
-            .ok_or_else(|| DomainError::TaskNotFound(id.to_string()))
+            .ok_or_else(|| DomainError::ReminderNotFound(id.to_string()))
+    }
+
+    /// When a reminder fires next, `None` if it is inactive or will not fire again
+    pub fn get_next_trigger(&self, id: &str) -> DomainResult<Option<DateTime<Utc>>> {
+        Ok(self.get_reminder(id)?.upcoming_trigger())
     }
 
     /// Get all reminders
@@ -143,6 +193,34 @@ impl<'a> ReminderService<'a> {
         })
     }
 
+    /// Get a reminder together with its task and the task's other reminders
+    pub fn get_reminder_context(&self, id: &str) -> DomainResult<ReminderContext> {
+        let reminder = self.get_reminder(id)?;
+
+        let task = match reminder.task_id.as_deref() {
+            Some(task_id) => TaskRepository::new(self.db)
+                .find_by_id(task_id)
+                .map_err(|e| {
+                    DomainError::BusinessRuleViolation(format!("Database error: {}", e))
+                })?,
+            None => None,
+        };
+        let siblings = match &task {
+            Some(task) => self
+                .get_reminders_by_task(&task.id)?
+                .into_iter()
+                .filter(|sibling| sibling.id != reminder.id)
+                .collect(),
+            None => Vec::new(),
+        };
+
+        Ok(ReminderContext {
+            reminder,
+            task,
+            siblings,
+        })
+    }
+
     /// Get reminders for a specific task
     pub fn get_reminders_by_task(&self, task_id: &str) -> DomainResult<Vec<Reminder>> {
         let repo = ReminderRepository::new(self.db);
@@ -151,30 +229,120 @@ impl<'a> ReminderService<'a> {
         })
     }
 
-    /// Get all reminders or filter by task_id if provided
-    pub fn get_reminders(&self, task_id: Option<&str>) -> DomainResult<Vec<Reminder>> {
+    /// Get all reminders or filter by task_id if provided, ordered by `sort`
+    pub fn get_reminders(
+        &self,
+        task_id: Option<&str>,
+        sort: Option<Vec<ReminderSort>>,
+    ) -> DomainResult<Vec<Reminder>> {
+        let repo = ReminderRepository::new(self.db);
+
+        let filter = ReminderFilter {
+            task_id: task_id.map(str::to_string),
+            ..Default::default()
+        };
+        repo.find_filtered(&filter, sort).map_err(|e| {
+            DomainError::BusinessRuleViolation(format!("Failed to fetch reminders: {}", e))
+        })
+    }
+
+    /// Get reminders matching a filter, ordered by reminder time
+    pub fn get_reminders_filtered(&self, filter: ReminderFilter) -> DomainResult<Vec<Reminder>> {
+        if let (Some(after), Some(before)) = (filter.due_after, filter.due_before) {
+            if after > before {
+                return Err(DomainError::InvalidDateTime(
+                    "due_after must not be later than due_before".to_string(),
+                ));
+            }
+        }
+
+        let repo = ReminderRepository::new(self.db);
+        repo.find_filtered(&filter, None).map_err(|e| {
+            DomainError::BusinessRuleViolation(format!("Failed to fetch reminders: {}", e))
+        })
+    }
+
+    /// Get one page of reminders matching an optional filter
+    pub fn get_reminders_paginated(
+        &self,
+        filter: Option<ReminderFilter>,
+        pagination: Pagination,
+    ) -> DomainResult<PaginatedResponse<Reminder>> {
+        if pagination.page == 0 || pagination.page_size == 0 {
+            return Err(DomainError::InvalidInput(
+                "Page and page size must be at least 1".to_string(),
+            ));
+        }
+
         let repo = ReminderRepository::new(self.db);
+        repo.find_paginated(filter, None, pagination).map_err(|e| {
+            DomainError::BusinessRuleViolation(format!("Failed to fetch reminders: {}", e))
+        })
+    }
+
+    /// Group active reminders by their local calendar date
+    ///
+    /// `offset_minutes` is the user's UTC offset, so a reminder at 23:30 UTC lands on
+    /// the next day for a +60 offset. Reminders within a day stay ordered by time.
+    pub fn group_by_day(
+        &self,
+        offset_minutes: i32,
+    ) -> DomainResult<BTreeMap<NaiveDate, Vec<Reminder>>> {
+        let offset = FixedOffset::east_opt(offset_minutes * 60).ok_or_else(|| {
+            DomainError::InvalidInput(format!("Invalid UTC offset: {} minutes", offset_minutes))
+        })?;
 
-        if let Some(tid) = task_id {
-            repo.find_by_task_id(tid).map_err(|e| {
+        let repo = ReminderRepository::new(self.db);
+        let reminders = repo
+            .find_filtered(
+                &ReminderFilter {
+                    is_active: Some(true),
+                    ..Default::default()
+                },
+                None,
+            )
+            .map_err(|e| {
                 DomainError::BusinessRuleViolation(format!("Failed to fetch reminders: {}", e))
-            })
-        } else {
-            repo.find_all().map_err(|e| {
-                DomainError::BusinessRuleViolation(format!("Failed to fetch all reminders: {}", e))
-            })
+            })?;
+
+        let mut days: BTreeMap<NaiveDate, Vec<Reminder>> = BTreeMap::new();
+        for reminder in reminders {
+            let date = reminder.remind_at.with_timezone(&offset).date_naive();
+            days.entry(date).or_default().push(reminder);
         }
+
+        Ok(days)
     }
 
     /// Get all reminders that are currently due
     /// Used by notification service to check what should fire
     pub fn get_due_reminders(&self) -> DomainResult<Vec<Reminder>> {
         let repo = ReminderRepository::new(self.db);
-        repo.find_due_reminders().map_err(|e| {
-            DomainError::BusinessRuleViolation(format!("Failed to fetch due reminders: {}", e))
+        repo.find_due_reminders(self.skips_closed_tasks()?)
+            .map_err(|e| {
+                DomainError::BusinessRuleViolation(format!("Failed to fetch due reminders: {}", e))
+            })
+    }
+
+    /// Count reminders that are currently due (see `ReminderRepository::count_due`)
+    pub fn count_due_reminders(&self) -> DomainResult<u32> {
+        let repo = ReminderRepository::new(self.db);
+        repo.count_due(self.skips_closed_tasks()?).map_err(|e| {
+            DomainError::BusinessRuleViolation(format!("Failed to count due reminders: {}", e))
         })
     }
 
+    /// Whether reminders of completed or cancelled tasks are held back from firing,
+    /// per the `closed_task_reminders` setting
+    fn skips_closed_tasks(&self) -> DomainResult<bool> {
+        let settings = SettingsRepository::new(self.db)
+            .get_app_settings()
+            .map_err(|e| {
+                DomainError::BusinessRuleViolation(format!("Failed to load settings: {}", e))
+            })?;
+        Ok(settings.closed_task_reminders != "fire")
+    }
+
     /// Mark a reminder as triggered
     /// Updates last_triggered_at timestamp
     pub fn mark_as_triggered(&self, id: &str) -> DomainResult<Reminder> {
@@ -184,7 +352,7 @@ impl<'a> ReminderService<'a> {
         let reminder = repo
             .find_by_id(id)
             .map_err(|e| DomainError::BusinessRuleViolation(format!("Database error: {}", e)))?
-            .ok_or_else(|| DomainError::TaskNotFound(id.to_string()))?;
+            .ok_or_else(|| DomainError::ReminderNotFound(id.to_string()))?;
 
         // If non-repeating, deactivate it after triggering
         if !reminder.repeat_interval.is_repeating() {
@@ -202,8 +370,220 @@ impl<'a> ReminderService<'a> {
             // Return the updated reminder
             repo.find_by_id(id)
                 .map_err(|e| DomainError::BusinessRuleViolation(format!("Database error: {}", e)))?
-                .ok_or_else(|| DomainError::TaskNotFound(id.to_string()))
+                .ok_or_else(|| DomainError::ReminderNotFound(id.to_string()))
+        }
+    }
+
+    /// Acknowledge a fired reminder so the notification loop may fire it again
+    pub fn acknowledge_reminder(&self, id: &str) -> DomainResult<Reminder> {
+        let repo = ReminderRepository::new(self.db);
+
+        repo.find_by_id(id)
+            .map_err(|e| DomainError::BusinessRuleViolation(format!("Database error: {}", e)))?
+            .ok_or_else(|| DomainError::ReminderNotFound(id.to_string()))?;
+
+        repo.acknowledge(id).map_err(|e| {
+            DomainError::BusinessRuleViolation(format!("Failed to acknowledge reminder: {}", e))
+        })
+    }
+
+    /// Dismiss the current occurrence of a fired reminder
+    ///
+    /// Acknowledges it, then moves a repeating reminder on to its next slot
+    /// while keeping it active; a one-off reminder is deactivated instead.
+    pub fn dismiss_occurrence(&self, id: &str) -> DomainResult<Reminder> {
+        let repo = ReminderRepository::new(self.db);
+        let reminder = self.get_reminder(id)?;
+
+        repo.acknowledge(id).map_err(|e| {
+            DomainError::BusinessRuleViolation(format!("Failed to acknowledge reminder: {}", e))
+        })?;
+
+        match reminder.next_trigger_after(Utc::now().max(reminder.remind_at)) {
+            Some(next) => repo.update_next_trigger_time(id, &next).map_err(|e| {
+                DomainError::BusinessRuleViolation(format!("Failed to reschedule reminder: {}", e))
+            })?,
+            None => {
+                repo.deactivate(id).map_err(|e| {
+                    DomainError::BusinessRuleViolation(format!(
+                        "Failed to deactivate reminder: {}",
+                        e
+                    ))
+                })?;
+            }
+        }
+
+        self.get_reminder(id)
+    }
+
+    /// Turn a standalone reminder into a task and link the reminder to it
+    ///
+    /// The task takes the reminder's title and description, and `remind_at` as its
+    /// due date when that is still in the future.
+    pub fn promote_to_task(&self, id: &str, priority: TaskPriority) -> DomainResult<Task> {
+        let reminder = self.get_reminder(id)?;
+        if reminder.task_id.is_some() {
+            return Err(DomainError::InvalidInput(
+                "Reminder is already linked to a task".to_string(),
+            ));
+        }
+
+        // Roll back the new task if the reminder cannot be linked to it
+        self.db.try_transaction(|_| {
+            let task = TaskService::new(self.db).create_task(CreateTaskDto {
+                title: reminder.title.clone(),
+                description: reminder.description.clone(),
+                priority,
+                due_date: Some(reminder.remind_at).filter(|remind_at| *remind_at > Utc::now()),
+                image_path: None,
+                notes: None,
+                estimated_minutes: None,
+                tag_ids: vec![],
+                parent_id: None,
+                color: None,
+            })?;
+
+            let link = UpdateReminderDto {
+                task_id: Some(task.id.clone()),
+                title: None,
+                description: None,
+                remind_at: None,
+                repeat_interval: None,
+                is_active: None,
+                delivery: None,
+            };
+            ReminderRepository::new(self.db)
+                .update(id, link)
+                .map_err(|e| {
+                    DomainError::BusinessRuleViolation(format!("Failed to link reminder: {}", e))
+                })?;
+
+            Ok(task)
+        })
+    }
+
+    /// Move a reminder to another task, or detach it with `None`
+    ///
+    /// Escalating reminders cannot be detached, since escalation needs a task.
+    pub fn reassign(&self, id: &str, new_task_id: Option<String>) -> DomainResult<Reminder> {
+        let reminder = self.get_reminder(id)?;
+
+        match &new_task_id {
+            Some(task_id) => self.ensure_task_exists(task_id)?,
+            None => {
+                if let Some(minutes) = reminder.escalation_minutes {
+                    Self::validate_escalation(minutes, false)?;
+                }
+            }
+        }
+
+        ReminderRepository::new(self.db)
+            .set_task_id(id, new_task_id.as_deref())
+            .map_err(|e| {
+                DomainError::BusinessRuleViolation(format!("Failed to reassign reminder: {}", e))
+            })
+    }
+
+    /// Set or clear (`None`) how often a fired reminder re-notifies while its task is open
+    pub fn set_reminder_escalation(
+        &self,
+        id: &str,
+        escalation_minutes: Option<i64>,
+    ) -> DomainResult<Reminder> {
+        let repo = ReminderRepository::new(self.db);
+
+        let reminder = repo
+            .find_by_id(id)
+            .map_err(|e| DomainError::BusinessRuleViolation(format!("Database error: {}", e)))?
+            .ok_or_else(|| DomainError::ReminderNotFound(id.to_string()))?;
+
+        if let Some(minutes) = escalation_minutes {
+            Self::validate_escalation(minutes, reminder.task_id.is_some())?;
+        }
+
+        repo.set_escalation(id, escalation_minutes).map_err(|e| {
+            DomainError::BusinessRuleViolation(format!("Failed to update escalation: {}", e))
+        })
+    }
+
+    /// Reject intervals the scheduler cannot understand instead of storing them silently
+    ///
+    /// The first step from `remind_at` must also stay within the representable date range.
+    fn validate_repeat_interval(
+        interval: &RepeatInterval,
+        remind_at: DateTime<Utc>,
+    ) -> DomainResult<()> {
+        let in_range = match interval.step() {
+            Some(step) => remind_at.checked_add_signed(step).is_some(),
+            None => true,
+        };
+        if interval.is_valid() && in_range {
+            Ok(())
+        } else {
+            Err(DomainError::ValidationError(format!(
+                "Invalid repeat interval: {}",
+                interval.as_str()
+            )))
+        }
+    }
+
+    /// Escalation follows up on an open task, so it needs one and a positive interval
+    fn validate_escalation(minutes: i64, has_task: bool) -> DomainResult<()> {
+        if !(1..=MAX_ESCALATION_MINUTES).contains(&minutes) {
+            return Err(DomainError::field(
+                "escalation_minutes",
+                format!(
+                    "Escalation interval must be between 1 and {} minutes",
+                    MAX_ESCALATION_MINUTES
+                ),
+            ));
+        }
+        if !has_task {
+            return Err(DomainError::field(
+                "escalation_minutes",
+                "Escalation requires a reminder linked to a task",
+            ));
+        }
+        Ok(())
+    }
+
+    /// Get active reminders that have fired but not been acknowledged
+    pub fn get_unacknowledged_reminders(&self) -> DomainResult<Vec<Reminder>> {
+        let repo = ReminderRepository::new(self.db);
+        repo.find_unacknowledged().map_err(|e| {
+            DomainError::BusinessRuleViolation(format!(
+                "Failed to fetch unacknowledged reminders: {}",
+                e
+            ))
+        })
+    }
+
+    /// Take the in-app reminders that fired since the window last had focus
+    ///
+    /// The returned reminders are acknowledged, so each firing is surfaced once.
+    pub fn take_pending_in_app_reminders(&self) -> DomainResult<Vec<Reminder>> {
+        let repo = ReminderRepository::new(self.db);
+        let mut pending = repo.find_pending_in_app().map_err(|e| {
+            DomainError::BusinessRuleViolation(format!(
+                "Failed to fetch pending in-app reminders: {}",
+                e
+            ))
+        })?;
+
+        if pending.is_empty() {
+            return Ok(pending);
         }
+
+        let now = Utc::now();
+        let ids: Vec<String> = pending.iter().map(|r| r.id.clone()).collect();
+        repo.acknowledge_all(&ids, now).map_err(|e| {
+            DomainError::BusinessRuleViolation(format!("Failed to acknowledge reminders: {}", e))
+        })?;
+        for reminder in &mut pending {
+            reminder.acknowledged_at = Some(now);
+        }
+
+        Ok(pending)
     }
 
     /// Deactivate a reminder (disable it)
@@ -241,7 +621,7 @@ impl<'a> ReminderService<'a> {
         let reminder = repo
             .find_by_id(id)
             .map_err(|e| DomainError::BusinessRuleViolation(format!("Database error: {}", e)))?
-            .ok_or_else(|| DomainError::TaskNotFound(id.to_string()))?;
+            .ok_or_else(|| DomainError::ReminderNotFound(id.to_string()))?;
 
         // Calculate new remind_at time
         let snooze_duration = if let Some(minutes) = snooze_minutes {
@@ -255,27 +635,335 @@ impl<'a> ReminderService<'a> {
 
         // Update reminder
         let update_dto = UpdateReminderDto {
+            task_id: None,
             title: None,
             description: None,
             remind_at: Some(new_remind_at),
             repeat_interval: None,
             is_active: Some(true),
+            delivery: None,
         };
 
         repo.update(id, update_dto).map_err(|e| {
             DomainError::BusinessRuleViolation(format!("Failed to snooze reminder: {}", e))
         })
     }
+
+    /// Snooze every currently due reminder by `minutes` from now, returning how many moved
+    ///
+    /// All reminders are rescheduled in a single transaction; reminders that are not
+    /// due yet are left untouched.
+    pub fn snooze_all_due(&self, minutes: i64) -> DomainResult<usize> {
+        let snooze = Duration::try_minutes(minutes)
+            .filter(|d| *d > Duration::zero())
+            .ok_or_else(|| DomainError::field("minutes", "Snooze minutes must be positive"))?;
+
+        let remind_at = Utc::now()
+            .checked_add_signed(snooze)
+            .ok_or_else(|| DomainError::field("minutes", "Snooze minutes are too large"))?;
+        let remind_times: Vec<(String, DateTime<Utc>)> = self
+            .get_due_reminders()?
+            .into_iter()
+            .map(|reminder| (reminder.id, remind_at))
+            .collect();
+
+        ReminderRepository::new(self.db)
+            .set_remind_times(&remind_times)
+            .map_err(|e| {
+                DomainError::BusinessRuleViolation(format!("Failed to snooze reminders: {}", e))
+            })
+    }
+
+    /// Move every reminder's `remind_at` by `delta`, e.g. after a time zone or DST change
+    ///
+    /// Only active reminders are moved when `only_active` is set. A reminder that would
+    /// land in the past is skipped, or moved to now instead when `clamp_to_now` is set.
+    /// All shifts are applied in a single transaction.
+    pub fn shift_all(
+        &self,
+        delta: Duration,
+        only_active: bool,
+        clamp_to_now: bool,
+    ) -> DomainResult<ReminderShiftResult> {
+        if delta.is_zero() {
+            return Err(DomainError::ValidationError(
+                "Shift duration must not be zero".to_string(),
+            ));
+        }
+
+        let repo = ReminderRepository::new(self.db);
+
+        let reminders = repo.find_all().map_err(|e| {
+            DomainError::BusinessRuleViolation(format!("Failed to fetch reminders: {}", e))
+        })?;
+
+        let now = Utc::now();
+        let mut remind_times = Vec::new();
+        let mut skipped = 0;
+        for reminder in reminders {
+            if only_active && !reminder.is_active {
+                continue;
+            }
+
+            let shifted = reminder
+                .remind_at
+                .checked_add_signed(delta)
+                .ok_or_else(|| {
+                    DomainError::field(
+                        "hours",
+                        format!(
+                            "Shifting reminder {} by {} minutes is out of range",
+                            reminder.id,
+                            delta.num_minutes()
+                        ),
+                    )
+                })?;
+            if shifted > now {
+                remind_times.push((reminder.id, shifted));
+            } else if clamp_to_now {
+                remind_times.push((reminder.id, now));
+            } else {
+                skipped += 1;
+            }
+        }
+
+        repo.set_remind_times(&remind_times).map_err(|e| {
+            DomainError::BusinessRuleViolation(format!("Failed to shift reminders: {}", e))
+        })?;
+
+        Ok(ReminderShiftResult {
+            shifted_ids: remind_times.into_iter().map(|(id, _)| id).collect(),
+            skipped,
+        })
+    }
+
+    /// Create a reminder template
+    ///
+    /// Business rules:
+    /// - Name cannot be empty or exceed 100 characters
+    /// - Title and description follow the reminder rules
+    /// - The default offset must be at least one minute, so instances are in the future
+    pub fn create_template(
+        &self,
+        mut dto: CreateReminderTemplateDto,
+    ) -> DomainResult<ReminderTemplate> {
+        dto.name = dto.name.trim().to_string();
+        dto.title = dto.title.trim().to_string();
+        Self::validate_template_fields(
+            Some(&dto.name),
+            Some(&dto.title),
+            dto.description.as_deref(),
+            Some(dto.default_offset_minutes),
+        )?;
+
+        ReminderTemplateRepository::new(self.db)
+            .create(dto)
+            .map_err(|e| {
+                DomainError::BusinessRuleViolation(format!("Failed to create template: {}", e))
+            })
+    }
+
+    /// Update a reminder template
+    pub fn update_template(
+        &self,
+        id: &str,
+        mut dto: UpdateReminderTemplateDto,
+    ) -> DomainResult<ReminderTemplate> {
+        self.get_template(id)?;
+
+        dto.name = dto.name.map(|name| name.trim().to_string());
+        dto.title = dto.title.map(|title| title.trim().to_string());
+        Self::validate_template_fields(
+            dto.name.as_deref(),
+            dto.title.as_deref(),
+            dto.description.as_deref(),
+            dto.default_offset_minutes,
+        )?;
+
+        ReminderTemplateRepository::new(self.db)
+            .update(id, dto)
+            .map_err(|e| {
+                DomainError::BusinessRuleViolation(format!("Failed to update template: {}", e))
+            })
+    }
+
+    /// Delete a reminder template; reminders created from it are kept
+    pub fn delete_template(&self, id: &str) -> DomainResult<bool> {
+        self.get_template(id)?;
+
+        ReminderTemplateRepository::new(self.db)
+            .delete(id)
+            .map_err(|e| {
+                DomainError::BusinessRuleViolation(format!("Failed to delete template: {}", e))
+            })
+    }
+
+    /// Get a reminder template by ID
+    pub fn get_template(&self, id: &str) -> DomainResult<ReminderTemplate> {
+        ReminderTemplateRepository::new(self.db)
+            .find_by_id(id)
+            .map_err(|e| DomainError::BusinessRuleViolation(format!("Database error: {}", e)))?
+            .ok_or_else(|| DomainError::ReminderTemplateNotFound(id.to_string()))
+    }
+
+    /// Get all reminder templates ordered by name
+    pub fn get_templates(&self) -> DomainResult<Vec<ReminderTemplate>> {
+        ReminderTemplateRepository::new(self.db)
+            .find_all()
+            .map_err(|e| {
+                DomainError::BusinessRuleViolation(format!("Failed to fetch templates: {}", e))
+            })
+    }
+
+    /// Create a reminder from a template, due `default_offset_minutes` from now
+    pub fn create_reminder_from_template(&self, template_id: &str) -> DomainResult<Reminder> {
+        let template = self.get_template(template_id)?;
+
+        self.create_reminder(CreateReminderDto {
+            task_id: None,
+            title: template.title,
+            description: template.description,
+            remind_at: Utc::now() + Duration::minutes(template.default_offset_minutes),
+            repeat_interval: template.repeat_interval,
+            escalation_minutes: None,
+            delivery: None,
+        })
+    }
+
+    /// Validate the template fields being set; `None` means unchanged
+    fn validate_template_fields(
+        name: Option<&str>,
+        title: Option<&str>,
+        description: Option<&str>,
+        default_offset_minutes: Option<i64>,
+    ) -> DomainResult<()> {
+        if let Some(name) = name {
+            if name.is_empty() {
+                return Err(DomainError::field("name", "Name cannot be empty"));
+            }
+            if name.len() > 100 {
+                return Err(DomainError::field(
+                    "name",
+                    "Name cannot exceed 100 characters",
+                ));
+            }
+        }
+
+        if let Some(title) = title {
+            if title.is_empty() {
+                return Err(DomainError::field("title", "Title cannot be empty"));
+            }
+            if title.len() > 200 {
+                return Err(DomainError::field(
+                    "title",
+                    "Title cannot exceed 200 characters",
+                ));
+            }
+        }
+
+        if let Some(desc) = description {
+            if desc.len() > 1000 {
+                return Err(DomainError::field(
+                    "description",
+                    "Description cannot exceed 1000 characters",
+                ));
+            }
+        }
+
+        if let Some(offset) = default_offset_minutes {
+            if offset < 1 {
+                return Err(DomainError::field(
+                    "default_offset_minutes",
+                    "Default offset must be at least 1 minute",
+                ));
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Ensure a reminder's linked task exists
+    fn ensure_task_exists(&self, task_id: &str) -> DomainResult<()> {
+        TaskRepository::new(self.db)
+            .find_by_id(task_id)
+            .map_err(|e| DomainError::BusinessRuleViolation(format!("Database error: {}", e)))?
+            .ok_or_else(|| DomainError::TaskNotFound(task_id.to_string()))?;
+        Ok(())
+    }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::error::AppError;
     use chrono::Duration;
 
     fn setup_test_db() -> Database {
         let conn = rusqlite::Connection::open_in_memory().unwrap();
 
+        // Tables needed to look up linked tasks
+        conn.execute(
+            "CREATE TABLE tasks (
+                id TEXT PRIMARY KEY,
+                title TEXT NOT NULL,
+                description TEXT,
+                status TEXT NOT NULL,
+                priority TEXT NOT NULL,
+                due_date TEXT,
+                completed_at TEXT,
+                image_path TEXT,
+                notes TEXT,
+                estimated_minutes INTEGER,
+                actual_minutes INTEGER,
+                color TEXT,
+                is_pinned INTEGER NOT NULL DEFAULT 0,
+                series_id TEXT,
+                is_deferred INTEGER NOT NULL DEFAULT 0,
+                deferred_until TEXT,
+                completion_note TEXT,
+                parent_id TEXT,
+                sort_order INTEGER NOT NULL DEFAULT 0,
+                overdue_notified_at TEXT,
+                created_at TEXT NOT NULL,
+                updated_at TEXT NOT NULL
+            )",
+            [],
+        )
+        .unwrap();
+
+        conn.execute(
+            "CREATE TABLE task_tags (
+                task_id TEXT NOT NULL,
+                tag_id TEXT NOT NULL,
+                PRIMARY KEY (task_id, tag_id)
+            )",
+            [],
+        )
+        .unwrap();
+
+        conn.execute(
+            "CREATE TABLE tags (
+                id TEXT PRIMARY KEY,
+                name TEXT NOT NULL,
+                color TEXT NOT NULL,
+                created_at TEXT NOT NULL
+            )",
+            [],
+        )
+        .unwrap();
+
+        conn.execute(
+            "CREATE TABLE task_checklist_items (
+                id TEXT PRIMARY KEY,
+                task_id TEXT NOT NULL,
+                text TEXT NOT NULL,
+                is_done INTEGER NOT NULL DEFAULT 0,
+                position INTEGER NOT NULL DEFAULT 0
+            )",
+            [],
+        )
+        .unwrap();
+
         // Create reminders table schema
         conn.execute(
             "CREATE TABLE reminders (
@@ -288,27 +976,348 @@ mod tests {
                 is_active INTEGER NOT NULL DEFAULT 1,
                 last_triggered_at TEXT,
                 created_at TEXT NOT NULL,
-                updated_at TEXT NOT NULL
+                updated_at TEXT NOT NULL,
+                acknowledged_at TEXT,
+                escalation_minutes INTEGER,
+                escalation_count INTEGER NOT NULL DEFAULT 0,
+                delivery TEXT NOT NULL DEFAULT 'notification'
             )",
             [],
         )
         .unwrap();
 
-        Database::new_from_connection(conn)
-    }
+        conn.execute(
+            "CREATE TABLE reminder_trigger_log (
+                reminder_id TEXT NOT NULL,
+                scheduled_at TEXT NOT NULL,
+                fired_at TEXT NOT NULL,
+                PRIMARY KEY (reminder_id, scheduled_at)
+            )",
+            [],
+        )
+        .unwrap();
 
-    #[test]
-    fn test_create_reminder_success() {
-        let db = setup_test_db();
-        let service = ReminderService::new(&db);
+        conn.execute(
+            "CREATE TABLE settings (
+                key TEXT PRIMARY KEY,
+                value TEXT NOT NULL,
+                updated_at TEXT NOT NULL DEFAULT (datetime('now'))
+            )",
+            [],
+        )
+        .unwrap();
 
-        let future_time = Utc::now() + Duration::hours(1);
-        let dto = CreateReminderDto {
-            task_id: Some("task-1".to_string()),
+        conn.execute(
+            "CREATE TABLE reminder_templates (
+                id TEXT PRIMARY KEY,
+                name TEXT NOT NULL,
+                title TEXT NOT NULL,
+                description TEXT,
+                repeat_interval TEXT NOT NULL,
+                default_offset_minutes INTEGER NOT NULL,
+                created_at TEXT NOT NULL,
+                updated_at TEXT NOT NULL
+            )",
+            [],
+        )
+        .unwrap();
+
+        conn.execute(
+            "CREATE TABLE undo_log (
+                id INTEGER PRIMARY KEY AUTOINCREMENT,
+                entity_type TEXT NOT NULL,
+                entity_id TEXT NOT NULL,
+                snapshot TEXT NOT NULL,
+                deleted_at TEXT NOT NULL
+            )",
+            [],
+        )
+        .unwrap();
+
+        Database::new_from_connection(conn)
+    }
+
+    fn create_task(db: &Database) -> String {
+        TaskRepository::new(db)
+            .create(CreateTaskDto {
+                title: "Linked task".to_string(),
+                description: None,
+                priority: TaskPriority::Medium,
+                due_date: None,
+                image_path: None,
+                notes: None,
+                estimated_minutes: None,
+                tag_ids: vec![],
+                parent_id: None,
+                color: None,
+            })
+            .unwrap()
+            .id
+    }
+
+    fn create_linked_reminder(
+        service: &ReminderService,
+        task_id: Option<String>,
+        title: &str,
+        hours: i64,
+    ) -> Reminder {
+        service
+            .create_reminder(CreateReminderDto {
+                task_id,
+                title: title.to_string(),
+                description: None,
+                remind_at: Utc::now() + Duration::hours(hours),
+                repeat_interval: RepeatInterval::none(),
+                escalation_minutes: None,
+                delivery: None,
+            })
+            .unwrap()
+    }
+
+    fn backdate_remind_at(db: &Database, id: &str, remind_at: DateTime<Utc>) {
+        db.connection()
+            .execute(
+                "UPDATE reminders SET remind_at = ?1 WHERE id = ?2",
+                rusqlite::params![remind_at.to_rfc3339(), id],
+            )
+            .unwrap();
+    }
+
+    #[test]
+    fn test_dismiss_occurrence_advances_repeating_reminder() {
+        let db = setup_test_db();
+        let service = ReminderService::new(&db);
+        let reminder = service
+            .create_reminder(CreateReminderDto {
+                task_id: None,
+                title: "Stand up".to_string(),
+                description: None,
+                remind_at: Utc::now() + Duration::hours(1),
+                repeat_interval: RepeatInterval::from_str("every_1_hours"),
+                escalation_minutes: None,
+                delivery: None,
+            })
+            .unwrap();
+        let fired_at = Utc::now() - Duration::minutes(30);
+        backdate_remind_at(&db, &reminder.id, fired_at);
+
+        let dismissed = service.dismiss_occurrence(&reminder.id).unwrap();
+
+        assert!(dismissed.is_active);
+        assert!(dismissed.acknowledged_at.is_some());
+        assert_eq!(dismissed.remind_at, fired_at + Duration::hours(1));
+        assert!(dismissed.remind_at > Utc::now());
+    }
+
+    #[test]
+    fn test_dismiss_occurrence_deactivates_one_off_reminder() {
+        let db = setup_test_db();
+        let service = ReminderService::new(&db);
+        let reminder = create_linked_reminder(&service, None, "Call back", 1);
+        let fired_at = Utc::now() - Duration::minutes(5);
+        backdate_remind_at(&db, &reminder.id, fired_at);
+
+        let dismissed = service.dismiss_occurrence(&reminder.id).unwrap();
+
+        assert!(!dismissed.is_active);
+        assert!(dismissed.acknowledged_at.is_some());
+        assert_eq!(dismissed.remind_at, fired_at);
+
+        assert!(matches!(
+            service.dismiss_occurrence("missing"),
+            Err(DomainError::ReminderNotFound(_))
+        ));
+    }
+
+    #[test]
+    fn test_promote_reminder_to_task() {
+        let db = setup_test_db();
+        let service = ReminderService::new(&db);
+        let reminder = service
+            .create_reminder(CreateReminderDto {
+                task_id: None,
+                title: "Plan offsite".to_string(),
+                description: Some("Venue and agenda".to_string()),
+                remind_at: Utc::now() + Duration::days(2),
+                repeat_interval: RepeatInterval::none(),
+                escalation_minutes: None,
+                delivery: None,
+            })
+            .unwrap();
+
+        let task = service
+            .promote_to_task(&reminder.id, TaskPriority::High)
+            .unwrap();
+
+        assert_eq!(task.title, "Plan offsite");
+        assert_eq!(task.description.as_deref(), Some("Venue and agenda"));
+        assert_eq!(task.priority, TaskPriority::High);
+        assert_eq!(task.due_date, Some(reminder.remind_at));
+        let relinked = service.get_reminder(&reminder.id).unwrap();
+        assert_eq!(relinked.task_id, Some(task.id.clone()));
+        assert_eq!(relinked.remind_at, reminder.remind_at);
+
+        assert!(matches!(
+            service.promote_to_task(&reminder.id, TaskPriority::Low),
+            Err(DomainError::InvalidInput(_))
+        ));
+    }
+
+    #[test]
+    fn test_promote_fired_reminder_has_no_due_date() {
+        let db = setup_test_db();
+        let service = ReminderService::new(&db);
+        let reminder = create_linked_reminder(&service, None, "Call plumber", 1);
+        backdate_remind_at(&db, &reminder.id, Utc::now() - Duration::hours(1));
+
+        let task = service
+            .promote_to_task(&reminder.id, TaskPriority::Medium)
+            .unwrap();
+
+        assert_eq!(task.due_date, None);
+        assert_eq!(
+            service.get_reminder(&reminder.id).unwrap().task_id,
+            Some(task.id)
+        );
+        assert!(matches!(
+            service.promote_to_task("missing", TaskPriority::Medium),
+            Err(DomainError::ReminderNotFound(_))
+        ));
+    }
+
+    #[test]
+    fn test_promote_rolls_back_task_when_link_fails() {
+        let db = setup_test_db();
+        let service = ReminderService::new(&db);
+        let reminder = create_linked_reminder(&service, None, "Call plumber", 1);
+        db.connection()
+            .execute_batch(
+                "CREATE TRIGGER reject_relink BEFORE UPDATE OF task_id ON reminders
+                 BEGIN SELECT RAISE(ABORT, 'rejected'); END;",
+            )
+            .unwrap();
+
+        assert!(service
+            .promote_to_task(&reminder.id, TaskPriority::Medium)
+            .is_err());
+        let tasks: i64 = db
+            .connection()
+            .query_row("SELECT COUNT(*) FROM tasks", [], |row| row.get(0))
+            .unwrap();
+        assert_eq!(tasks, 0);
+    }
+
+    #[test]
+    fn test_reassign_reminder_to_other_task() {
+        let db = setup_test_db();
+        let service = ReminderService::new(&db);
+        let original = create_task(&db);
+        let target = create_task(&db);
+        let reminder = create_linked_reminder(&service, Some(original), "Follow up", 1);
+
+        let moved = service
+            .reassign(&reminder.id, Some(target.clone()))
+            .unwrap();
+
+        assert_eq!(moved.task_id, Some(target.clone()));
+        let on_target = service.get_reminders_by_task(&target).unwrap();
+        assert_eq!(on_target.len(), 1);
+        assert_eq!(on_target[0].id, reminder.id);
+    }
+
+    #[test]
+    fn test_reassign_reminder_detach() {
+        let db = setup_test_db();
+        let service = ReminderService::new(&db);
+        let task_id = create_task(&db);
+        let reminder = create_linked_reminder(&service, Some(task_id.clone()), "Follow up", 1);
+
+        let detached = service.reassign(&reminder.id, None).unwrap();
+        assert_eq!(detached.task_id, None);
+
+        // An escalating reminder must stay linked
+        let escalating = create_linked_reminder(&service, Some(task_id), "Nag", 2);
+        service
+            .set_reminder_escalation(&escalating.id, Some(10))
+            .unwrap();
+        assert!(matches!(
+            service.set_reminder_escalation(&escalating.id, Some(MAX_ESCALATION_MINUTES + 1)),
+            Err(DomainError::FieldValidation { ref field, .. }) if field == "escalation_minutes"
+        ));
+        assert!(matches!(
+            service.reassign(&escalating.id, None),
+            Err(DomainError::FieldValidation { ref field, .. }) if field == "escalation_minutes"
+        ));
+    }
+
+    #[test]
+    fn test_reassign_reminder_rejects_missing_task() {
+        let db = setup_test_db();
+        let service = ReminderService::new(&db);
+        let task_id = create_task(&db);
+        let reminder = create_linked_reminder(&service, Some(task_id.clone()), "Follow up", 1);
+
+        let result = service.reassign(&reminder.id, Some("missing".to_string()));
+
+        assert!(matches!(result, Err(DomainError::TaskNotFound(ref id)) if id == "missing"));
+        assert_eq!(
+            service.get_reminder(&reminder.id).unwrap().task_id,
+            Some(task_id)
+        );
+    }
+
+    #[test]
+    fn test_reminder_context_with_task() {
+        let db = setup_test_db();
+        let service = ReminderService::new(&db);
+        let task_id = create_task(&db);
+        let first = create_linked_reminder(&service, Some(task_id.clone()), "Draft", 1);
+        let second = create_linked_reminder(&service, Some(task_id.clone()), "Review", 2);
+        let third = create_linked_reminder(&service, Some(task_id.clone()), "Send", 3);
+        create_linked_reminder(&service, None, "Unrelated", 1);
+
+        let context = service.get_reminder_context(&second.id).unwrap();
+
+        assert_eq!(context.reminder.id, second.id);
+        assert_eq!(context.task.map(|task| task.id), Some(task_id));
+        let sibling_ids: Vec<String> = context.siblings.into_iter().map(|r| r.id).collect();
+        assert_eq!(sibling_ids, vec![first.id, third.id]);
+    }
+
+    #[test]
+    fn test_reminder_context_standalone() {
+        let db = setup_test_db();
+        let service = ReminderService::new(&db);
+        let standalone = create_linked_reminder(&service, None, "Stretch", 1);
+        create_linked_reminder(&service, None, "Drink water", 2);
+
+        let context = service.get_reminder_context(&standalone.id).unwrap();
+
+        assert_eq!(context.reminder.id, standalone.id);
+        assert!(context.task.is_none());
+        assert!(context.siblings.is_empty());
+
+        assert!(matches!(
+            service.get_reminder_context("missing"),
+            Err(DomainError::ReminderNotFound(_))
+        ));
+    }
+
+    #[test]
+    fn test_create_reminder_success() {
+        let db = setup_test_db();
+        let service = ReminderService::new(&db);
+        let task_id = create_task(&db);
+
+        let future_time = Utc::now() + Duration::hours(1);
+        let dto = CreateReminderDto {
+            task_id: Some(task_id),
             title: "Test Reminder".to_string(),
             description: Some("Test description".to_string()),
             remind_at: future_time,
             repeat_interval: RepeatInterval::none(),
+            escalation_minutes: None,
+            delivery: None,
         };
 
         let result = service.create_reminder(dto);
@@ -331,13 +1340,15 @@ mod tests {
             description: None,
             remind_at: future_time,
             repeat_interval: RepeatInterval::none(),
+            escalation_minutes: None,
+            delivery: None,
         };
 
         let result = service.create_reminder(dto);
         assert!(result.is_err());
         assert!(matches!(
             result.unwrap_err(),
-            DomainError::ValidationError(_)
+            DomainError::FieldValidation { ref field, .. } if field == "title"
         ));
     }
 
@@ -354,13 +1365,15 @@ mod tests {
             description: None,
             remind_at: future_time,
             repeat_interval: RepeatInterval::none(),
+            escalation_minutes: None,
+            delivery: None,
         };
 
         let result = service.create_reminder(dto);
         assert!(result.is_err());
         assert!(matches!(
             result.unwrap_err(),
-            DomainError::ValidationError(_)
+            DomainError::FieldValidation { ref field, .. } if field == "title"
         ));
     }
 
@@ -376,16 +1389,65 @@ mod tests {
             description: None,
             remind_at: past_time,
             repeat_interval: RepeatInterval::none(),
+            escalation_minutes: None,
+            delivery: None,
         };
 
         let result = service.create_reminder(dto);
         assert!(result.is_err());
         assert!(matches!(
             result.unwrap_err(),
-            DomainError::InvalidDateTime(_)
+            DomainError::FieldValidation { ref field, .. } if field == "remind_at"
         ));
     }
 
+    #[test]
+    fn test_create_and_update_reminder_reject_malformed_interval() {
+        let db = setup_test_db();
+        let service = ReminderService::new(&db);
+        let dto = |interval: &str| CreateReminderDto {
+            task_id: None,
+            title: "Stretch".to_string(),
+            description: None,
+            remind_at: Utc::now() + Duration::hours(1),
+            repeat_interval: RepeatInterval::from_str(interval),
+            escalation_minutes: None,
+            delivery: None,
+        };
+
+        let result = service.create_reminder(dto("every_10_minutess"));
+        assert!(matches!(result, Err(DomainError::ValidationError(_))));
+
+        // Too large to represent, or stepping past the last representable date
+        for interval in ["every_9999999999_years", "every_100000000_days"] {
+            let result = service.create_reminder(dto(interval));
+            assert!(
+                matches!(result, Err(DomainError::ValidationError(_))),
+                "{}",
+                interval
+            );
+        }
+
+        let reminder = service.create_reminder(dto("every_10_minutes")).unwrap();
+        let result = service.update_reminder(
+            &reminder.id,
+            UpdateReminderDto {
+                task_id: None,
+                title: None,
+                description: None,
+                remind_at: None,
+                repeat_interval: Some(RepeatInterval::from_str("daily")),
+                is_active: None,
+                delivery: None,
+            },
+        );
+        assert!(matches!(result, Err(DomainError::ValidationError(_))));
+        assert_eq!(
+            service.get_reminder(&reminder.id).unwrap().repeat_interval,
+            RepeatInterval::from_str("every_10_minutes")
+        );
+    }
+
     #[test]
     fn test_delete_reminder_success() {
         let db = setup_test_db();
@@ -399,6 +1461,8 @@ mod tests {
             description: None,
             remind_at: future_time,
             repeat_interval: RepeatInterval::none(),
+            escalation_minutes: None,
+            delivery: None,
         };
         let reminder = service.create_reminder(dto).unwrap();
 
@@ -425,23 +1489,27 @@ mod tests {
             description: None,
             remind_at: future_time,
             repeat_interval: RepeatInterval::none(),
+            escalation_minutes: None,
+            delivery: None,
         };
         let reminder = service.create_reminder(dto).unwrap();
 
         // Try to update with empty title
         let update_dto = UpdateReminderDto {
+            task_id: None,
             title: Some("   ".to_string()), // Whitespace
             description: None,
             remind_at: None,
             repeat_interval: None,
             is_active: None,
+            delivery: None,
         };
 
         let result = service.update_reminder(&reminder.id, update_dto);
         assert!(result.is_err());
         assert!(matches!(
             result.unwrap_err(),
-            DomainError::ValidationError(_)
+            DomainError::FieldValidation { ref field, .. } if field == "title"
         ));
     }
 
@@ -458,6 +1526,8 @@ mod tests {
             description: None,
             remind_at: future_time,
             repeat_interval: RepeatInterval::none(),
+            escalation_minutes: None,
+            delivery: None,
         };
         let reminder = service.create_reminder(dto).unwrap();
 
@@ -480,4 +1550,414 @@ mod tests {
         );
         assert!(snoozed.is_active);
     }
+
+    #[test]
+    fn test_reminder_task_must_exist() {
+        let db = setup_test_db();
+        let service = ReminderService::new(&db);
+
+        let dto = CreateReminderDto {
+            task_id: Some("missing-task".to_string()),
+            title: "Dangling".to_string(),
+            description: None,
+            remind_at: Utc::now() + Duration::hours(1),
+            repeat_interval: RepeatInterval::none(),
+            escalation_minutes: None,
+            delivery: None,
+        };
+        let result = service.create_reminder(dto);
+        assert!(matches!(result, Err(DomainError::TaskNotFound(_))));
+
+        let dto = CreateReminderDto {
+            task_id: None,
+            title: "Standalone".to_string(),
+            description: None,
+            remind_at: Utc::now() + Duration::hours(1),
+            repeat_interval: RepeatInterval::none(),
+            escalation_minutes: None,
+            delivery: None,
+        };
+        let reminder = service.create_reminder(dto).unwrap();
+
+        let update_dto = UpdateReminderDto {
+            task_id: Some("missing-task".to_string()),
+            title: None,
+            description: None,
+            remind_at: None,
+            repeat_interval: None,
+            is_active: None,
+            delivery: None,
+        };
+        let result = service.update_reminder(&reminder.id, update_dto);
+        assert!(matches!(result, Err(DomainError::TaskNotFound(_))));
+
+        let task_id = create_task(&db);
+        let update_dto = UpdateReminderDto {
+            task_id: Some(task_id.clone()),
+            title: None,
+            description: None,
+            remind_at: None,
+            repeat_interval: None,
+            is_active: None,
+            delivery: None,
+        };
+        let updated = service.update_reminder(&reminder.id, update_dto).unwrap();
+        assert_eq!(updated.task_id, Some(task_id));
+    }
+
+    #[test]
+    fn test_group_by_day_uses_local_date() {
+        let db = setup_test_db();
+        let service = ReminderService::new(&db);
+        let repo = ReminderRepository::new(&db);
+
+        let at = |rfc3339: &str| {
+            DateTime::parse_from_rfc3339(rfc3339)
+                .unwrap()
+                .with_timezone(&Utc)
+        };
+        for (title, remind_at) in [
+            ("Late evening", "2030-01-01T16:59:00Z"),
+            ("Just after midnight", "2030-01-01T17:30:00Z"),
+            ("Next afternoon", "2030-01-02T08:00:00Z"),
+        ] {
+            repo.create(CreateReminderDto {
+                task_id: None,
+                title: title.to_string(),
+                description: None,
+                remind_at: at(remind_at),
+                repeat_interval: RepeatInterval::none(),
+                escalation_minutes: None,
+                delivery: None,
+            })
+            .unwrap();
+        }
+
+        // UTC+7: 17:00 UTC is local midnight
+        let days = service.group_by_day(7 * 60).unwrap();
+        let titles = |date: &str| -> Vec<String> {
+            days[&date.parse::<NaiveDate>().unwrap()]
+                .iter()
+                .map(|r| r.title.clone())
+                .collect()
+        };
+
+        assert_eq!(days.len(), 2);
+        assert_eq!(titles("2030-01-01"), vec!["Late evening"]);
+        assert_eq!(
+            titles("2030-01-02"),
+            vec!["Just after midnight", "Next afternoon"]
+        );
+
+        // In UTC the first two share a day
+        let utc_days = service.group_by_day(0).unwrap();
+        assert_eq!(utc_days.len(), 2);
+        assert_eq!(
+            utc_days[&"2030-01-01".parse::<NaiveDate>().unwrap()].len(),
+            2
+        );
+    }
+
+    #[test]
+    fn test_group_by_day_skips_inactive_and_rejects_bad_offset() {
+        let db = setup_test_db();
+        let service = ReminderService::new(&db);
+        let repo = ReminderRepository::new(&db);
+
+        let reminder = repo
+            .create(CreateReminderDto {
+                task_id: None,
+                title: "Disabled".to_string(),
+                description: None,
+                remind_at: Utc::now() + Duration::hours(1),
+                repeat_interval: RepeatInterval::none(),
+                escalation_minutes: None,
+                delivery: None,
+            })
+            .unwrap();
+        repo.deactivate(&reminder.id).unwrap();
+
+        assert!(service.group_by_day(0).unwrap().is_empty());
+        assert!(matches!(
+            service.group_by_day(24 * 60),
+            Err(DomainError::InvalidInput(_))
+        ));
+    }
+
+    #[test]
+    fn test_missing_reminder_reports_reminder_not_found() {
+        let db = setup_test_db();
+        let service = ReminderService::new(&db);
+
+        let err = service.get_reminder("missing-reminder").unwrap_err();
+        assert!(matches!(err, DomainError::ReminderNotFound(_)));
+        assert_eq!(
+            err.to_string(),
+            "Reminder with id 'missing-reminder' not found"
+        );
+
+        let app_error = AppError::from(service.delete_reminder("missing-reminder").unwrap_err());
+        assert!(matches!(app_error, AppError::NotFound(_)));
+        assert!(app_error.to_string().contains("Reminder"));
+    }
+
+    #[test]
+    fn test_template_crud_and_validation() {
+        let db = setup_test_db();
+        let service = ReminderService::new(&db);
+
+        let template = service
+            .create_template(CreateReminderTemplateDto {
+                name: "  Pomodoro ".to_string(),
+                title: "Take a break".to_string(),
+                description: None,
+                repeat_interval: RepeatInterval::none(),
+                default_offset_minutes: 25,
+            })
+            .unwrap();
+        assert_eq!(template.name, "Pomodoro");
+
+        let err = service
+            .update_template(
+                &template.id,
+                UpdateReminderTemplateDto {
+                    default_offset_minutes: Some(0),
+                    ..Default::default()
+                },
+            )
+            .unwrap_err();
+        assert!(matches!(
+            err,
+            DomainError::FieldValidation { ref field, .. } if field == "default_offset_minutes"
+        ));
+
+        let updated = service
+            .update_template(
+                &template.id,
+                UpdateReminderTemplateDto {
+                    title: Some("Stretch".to_string()),
+                    ..Default::default()
+                },
+            )
+            .unwrap();
+        assert_eq!(updated.title, "Stretch");
+        assert_eq!(service.get_templates().unwrap().len(), 1);
+
+        assert!(service.delete_template(&template.id).unwrap());
+        assert!(matches!(
+            service.get_template(&template.id),
+            Err(DomainError::ReminderTemplateNotFound(_))
+        ));
+    }
+
+    #[test]
+    fn test_create_reminder_from_template_is_in_future() {
+        let db = setup_test_db();
+        let service = ReminderService::new(&db);
+
+        let template = service
+            .create_template(CreateReminderTemplateDto {
+                name: "Hydrate".to_string(),
+                title: "Drink water".to_string(),
+                description: Some("One glass".to_string()),
+                repeat_interval: RepeatInterval::from_str("every_1_hours"),
+                default_offset_minutes: 30,
+            })
+            .unwrap();
+
+        let before = Utc::now();
+        let reminder = service.create_reminder_from_template(&template.id).unwrap();
+
+        assert_eq!(reminder.title, "Drink water");
+        assert_eq!(reminder.description.as_deref(), Some("One glass"));
+        assert_eq!(
+            reminder.repeat_interval,
+            RepeatInterval::from_str("every_1_hours")
+        );
+        assert!(reminder.remind_at >= before + Duration::minutes(30));
+        assert!(reminder.remind_at <= Utc::now() + Duration::minutes(30));
+        assert!(matches!(
+            service.create_reminder_from_template("missing"),
+            Err(DomainError::ReminderTemplateNotFound(_))
+        ));
+    }
+
+    #[test]
+    fn test_snooze_all_due_only_moves_due_reminders() {
+        let db = setup_test_db();
+        let service = ReminderService::new(&db);
+        let create = |title: &str| {
+            service
+                .create_reminder(CreateReminderDto {
+                    task_id: None,
+                    title: title.to_string(),
+                    description: None,
+                    remind_at: Utc::now() + Duration::hours(2),
+                    repeat_interval: RepeatInterval::none(),
+                    escalation_minutes: None,
+                    delivery: None,
+                })
+                .unwrap()
+        };
+        let first = create("First");
+        let second = create("Second");
+        let future = create("Future");
+        backdate_remind_at(&db, &first.id, Utc::now() - Duration::minutes(5));
+        backdate_remind_at(&db, &second.id, Utc::now() - Duration::minutes(1));
+
+        let before = Utc::now();
+        assert_eq!(service.snooze_all_due(10).unwrap(), 2);
+
+        for id in [&first.id, &second.id] {
+            let snoozed = service.get_reminder(id).unwrap();
+            assert!(snoozed.remind_at >= before + Duration::minutes(10));
+            assert!(snoozed.remind_at <= Utc::now() + Duration::minutes(10));
+        }
+        assert_eq!(
+            service.get_reminder(&future.id).unwrap().remind_at,
+            future.remind_at
+        );
+        assert!(service.get_due_reminders().unwrap().is_empty());
+        assert_eq!(service.snooze_all_due(10).unwrap(), 0);
+    }
+
+    #[test]
+    fn test_due_counts_follow_closed_task_setting() {
+        let db = setup_test_db();
+        let service = ReminderService::new(&db);
+        let task = TaskService::new(&db)
+            .create_task(CreateTaskDto {
+                title: "File taxes".to_string(),
+                description: None,
+                priority: TaskPriority::Medium,
+                due_date: None,
+                image_path: None,
+                notes: None,
+                estimated_minutes: None,
+                tag_ids: vec![],
+                parent_id: None,
+                color: None,
+            })
+            .unwrap();
+        let reminder = service
+            .create_reminder(CreateReminderDto {
+                task_id: Some(task.id.clone()),
+                title: "Taxes due".to_string(),
+                description: None,
+                remind_at: Utc::now() + Duration::hours(1),
+                repeat_interval: RepeatInterval::none(),
+                escalation_minutes: None,
+                delivery: None,
+            })
+            .unwrap();
+        backdate_remind_at(&db, &reminder.id, Utc::now() - Duration::minutes(1));
+        db.connection()
+            .execute(
+                "UPDATE tasks SET status = 'completed' WHERE id = ?1",
+                [&task.id],
+            )
+            .unwrap();
+
+        // "fire" is the default: the closed task's reminder still counts as due
+        assert_eq!(service.count_due_reminders().unwrap(), 1);
+        assert_eq!(service.get_due_reminders().unwrap().len(), 1);
+
+        SettingsRepository::new(&db)
+            .set("closed_task_reminders", "skip")
+            .unwrap();
+        assert_eq!(service.count_due_reminders().unwrap(), 0);
+        assert!(service.get_due_reminders().unwrap().is_empty());
+        assert_eq!(service.snooze_all_due(10).unwrap(), 0);
+    }
+
+    #[test]
+    fn test_snooze_all_due_rejects_invalid_minutes() {
+        let db = setup_test_db();
+        let service = ReminderService::new(&db);
+
+        for minutes in [0, -5, i64::MAX / 60_000, i64::MAX] {
+            assert!(matches!(
+                service.snooze_all_due(minutes),
+                Err(DomainError::FieldValidation { ref field, .. }) if field == "minutes"
+            ));
+        }
+    }
+
+    #[test]
+    fn test_shift_all_moves_reminders_and_skips_past() {
+        let db = setup_test_db();
+        let service = ReminderService::new(&db);
+        let repo = ReminderRepository::new(&db);
+
+        let create = |title: &str, remind_at| {
+            service
+                .create_reminder(CreateReminderDto {
+                    task_id: None,
+                    title: title.to_string(),
+                    description: None,
+                    remind_at,
+                    repeat_interval: RepeatInterval::none(),
+                    escalation_minutes: None,
+                    delivery: None,
+                })
+                .unwrap()
+        };
+        let later = create("Later", Utc::now() + Duration::hours(3));
+        let soon = create("Soon", Utc::now() + Duration::minutes(30));
+        let paused = create("Paused", Utc::now() + Duration::hours(5));
+        repo.deactivate(&paused.id).unwrap();
+
+        let result = service.shift_all(-Duration::hours(1), true, false).unwrap();
+        assert_eq!(result.shifted_ids, vec![later.id.clone()]);
+        assert_eq!(result.skipped, 1);
+
+        let find = |id: &str| repo.find_by_id(id).unwrap().unwrap().remind_at;
+        assert_eq!(find(&later.id), later.remind_at - Duration::hours(1));
+        assert_eq!(find(&soon.id), soon.remind_at);
+        assert_eq!(find(&paused.id), paused.remind_at);
+
+        // Inactive reminders move too when not restricted to active ones
+        let result = service.shift_all(Duration::hours(2), false, false).unwrap();
+        assert_eq!(result.shifted_ids.len(), 3);
+        assert_eq!(find(&paused.id), paused.remind_at + Duration::hours(2));
+
+        assert!(matches!(
+            service.shift_all(Duration::zero(), true, false),
+            Err(DomainError::ValidationError(_))
+        ));
+
+        // A shift past the representable range is rejected instead of panicking
+        let result = service.shift_all(Duration::days(100_000_000), false, false);
+        assert!(
+            matches!(result, Err(DomainError::FieldValidation { ref field, .. }) if field == "hours")
+        );
+        assert_eq!(find(&later.id), later.remind_at + Duration::hours(1));
+    }
+
+    #[test]
+    fn test_shift_all_can_clamp_to_now() {
+        let db = setup_test_db();
+        let service = ReminderService::new(&db);
+
+        let reminder = service
+            .create_reminder(CreateReminderDto {
+                task_id: None,
+                title: "Soon".to_string(),
+                description: None,
+                remind_at: Utc::now() + Duration::minutes(30),
+                repeat_interval: RepeatInterval::none(),
+                escalation_minutes: None,
+                delivery: None,
+            })
+            .unwrap();
+
+        let before = Utc::now();
+        let result = service.shift_all(-Duration::hours(1), true, true).unwrap();
+        assert_eq!(result.shifted_ids, vec![reminder.id.clone()]);
+        assert_eq!(result.skipped, 0);
+
+        let clamped = service.get_reminder(&reminder.id).unwrap();
+        assert!(clamped.remind_at >= before);
+        assert!(clamped.remind_at <= Utc::now());
+    }
 }
@@ -1,12 +1,85 @@
 use crate::db::Database;
 use crate::error::{DomainError, DomainResult};
 use crate::models::{
-    CreateTaskDto, EffectiveTaskStatus, Task, TaskPriority, TaskStatus, UpdateTaskDto,
+    attachment::guess_mime_type, comment::MAX_COMMENT_LENGTH, task::MAX_COMPLETION_NOTE_LENGTH,
+    ChecklistItem, CreateReminderDto, CreateTaskDto, CreateTaskResult, EditScope,
+    EffectiveTaskStatus, EstimationReport, FacetCount, FilterFacets, FocusSession,
+    GlobalSearchResult, MarkDoneResult, Pagination, Reminder, RepeatInterval, SortDirection, Tag,
+    TagAssignment, Task, TaskAttachment, TaskComment, TaskDelta, TaskDetail, TaskFilter,
+    TaskPriority, TaskSort, TaskSortField, TaskStatus, TaskStatusChange, TaskWithProgress,
+    UndoEntityType, UpdateTaskDto, UpdateTaskResult,
 };
-use crate::repositories::TaskRepository;
-use chrono::{DateTime, Utc};
+use crate::repositories::task_repository::SORT_ORDER_GAP;
+use crate::repositories::{
+    ReminderRepository, SettingsRepository, TagRepository, TaskRepository, UndoRepository,
+};
+use crate::services::UndoService;
+use chrono::{DateTime, Datelike, Duration, FixedOffset, Utc, Weekday};
 use uuid::Uuid;
 
+/// First occurrence of a repeating reminder strictly after `now`, `None` if it doesn't repeat
+fn next_future_occurrence(reminder: &Reminder, now: DateTime<Utc>) -> Option<DateTime<Utc>> {
+    let mut probe = reminder.clone();
+    probe.is_active = true;
+    probe.next_trigger_after(now)
+}
+
+/// Turn `(value, count)` rows from a grouped query into facet entries
+fn to_facets<T>(counts: Vec<(T, u32)>) -> Vec<FacetCount<T>> {
+    counts.into_iter().map(FacetCount::from).collect()
+}
+
+/// Maximum results per category returned by `global_search`
+const GLOBAL_SEARCH_LIMIT: u32 = 20;
+
+/// Results returned by `search_tasks` when no limit is given
+const DEFAULT_SEARCH_LIMIT: u32 = 50;
+
+/// Upper bound on the `limit` accepted by `search_tasks`
+const MAX_SEARCH_LIMIT: u32 = 1000;
+
+/// File extensions accepted for a task's `image_path`
+const IMAGE_EXTENSIONS: [&str; 5] = ["png", "jpg", "jpeg", "gif", "webp"];
+
+/// Check that a non-empty `image_path` is a readable file with an image extension
+fn validate_image_path(image_path: &str) -> DomainResult<()> {
+    let path = std::path::Path::new(image_path);
+
+    let is_image = path
+        .extension()
+        .and_then(|ext| ext.to_str())
+        .is_some_and(|ext| IMAGE_EXTENSIONS.contains(&ext.to_lowercase().as_str()));
+    if !is_image {
+        return Err(DomainError::field(
+            "image_path",
+            format!("Image must be one of: {}", IMAGE_EXTENSIONS.join(", ")),
+        ));
+    }
+
+    if !path.is_file() || std::fs::File::open(path).is_err() {
+        return Err(DomainError::field(
+            "image_path",
+            format!(
+                "Image file does not exist or is not readable: {}",
+                image_path
+            ),
+        ));
+    }
+
+    Ok(())
+}
+
+/// Check that a task color is a hex color, using the same rule as tag colors
+fn validate_color(color: &str) -> DomainResult<()> {
+    if !Tag::is_valid_color(color) {
+        return Err(DomainError::field(
+            "color",
+            "Color must be a hex value like #3b82f6",
+        ));
+    }
+    Ok(())
+}
+
 /// Service layer for task business logic and domain rules.
 ///
 /// This service acts as the bridge between the API layer (Tauri commands)
@@ -37,25 +110,50 @@ impl<'a> TaskService<'a> {
     /// - Description <= 2000 chars
     /// - Due date must be in the future (if provided)
     /// - Estimated minutes must be positive (if provided)
-    pub fn create_task(&self, mut dto: CreateTaskDto) -> DomainResult<Task> {
+    /// - Image path must be a readable png/jpg/jpeg/gif/webp file (if non-empty)
+    ///
+    /// When `auto_reminder_lead_minutes` is configured and the task has a due date,
+    /// a reminder is created that many minutes before it (skipped if already past).
+    pub fn create_task(&self, dto: CreateTaskDto) -> DomainResult<Task> {
+        self.create_task_with_result(dto).map(|result| result.task)
+    }
+
+    /// `create_task`, also reporting requested tag ids that were skipped as unknown
+    pub fn create_task_with_result(&self, dto: CreateTaskDto) -> DomainResult<CreateTaskResult> {
+        self.create_validated(dto, true)
+    }
+
+    /// `create_task` without the automatic due-date reminder
+    ///
+    /// For restores and imports, which bring their own reminders.
+    pub fn create_task_without_auto_reminder(&self, dto: CreateTaskDto) -> DomainResult<Task> {
+        self.create_validated(dto, false).map(|result| result.task)
+    }
+
+    /// Validate and insert a task, adding the auto reminder when `auto_reminder` is set
+    fn create_validated(
+        &self,
+        mut dto: CreateTaskDto,
+        auto_reminder: bool,
+    ) -> DomainResult<CreateTaskResult> {
         // Validate title
         dto.title = dto.title.trim().to_string();
         if dto.title.is_empty() {
-            return Err(DomainError::ValidationError(
-                "Title cannot be empty".to_string(),
-            ));
+            return Err(DomainError::field("title", "Title cannot be empty"));
         }
         if dto.title.len() > 200 {
-            return Err(DomainError::ValidationError(
-                "Title cannot exceed 200 characters".to_string(),
+            return Err(DomainError::field(
+                "title",
+                "Title cannot exceed 200 characters",
             ));
         }
 
         // Validate description
         if let Some(desc) = &dto.description {
             if desc.len() > 2000 {
-                return Err(DomainError::ValidationError(
-                    "Description cannot exceed 2000 characters".to_string(),
+                return Err(DomainError::field(
+                    "description",
+                    "Description cannot exceed 2000 characters",
                 ));
             }
         }
@@ -63,8 +161,9 @@ impl<'a> TaskService<'a> {
         // Validate due date (must be in the future)
         if let Some(due_date) = dto.due_date {
             if due_date <= Utc::now() {
-                return Err(DomainError::InvalidDateTime(
-                    "Due date must be in the future".to_string(),
+                return Err(DomainError::field(
+                    "due_date",
+                    "Due date must be in the future",
                 ));
             }
         }
@@ -72,19 +171,212 @@ impl<'a> TaskService<'a> {
         // Validate estimated minutes
         if let Some(estimated) = dto.estimated_minutes {
             if estimated <= 0 {
-                return Err(DomainError::ValidationError(
-                    "Estimated minutes must be positive".to_string(),
+                return Err(DomainError::field(
+                    "estimated_minutes",
+                    "Estimated minutes must be positive",
                 ));
             }
         }
 
-        // Validate tag IDs (ensure they're not empty strings)
-        dto.tag_ids.retain(|id| !id.trim().is_empty());
+        // Validate image path (an empty string means no image)
+        dto.image_path = dto.image_path.filter(|p| !p.trim().is_empty());
+        if let Some(image_path) = &dto.image_path {
+            validate_image_path(image_path)?;
+        }
+
+        if let Some(color) = &dto.color {
+            validate_color(color)?;
+        }
+
+        // Only associate tags that exist; unknown ids are reported back
+        let (tag_ids, invalid_tag_ids) =
+            self.partition_tag_ids(std::mem::take(&mut dto.tag_ids))?;
+        dto.tag_ids = tag_ids;
+
+        let repo = TaskRepository::new(self.db);
+
+        // Validate parent task exists (subtasks)
+        if let Some(parent_id) = &dto.parent_id {
+            repo.find_by_id(parent_id)
+                .map_err(|e| DomainError::BusinessRuleViolation(format!("Database error: {}", e)))?
+                .ok_or_else(|| DomainError::TaskNotFound(parent_id.to_string()))?;
+        }
+
+        // The task and its auto reminder are created together or not at all
+        let task = self.db.try_transaction(|_| {
+            let task = repo.create(dto).map_err(|e| {
+                DomainError::BusinessRuleViolation(format!("Failed to create task: {}", e))
+            })?;
+
+            if auto_reminder {
+                self.create_auto_reminder(&task)?;
+            }
+            Ok::<_, DomainError>(task)
+        })?;
+
+        Ok(CreateTaskResult {
+            task,
+            invalid_tag_ids,
+        })
+    }
+
+    /// Split tag ids into existing and unknown ones, dropping blanks and duplicates
+    fn partition_tag_ids(&self, tag_ids: Vec<String>) -> DomainResult<(Vec<String>, Vec<String>)> {
+        let tag_repo = TagRepository::new(self.db);
+        let mut valid_ids = Vec::new();
+        let mut invalid_ids = Vec::new();
+        for tag_id in tag_ids {
+            if tag_id.trim().is_empty()
+                || valid_ids.contains(&tag_id)
+                || invalid_ids.contains(&tag_id)
+            {
+                continue;
+            }
+            let tag = tag_repo.find_by_id(&tag_id).map_err(|e| {
+                DomainError::BusinessRuleViolation(format!("Database error: {}", e))
+            })?;
+            if tag.is_some() {
+                valid_ids.push(tag_id);
+            } else {
+                invalid_ids.push(tag_id);
+            }
+        }
+        Ok((valid_ids, invalid_ids))
+    }
+
+    /// Create the automatic due-date reminder for a new task, if configured
+    fn create_auto_reminder(&self, task: &Task) -> DomainResult<()> {
+        let Some(due_date) = task.due_date else {
+            return Ok(());
+        };
+
+        let settings = SettingsRepository::new(self.db)
+            .get_app_settings()
+            .map_err(|e| DomainError::BusinessRuleViolation(format!("Database error: {}", e)))?;
+        let Some(lead_minutes) = settings.auto_reminder_lead_minutes else {
+            return Ok(());
+        };
+
+        // A stored lead too large to subtract leaves the task without a reminder
+        let Some(remind_at) = Duration::try_minutes(lead_minutes)
+            .and_then(|lead| due_date.checked_sub_signed(lead))
+            .filter(|remind_at| *remind_at > Utc::now())
+        else {
+            return Ok(());
+        };
+
+        ReminderRepository::new(self.db)
+            .create(CreateReminderDto {
+                task_id: Some(task.id.clone()),
+                title: task.title.clone(),
+                description: task.description.clone(),
+                remind_at,
+                repeat_interval: RepeatInterval::none(),
+                escalation_minutes: None,
+                delivery: None,
+            })
+            .map_err(|e| {
+                DomainError::BusinessRuleViolation(format!("Failed to create reminder: {}", e))
+            })?;
+
+        Ok(())
+    }
 
-        // Create task via repository
+    /// Duplicate a task as a fresh Pending task
+    ///
+    /// Copies title (with " (copy)" suffix), description, priority, notes,
+    /// estimated minutes and parent. Tags and reminders are copied on request;
+    /// past reminders are moved forward by their repeat interval, or copied
+    /// inactive when they don't repeat.
+    pub fn duplicate(
+        &self,
+        id: &str,
+        include_tags: bool,
+        include_reminders: bool,
+    ) -> DomainResult<Task> {
         let repo = TaskRepository::new(self.db);
-        repo.create(dto).map_err(|e| {
-            DomainError::BusinessRuleViolation(format!("Failed to create task: {}", e))
+
+        let original = repo
+            .find_by_id(id)
+            .map_err(|e| DomainError::BusinessRuleViolation(format!("Database error: {}", e)))?
+            .ok_or_else(|| DomainError::TaskNotFound(id.to_string()))?;
+
+        // Keep the copied title within the 200 character limit
+        const SUFFIX: &str = " (copy)";
+        let mut title = original.title.clone();
+        while title.len() + SUFFIX.len() > 200 {
+            title.pop();
+        }
+        title.push_str(SUFFIX);
+
+        let dto = CreateTaskDto {
+            title,
+            description: original.description.clone(),
+            priority: original.priority.clone(),
+            due_date: None,
+            image_path: None,
+            notes: original.notes.clone(),
+            estimated_minutes: original.estimated_minutes,
+            tag_ids: if include_tags {
+                original.tags.iter().map(|t| t.id.clone()).collect()
+            } else {
+                vec![]
+            },
+            parent_id: original.parent_id.clone(),
+            color: original.color.clone(),
+        };
+
+        // The copy and its reminders are created together or not at all
+        self.db.try_transaction(|_| {
+            let copy = repo.create(dto).map_err(|e| {
+                DomainError::BusinessRuleViolation(format!("Failed to duplicate task: {}", e))
+            })?;
+            copy.validate()
+                .map_err(|errors| DomainError::ValidationError(errors.join("; ")))?;
+
+            if include_reminders {
+                let reminder_repo = ReminderRepository::new(self.db);
+                let reminders = reminder_repo.find_by_task_id(id).map_err(|e| {
+                    DomainError::BusinessRuleViolation(format!("Failed to fetch reminders: {}", e))
+                })?;
+
+                let now = Utc::now();
+                for reminder in reminders {
+                    let remind_at = if reminder.remind_at > now {
+                        Some(reminder.remind_at)
+                    } else {
+                        next_future_occurrence(&reminder, now)
+                    };
+
+                    let created = reminder_repo
+                        .create(CreateReminderDto {
+                            task_id: Some(copy.id.clone()),
+                            title: reminder.title.clone(),
+                            description: reminder.description.clone(),
+                            remind_at: remind_at.unwrap_or(reminder.remind_at),
+                            repeat_interval: reminder.repeat_interval.clone(),
+                            escalation_minutes: reminder.escalation_minutes,
+                            delivery: Some(reminder.delivery),
+                        })
+                        .map_err(|e| {
+                            DomainError::BusinessRuleViolation(format!(
+                                "Failed to copy reminder: {}",
+                                e
+                            ))
+                        })?;
+
+                    if remind_at.is_none() || !reminder.is_active {
+                        reminder_repo.deactivate(&created.id).map_err(|e| {
+                            DomainError::BusinessRuleViolation(format!(
+                                "Failed to deactivate reminder: {}",
+                                e
+                            ))
+                        })?;
+                    }
+                }
+            }
+
+            Ok(copy)
         })
     }
 
@@ -94,7 +386,14 @@ impl<'a> TaskService<'a> {
     /// - Cannot modify completed or cancelled tasks
     /// - Status transitions must be valid
     /// - Same validation as create for other fields
-    pub fn update_task(&self, id: &str, dto: UpdateTaskDto) -> DomainResult<Task> {
+    /// - A new due date earlier than active reminders either moves those reminders
+    ///   to the due date (`shift_reminders`) or reports them with a warning
+    pub fn update_task(
+        &self,
+        id: &str,
+        mut dto: UpdateTaskDto,
+        shift_reminders: bool,
+    ) -> DomainResult<UpdateTaskResult> {
         let repo = TaskRepository::new(self.db);
 
         // Fetch existing task
@@ -115,13 +414,12 @@ impl<'a> TaskService<'a> {
         if let Some(title) = &dto.title {
             let trimmed = title.trim();
             if trimmed.is_empty() {
-                return Err(DomainError::ValidationError(
-                    "Title cannot be empty".to_string(),
-                ));
+                return Err(DomainError::field("title", "Title cannot be empty"));
             }
             if trimmed.len() > 200 {
-                return Err(DomainError::ValidationError(
-                    "Title cannot exceed 200 characters".to_string(),
+                return Err(DomainError::field(
+                    "title",
+                    "Title cannot exceed 200 characters",
                 ));
             }
         }
@@ -129,12 +427,32 @@ impl<'a> TaskService<'a> {
         // Validate description if provided
         if let Some(desc) = &dto.description {
             if desc.len() > 2000 {
-                return Err(DomainError::ValidationError(
-                    "Description cannot exceed 2000 characters".to_string(),
+                return Err(DomainError::field(
+                    "description",
+                    "Description cannot exceed 2000 characters",
                 ));
             }
         }
 
+        // Validate fields to clear: each must be clearable and not also given a value
+        for field in &dto.clear_fields {
+            match dto.sets_clearable(field) {
+                None => {
+                    return Err(DomainError::field(
+                        "clear_fields",
+                        format!("Field '{}' cannot be cleared", field),
+                    ))
+                }
+                Some(true) => {
+                    return Err(DomainError::field(
+                        field,
+                        "Field cannot be both set and cleared",
+                    ))
+                }
+                Some(false) => {}
+            }
+        }
+
         // Validate status transition if provided
         if let Some(new_status) = &dto.status {
             if !existing_task.status.can_transition_to(new_status) {
@@ -151,8 +469,9 @@ impl<'a> TaskService<'a> {
             // Allow updating to past dates for existing tasks (rescheduling)
             // but warn if it makes the task immediately overdue
             if due_date < existing_task.created_at {
-                return Err(DomainError::InvalidDateTime(
-                    "Due date cannot be before task creation date".to_string(),
+                return Err(DomainError::field(
+                    "due_date",
+                    "Due date cannot be before task creation date",
                 ));
             }
         }
@@ -160,35 +479,266 @@ impl<'a> TaskService<'a> {
         // Validate estimated minutes if provided
         if let Some(estimated) = dto.estimated_minutes {
             if estimated <= 0 {
-                return Err(DomainError::ValidationError(
-                    "Estimated minutes must be positive".to_string(),
+                return Err(DomainError::field(
+                    "estimated_minutes",
+                    "Estimated minutes must be positive",
                 ));
             }
         }
 
+        // Validate image path if provided (an empty string clears it)
+        if let Some(image_path) = &dto.image_path {
+            if !image_path.trim().is_empty() {
+                validate_image_path(image_path)?;
+            }
+        }
+
         // Validate actual minutes if provided
         if let Some(actual) = dto.actual_minutes {
             if actual <= 0 {
-                return Err(DomainError::ValidationError(
-                    "Actual minutes must be positive".to_string(),
+                return Err(DomainError::field(
+                    "actual_minutes",
+                    "Actual minutes must be positive",
                 ));
             }
         }
 
+        if let Some(color) = &dto.color {
+            validate_color(color)?;
+        }
+
+        // Only associate tags that exist; unknown ids are reported back
+        let mut invalid_tag_ids = vec![];
+        if let Some(tag_ids) = dto.tag_ids.take() {
+            let (valid_ids, invalid_ids) = self.partition_tag_ids(tag_ids)?;
+            dto.tag_ids = Some(valid_ids);
+            invalid_tag_ids = invalid_ids;
+        }
+
+        let new_due_date = dto.due_date;
+
         // Update task via repository
-        repo.update(id, dto).map_err(|e| {
+        let task = repo.update(id, dto).map_err(|e| {
             DomainError::BusinessRuleViolation(format!("Failed to update task: {}", e))
+        })?;
+
+        let mut result = UpdateTaskResult {
+            task,
+            late_reminder_ids: vec![],
+            shifted_reminder_ids: vec![],
+            warning: None,
+            updated_occurrence_ids: vec![],
+            invalid_tag_ids,
+        };
+        if let Some(due_date) = new_due_date {
+            self.reconcile_late_reminders(&mut result, due_date, shift_reminders)?;
+        }
+
+        Ok(result)
+    }
+
+    /// Update a task, or with `EditScope::ThisAndFuture` every open occurrence of its series
+    ///
+    /// Tasks outside a series are always updated on their own.
+    pub fn update_task_scoped(
+        &self,
+        id: &str,
+        mut dto: UpdateTaskDto,
+        scope: EditScope,
+        shift_reminders: bool,
+    ) -> DomainResult<UpdateTaskResult> {
+        let existing_task = self.get_task(id)?;
+        let series_id = match (scope, existing_task.series_id.clone()) {
+            (EditScope::ThisAndFuture, Some(series_id)) => series_id,
+            _ => return self.update_task(id, dto, shift_reminders),
+        };
+
+        if !existing_task.is_modifiable() {
+            return Err(DomainError::TaskNotModifiable(format!(
+                "Task is in terminal state: {}",
+                existing_task.status.as_str()
+            )));
+        }
+
+        let mut invalid_tag_ids = vec![];
+        if let Some(tag_ids) = dto.tag_ids.take() {
+            let (valid_ids, invalid_ids) = self.partition_tag_ids(tag_ids)?;
+            dto.tag_ids = Some(valid_ids);
+            invalid_tag_ids = invalid_ids;
+        }
+
+        let (edited, others): (Vec<Task>, Vec<Task>) = self
+            .update_occurrences(&series_id, existing_task.due_date, dto)?
+            .into_iter()
+            .partition(|task| task.id == id);
+        let task = edited
+            .into_iter()
+            .next()
+            .ok_or_else(|| DomainError::TaskNotFound(id.to_string()))?;
+
+        Ok(UpdateTaskResult {
+            task,
+            late_reminder_ids: vec![],
+            shifted_reminder_ids: vec![],
+            warning: None,
+            updated_occurrence_ids: others.into_iter().map(|t| t.id).collect(),
+            invalid_tag_ids,
+        })
+    }
+
+    /// Apply `dto` to every open occurrence of a recurring task series
+    ///
+    /// Completed and cancelled occurrences keep their values. The due date cannot be
+    /// changed series-wide since each occurrence has its own.
+    pub fn update_series(&self, series_id: &str, dto: UpdateTaskDto) -> DomainResult<Vec<Task>> {
+        self.update_occurrences(series_id, None, dto)
+    }
+
+    /// Apply `dto` to the open occurrences of a series due at or after `from`
+    ///
+    /// Occurrences without a due date are always included. All updates are applied
+    /// in a single transaction.
+    fn update_occurrences(
+        &self,
+        series_id: &str,
+        from: Option<DateTime<Utc>>,
+        dto: UpdateTaskDto,
+    ) -> DomainResult<Vec<Task>> {
+        if dto.due_date.is_some() || dto.clear_fields.iter().any(|f| f == "due_date") {
+            return Err(DomainError::field(
+                "due_date",
+                "Due date cannot be changed for a whole series",
+            ));
+        }
+
+        let occurrences = TaskRepository::new(self.db)
+            .find_by_series(series_id)
+            .map_err(|e| DomainError::BusinessRuleViolation(format!("Database error: {}", e)))?;
+        if occurrences.is_empty() {
+            return Err(DomainError::InvalidInput(format!(
+                "Series not found: {}",
+                series_id
+            )));
+        }
+
+        let is_future = |occurrence: &Task| match (occurrence.due_date, from) {
+            (Some(due_date), Some(from)) => due_date >= from,
+            _ => true,
+        };
+
+        self.db.try_transaction(|_| {
+            occurrences
+                .into_iter()
+                .filter(|occurrence| occurrence.is_modifiable() && is_future(occurrence))
+                .map(|occurrence| {
+                    self.update_task(&occurrence.id, dto.clone(), false)
+                        .map(|result| result.task)
+                })
+                .collect()
         })
     }
 
+    /// Generate the next occurrence of a recurring task, due at `due_date`
+    ///
+    /// Copies title, description, priority, notes, estimated minutes, tags, parent
+    /// and color. The new task joins the original's series; a task outside a series
+    /// starts one keyed by its own id.
+    pub fn create_next_occurrence(&self, id: &str, due_date: DateTime<Utc>) -> DomainResult<Task> {
+        let original = self.get_task(id)?;
+        if original.due_date.is_some_and(|current| due_date <= current) {
+            return Err(DomainError::field(
+                "due_date",
+                "Next occurrence must be due after the current one",
+            ));
+        }
+
+        let series_id = original
+            .series_id
+            .clone()
+            .unwrap_or_else(|| original.id.clone());
+        let repo = TaskRepository::new(self.db);
+
+        let occurrence = self.db.try_transaction(|_| {
+            let occurrence = self.create_task(CreateTaskDto {
+                title: original.title.clone(),
+                description: original.description.clone(),
+                priority: original.priority.clone(),
+                due_date: Some(due_date),
+                image_path: None,
+                notes: original.notes.clone(),
+                estimated_minutes: original.estimated_minutes,
+                tag_ids: original.tags.iter().map(|t| t.id.clone()).collect(),
+                parent_id: original.parent_id.clone(),
+                color: original.color.clone(),
+            })?;
+
+            for task_id in [&original.id, &occurrence.id] {
+                repo.set_series_id(task_id, &series_id)?;
+            }
+            Ok::<_, DomainError>(occurrence)
+        })?;
+
+        self.get_task(&occurrence.id)
+    }
+
+    /// Handle active reminders of `result.task` scheduled after `due_date`
+    fn reconcile_late_reminders(
+        &self,
+        result: &mut UpdateTaskResult,
+        due_date: DateTime<Utc>,
+        shift_reminders: bool,
+    ) -> DomainResult<()> {
+        let reminder_repo = ReminderRepository::new(self.db);
+
+        let late_ids: Vec<String> = reminder_repo
+            .find_by_task_id(&result.task.id)
+            .map_err(|e| {
+                DomainError::BusinessRuleViolation(format!("Failed to load reminders: {}", e))
+            })?
+            .into_iter()
+            .filter(|r| r.is_active && r.remind_at > due_date)
+            .map(|r| r.id)
+            .collect();
+
+        if late_ids.is_empty() {
+            return Ok(());
+        }
+
+        if shift_reminders {
+            let remind_times: Vec<(String, DateTime<Utc>)> =
+                late_ids.iter().map(|id| (id.clone(), due_date)).collect();
+            reminder_repo.set_remind_times(&remind_times).map_err(|e| {
+                DomainError::BusinessRuleViolation(format!("Failed to shift reminders: {}", e))
+            })?;
+            result.shifted_reminder_ids = late_ids;
+        } else {
+            result.warning = Some(format!(
+                "{} reminder(s) scheduled after the due date",
+                late_ids.len()
+            ));
+            result.late_reminder_ids = late_ids;
+        }
+
+        Ok(())
+    }
+
     /// Mark a task as done (completed)
     ///
     /// Business rules:
     /// - Task must exist
     /// - Task must not already be in terminal state
     /// - Sets completed_at timestamp
-    /// - Optionally record actual minutes spent
-    pub fn mark_done(&self, id: &str, actual_minutes: Option<i32>) -> DomainResult<Task> {
+    /// - Optionally record actual minutes spent and a completion note
+    /// - All blocking tasks must be completed, unless `force` is set
+    /// - Active reminders of the task are deactivated, unless `keep_reminders` is set
+    pub fn mark_done(
+        &self,
+        id: &str,
+        actual_minutes: Option<i32>,
+        completion_note: Option<String>,
+        force: bool,
+        keep_reminders: bool,
+    ) -> DomainResult<MarkDoneResult> {
         let repo = TaskRepository::new(self.db);
 
         // Fetch existing task
@@ -199,7 +749,7 @@ impl<'a> TaskService<'a> {
 
         // Check if already completed
         if existing_task.status == TaskStatus::Completed {
-            return Ok(existing_task); // Idempotent operation
+            return Ok(MarkDoneResult::new(existing_task)); // Idempotent operation
         }
 
         // Check if task can be modified
@@ -213,12 +763,47 @@ impl<'a> TaskService<'a> {
         // Validate actual minutes if provided
         if let Some(actual) = actual_minutes {
             if actual <= 0 {
-                return Err(DomainError::ValidationError(
-                    "Actual minutes must be positive".to_string(),
+                return Err(DomainError::field(
+                    "actual_minutes",
+                    "Actual minutes must be positive",
+                ));
+            }
+        }
+
+        let completion_note = completion_note
+            .map(|note| note.trim().to_string())
+            .filter(|note| !note.is_empty());
+        if let Some(note) = &completion_note {
+            if note.chars().count() > MAX_COMPLETION_NOTE_LENGTH {
+                return Err(DomainError::field(
+                    "completion_note",
+                    format!(
+                        "Completion note cannot exceed {} characters",
+                        MAX_COMPLETION_NOTE_LENGTH
+                    ),
                 ));
             }
         }
 
+        // Check that no blocker is still incomplete
+        if !force {
+            let blockers = repo.find_blockers(id).map_err(|e| {
+                DomainError::BusinessRuleViolation(format!("Failed to fetch blockers: {}", e))
+            })?;
+            let incomplete: Vec<&str> = blockers
+                .iter()
+                .filter(|blocker| !blocker.is_completed())
+                .map(|blocker| blocker.title.as_str())
+                .collect();
+
+            if !incomplete.is_empty() {
+                return Err(DomainError::BusinessRuleViolation(format!(
+                    "Task is blocked by incomplete tasks: {}",
+                    incomplete.join(", ")
+                )));
+            }
+        }
+
         // Update to completed status
         let update_dto = UpdateTaskDto {
             title: None,
@@ -231,10 +816,78 @@ impl<'a> TaskService<'a> {
             estimated_minutes: None,
             actual_minutes,
             tag_ids: None,
+            color: None,
+            clear_fields: vec![],
+        };
+
+        // Completion and reminder deactivation commit together
+        self.db.try_transaction(|_| {
+            let task = repo
+                .update_with_completion_note(id, update_dto, completion_note.as_deref())
+                .map_err(|e| {
+                    DomainError::BusinessRuleViolation(format!(
+                        "Failed to mark task as done: {}",
+                        e
+                    ))
+                })?;
+
+            // Pending reminders are pointless once the task is done
+            if !keep_reminders {
+                ReminderRepository::new(self.db)
+                    .deactivate_by_task_id(id)
+                    .map_err(|e| {
+                        DomainError::BusinessRuleViolation(format!(
+                            "Failed to deactivate reminders: {}",
+                            e
+                        ))
+                    })?;
+            }
+
+            Ok(MarkDoneResult::new(task))
+        })
+    }
+
+    /// Reopen a completed task, moving it back to Pending (or `status` if given)
+    ///
+    /// The only way out of Completed: clears `completed_at` and records the
+    /// transition in the status history. Cancelled tasks stay terminal.
+    pub fn reopen(&self, id: &str, status: Option<TaskStatus>) -> DomainResult<Task> {
+        let repo = TaskRepository::new(self.db);
+        let existing_task = self.get_task(id)?;
+        let new_status = status.unwrap_or(TaskStatus::Pending);
+
+        if existing_task.status != TaskStatus::Completed {
+            return Err(DomainError::InvalidStatusTransition {
+                from: existing_task.status.as_str().to_string(),
+                to: new_status.as_str().to_string(),
+                reason: "Only completed tasks can be reopened".to_string(),
+            });
+        }
+        if !matches!(new_status, TaskStatus::Pending | TaskStatus::InProgress) {
+            return Err(DomainError::InvalidStatusTransition {
+                from: existing_task.status.as_str().to_string(),
+                to: new_status.as_str().to_string(),
+                reason: "A reopened task must be pending or in progress".to_string(),
+            });
+        }
+
+        let update_dto = UpdateTaskDto {
+            title: None,
+            description: None,
+            status: Some(new_status),
+            priority: None,
+            due_date: None,
+            image_path: None,
+            notes: None,
+            estimated_minutes: None,
+            actual_minutes: None,
+            tag_ids: None,
+            color: None,
+            clear_fields: vec![],
         };
 
         repo.update(id, update_dto).map_err(|e| {
-            DomainError::BusinessRuleViolation(format!("Failed to mark task as done: {}", e))
+            DomainError::BusinessRuleViolation(format!("Failed to reopen task: {}", e))
         })
     }
 
@@ -256,309 +909,3837 @@ impl<'a> TaskService<'a> {
             return Err(DomainError::TaskNotFound(id.to_string()));
         }
 
-        // Delete task
-        repo.delete(id).map_err(|e| {
-            DomainError::BusinessRuleViolation(format!("Failed to delete task: {}", e))
-        })
-    }
+        // Snapshot, delete and undo entry commit together
+        self.db.try_transaction(|_| {
+            let undo = UndoService::new(self.db);
+            let snapshot = undo.snapshot(UndoEntityType::Task, id)?;
 
-    /// Get a task by ID
-    pub fn get_task(&self, id: &str) -> DomainResult<Task> {
-        let repo = TaskRepository::new(self.db);
-        repo.find_by_id(id)
-            .map_err(|e| DomainError::BusinessRuleViolation(format!("Database error: {}", e)))?
-            .ok_or_else(|| DomainError::TaskNotFound(id.to_string()))
+            // Remove reminders linked to the task
+            ReminderRepository::new(self.db)
+                .delete_by_task_id(id)
+                .map_err(|e| {
+                    DomainError::BusinessRuleViolation(format!("Failed to delete reminders: {}", e))
+                })?;
+
+            // Delete task
+            let deleted = repo.delete(id).map_err(|e| {
+                DomainError::BusinessRuleViolation(format!("Failed to delete task: {}", e))
+            })?;
+
+            if deleted {
+                undo.record(UndoEntityType::Task, id, &snapshot)?;
+            }
+            Ok(deleted)
+        })
     }
 
-    /// Search tasks by query string
+    /// Move a task in the manual ordering
     ///
-    /// Searches in task title and description (case-insensitive)
-    pub fn search_tasks(&self, query: &str) -> DomainResult<Vec<Task>> {
+    /// Places the task directly after `after_id`, or at the front when `after_id` is None.
+    /// Uses gap-based positions so only the moved task is rewritten, renumbering
+    /// all tasks only when there is no room left between neighbours.
+    pub fn reorder(&self, id: &str, after_id: Option<String>) -> DomainResult<Task> {
         let repo = TaskRepository::new(self.db);
+        let db_error = |e: rusqlite::Error| {
+            DomainError::BusinessRuleViolation(format!("Database error: {}", e))
+        };
 
-        let pagination = crate::models::Pagination {
-            page: 1,
-            page_size: 1000,
+        self.get_task(id)?;
+
+        let after_id = match after_id {
+            Some(after_id) if after_id == id => {
+                return Err(DomainError::InvalidInput(
+                    "A task cannot be placed after itself".to_string(),
+                ));
+            }
+            other => other,
         };
 
-        let tasks = repo.find_all(None, None, pagination).map_err(|e| {
-            DomainError::BusinessRuleViolation(format!("Failed to search tasks: {}", e))
+        // A renumbering and the move commit together
+        self.db.try_transaction(|_| {
+            let new_position = match &after_id {
+                None => repo.min_sort_order_excluding(id).map_err(db_error)? - SORT_ORDER_GAP,
+                Some(after_id) => {
+                    let mut after = self.get_task(after_id)?.sort_order;
+                    let mut next = repo.next_sort_order_after(after, id).map_err(db_error)?;
+
+                    // No integer left between neighbours: spread everything out and retry
+                    if matches!(next, Some(next) if next - after < 2) {
+                        repo.renumber_sort_orders().map_err(db_error)?;
+                        after = self.get_task(after_id)?.sort_order;
+                        next = repo.next_sort_order_after(after, id).map_err(db_error)?;
+                    }
+
+                    match next {
+                        Some(next) => after + (next - after) / 2,
+                        None => after + SORT_ORDER_GAP,
+                    }
+                }
+            };
+
+            repo.set_sort_order(id, new_position).map_err(|e| {
+                DomainError::BusinessRuleViolation(format!("Failed to reorder task: {}", e))
+            })?;
+            Ok::<_, DomainError>(())
         })?;
 
-        let query_lower = query.to_lowercase();
-        let filtered: Vec<Task> = tasks
-            .items
-            .into_iter()
-            .filter(|task| {
-                task.title.to_lowercase().contains(&query_lower)
-                    || task
-                        .description
-                        .as_ref()
-                        .map_or(false, |d| d.to_lowercase().contains(&query_lower))
-            })
-            .collect();
+        self.get_task(id)
+    }
 
-        Ok(filtered)
+    /// Pin a task so it is listed before unpinned tasks
+    pub fn pin_task(&self, id: &str) -> DomainResult<Task> {
+        self.set_pinned(id, true)
     }
 
-    /// Auto-update overdue status for tasks
-    ///
-    /// This method finds all overdue tasks and returns them for notification purposes.
-    /// The "overdue" status is computed, not stored in the database.
-    ///
-    /// Business rules:
-    /// - A task is overdue if:
-    ///   - It has a due_date
-    ///   - The due_date is in the past
-    ///   - The status is not Completed or Cancelled
-    pub fn auto_update_overdue_status(&self) -> DomainResult<Vec<Task>> {
+    /// Unpin a task, returning it to the regular ordering
+    pub fn unpin_task(&self, id: &str) -> DomainResult<Task> {
+        self.set_pinned(id, false)
+    }
+
+    fn set_pinned(&self, id: &str, pinned: bool) -> DomainResult<Task> {
         let repo = TaskRepository::new(self.db);
 
-        // Get overdue tasks from repository
-        let overdue_tasks = repo.find_overdue().map_err(|e| {
-            DomainError::BusinessRuleViolation(format!("Failed to fetch overdue tasks: {}", e))
+        let found = repo.set_pinned(id, pinned).map_err(|e| {
+            DomainError::BusinessRuleViolation(format!("Failed to pin task: {}", e))
         })?;
+        if !found {
+            return Err(DomainError::TaskNotFound(id.to_string()));
+        }
 
-        Ok(overdue_tasks)
+        self.get_task(id)
     }
 
-    /// Get tasks with their effective status (including computed Overdue)
-    pub fn get_tasks_with_effective_status(
+    /// Park a task as "someday/maybe", optionally until `until`
+    ///
+    /// Deferred tasks keep their status but are left out of default listings and
+    /// overdue checks. With `until`, the notification loop brings the task back
+    /// once that time passes.
+    pub fn defer_task(&self, id: &str, until: Option<DateTime<Utc>>) -> DomainResult<Task> {
+        let task = self.get_task(id)?;
+        if !task.is_modifiable() {
+            return Err(DomainError::TaskNotModifiable(format!(
+                "Task is in terminal state: {}",
+                task.status.as_str()
+            )));
+        }
+        if until.is_some_and(|until| until <= Utc::now()) {
+            return Err(DomainError::field(
+                "deferred_until",
+                "Deferred until must be in the future",
+            ));
+        }
+
+        self.set_deferred(id, true, until)
+    }
+
+    /// Bring a deferred task back to the active lists
+    pub fn undefer_task(&self, id: &str) -> DomainResult<Task> {
+        self.set_deferred(id, false, None)
+    }
+
+    fn set_deferred(
         &self,
-    ) -> DomainResult<Vec<(Task, EffectiveTaskStatus)>> {
+        id: &str,
+        deferred: bool,
+        until: Option<DateTime<Utc>>,
+    ) -> DomainResult<Task> {
+        let found = TaskRepository::new(self.db)
+            .set_deferred(id, deferred, until)
+            .map_err(|e| {
+                DomainError::BusinessRuleViolation(format!("Failed to defer task: {}", e))
+            })?;
+        if !found {
+            return Err(DomainError::TaskNotFound(id.to_string()));
+        }
+
+        self.get_task(id)
+    }
+
+    /// Compute task progress from its direct subtasks
+    ///
+    /// - With subtasks: completed children / total children
+    /// - Leaf task: 1.0 if completed, otherwise 0.0
+    pub fn compute_progress(&self, task_id: &str) -> DomainResult<f32> {
+        let task = self.get_task(task_id)?;
+        self.progress_for(&task)
+    }
+
+    /// Get a task together with its computed progress
+    pub fn get_task_with_progress(&self, task_id: &str) -> DomainResult<TaskWithProgress> {
+        let task = self.get_task(task_id)?;
+        let progress = self.progress_for(&task)?;
+        Ok(TaskWithProgress { task, progress })
+    }
+
+    /// Progress for an already-loaded task
+    fn progress_for(&self, task: &Task) -> DomainResult<f32> {
         let repo = TaskRepository::new(self.db);
 
-        // Get all non-terminal tasks
-        let tasks = repo
-            .find_all(None, None, crate::models::Pagination::default())
+        let (total, completed) = repo.count_children_by_completion(&task.id).map_err(|e| {
+            DomainError::BusinessRuleViolation(format!("Failed to compute progress: {}", e))
+        })?;
+
+        if total == 0 {
+            return Ok(if task.is_completed() { 1.0 } else { 0.0 });
+        }
+
+        Ok(completed as f32 / total as f32)
+    }
+
+    /// Make `task_id` depend on (be blocked by) `depends_on_id`
+    ///
+    /// Business rules:
+    /// - Both tasks must exist
+    /// - A task cannot depend on itself
+    /// - The new link must not create a dependency cycle
+    pub fn add_dependency(&self, task_id: &str, depends_on_id: &str) -> DomainResult<()> {
+        let repo = TaskRepository::new(self.db);
+
+        if task_id == depends_on_id {
+            return Err(DomainError::BusinessRuleViolation(
+                "A task cannot depend on itself".to_string(),
+            ));
+        }
+
+        for id in [task_id, depends_on_id] {
+            repo.find_by_id(id)
+                .map_err(|e| DomainError::BusinessRuleViolation(format!("Database error: {}", e)))?
+                .ok_or_else(|| DomainError::TaskNotFound(id.to_string()))?;
+        }
+
+        // Reject the link if the blocker already (transitively) depends on this task
+        let creates_cycle = repo
+            .depends_on_transitively(depends_on_id, task_id)
+            .map_err(|e| DomainError::BusinessRuleViolation(format!("Database error: {}", e)))?;
+        if creates_cycle {
+            return Err(DomainError::BusinessRuleViolation(
+                "Adding this dependency would create a cycle".to_string(),
+            ));
+        }
+
+        repo.add_dependency(task_id, depends_on_id).map_err(|e| {
+            DomainError::BusinessRuleViolation(format!("Failed to add dependency: {}", e))
+        })
+    }
+
+    /// Remove a dependency link between two tasks
+    pub fn remove_dependency(&self, task_id: &str, depends_on_id: &str) -> DomainResult<bool> {
+        let repo = TaskRepository::new(self.db);
+        repo.remove_dependency(task_id, depends_on_id).map_err(|e| {
+            DomainError::BusinessRuleViolation(format!("Failed to remove dependency: {}", e))
+        })
+    }
+
+    /// Get tasks having ALL (`match_all`) or ANY of the given tags
+    pub fn get_tasks_by_tags(
+        &self,
+        tag_ids: &[String],
+        match_all: bool,
+    ) -> DomainResult<Vec<Task>> {
+        let repo = TaskRepository::new(self.db);
+        repo.find_by_tags(tag_ids, match_all).map_err(|e| {
+            DomainError::BusinessRuleViolation(format!("Failed to fetch tasks by tags: {}", e))
+        })
+    }
+
+    /// Get the tasks blocking the given task
+    pub fn get_dependencies(&self, task_id: &str) -> DomainResult<Vec<Task>> {
+        let repo = TaskRepository::new(self.db);
+
+        repo.find_by_id(task_id)
+            .map_err(|e| DomainError::BusinessRuleViolation(format!("Database error: {}", e)))?
+            .ok_or_else(|| DomainError::TaskNotFound(task_id.to_string()))?;
+
+        repo.find_blockers(task_id).map_err(|e| {
+            DomainError::BusinessRuleViolation(format!("Failed to fetch dependencies: {}", e))
+        })
+    }
+
+    /// Get the status transitions of a task, oldest first
+    pub fn get_task_history(&self, id: &str) -> DomainResult<Vec<TaskStatusChange>> {
+        let repo = TaskRepository::new(self.db);
+
+        repo.find_by_id(id)
+            .map_err(|e| DomainError::BusinessRuleViolation(format!("Database error: {}", e)))?
+            .ok_or_else(|| DomainError::TaskNotFound(id.to_string()))?;
+
+        repo.find_status_history(id).map_err(|e| {
+            DomainError::BusinessRuleViolation(format!("Failed to fetch task history: {}", e))
+        })
+    }
+
+    /// Add a comment to a task
+    ///
+    /// Business rules:
+    /// - Task must exist
+    /// - Body cannot be empty (after trimming) or exceed 5000 characters
+    pub fn add_comment(&self, task_id: &str, body: &str) -> DomainResult<TaskComment> {
+        let repo = TaskRepository::new(self.db);
+
+        repo.find_by_id(task_id)
+            .map_err(|e| DomainError::BusinessRuleViolation(format!("Database error: {}", e)))?
+            .ok_or_else(|| DomainError::TaskNotFound(task_id.to_string()))?;
+
+        let body = body.trim();
+        if body.is_empty() {
+            return Err(DomainError::ValidationError(
+                "Comment cannot be empty".to_string(),
+            ));
+        }
+        if body.chars().count() > MAX_COMMENT_LENGTH {
+            return Err(DomainError::ValidationError(format!(
+                "Comment cannot exceed {} characters",
+                MAX_COMMENT_LENGTH
+            )));
+        }
+
+        repo.add_comment(task_id, body).map_err(|e| {
+            DomainError::BusinessRuleViolation(format!("Failed to add comment: {}", e))
+        })
+    }
+
+    /// List comments of a task, oldest first
+    pub fn list_comments(&self, task_id: &str) -> DomainResult<Vec<TaskComment>> {
+        let repo = TaskRepository::new(self.db);
+
+        repo.find_by_id(task_id)
+            .map_err(|e| DomainError::BusinessRuleViolation(format!("Database error: {}", e)))?
+            .ok_or_else(|| DomainError::TaskNotFound(task_id.to_string()))?;
+
+        repo.list_comments(task_id).map_err(|e| {
+            DomainError::BusinessRuleViolation(format!("Failed to fetch comments: {}", e))
+        })
+    }
+
+    /// Delete a comment
+    pub fn delete_comment(&self, id: &str) -> DomainResult<bool> {
+        let repo = TaskRepository::new(self.db);
+        repo.delete_comment(id).map_err(|e| {
+            DomainError::BusinessRuleViolation(format!("Failed to delete comment: {}", e))
+        })
+    }
+
+    /// Add a checklist item to the end of a task's checklist
+    ///
+    /// Business rules:
+    /// - Task must exist
+    /// - Item text cannot be empty (after trimming)
+    pub fn add_checklist_item(&self, task_id: &str, text: &str) -> DomainResult<ChecklistItem> {
+        let repo = TaskRepository::new(self.db);
+
+        repo.find_by_id(task_id)
+            .map_err(|e| DomainError::BusinessRuleViolation(format!("Database error: {}", e)))?
+            .ok_or_else(|| DomainError::TaskNotFound(task_id.to_string()))?;
+
+        let text = text.trim();
+        if text.is_empty() {
+            return Err(DomainError::ValidationError(
+                "Checklist item text cannot be empty".to_string(),
+            ));
+        }
+
+        repo.add_checklist_item(task_id, text).map_err(|e| {
+            DomainError::BusinessRuleViolation(format!("Failed to add checklist item: {}", e))
+        })
+    }
+
+    /// Flip a checklist item between done and not done
+    pub fn toggle_checklist_item(&self, id: &str) -> DomainResult<ChecklistItem> {
+        let repo = TaskRepository::new(self.db);
+
+        let toggled = repo.toggle_checklist_item(id).map_err(|e| {
+            DomainError::BusinessRuleViolation(format!("Failed to toggle checklist item: {}", e))
+        })?;
+        if !toggled {
+            return Err(DomainError::InvalidInput(format!(
+                "Checklist item '{}' not found",
+                id
+            )));
+        }
+
+        repo.find_checklist_item(id)
+            .map_err(|e| DomainError::BusinessRuleViolation(format!("Database error: {}", e)))?
+            .ok_or_else(|| DomainError::InvalidInput(format!("Checklist item '{}' not found", id)))
+    }
+
+    /// Delete a checklist item
+    pub fn delete_checklist_item(&self, id: &str) -> DomainResult<bool> {
+        let repo = TaskRepository::new(self.db);
+        repo.delete_checklist_item(id).map_err(|e| {
+            DomainError::BusinessRuleViolation(format!("Failed to delete checklist item: {}", e))
+        })
+    }
+
+    /// List a task's checklist items in order
+    pub fn list_checklist_items(&self, task_id: &str) -> DomainResult<Vec<ChecklistItem>> {
+        let repo = TaskRepository::new(self.db);
+
+        repo.find_by_id(task_id)
+            .map_err(|e| DomainError::BusinessRuleViolation(format!("Database error: {}", e)))?
+            .ok_or_else(|| DomainError::TaskNotFound(task_id.to_string()))?;
+
+        repo.list_checklist_items(task_id).map_err(|e| {
+            DomainError::BusinessRuleViolation(format!("Failed to fetch checklist items: {}", e))
+        })
+    }
+
+    /// Attach a local file to a task
+    ///
+    /// Business rules:
+    /// - Task must exist
+    /// - Path must not contain `..` components and must point to an existing file
+    /// - MIME type is guessed from the extension when not provided
+    pub fn add_attachment(
+        &self,
+        task_id: &str,
+        file_path: &str,
+        mime_type: Option<String>,
+    ) -> DomainResult<TaskAttachment> {
+        let repo = TaskRepository::new(self.db);
+
+        repo.find_by_id(task_id)
+            .map_err(|e| DomainError::BusinessRuleViolation(format!("Database error: {}", e)))?
+            .ok_or_else(|| DomainError::TaskNotFound(task_id.to_string()))?;
+
+        let file_path = file_path.trim();
+        if file_path.is_empty() {
+            return Err(DomainError::InvalidInput(
+                "Attachment path cannot be empty".to_string(),
+            ));
+        }
+
+        let path = std::path::Path::new(file_path);
+        if path
+            .components()
+            .any(|c| matches!(c, std::path::Component::ParentDir))
+        {
+            return Err(DomainError::InvalidInput(
+                "Attachment path cannot contain '..'".to_string(),
+            ));
+        }
+
+        if !path.is_file() {
+            return Err(DomainError::InvalidInput(format!(
+                "Attachment file does not exist: {}",
+                file_path
+            )));
+        }
+
+        let mime_type = mime_type
+            .filter(|m| !m.trim().is_empty())
+            .or_else(|| guess_mime_type(file_path));
+
+        repo.add_attachment(task_id, file_path, mime_type)
             .map_err(|e| {
-                DomainError::BusinessRuleViolation(format!("Failed to fetch tasks: {}", e))
+                DomainError::BusinessRuleViolation(format!("Failed to add attachment: {}", e))
+            })
+    }
+
+    /// List attachments of a task
+    pub fn list_attachments(&self, task_id: &str) -> DomainResult<Vec<TaskAttachment>> {
+        let repo = TaskRepository::new(self.db);
+
+        repo.find_by_id(task_id)
+            .map_err(|e| DomainError::BusinessRuleViolation(format!("Database error: {}", e)))?
+            .ok_or_else(|| DomainError::TaskNotFound(task_id.to_string()))?;
+
+        repo.list_attachments(task_id).map_err(|e| {
+            DomainError::BusinessRuleViolation(format!("Failed to fetch attachments: {}", e))
+        })
+    }
+
+    /// Remove an attachment by ID (the file itself is left untouched)
+    pub fn remove_attachment(&self, attachment_id: &str) -> DomainResult<bool> {
+        let repo = TaskRepository::new(self.db);
+        repo.remove_attachment(attachment_id).map_err(|e| {
+            DomainError::BusinessRuleViolation(format!("Failed to remove attachment: {}", e))
+        })
+    }
+
+    /// Start a Pomodoro-style focus session on a task
+    ///
+    /// Creates two reminders repeating every work + break cycle: one when each work
+    /// period ends and one when each break ends.
+    ///
+    /// Business rules:
+    /// - Task must exist and not be in a terminal state
+    /// - Work and break lengths must be positive
+    /// - A task has at most one running session
+    pub fn start_focus_session(
+        &self,
+        task_id: &str,
+        work_minutes: i32,
+        break_minutes: i32,
+    ) -> DomainResult<FocusSession> {
+        let repo = TaskRepository::new(self.db);
+
+        let task = repo
+            .find_by_id(task_id)
+            .map_err(|e| DomainError::BusinessRuleViolation(format!("Database error: {}", e)))?
+            .ok_or_else(|| DomainError::TaskNotFound(task_id.to_string()))?;
+
+        if !task.is_modifiable() {
+            return Err(DomainError::TaskNotModifiable(format!(
+                "Task is already in terminal state: {}",
+                task.status.as_str()
+            )));
+        }
+        if work_minutes <= 0 {
+            return Err(DomainError::field(
+                "work_minutes",
+                "Work minutes must be positive",
+            ));
+        }
+        if break_minutes <= 0 {
+            return Err(DomainError::field(
+                "break_minutes",
+                "Break minutes must be positive",
+            ));
+        }
+
+        let active = repo
+            .find_active_focus_session(task_id)
+            .map_err(|e| DomainError::BusinessRuleViolation(format!("Database error: {}", e)))?;
+        if active.is_some() {
+            return Err(DomainError::BusinessRuleViolation(
+                "Task already has an active focus session".to_string(),
+            ));
+        }
+
+        let started_at = Utc::now();
+        let cycle =
+            RepeatInterval::from_str(&format!("every_{}_minutes", work_minutes + break_minutes));
+        let reminder_repo = ReminderRepository::new(self.db);
+        let create_reminder = |title: String, description: String, remind_at| {
+            reminder_repo
+                .create(CreateReminderDto {
+                    task_id: Some(task.id.clone()),
+                    title,
+                    description: Some(description),
+                    remind_at,
+                    repeat_interval: cycle.clone(),
+                    escalation_minutes: None,
+                    delivery: None,
+                })
+                .map_err(|e| {
+                    DomainError::BusinessRuleViolation(format!("Failed to create reminder: {}", e))
+                })
+        };
+
+        // Both reminders and the session row are written together or not at all
+        self.db.try_transaction(|_| {
+            let break_reminder = create_reminder(
+                format!("Break: {}", task.title),
+                format!("Take a {}-minute break", break_minutes),
+                started_at + Duration::minutes(work_minutes.into()),
+            )?;
+            let work_reminder = create_reminder(
+                format!("Focus: {}", task.title),
+                format!("Back to work for {} minutes", work_minutes),
+                started_at + Duration::minutes((work_minutes + break_minutes).into()),
+            )?;
+
+            let session = FocusSession {
+                id: Uuid::new_v4().to_string(),
+                task_id: task.id.clone(),
+                work_minutes,
+                break_minutes,
+                break_reminder_id: Some(break_reminder.id),
+                work_reminder_id: Some(work_reminder.id),
+                started_at,
+                ended_at: None,
+                logged_minutes: None,
+            };
+            repo.add_focus_session(&session).map_err(|e| {
+                DomainError::BusinessRuleViolation(format!("Failed to start focus session: {}", e))
+            })?;
+
+            Ok(session)
+        })
+    }
+
+    /// Stop a task's running focus session
+    ///
+    /// Adds the work minutes accrued so far to the task's actual minutes and removes the
+    /// session's transition reminders.
+    pub fn stop_focus_session(&self, task_id: &str) -> DomainResult<FocusSession> {
+        let repo = TaskRepository::new(self.db);
+
+        repo.find_by_id(task_id)
+            .map_err(|e| DomainError::BusinessRuleViolation(format!("Database error: {}", e)))?
+            .ok_or_else(|| DomainError::TaskNotFound(task_id.to_string()))?;
+
+        let mut session = repo
+            .find_active_focus_session(task_id)
+            .map_err(|e| DomainError::BusinessRuleViolation(format!("Database error: {}", e)))?
+            .ok_or_else(|| {
+                DomainError::BusinessRuleViolation("Task has no active focus session".to_string())
+            })?;
+
+        let ended_at = Utc::now();
+        let logged_minutes = session.worked_minutes(ended_at);
+        repo.finish_focus_session(&session, ended_at, logged_minutes)
+            .map_err(|e| {
+                DomainError::BusinessRuleViolation(format!("Failed to stop focus session: {}", e))
             })?;
 
-        // Map to effective status
-        let tasks_with_status: Vec<(Task, EffectiveTaskStatus)> = tasks
-            .items
-            .into_iter()
-            .map(|task| {
-                let status = task.effective_status();
-                (task, status)
+        session.ended_at = Some(ended_at);
+        session.logged_minutes = Some(logged_minutes);
+        Ok(session)
+    }
+
+    /// Get a task by ID
+    pub fn get_task(&self, id: &str) -> DomainResult<Task> {
+        let repo = TaskRepository::new(self.db);
+        repo.find_by_id(id)
+            .map_err(|e| DomainError::BusinessRuleViolation(format!("Database error: {}", e)))?
+            .ok_or_else(|| DomainError::TaskNotFound(id.to_string()))
+    }
+
+    /// Get the tags applied to a task
+    pub fn get_task_tags(&self, task_id: &str) -> DomainResult<Vec<Tag>> {
+        let repo = TaskRepository::new(self.db);
+
+        repo.find_by_id(task_id)
+            .map_err(|e| DomainError::BusinessRuleViolation(format!("Database error: {}", e)))?
+            .ok_or_else(|| DomainError::TaskNotFound(task_id.to_string()))?;
+
+        repo.find_tags(task_id)
+            .map_err(|e| DomainError::BusinessRuleViolation(format!("Failed to load tags: {}", e)))
+    }
+
+    /// Replace a task's tags; an empty list clears them
+    ///
+    /// Ids without a matching tag are skipped and reported in `invalid_tag_ids`
+    /// instead of failing the whole assignment.
+    pub fn set_task_tags(
+        &self,
+        task_id: &str,
+        tag_ids: Vec<String>,
+    ) -> DomainResult<TagAssignment> {
+        let repo = TaskRepository::new(self.db);
+
+        repo.find_by_id(task_id)
+            .map_err(|e| DomainError::BusinessRuleViolation(format!("Database error: {}", e)))?
+            .ok_or_else(|| DomainError::TaskNotFound(task_id.to_string()))?;
+
+        let (valid_ids, invalid_tag_ids) = self.partition_tag_ids(tag_ids)?;
+
+        let tags = repo.set_tags(task_id, &valid_ids).map_err(|e| {
+            DomainError::BusinessRuleViolation(format!("Failed to set tags: {}", e))
+        })?;
+
+        Ok(TagAssignment {
+            tags,
+            invalid_tag_ids,
+        })
+    }
+
+    /// Get a task together with its reminders, subtasks and checklist
+    pub fn get_task_detail(&self, id: &str) -> DomainResult<TaskDetail> {
+        let repo = TaskRepository::new(self.db);
+        let db_error = |e: rusqlite::Error| {
+            DomainError::BusinessRuleViolation(format!("Failed to load task detail: {}", e))
+        };
+
+        let task = repo
+            .find_by_id(id)
+            .map_err(db_error)?
+            .ok_or_else(|| DomainError::TaskNotFound(id.to_string()))?;
+
+        let reminders = ReminderRepository::new(self.db)
+            .find_by_task_id(id)
+            .map_err(db_error)?;
+        let subtasks = repo.find_children(id).map_err(db_error)?;
+        let checklist = repo.list_checklist_items(id).map_err(db_error)?;
+
+        Ok(TaskDetail {
+            task,
+            reminders,
+            subtasks,
+            checklist,
+        })
+    }
+
+    /// Count tasks per status, priority and applied tag for filter dropdowns
+    pub fn get_filter_facets(&self) -> DomainResult<FilterFacets> {
+        let repo = TaskRepository::new(self.db);
+        let db_error = |e: rusqlite::Error| {
+            DomainError::BusinessRuleViolation(format!("Failed to load filter facets: {}", e))
+        };
+
+        Ok(FilterFacets {
+            statuses: to_facets(repo.count_by_status().map_err(db_error)?),
+            priorities: to_facets(repo.count_by_priority().map_err(db_error)?),
+            tags: to_facets(repo.count_by_tag().map_err(db_error)?),
+        })
+    }
+
+    /// Search tasks by query string, best matches first
+    ///
+    /// Searches title, description and notes (case- and accent-insensitive).
+    /// Results are ranked in SQL by `TaskRepository::search_ranked`, then capped at `limit` (default `DEFAULT_SEARCH_LIMIT`, at most `MAX_SEARCH_LIMIT`).
+    pub fn search_tasks(&self, query: &str, limit: Option<u32>) -> DomainResult<Vec<Task>> {
+        let limit = limit.unwrap_or(DEFAULT_SEARCH_LIMIT);
+        if limit == 0 || limit > MAX_SEARCH_LIMIT {
+            return Err(DomainError::field(
+                "limit",
+                format!("Limit must be between 1 and {}", MAX_SEARCH_LIMIT),
+            ));
+        }
+
+        TaskRepository::new(self.db)
+            .search_ranked(query, limit)
+            .map_err(|e| {
+                DomainError::BusinessRuleViolation(format!("Failed to search tasks: {}", e))
+            })
+    }
+
+    /// Search tasks (title, description, notes) and reminders (title, description) at once
+    ///
+    /// Matching is case-insensitive and each category is capped at
+    /// `GLOBAL_SEARCH_LIMIT` results. A blank query matches nothing.
+    pub fn global_search(&self, query: &str) -> DomainResult<GlobalSearchResult> {
+        let query = query.trim();
+        if query.is_empty() {
+            return Ok(GlobalSearchResult {
+                tasks: vec![],
+                reminders: vec![],
+            });
+        }
+
+        let tasks = TaskRepository::new(self.db)
+            .search(query, GLOBAL_SEARCH_LIMIT)
+            .map_err(|e| {
+                DomainError::BusinessRuleViolation(format!("Failed to search tasks: {}", e))
+            })?;
+
+        let reminders = ReminderRepository::new(self.db)
+            .search(query, GLOBAL_SEARCH_LIMIT)
+            .map_err(|e| {
+                DomainError::BusinessRuleViolation(format!("Failed to search reminders: {}", e))
+            })?;
+
+        Ok(GlobalSearchResult { tasks, reminders })
+    }
+
+    /// Get tasks due within a half-open range `[start, end)`, ordered by due date
+    ///
+    /// Business rules:
+    /// - `start` must be before `end`
+    /// - Tasks without a due date are never included
+    pub fn get_tasks_due_between(
+        &self,
+        start: DateTime<Utc>,
+        end: DateTime<Utc>,
+    ) -> DomainResult<Vec<Task>> {
+        if start >= end {
+            return Err(DomainError::InvalidDateTime(
+                "Range start must be before range end".to_string(),
+            ));
+        }
+
+        let repo = TaskRepository::new(self.db);
+
+        let filter = TaskFilter {
+            due_after: Some(start),
+            due_before: Some(end),
+            ..Default::default()
+        };
+        let sort = vec![TaskSort {
+            field: TaskSortField::DueDate,
+            direction: SortDirection::Asc,
+        }];
+        let pagination = Pagination {
+            page: 1,
+            page_size: 10000,
+        };
+
+        repo.find_all(Some(filter), Some(sort), pagination)
+            .map(|response| response.items)
+            .map_err(|e| {
+                DomainError::BusinessRuleViolation(format!("Failed to fetch tasks: {}", e))
+            })
+    }
+
+    /// Compare estimated and actual minutes of tasks completed in the last `days` days
+    ///
+    /// Only tasks with both values recorded are included.
+    pub fn get_estimation_report(&self, days: u32) -> DomainResult<EstimationReport> {
+        if days == 0 {
+            return Err(DomainError::field("days", "Days must be positive"));
+        }
+
+        let since = Utc::now()
+            .checked_sub_signed(Duration::days(days.into()))
+            .ok_or_else(|| {
+                DomainError::field(
+                    "days",
+                    format!("{} days reaches before the earliest date", days),
+                )
+            })?;
+        let entries = TaskRepository::new(self.db)
+            .find_estimation_pairs(since)
+            .map_err(|e| {
+                DomainError::BusinessRuleViolation(format!("Failed to fetch estimates: {}", e))
+            })?;
+
+        Ok(EstimationReport::new(entries))
+    }
+
+    /// Tasks created or updated after `since`, plus ids of tasks deleted since then
+    ///
+    /// Deletions are only known while they are still in the undo log, and undone
+    /// deletions are no longer reported.
+    pub fn get_tasks_modified_since(&self, since: DateTime<Utc>) -> DomainResult<TaskDelta> {
+        let as_of = Utc::now();
+
+        let tasks = TaskRepository::new(self.db)
+            .find_modified_since(since)
+            .map_err(|e| {
+                DomainError::BusinessRuleViolation(format!("Failed to fetch tasks: {}", e))
+            })?;
+        let deleted_ids = UndoRepository::new(self.db)
+            .deleted_task_ids_since(since)
+            .map_err(|e| {
+                DomainError::BusinessRuleViolation(format!("Failed to fetch deleted tasks: {}", e))
+            })?;
+
+        Ok(TaskDelta {
+            tasks,
+            deleted_ids,
+            as_of,
+        })
+    }
+
+    /// Get the "someday/maybe" list: deferred tasks, oldest first
+    pub fn get_deferred_tasks(&self) -> DomainResult<Vec<Task>> {
+        let repo = TaskRepository::new(self.db);
+
+        let filter = TaskFilter {
+            only_deferred: true,
+            ..Default::default()
+        };
+        let sort = vec![TaskSort {
+            field: TaskSortField::CreatedAt,
+            direction: SortDirection::Asc,
+        }];
+        let pagination = Pagination {
+            page: 1,
+            page_size: 10000,
+        };
+
+        repo.find_all(Some(filter), Some(sort), pagination)
+            .map(|response| response.items)
+            .map_err(|e| {
+                DomainError::BusinessRuleViolation(format!("Failed to fetch tasks: {}", e))
+            })
+    }
+
+    /// Get the inbox: tasks without a due date, oldest first
+    ///
+    /// `status` narrows the inbox further; completed tasks are left out unless
+    /// `include_completed` is set or `status` asks for them.
+    pub fn get_inbox_tasks(
+        &self,
+        status: Option<TaskStatus>,
+        include_completed: bool,
+    ) -> DomainResult<Vec<Task>> {
+        let repo = TaskRepository::new(self.db);
+
+        let filter = TaskFilter {
+            status,
+            include_completed: Some(include_completed),
+            only_no_due_date: true,
+            ..Default::default()
+        };
+        let sort = vec![TaskSort {
+            field: TaskSortField::CreatedAt,
+            direction: SortDirection::Asc,
+        }];
+        let pagination = Pagination {
+            page: 1,
+            page_size: 10000,
+        };
+
+        repo.find_all(Some(filter), Some(sort), pagination)
+            .map(|response| response.items)
+            .map_err(|e| {
+                DomainError::BusinessRuleViolation(format!("Failed to fetch tasks: {}", e))
+            })
+    }
+
+    /// Get tasks due during the user's current local day
+    ///
+    /// `utc_offset_minutes` is the user's offset from UTC (e.g. 420 for UTC+7),
+    /// so "today" follows the local calendar day rather than UTC midnight.
+    pub fn get_tasks_due_today(&self, utc_offset_minutes: i32) -> DomainResult<Vec<Task>> {
+        let (start, end) = local_day_bounds(Utc::now(), utc_offset_minutes)?;
+        self.get_tasks_due_between(start, end)
+    }
+
+    /// Get tasks due during the user's local week
+    ///
+    /// The week starts on the day chosen by the `first_day_of_week` setting.
+    pub fn get_tasks_due_this_week(&self, utc_offset_minutes: i32) -> DomainResult<Vec<Task>> {
+        self.get_tasks_due_in_week_of(Utc::now(), utc_offset_minutes)
+    }
+
+    /// Get tasks due during the local week containing `now`
+    fn get_tasks_due_in_week_of(
+        &self,
+        now: DateTime<Utc>,
+        utc_offset_minutes: i32,
+    ) -> DomainResult<Vec<Task>> {
+        let settings = SettingsRepository::new(self.db)
+            .get_app_settings()
+            .map_err(|e| {
+                DomainError::BusinessRuleViolation(format!("Failed to read settings: {}", e))
+            })?;
+
+        let (start, end) = local_week_bounds(now, utc_offset_minutes, settings.week_start())?;
+        self.get_tasks_due_between(start, end)
+    }
+
+    /// Auto-update overdue status for tasks
+    ///
+    /// This method finds all overdue tasks and returns them for notification purposes.
+    /// The "overdue" status is computed, not stored in the database.
+    ///
+    /// Business rules:
+    /// - A task is overdue if:
+    ///   - It has a due_date
+    ///   - The due_date plus the `overdue_grace_minutes` setting is in the past
+    ///   - The status is not Completed or Cancelled
+    pub fn auto_update_overdue_status(&self) -> DomainResult<Vec<Task>> {
+        let repo = TaskRepository::new(self.db);
+        let grace = self.overdue_grace()?;
+
+        // Get overdue tasks from repository
+        let overdue_tasks = repo.find_overdue(grace).map_err(|e| {
+            DomainError::BusinessRuleViolation(format!("Failed to fetch overdue tasks: {}", e))
+        })?;
+
+        Ok(overdue_tasks)
+    }
+
+    /// Grace period from the `overdue_grace_minutes` setting
+    fn overdue_grace(&self) -> DomainResult<Duration> {
+        SettingsRepository::new(self.db)
+            .get_app_settings()
+            .map(|settings| settings.overdue_grace())
+            .map_err(|e| {
+                DomainError::BusinessRuleViolation(format!("Failed to read settings: {}", e))
+            })
+    }
+
+    /// Push every overdue task's due date forward by `by`
+    ///
+    /// Only non-terminal tasks past their due date (and grace period) are moved; all
+    /// shifts are applied in a single transaction. Returns the number of tasks updated.
+    pub fn postpone_overdue(&self, by: Duration) -> DomainResult<usize> {
+        if by <= Duration::zero() {
+            return Err(DomainError::ValidationError(
+                "Postpone duration must be positive".to_string(),
+            ));
+        }
+
+        let repo = TaskRepository::new(self.db);
+        let grace = self.overdue_grace()?;
+
+        let overdue_tasks = repo.find_overdue(grace).map_err(|e| {
+            DomainError::BusinessRuleViolation(format!("Failed to fetch overdue tasks: {}", e))
+        })?;
+
+        let due_dates: Vec<(String, DateTime<Utc>)> = overdue_tasks
+            .into_iter()
+            .filter_map(|task| task.due_date.map(|due| (task.id, due + by)))
+            .collect();
+
+        repo.set_due_dates(&due_dates).map_err(|e| {
+            DomainError::BusinessRuleViolation(format!("Failed to postpone tasks: {}", e))
+        })
+    }
+
+    /// Ids of completed (and optionally cancelled) tasks finished before `cutoff`
+    ///
+    /// `None` matches every finished task. Tasks with unfinished subtasks are excluded.
+    pub fn completed_ids_before(
+        &self,
+        cutoff: Option<DateTime<Utc>>,
+        include_cancelled: bool,
+    ) -> DomainResult<Vec<String>> {
+        TaskRepository::new(self.db)
+            .find_finished_ids_before(cutoff, include_cancelled)
+            .map_err(|e| {
+                DomainError::BusinessRuleViolation(format!(
+                    "Failed to fetch completed tasks: {}",
+                    e
+                ))
+            })
+    }
+
+    /// Delete completed (and optionally cancelled) tasks finished before `cutoff` in one
+    /// transaction, returning how many were deleted
+    pub fn delete_completed_before(
+        &self,
+        cutoff: Option<DateTime<Utc>>,
+        include_cancelled: bool,
+    ) -> DomainResult<usize> {
+        let ids = self.completed_ids_before(cutoff, include_cancelled)?;
+
+        TaskRepository::new(self.db).delete_many(&ids).map_err(|e| {
+            DomainError::BusinessRuleViolation(format!("Failed to delete completed tasks: {}", e))
+        })
+    }
+
+    /// Get tasks with their effective status (including computed Overdue)
+    pub fn get_tasks_with_effective_status(
+        &self,
+    ) -> DomainResult<Vec<(Task, EffectiveTaskStatus)>> {
+        let repo = TaskRepository::new(self.db);
+        let grace = self.overdue_grace()?;
+
+        // Get all non-terminal tasks
+        let tasks = repo
+            .find_all(None, None, crate::models::Pagination::default())
+            .map_err(|e| {
+                DomainError::BusinessRuleViolation(format!("Failed to fetch tasks: {}", e))
+            })?;
+
+        // Map to effective status
+        let tasks_with_status: Vec<(Task, EffectiveTaskStatus)> = tasks
+            .items
+            .into_iter()
+            .map(|task| {
+                let status = task.effective_status(grace);
+                (task, status)
+            })
+            .collect();
+
+        Ok(tasks_with_status)
+    }
+
+    /// Transition a task to a new status with validation
+    ///
+    /// Business rules:
+    /// - Status transition must be valid according to state machine
+    /// - Cannot transition from terminal states
+    pub fn transition_status(&self, id: &str, new_status: TaskStatus) -> DomainResult<Task> {
+        let repo = TaskRepository::new(self.db);
+
+        // Fetch existing task
+        let existing_task = repo
+            .find_by_id(id)
+            .map_err(|e| DomainError::BusinessRuleViolation(format!("Database error: {}", e)))?
+            .ok_or_else(|| DomainError::TaskNotFound(id.to_string()))?;
+
+        // Validate transition
+        if !existing_task.status.can_transition_to(&new_status) {
+            return Err(DomainError::InvalidStatusTransition {
+                from: existing_task.status.as_str().to_string(),
+                to: new_status.as_str().to_string(),
+                reason: format!(
+                    "Cannot transition from {} to {}. Valid transitions: {}",
+                    existing_task.status.as_str(),
+                    new_status.as_str(),
+                    self.get_valid_transitions(&existing_task.status)
+                ),
+            });
+        }
+
+        // Update status
+        let update_dto = UpdateTaskDto {
+            title: None,
+            description: None,
+            status: Some(new_status),
+            priority: None,
+            due_date: None,
+            image_path: None,
+            notes: None,
+            estimated_minutes: None,
+            actual_minutes: None,
+            tag_ids: None,
+            color: None,
+            clear_fields: vec![],
+        };
+
+        repo.update(id, update_dto).map_err(|e| {
+            DomainError::BusinessRuleViolation(format!("Failed to transition status: {}", e))
+        })
+    }
+
+    /// Get valid transition states for a given status
+    fn get_valid_transitions(&self, status: &TaskStatus) -> String {
+        match status {
+            TaskStatus::Pending => "InProgress, Completed, Cancelled".to_string(),
+            TaskStatus::InProgress => "Pending, Completed, Cancelled".to_string(),
+            TaskStatus::Completed => "None (terminal state)".to_string(),
+            TaskStatus::Cancelled => "None (terminal state)".to_string(),
+        }
+    }
+
+    /// Count tasks by their effective status (including overdue)
+    pub fn count_by_effective_status(&self) -> DomainResult<Vec<(EffectiveTaskStatus, usize)>> {
+        let tasks_with_status = self.get_tasks_with_effective_status()?;
+
+        // Count by effective status
+        let mut counts = std::collections::HashMap::new();
+        for (_, status) in tasks_with_status {
+            *counts.entry(status).or_insert(0) += 1;
+        }
+
+        let result: Vec<(EffectiveTaskStatus, usize)> = counts.into_iter().collect();
+        Ok(result)
+    }
+}
+
+/// Convert a UTC offset in minutes, rejecting offsets of a day or more
+fn local_offset(utc_offset_minutes: i32) -> DomainResult<FixedOffset> {
+    utc_offset_minutes
+        .checked_mul(60)
+        .and_then(FixedOffset::east_opt)
+        .ok_or_else(|| {
+            DomainError::field(
+                "utc_offset_minutes",
+                format!("Invalid UTC offset: {} minutes", utc_offset_minutes),
+            )
+        })
+}
+
+/// Compute the UTC bounds `[start, end)` of the local calendar day containing `now`
+fn local_day_bounds(
+    now: DateTime<Utc>,
+    utc_offset_minutes: i32,
+) -> DomainResult<(DateTime<Utc>, DateTime<Utc>)> {
+    let offset = local_offset(utc_offset_minutes)?;
+
+    let local_midnight = now
+        .with_timezone(&offset)
+        .date_naive()
+        .and_hms_opt(0, 0, 0)
+        .and_then(|midnight| midnight.and_local_timezone(offset).single())
+        .ok_or_else(|| {
+            DomainError::InvalidDateTime("Could not determine start of local day".to_string())
+        })?;
+
+    let start = local_midnight.with_timezone(&Utc);
+    Ok((start, start + Duration::days(1)))
+}
+
+/// Compute the UTC bounds `[start, end)` of the local week containing `now`, starting on `week_start`
+fn local_week_bounds(
+    now: DateTime<Utc>,
+    utc_offset_minutes: i32,
+    week_start: Weekday,
+) -> DomainResult<(DateTime<Utc>, DateTime<Utc>)> {
+    let offset = local_offset(utc_offset_minutes)?;
+    let (day_start, _) = local_day_bounds(now, utc_offset_minutes)?;
+    let days_into_week = now.with_timezone(&offset).weekday().days_since(week_start);
+
+    let start = day_start - Duration::days(days_into_week as i64);
+    Ok((start, start + Duration::days(7)))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::db::Database;
+    use crate::models::{CreateTaskDto, TaskPriority, TaskStatus, UpdateTaskDto};
+    use rusqlite::Connection;
+
+    fn setup_test_db() -> Database {
+        let conn = Connection::open_in_memory().unwrap();
+
+        // Create tables matching actual schema
+        conn.execute(
+            "CREATE TABLE tasks (
+                id TEXT PRIMARY KEY,
+                title TEXT NOT NULL,
+                description TEXT,
+                status TEXT NOT NULL,
+                priority TEXT NOT NULL,
+                due_date TEXT,
+                completed_at TEXT,
+                image_path TEXT,
+                notes TEXT,
+                estimated_minutes INTEGER,
+                actual_minutes INTEGER,
+                color TEXT,
+                is_pinned INTEGER NOT NULL DEFAULT 0,
+                series_id TEXT,
+                is_deferred INTEGER NOT NULL DEFAULT 0,
+                deferred_until TEXT,
+                completion_note TEXT,
+                parent_id TEXT,
+                sort_order INTEGER NOT NULL DEFAULT 0,
+                overdue_notified_at TEXT,
+                created_at TEXT NOT NULL,
+                updated_at TEXT NOT NULL,
+                FOREIGN KEY (parent_id) REFERENCES tasks(id) ON DELETE CASCADE
+            )",
+            [],
+        )
+        .unwrap();
+
+        conn.execute(
+            "CREATE TABLE task_tags (
+                task_id TEXT NOT NULL,
+                tag_id TEXT NOT NULL,
+                created_at TEXT NOT NULL,
+                PRIMARY KEY (task_id, tag_id),
+                FOREIGN KEY (task_id) REFERENCES tasks(id) ON DELETE CASCADE
+            )",
+            [],
+        )
+        .unwrap();
+
+        conn.execute(
+            "CREATE TABLE tags (
+                id TEXT PRIMARY KEY,
+                name TEXT NOT NULL,
+                color TEXT NOT NULL,
+                created_at TEXT NOT NULL
+            )",
+            [],
+        )
+        .unwrap();
+
+        conn.execute(
+            "CREATE TABLE task_dependencies (
+                task_id TEXT NOT NULL,
+                depends_on_id TEXT NOT NULL,
+                created_at TEXT NOT NULL,
+                PRIMARY KEY (task_id, depends_on_id)
+            )",
+            [],
+        )
+        .unwrap();
+
+        conn.execute(
+            "CREATE TABLE reminders (
+                id TEXT PRIMARY KEY,
+                task_id TEXT,
+                title TEXT NOT NULL,
+                description TEXT,
+                remind_at TEXT NOT NULL,
+                repeat_interval TEXT NOT NULL,
+                is_active INTEGER NOT NULL DEFAULT 1,
+                last_triggered_at TEXT,
+                created_at TEXT NOT NULL,
+                updated_at TEXT NOT NULL,
+                acknowledged_at TEXT,
+                escalation_minutes INTEGER,
+                escalation_count INTEGER NOT NULL DEFAULT 0,
+                delivery TEXT NOT NULL DEFAULT 'notification'
+            )",
+            [],
+        )
+        .unwrap();
+
+        conn.execute(
+            "CREATE TABLE settings (
+                key TEXT PRIMARY KEY,
+                value TEXT NOT NULL,
+                updated_at TEXT NOT NULL DEFAULT (datetime('now'))
+            )",
+            [],
+        )
+        .unwrap();
+
+        conn.execute(
+            "CREATE TABLE task_checklist_items (
+                id TEXT PRIMARY KEY,
+                task_id TEXT NOT NULL,
+                text TEXT NOT NULL,
+                is_done INTEGER NOT NULL DEFAULT 0,
+                position INTEGER NOT NULL DEFAULT 0
+            )",
+            [],
+        )
+        .unwrap();
+
+        conn.execute(
+            "CREATE TABLE task_comments (
+                id TEXT PRIMARY KEY,
+                task_id TEXT NOT NULL,
+                body TEXT NOT NULL,
+                created_at TEXT NOT NULL
+            )",
+            [],
+        )
+        .unwrap();
+
+        conn.execute(
+            "CREATE TABLE task_status_history (
+                id TEXT PRIMARY KEY,
+                task_id TEXT NOT NULL,
+                from_status TEXT NOT NULL,
+                to_status TEXT NOT NULL,
+                changed_at TEXT NOT NULL
+            )",
+            [],
+        )
+        .unwrap();
+
+        conn.execute(
+            "CREATE TABLE task_focus_sessions (
+                id TEXT PRIMARY KEY,
+                task_id TEXT NOT NULL,
+                work_minutes INTEGER NOT NULL,
+                break_minutes INTEGER NOT NULL,
+                break_reminder_id TEXT,
+                work_reminder_id TEXT,
+                started_at TEXT NOT NULL,
+                ended_at TEXT,
+                logged_minutes INTEGER
+            )",
+            [],
+        )
+        .unwrap();
+
+        conn.execute(
+            "CREATE TABLE task_attachments (
+                id TEXT PRIMARY KEY,
+                task_id TEXT NOT NULL,
+                file_path TEXT NOT NULL,
+                mime_type TEXT,
+                created_at TEXT NOT NULL
+            )",
+            [],
+        )
+        .unwrap();
+
+        conn.execute(
+            "CREATE TABLE reminder_trigger_log (
+                reminder_id TEXT NOT NULL,
+                scheduled_at TEXT NOT NULL,
+                fired_at TEXT NOT NULL,
+                PRIMARY KEY (reminder_id, scheduled_at)
+            )",
+            [],
+        )
+        .unwrap();
+
+        conn.execute(
+            "CREATE TABLE undo_log (
+                id INTEGER PRIMARY KEY AUTOINCREMENT,
+                entity_type TEXT NOT NULL,
+                entity_id TEXT NOT NULL,
+                snapshot TEXT NOT NULL,
+                deleted_at TEXT NOT NULL
+            )",
+            [],
+        )
+        .unwrap();
+
+        Database::new_from_connection(conn)
+    }
+
+    #[test]
+    fn test_status_transitions() {
+        // Pending can go to any state
+        assert!(TaskStatus::Pending.can_transition_to(&TaskStatus::InProgress));
+        assert!(TaskStatus::Pending.can_transition_to(&TaskStatus::Completed));
+        assert!(TaskStatus::Pending.can_transition_to(&TaskStatus::Cancelled));
+
+        // InProgress can go to Pending, Completed, or Cancelled
+        assert!(TaskStatus::InProgress.can_transition_to(&TaskStatus::Pending));
+        assert!(TaskStatus::InProgress.can_transition_to(&TaskStatus::Completed));
+        assert!(TaskStatus::InProgress.can_transition_to(&TaskStatus::Cancelled));
+
+        // Terminal states cannot transition
+        assert!(!TaskStatus::Completed.can_transition_to(&TaskStatus::Pending));
+        assert!(!TaskStatus::Completed.can_transition_to(&TaskStatus::InProgress));
+        assert!(!TaskStatus::Cancelled.can_transition_to(&TaskStatus::Pending));
+    }
+
+    #[test]
+    fn test_terminal_states() {
+        assert!(TaskStatus::Completed.is_terminal());
+        assert!(TaskStatus::Cancelled.is_terminal());
+        assert!(!TaskStatus::Pending.is_terminal());
+        assert!(!TaskStatus::InProgress.is_terminal());
+    }
+
+    #[test]
+    fn test_create_task_success() {
+        let db = setup_test_db();
+        let service = TaskService::new(&db);
+
+        let dto = CreateTaskDto {
+            title: "Test Task".to_string(),
+            description: Some("Test Description".to_string()),
+            priority: TaskPriority::High,
+            due_date: None,
+            notes: None,
+            estimated_minutes: Some(60),
+            image_path: None,
+            tag_ids: vec![],
+            parent_id: None,
+            color: None,
+        };
+
+        let result = service.create_task(dto);
+        assert!(result.is_ok());
+
+        let task = result.unwrap();
+        assert_eq!(task.title, "Test Task");
+        assert_eq!(task.priority, TaskPriority::High);
+        assert_eq!(task.status, TaskStatus::Pending);
+    }
+
+    #[test]
+    fn test_create_task_empty_title_reports_title_field() {
+        let db = setup_test_db();
+        let service = TaskService::new(&db);
+
+        let dto = CreateTaskDto {
+            title: "   ".to_string(),
+            description: None,
+            priority: TaskPriority::Medium,
+            due_date: None,
+            notes: None,
+            estimated_minutes: None,
+            image_path: None,
+            tag_ids: vec![],
+            parent_id: None,
+            color: None,
+        };
+
+        match service.create_task(dto) {
+            Err(DomainError::FieldValidation { field, message }) => {
+                assert_eq!(field, "title");
+                assert_eq!(message, "Title cannot be empty");
+            }
+            other => panic!("expected title field error, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn test_create_task_validates_title_length() {
+        let db = setup_test_db();
+        let service = TaskService::new(&db);
+
+        // Empty title
+        let dto = CreateTaskDto {
+            title: "   ".to_string(), // Whitespace only - will be trimmed to empty
+            description: None,
+            priority: TaskPriority::Medium,
+            due_date: None,
+            notes: None,
+            estimated_minutes: None,
+            image_path: None,
+            tag_ids: vec![],
+            parent_id: None,
+            color: None,
+        };
+
+        let result = service.create_task(dto);
+        assert!(result.is_err());
+        assert!(matches!(
+            result.unwrap_err(),
+            DomainError::FieldValidation { ref field, .. } if field == "title"
+        ));
+    }
+
+    #[test]
+    fn test_delete_task_success() {
+        let db = setup_test_db();
+        let service = TaskService::new(&db);
+
+        // Create a task
+        let dto = CreateTaskDto {
+            title: "Task to Delete".to_string(),
+            description: None,
+            priority: TaskPriority::Medium,
+            due_date: None,
+            notes: None,
+            estimated_minutes: None,
+            image_path: None,
+            tag_ids: vec![],
+            parent_id: None,
+            color: None,
+        };
+
+        let task = service.create_task(dto).unwrap();
+
+        // Delete it
+        let result = service.delete_task(&task.id);
+        assert!(result.is_ok());
+        assert_eq!(result.unwrap(), true);
+
+        // Verify it's deleted
+        let get_result = service.get_task(&task.id);
+        assert!(get_result.is_err());
+    }
+
+    fn create_simple_task(service: &TaskService, title: &str) -> Task {
+        service
+            .create_task(CreateTaskDto {
+                title: title.to_string(),
+                description: None,
+                priority: TaskPriority::Medium,
+                due_date: None,
+                notes: None,
+                estimated_minutes: None,
+                image_path: None,
+                tag_ids: vec![],
+                parent_id: None,
+                color: None,
+            })
+            .unwrap()
+    }
+
+    #[test]
+    fn test_mark_done_rejected_while_blocked() {
+        let db = setup_test_db();
+        let service = TaskService::new(&db);
+
+        let task = create_simple_task(&service, "Deploy");
+        let blocker = create_simple_task(&service, "Write tests");
+        service.add_dependency(&task.id, &blocker.id).unwrap();
+
+        let result = service.mark_done(&task.id, None, None, false, false);
+        assert!(matches!(result, Err(DomainError::BusinessRuleViolation(_))));
+
+        // Completing the blocker unblocks the task
+        service
+            .mark_done(&blocker.id, None, None, false, false)
+            .unwrap();
+        let done = service
+            .mark_done(&task.id, None, None, false, false)
+            .unwrap();
+        assert_eq!(done.task.status, TaskStatus::Completed);
+    }
+
+    #[test]
+    fn test_mark_done_with_force_ignores_blockers() {
+        let db = setup_test_db();
+        let service = TaskService::new(&db);
+
+        let task = create_simple_task(&service, "Deploy");
+        let blocker = create_simple_task(&service, "Write tests");
+        service.add_dependency(&task.id, &blocker.id).unwrap();
+
+        let done = service
+            .mark_done(&task.id, None, None, true, false)
+            .unwrap();
+        assert_eq!(done.task.status, TaskStatus::Completed);
+    }
+
+    #[test]
+    fn test_add_dependency_rejects_cycles() {
+        let db = setup_test_db();
+        let service = TaskService::new(&db);
+
+        let a = create_simple_task(&service, "A");
+        let b = create_simple_task(&service, "B");
+        let c = create_simple_task(&service, "C");
+
+        service.add_dependency(&a.id, &b.id).unwrap();
+        service.add_dependency(&b.id, &c.id).unwrap();
+
+        // C -> A would close the loop A -> B -> C -> A
+        let result = service.add_dependency(&c.id, &a.id);
+        assert!(matches!(result, Err(DomainError::BusinessRuleViolation(_))));
+
+        // Self-dependency is a trivial cycle
+        assert!(service.add_dependency(&a.id, &a.id).is_err());
+
+        let deps = service.get_dependencies(&a.id).unwrap();
+        assert_eq!(deps.len(), 1);
+        assert_eq!(deps[0].id, b.id);
+    }
+
+    fn create_subtask(service: &TaskService, parent_id: &str, title: &str) -> Task {
+        service
+            .create_task(CreateTaskDto {
+                title: title.to_string(),
+                description: None,
+                priority: TaskPriority::Medium,
+                due_date: None,
+                notes: None,
+                estimated_minutes: None,
+                image_path: None,
+                tag_ids: vec![],
+                parent_id: Some(parent_id.to_string()),
+                color: None,
+            })
+            .unwrap()
+    }
+
+    #[test]
+    fn test_compute_progress_mixed_subtasks() {
+        let db = setup_test_db();
+        let service = TaskService::new(&db);
+
+        let parent = create_simple_task(&service, "Release");
+        let children: Vec<Task> = ["Changelog", "Tag", "Publish", "Announce"]
+            .iter()
+            .map(|title| create_subtask(&service, &parent.id, title))
+            .collect();
+
+        assert_eq!(service.compute_progress(&parent.id).unwrap(), 0.0);
+
+        service
+            .mark_done(&children[0].id, None, None, false, false)
+            .unwrap();
+        service
+            .mark_done(&children[1].id, None, None, false, false)
+            .unwrap();
+        service
+            .transition_status(&children[2].id, TaskStatus::InProgress)
+            .unwrap();
+
+        let enriched = service.get_task_with_progress(&parent.id).unwrap();
+        assert_eq!(enriched.task.id, parent.id);
+        assert_eq!(enriched.progress, 0.5);
+    }
+
+    #[test]
+    fn test_compute_progress_leaf_task() {
+        let db = setup_test_db();
+        let service = TaskService::new(&db);
+
+        let leaf = create_simple_task(&service, "Leaf");
+        assert_eq!(service.compute_progress(&leaf.id).unwrap(), 0.0);
+
+        service
+            .mark_done(&leaf.id, None, None, false, false)
+            .unwrap();
+        assert_eq!(service.compute_progress(&leaf.id).unwrap(), 1.0);
+    }
+
+    #[test]
+    fn test_create_subtask_requires_existing_parent() {
+        let db = setup_test_db();
+        let service = TaskService::new(&db);
+
+        let result = service.create_task(CreateTaskDto {
+            title: "Orphan".to_string(),
+            description: None,
+            priority: TaskPriority::Medium,
+            due_date: None,
+            notes: None,
+            estimated_minutes: None,
+            image_path: None,
+            tag_ids: vec![],
+            parent_id: Some("missing".to_string()),
+            color: None,
+        });
+        assert!(matches!(result, Err(DomainError::TaskNotFound(_))));
+    }
+
+    fn ordered_titles(db: &Database) -> Vec<String> {
+        let repo = TaskRepository::new(db);
+        let sort = vec![TaskSort {
+            field: TaskSortField::SortOrder,
+            direction: SortDirection::Asc,
+        }];
+        repo.find_all(None, Some(sort), Pagination::default())
+            .unwrap()
+            .items
+            .into_iter()
+            .map(|t| t.title)
+            .collect()
+    }
+
+    #[test]
+    fn test_new_tasks_are_appended_to_the_end() {
+        let db = setup_test_db();
+        let service = TaskService::new(&db);
+
+        let first = create_simple_task(&service, "First");
+        let second = create_simple_task(&service, "Second");
+        assert!(second.sort_order > first.sort_order);
+    }
+
+    #[test]
+    fn test_pin_and_unpin_task() {
+        let db = setup_test_db();
+        let service = TaskService::new(&db);
+
+        let task = create_simple_task(&service, "Quarterly report");
+        assert!(!task.is_pinned);
+
+        assert!(service.pin_task(&task.id).unwrap().is_pinned);
+        assert!(!service.unpin_task(&task.id).unwrap().is_pinned);
+        assert!(matches!(
+            service.pin_task("missing"),
+            Err(DomainError::TaskNotFound(_))
+        ));
+    }
+
+    #[test]
+    fn test_deferred_tasks_excluded_from_listings_and_overdue() {
+        let db = setup_test_db();
+        let service = TaskService::new(&db);
+
+        let active = create_simple_task(&service, "Active");
+        let someday = create_simple_task(&service, "Learn piano");
+        db.connection()
+            .execute(
+                "UPDATE tasks SET due_date = ?1",
+                [(Utc::now() - Duration::hours(1)).to_rfc3339()],
+            )
+            .unwrap();
+
+        let deferred = service.defer_task(&someday.id, None).unwrap();
+        assert!(deferred.is_deferred);
+        assert_eq!(deferred.status, TaskStatus::Pending);
+        assert_eq!(
+            deferred.effective_status(Duration::zero()),
+            EffectiveTaskStatus::Pending
+        );
+
+        let listed = TaskRepository::new(&db)
+            .find_all(Some(TaskFilter::default()), None, Pagination::default())
+            .unwrap();
+        assert_eq!(listed.items.len(), 1);
+        assert_eq!(listed.items[0].id, active.id);
+
+        let overdue: Vec<String> = service
+            .auto_update_overdue_status()
+            .unwrap()
+            .into_iter()
+            .map(|t| t.id)
+            .collect();
+        assert_eq!(overdue, vec![active.id.clone()]);
+
+        let someday_list = service.get_deferred_tasks().unwrap();
+        assert_eq!(someday_list.len(), 1);
+        assert_eq!(someday_list[0].id, someday.id);
+
+        assert!(!service.undefer_task(&someday.id).unwrap().is_deferred);
+        assert!(service.get_deferred_tasks().unwrap().is_empty());
+        assert_eq!(service.auto_update_overdue_status().unwrap().len(), 2);
+    }
+
+    #[test]
+    fn test_defer_task_validation() {
+        let db = setup_test_db();
+        let service = TaskService::new(&db);
+        let task = create_simple_task(&service, "Read book");
+
+        let past = service.defer_task(&task.id, Some(Utc::now() - Duration::minutes(1)));
+        assert!(
+            matches!(past, Err(DomainError::FieldValidation { ref field, .. }) if field == "deferred_until")
+        );
+
+        service
+            .mark_done(&task.id, None, None, false, false)
+            .unwrap();
+        assert!(matches!(
+            service.defer_task(&task.id, None),
+            Err(DomainError::TaskNotModifiable(_))
+        ));
+        assert!(matches!(
+            service.undefer_task("missing"),
+            Err(DomainError::TaskNotFound(_))
+        ));
+    }
+
+    #[test]
+    fn test_reorder_between_two_items() {
+        let db = setup_test_db();
+        let service = TaskService::new(&db);
+
+        let a = create_simple_task(&service, "A");
+        create_simple_task(&service, "B");
+        let c = create_simple_task(&service, "C");
+
+        service.reorder(&c.id, Some(a.id.clone())).unwrap();
+        assert_eq!(ordered_titles(&db), vec!["A", "C", "B"]);
+    }
+
+    #[test]
+    fn test_reorder_to_front() {
+        let db = setup_test_db();
+        let service = TaskService::new(&db);
+
+        create_simple_task(&service, "A");
+        create_simple_task(&service, "B");
+        let c = create_simple_task(&service, "C");
+
+        service.reorder(&c.id, None).unwrap();
+        assert_eq!(ordered_titles(&db), vec!["C", "A", "B"]);
+    }
+
+    #[test]
+    fn test_reorder_renumbers_when_gap_is_exhausted() {
+        let db = setup_test_db();
+        let service = TaskService::new(&db);
+
+        let a = create_simple_task(&service, "A");
+        let b = create_simple_task(&service, "B");
+        let c = create_simple_task(&service, "C");
+
+        // Squeeze A and B next to each other so there is no room between them
+        let repo = TaskRepository::new(&db);
+        repo.set_sort_order(&a.id, 10).unwrap();
+        repo.set_sort_order(&b.id, 11).unwrap();
+
+        service.reorder(&c.id, Some(a.id.clone())).unwrap();
+        assert_eq!(ordered_titles(&db), vec!["A", "C", "B"]);
+    }
+
+    #[test]
+    fn test_reorder_rolls_back_renumbering_when_move_fails() {
+        let db = setup_test_db();
+        let service = TaskService::new(&db);
+
+        let a = create_simple_task(&service, "A");
+        let b = create_simple_task(&service, "B");
+        let c = create_simple_task(&service, "C");
+
+        let repo = TaskRepository::new(&db);
+        repo.set_sort_order(&a.id, 10).unwrap();
+        repo.set_sort_order(&b.id, 11).unwrap();
+        // Let the renumbering through but reject C's final, off-gap position
+        db.connection()
+            .execute_batch(&format!(
+                "CREATE TRIGGER reject_move BEFORE UPDATE OF sort_order ON tasks
+                 WHEN NEW.id = '{}' AND NEW.sort_order % {} != 0
+                 BEGIN SELECT RAISE(ABORT, 'rejected'); END;",
+                c.id, SORT_ORDER_GAP
+            ))
+            .unwrap();
+
+        assert!(service.reorder(&c.id, Some(a.id.clone())).is_err());
+        assert_eq!(service.get_task(&a.id).unwrap().sort_order, 10);
+        assert_eq!(service.get_task(&b.id).unwrap().sort_order, 11);
+    }
+
+    fn create_task_due_at(db: &Database, title: &str, due_date: DateTime<Utc>) -> Task {
+        let repo = TaskRepository::new(db);
+        repo.create(CreateTaskDto {
+            title: title.to_string(),
+            description: None,
+            priority: TaskPriority::Medium,
+            due_date: Some(due_date),
+            notes: None,
+            estimated_minutes: None,
+            image_path: None,
+            tag_ids: vec![],
+            parent_id: None,
+            color: None,
+        })
+        .unwrap()
+    }
+
+    #[test]
+    fn test_get_tasks_due_between_boundaries() {
+        let db = setup_test_db();
+        let service = TaskService::new(&db);
+
+        let start = Utc::now() + Duration::days(1);
+        let end = start + Duration::days(1);
+
+        create_task_due_at(&db, "At end", end);
+        create_task_due_at(&db, "Inside", start + Duration::hours(5));
+        create_task_due_at(&db, "At start", start);
+        create_task_due_at(&db, "Before start", start - Duration::seconds(1));
+
+        let tasks = service.get_tasks_due_between(start, end).unwrap();
+        let titles: Vec<&str> = tasks.iter().map(|t| t.title.as_str()).collect();
+
+        // Start is inclusive, end is exclusive, ordered by due date ascending
+        assert_eq!(titles, vec!["At start", "Inside"]);
+    }
+
+    #[test]
+    fn test_get_tasks_due_between_rejects_inverted_range() {
+        let db = setup_test_db();
+        let service = TaskService::new(&db);
+
+        let start = Utc::now();
+        let result = service.get_tasks_due_between(start, start - Duration::hours(1));
+        assert!(matches!(result, Err(DomainError::InvalidDateTime(_))));
+    }
+
+    #[test]
+    fn test_local_day_bounds_uses_offset() {
+        // 2024-03-10 20:00 UTC is already 2024-03-11 03:00 in UTC+7
+        let now = DateTime::parse_from_rfc3339("2024-03-10T20:00:00Z")
+            .unwrap()
+            .with_timezone(&Utc);
+
+        let (start, end) = local_day_bounds(now, 7 * 60).unwrap();
+        assert_eq!(start.to_rfc3339(), "2024-03-10T17:00:00+00:00");
+        assert_eq!(end.to_rfc3339(), "2024-03-11T17:00:00+00:00");
+
+        let (start, _) = local_day_bounds(now, -5 * 60).unwrap();
+        assert_eq!(start.to_rfc3339(), "2024-03-10T05:00:00+00:00");
+
+        assert!(local_day_bounds(now, 24 * 60).is_err());
+        assert!(matches!(
+            local_day_bounds(now, i32::MAX),
+            Err(DomainError::FieldValidation { ref field, .. }) if field == "utc_offset_minutes"
+        ));
+    }
+
+    #[test]
+    fn test_local_week_bounds_depends_on_first_day_of_week() {
+        // 2024-03-10 is a Sunday
+        let sunday = DateTime::parse_from_rfc3339("2024-03-10T12:00:00Z")
+            .unwrap()
+            .with_timezone(&Utc);
+
+        // Monday-start: Sunday is the last day of the week that began on the 4th
+        let (start, end) = local_week_bounds(sunday, 0, Weekday::Mon).unwrap();
+        assert_eq!(start.to_rfc3339(), "2024-03-04T00:00:00+00:00");
+        assert_eq!(end.to_rfc3339(), "2024-03-11T00:00:00+00:00");
+
+        // Sunday-start: the same instant opens a new week
+        let (start, end) = local_week_bounds(sunday, 0, Weekday::Sun).unwrap();
+        assert_eq!(start.to_rfc3339(), "2024-03-10T00:00:00+00:00");
+        assert_eq!(end.to_rfc3339(), "2024-03-17T00:00:00+00:00");
+
+        // The local date decides the weekday: it is already Monday in UTC+14
+        let (start, _) = local_week_bounds(sunday, 14 * 60, Weekday::Sun).unwrap();
+        assert_eq!(start.to_rfc3339(), "2024-03-09T10:00:00+00:00");
+
+        assert!(matches!(
+            local_week_bounds(sunday, i32::MIN, Weekday::Mon),
+            Err(DomainError::FieldValidation { ref field, .. }) if field == "utc_offset_minutes"
+        ));
+    }
+
+    #[test]
+    fn test_get_tasks_due_this_week_uses_setting() {
+        let db = setup_test_db();
+        let service = TaskService::new(&db);
+        let at = |rfc3339: &str| {
+            DateTime::parse_from_rfc3339(rfc3339)
+                .unwrap()
+                .with_timezone(&Utc)
+        };
+
+        let set_due = |title: &str, due: &str| {
+            let task = create_simple_task(&service, title);
+            db.connection()
+                .execute(
+                    "UPDATE tasks SET due_date = ?1 WHERE id = ?2",
+                    rusqlite::params![at(due).to_rfc3339(), task.id],
+                )
+                .unwrap();
+        };
+        set_due("Previous Sunday", "2024-03-03T09:00:00Z");
+        set_due("Wednesday", "2024-03-06T09:00:00Z");
+        set_due("Sunday", "2024-03-10T09:00:00Z");
+
+        // Wednesday 2024-03-06
+        let now = at("2024-03-06T12:00:00Z");
+        let titles = || -> Vec<String> {
+            let mut titles: Vec<String> = service
+                .get_tasks_due_in_week_of(now, 0)
+                .unwrap()
+                .into_iter()
+                .map(|t| t.title)
+                .collect();
+            titles.sort();
+            titles
+        };
+
+        // Default Monday start: Mon 4th .. Sun 10th
+        assert_eq!(titles(), vec!["Sunday", "Wednesday"]);
+
+        // Sunday start: Sun 3rd .. Sat 9th
+        SettingsRepository::new(&db)
+            .set("first_day_of_week", "sunday")
+            .unwrap();
+        assert_eq!(titles(), vec!["Previous Sunday", "Wednesday"]);
+    }
+
+    #[test]
+    fn test_add_attachment_guesses_mime_type() {
+        let db = setup_test_db();
+        let service = TaskService::new(&db);
+        let task = create_simple_task(&service, "With file");
+
+        let file = std::env::temp_dir().join(format!("attachment-{}.png", Uuid::new_v4()));
+        std::fs::write(&file, b"png").unwrap();
+        let file_path = file.to_str().unwrap();
+
+        let attachment = service.add_attachment(&task.id, file_path, None).unwrap();
+        assert_eq!(attachment.mime_type, Some("image/png".to_string()));
+
+        let attachments = service.list_attachments(&task.id).unwrap();
+        assert_eq!(attachments.len(), 1);
+        assert_eq!(attachments[0].file_path, file_path);
+
+        assert!(service.remove_attachment(&attachment.id).unwrap());
+        assert!(service.list_attachments(&task.id).unwrap().is_empty());
+
+        std::fs::remove_file(&file).unwrap();
+    }
+
+    #[test]
+    fn test_add_attachment_rejects_invalid_paths() {
+        let db = setup_test_db();
+        let service = TaskService::new(&db);
+        let task = create_simple_task(&service, "With file");
+
+        let result = service.add_attachment(&task.id, "  ", None);
+        assert!(matches!(result, Err(DomainError::InvalidInput(_))));
+
+        let result = service.add_attachment(&task.id, "../secret.txt", None);
+        assert!(matches!(result, Err(DomainError::InvalidInput(_))));
+
+        let missing = std::env::temp_dir().join(format!("missing-{}.txt", Uuid::new_v4()));
+        let result = service.add_attachment(&task.id, missing.to_str().unwrap(), None);
+        assert!(matches!(result, Err(DomainError::InvalidInput(_))));
+
+        let result = service.add_attachment("missing-task", "/tmp", None);
+        assert!(matches!(result, Err(DomainError::TaskNotFound(_))));
+    }
+
+    fn image_dto(image_path: &str) -> CreateTaskDto {
+        CreateTaskDto {
+            title: "With image".to_string(),
+            description: None,
+            priority: TaskPriority::Medium,
+            due_date: None,
+            notes: None,
+            estimated_minutes: None,
+            image_path: Some(image_path.to_string()),
+            tag_ids: vec![],
+            parent_id: None,
+            color: None,
+        }
+    }
+
+    fn image_update(image_path: &str) -> UpdateTaskDto {
+        UpdateTaskDto {
+            title: None,
+            description: None,
+            status: None,
+            priority: None,
+            due_date: None,
+            image_path: Some(image_path.to_string()),
+            notes: None,
+            estimated_minutes: None,
+            actual_minutes: None,
+            tag_ids: None,
+            color: None,
+            clear_fields: vec![],
+        }
+    }
+
+    #[test]
+    fn test_image_path_valid_file_and_clearing() {
+        let db = setup_test_db();
+        let service = TaskService::new(&db);
+
+        let file = std::env::temp_dir().join(format!("image-{}.PNG", Uuid::new_v4()));
+        std::fs::write(&file, b"png").unwrap();
+        let file_path = file.to_str().unwrap();
+
+        let task = service.create_task(image_dto(file_path)).unwrap();
+        assert_eq!(task.image_path.as_deref(), Some(file_path));
+
+        let cleared = service
+            .update_task(&task.id, image_update(""), false)
+            .unwrap();
+        assert_eq!(cleared.task.image_path, None);
+
+        let task = service.create_task(image_dto("")).unwrap();
+        assert_eq!(task.image_path, None);
+
+        std::fs::remove_file(&file).unwrap();
+    }
+
+    #[test]
+    fn test_update_task_clear_fields_validation() {
+        let db = setup_test_db();
+        let service = TaskService::new(&db);
+        let task = create_simple_task(&service, "Clear me");
+
+        let mut unknown = image_update("");
+        unknown.image_path = None;
+        unknown.clear_fields = vec!["title".to_string()];
+        let result = service.update_task(&task.id, unknown, false);
+        assert!(
+            matches!(result, Err(DomainError::FieldValidation { ref field, .. }) if field == "clear_fields")
+        );
+
+        let mut conflicting = image_update("");
+        conflicting.clear_fields = vec!["image_path".to_string()];
+        let result = service.update_task(&task.id, conflicting, false);
+        assert!(
+            matches!(result, Err(DomainError::FieldValidation { ref field, .. }) if field == "image_path")
+        );
+
+        // Older clients that don't send `clear_fields` still deserialize
+        let dto: UpdateTaskDto = serde_json::from_value(serde_json::json!({
+            "title": null, "description": null, "status": null, "priority": null,
+            "due_date": null, "image_path": null, "notes": null,
+            "estimated_minutes": null, "actual_minutes": null, "tag_ids": null
+        }))
+        .unwrap();
+        assert!(dto.clear_fields.is_empty());
+    }
+
+    #[test]
+    fn test_image_path_rejects_missing_file() {
+        let db = setup_test_db();
+        let service = TaskService::new(&db);
+
+        let missing = std::env::temp_dir().join(format!("missing-{}.png", Uuid::new_v4()));
+        let result = service.create_task(image_dto(missing.to_str().unwrap()));
+        assert!(
+            matches!(result, Err(DomainError::FieldValidation { ref field, .. }) if field == "image_path")
+        );
+
+        let task = create_simple_task(&service, "No image");
+        let result = service.update_task(&task.id, image_update(missing.to_str().unwrap()), false);
+        assert!(
+            matches!(result, Err(DomainError::FieldValidation { ref field, .. }) if field == "image_path")
+        );
+    }
+
+    #[test]
+    fn test_image_path_rejects_non_image_extension() {
+        let db = setup_test_db();
+        let service = TaskService::new(&db);
+
+        let file = std::env::temp_dir().join(format!("notes-{}.txt", Uuid::new_v4()));
+        std::fs::write(&file, b"text").unwrap();
+
+        let result = service.create_task(image_dto(file.to_str().unwrap()));
+        assert!(
+            matches!(result, Err(DomainError::FieldValidation { ref field, .. }) if field == "image_path")
+        );
+
+        std::fs::remove_file(&file).unwrap();
+    }
+
+    #[test]
+    fn test_postpone_overdue_moves_only_overdue_tasks() {
+        let db = setup_test_db();
+        let service = TaskService::new(&db);
+        let repo = TaskRepository::new(&db);
+        let now = Utc::now();
+
+        let insert = |title: &str, due_date: Option<DateTime<Utc>>| {
+            repo.create(CreateTaskDto {
+                title: title.to_string(),
+                description: None,
+                priority: TaskPriority::Medium,
+                due_date,
+                notes: None,
+                estimated_minutes: None,
+                image_path: None,
+                tag_ids: vec![],
+                parent_id: None,
+                color: None,
+            })
+            .unwrap()
+        };
+
+        let overdue_due = now - Duration::hours(2);
+        let overdue = insert("Overdue", Some(overdue_due));
+        let future = insert("Future", Some(now + Duration::days(1)));
+        let undated = insert("Undated", None);
+        let completed = insert("Completed", Some(overdue_due));
+        service
+            .mark_done(&completed.id, None, None, false, false)
+            .unwrap();
+
+        let updated = service.postpone_overdue(Duration::hours(3)).unwrap();
+        assert_eq!(updated, 1);
+
+        let find = |id: &str| repo.find_by_id(id).unwrap().unwrap();
+        let due_ts = |task: Task| task.due_date.map(|d| d.timestamp());
+
+        assert_eq!(
+            due_ts(find(&overdue.id)),
+            Some((overdue_due + Duration::hours(3)).timestamp())
+        );
+        assert_eq!(due_ts(find(&future.id)), due_ts(future));
+        assert_eq!(find(&undated.id).due_date, None);
+        assert_eq!(due_ts(find(&completed.id)), Some(overdue_due.timestamp()));
+    }
+
+    #[test]
+    fn test_postpone_overdue_rejects_non_positive_duration() {
+        let db = setup_test_db();
+        let service = TaskService::new(&db);
+
+        let result = service.postpone_overdue(Duration::zero());
+        assert!(matches!(result, Err(DomainError::ValidationError(_))));
+    }
+
+    /// Force a task's status and finish time, bypassing transition rules
+    fn set_finished(db: &Database, id: &str, status: &str, completed_at: Option<DateTime<Utc>>) {
+        let at = completed_at.map(|d| d.to_rfc3339());
+        db.connection()
+            .execute(
+                "UPDATE tasks SET status = ?1, completed_at = ?2, updated_at = COALESCE(?2, updated_at)
+                 WHERE id = ?3",
+                rusqlite::params![status, at, id],
+            )
+            .unwrap();
+    }
+
+    #[test]
+    fn test_delete_completed_before_respects_cutoff_boundary() {
+        let db = setup_test_db();
+        let service = TaskService::new(&db);
+        let cutoff = DateTime::parse_from_rfc3339("2024-06-01T12:00:00Z")
+            .unwrap()
+            .with_timezone(&Utc);
+
+        let old = create_simple_task(&service, "Old");
+        let at_cutoff = create_simple_task(&service, "At cutoff");
+        let cancelled = create_simple_task(&service, "Cancelled");
+        let pending = create_simple_task(&service, "Pending");
+        set_finished(
+            &db,
+            &old.id,
+            "completed",
+            Some(cutoff - Duration::seconds(1)),
+        );
+        set_finished(&db, &at_cutoff.id, "completed", Some(cutoff));
+        set_finished(
+            &db,
+            &cancelled.id,
+            "cancelled",
+            Some(cutoff - Duration::days(1)),
+        );
+
+        assert_eq!(
+            service.completed_ids_before(Some(cutoff), false).unwrap(),
+            vec![old.id.clone()]
+        );
+        assert_eq!(
+            service
+                .completed_ids_before(Some(cutoff), true)
+                .unwrap()
+                .len(),
+            2
+        );
+
+        assert_eq!(
+            service
+                .delete_completed_before(Some(cutoff), false)
+                .unwrap(),
+            1
+        );
+
+        let repo = TaskRepository::new(&db);
+        assert!(repo.find_by_id(&old.id).unwrap().is_none());
+        assert!(repo.find_by_id(&at_cutoff.id).unwrap().is_some());
+        assert!(repo.find_by_id(&cancelled.id).unwrap().is_some());
+        assert!(repo.find_by_id(&pending.id).unwrap().is_some());
+    }
+
+    #[test]
+    fn test_delete_completed_without_cutoff_leaves_unfinished_tasks() {
+        let db = setup_test_db();
+        let service = TaskService::new(&db);
+
+        let done = create_simple_task(&service, "Done");
+        let pending = create_simple_task(&service, "Pending");
+        let in_progress = create_simple_task(&service, "In progress");
+        set_finished(&db, &done.id, "completed", Some(Utc::now()));
+        set_finished(&db, &in_progress.id, "in_progress", None);
+
+        // A completed parent with an open subtask must stay, or the subtask would go with it
+        let parent = create_simple_task(&service, "Parent");
+        let child = service
+            .create_task(CreateTaskDto {
+                title: "Open subtask".to_string(),
+                description: None,
+                priority: TaskPriority::Medium,
+                due_date: None,
+                notes: None,
+                estimated_minutes: None,
+                image_path: None,
+                tag_ids: vec![],
+                parent_id: Some(parent.id.clone()),
+                color: None,
+            })
+            .unwrap();
+        set_finished(&db, &parent.id, "completed", Some(Utc::now()));
+
+        assert_eq!(service.delete_completed_before(None, true).unwrap(), 1);
+
+        let repo = TaskRepository::new(&db);
+        assert!(repo.find_by_id(&done.id).unwrap().is_none());
+        for id in [&pending.id, &in_progress.id, &parent.id, &child.id] {
+            assert!(repo.find_by_id(id).unwrap().is_some());
+        }
+    }
+
+    fn due_date_update(due_date: DateTime<Utc>) -> UpdateTaskDto {
+        UpdateTaskDto {
+            due_date: Some(due_date),
+            image_path: None,
+            ..image_update("")
+        }
+    }
+
+    /// Create a task with reminders one and three days out, returning the task and reminder ids
+    fn task_with_reminders(db: &Database, service: &TaskService) -> (Task, Vec<String>) {
+        let task = create_simple_task(service, "Launch");
+        let reminder_repo = ReminderRepository::new(db);
+        let ids = [1, 3]
+            .into_iter()
+            .map(|days| {
+                reminder_repo
+                    .create(CreateReminderDto {
+                        task_id: Some(task.id.clone()),
+                        title: format!("In {} days", days),
+                        description: None,
+                        remind_at: Utc::now() + Duration::days(days),
+                        repeat_interval: RepeatInterval::none(),
+                        escalation_minutes: None,
+                        delivery: None,
+                    })
+                    .unwrap()
+                    .id
+            })
+            .collect();
+        (task, ids)
+    }
+
+    #[test]
+    fn test_mark_done_deactivates_task_reminders() {
+        let db = setup_test_db();
+        let service = TaskService::new(&db);
+        let (task, ids) = task_with_reminders(&db, &service);
+        let reminder_repo = ReminderRepository::new(&db);
+        let standalone = reminder_repo
+            .create(CreateReminderDto {
+                task_id: None,
+                title: "Water plants".to_string(),
+                description: None,
+                remind_at: Utc::now() + Duration::days(1),
+                repeat_interval: RepeatInterval::none(),
+                escalation_minutes: None,
+                delivery: None,
+            })
+            .unwrap();
+
+        service
+            .mark_done(&task.id, None, None, false, false)
+            .unwrap();
+
+        for id in &ids {
+            assert!(!reminder_repo.find_by_id(id).unwrap().unwrap().is_active);
+        }
+        assert!(
+            reminder_repo
+                .find_by_id(&standalone.id)
+                .unwrap()
+                .unwrap()
+                .is_active
+        );
+    }
+
+    #[test]
+    fn test_mark_done_rolls_back_when_deactivation_fails() {
+        let db = setup_test_db();
+        let service = TaskService::new(&db);
+        let (task, ids) = task_with_reminders(&db, &service);
+        db.connection()
+            .execute_batch(
+                "CREATE TRIGGER reject_deactivate BEFORE UPDATE OF is_active ON reminders
+                 BEGIN SELECT RAISE(ABORT, 'rejected'); END;",
+            )
+            .unwrap();
+
+        assert!(service
+            .mark_done(&task.id, None, None, false, false)
+            .is_err());
+        assert_eq!(
+            service.get_task(&task.id).unwrap().status,
+            TaskStatus::Pending
+        );
+        let reminder_repo = ReminderRepository::new(&db);
+        for id in &ids {
+            assert!(reminder_repo.find_by_id(id).unwrap().unwrap().is_active);
+        }
+    }
+
+    #[test]
+    fn test_mark_done_keep_reminders() {
+        let db = setup_test_db();
+        let service = TaskService::new(&db);
+        let (task, ids) = task_with_reminders(&db, &service);
+
+        service
+            .mark_done(&task.id, None, None, false, true)
+            .unwrap();
+
+        let reminder_repo = ReminderRepository::new(&db);
+        for id in &ids {
+            assert!(reminder_repo.find_by_id(id).unwrap().unwrap().is_active);
+        }
+    }
+
+    #[test]
+    fn test_mark_done_persists_completion_note() {
+        let db = setup_test_db();
+        let service = TaskService::new(&db);
+        let task = create_simple_task(&service, "Ship release");
+
+        let result = service
+            .mark_done(
+                &task.id,
+                None,
+                Some("  Shipped v2, hotfix pending  ".to_string()),
+                false,
+                false,
+            )
+            .unwrap();
+
+        assert_eq!(
+            result.task.completion_note.as_deref(),
+            Some("Shipped v2, hotfix pending")
+        );
+        let stored = service.get_task(&task.id).unwrap();
+        assert_eq!(
+            stored.completion_note.as_deref(),
+            Some("Shipped v2, hotfix pending")
+        );
+
+        let reopened = service.reopen(&task.id, None).unwrap();
+        assert_eq!(reopened.completion_note, None);
+    }
+
+    #[test]
+    fn test_reopen_completed_task_makes_it_modifiable() {
+        let db = setup_test_db();
+        let service = TaskService::new(&db);
+        let task = create_simple_task(&service, "Send invoice");
+        service
+            .mark_done(&task.id, None, None, false, false)
+            .unwrap();
+
+        let reopened = service.reopen(&task.id, None).unwrap();
+        assert_eq!(reopened.status, TaskStatus::Pending);
+        assert!(reopened.completed_at.is_none());
+        assert!(reopened.is_modifiable());
+
+        let history: Vec<(TaskStatus, TaskStatus)> = service
+            .get_task_history(&task.id)
+            .unwrap()
+            .into_iter()
+            .map(|change| (change.from_status, change.to_status))
+            .collect();
+        assert_eq!(
+            history.last(),
+            Some(&(TaskStatus::Completed, TaskStatus::Pending))
+        );
+
+        let updated = service
+            .update_task(
+                &task.id,
+                UpdateTaskDto {
+                    title: Some("Send corrected invoice".to_string()),
+                    description: None,
+                    status: None,
+                    priority: None,
+                    due_date: None,
+                    image_path: None,
+                    notes: None,
+                    estimated_minutes: None,
+                    actual_minutes: None,
+                    tag_ids: None,
+                    color: None,
+                    clear_fields: vec![],
+                },
+                false,
+            )
+            .unwrap();
+        assert_eq!(updated.task.title, "Send corrected invoice");
+    }
+
+    #[test]
+    fn test_reopen_rules() {
+        let db = setup_test_db();
+        let service = TaskService::new(&db);
+        let open = create_simple_task(&service, "Still open");
+
+        let result = service.reopen(&open.id, None);
+        assert!(matches!(
+            result,
+            Err(DomainError::InvalidStatusTransition { .. })
+        ));
+
+        let done = create_simple_task(&service, "Done");
+        service
+            .mark_done(&done.id, None, None, false, false)
+            .unwrap();
+        let result = service.reopen(&done.id, Some(TaskStatus::Cancelled));
+        assert!(matches!(
+            result,
+            Err(DomainError::InvalidStatusTransition { .. })
+        ));
+
+        let reopened = service
+            .reopen(&done.id, Some(TaskStatus::InProgress))
+            .unwrap();
+        assert_eq!(reopened.status, TaskStatus::InProgress);
+    }
+
+    #[test]
+    fn test_mark_done_rejects_overlong_completion_note() {
+        let db = setup_test_db();
+        let service = TaskService::new(&db);
+        let task = create_simple_task(&service, "Write report");
+
+        let note = "x".repeat(MAX_COMPLETION_NOTE_LENGTH + 1);
+        let result = service.mark_done(&task.id, None, Some(note), false, false);
+
+        assert!(matches!(
+            result,
+            Err(DomainError::FieldValidation { ref field, .. }) if field == "completion_note"
+        ));
+        assert_eq!(
+            service.get_task(&task.id).unwrap().status,
+            TaskStatus::Pending
+        );
+    }
+
+    #[test]
+    fn test_due_date_before_reminder_warns() {
+        let db = setup_test_db();
+        let service = TaskService::new(&db);
+        let (task, ids) = task_with_reminders(&db, &service);
+        let due_date = Utc::now() + Duration::days(2);
+
+        let result = service
+            .update_task(&task.id, due_date_update(due_date), false)
+            .unwrap();
+
+        assert_eq!(result.task.due_date, Some(due_date));
+        assert_eq!(result.late_reminder_ids, vec![ids[1].clone()]);
+        assert!(result.shifted_reminder_ids.is_empty());
+        assert!(result.warning.is_some());
+
+        // The reminder itself is left alone
+        let reminder = ReminderRepository::new(&db)
+            .find_by_id(&ids[1])
+            .unwrap()
+            .unwrap();
+        assert!(reminder.remind_at > due_date);
+
+        // Updates that don't touch the due date don't report anything
+        let result = service
+            .update_task(&task.id, image_update(""), false)
+            .unwrap();
+        assert!(result.late_reminder_ids.is_empty());
+        assert!(result.warning.is_none());
+    }
+
+    #[test]
+    fn test_due_date_before_reminder_shifts_when_requested() {
+        let db = setup_test_db();
+        let service = TaskService::new(&db);
+        let (task, ids) = task_with_reminders(&db, &service);
+        let due_date = Utc::now() + Duration::days(2);
+
+        let result = service
+            .update_task(&task.id, due_date_update(due_date), true)
+            .unwrap();
+
+        assert_eq!(result.shifted_reminder_ids, vec![ids[1].clone()]);
+        assert!(result.late_reminder_ids.is_empty());
+        assert!(result.warning.is_none());
+
+        let reminder_repo = ReminderRepository::new(&db);
+        let shifted = reminder_repo.find_by_id(&ids[1]).unwrap().unwrap();
+        assert_eq!(shifted.remind_at.timestamp(), due_date.timestamp());
+        let untouched = reminder_repo.find_by_id(&ids[0]).unwrap().unwrap();
+        assert!(untouched.remind_at < due_date);
+    }
+
+    /// Create three weekly occurrences of a series
+    fn create_series(service: &TaskService) -> Vec<Task> {
+        let first = service
+            .create_task(CreateTaskDto {
+                title: "Weekly review".to_string(),
+                description: None,
+                priority: TaskPriority::Medium,
+                due_date: Some(Utc::now() + Duration::days(1)),
+                image_path: None,
+                notes: None,
+                estimated_minutes: None,
+                tag_ids: vec![],
+                parent_id: None,
+                color: None,
+            })
+            .unwrap();
+        let mut occurrences = vec![first];
+        for _ in 0..2 {
+            let last = occurrences.last().unwrap();
+            let next = service
+                .create_next_occurrence(&last.id, last.due_date.unwrap() + Duration::weeks(1))
+                .unwrap();
+            occurrences.push(next);
+        }
+        occurrences
+    }
+
+    #[test]
+    fn test_create_next_occurrence_joins_series() {
+        let db = setup_test_db();
+        let service = TaskService::new(&db);
+        let occurrences = create_series(&service);
+
+        let first = service.get_task(&occurrences[0].id).unwrap();
+        assert_eq!(first.series_id.as_deref(), Some(first.id.as_str()));
+        assert!(occurrences[1..]
+            .iter()
+            .all(|task| task.series_id == first.series_id && task.title == first.title));
+
+        let err = service
+            .create_next_occurrence(&occurrences[2].id, occurrences[1].due_date.unwrap())
+            .unwrap_err();
+        assert!(
+            matches!(err, DomainError::FieldValidation { ref field, .. } if field == "due_date")
+        );
+    }
+
+    fn priority_update(priority: TaskPriority) -> UpdateTaskDto {
+        UpdateTaskDto {
+            priority: Some(priority),
+            image_path: None,
+            ..image_update("")
+        }
+    }
+
+    #[test]
+    fn test_this_and_future_updates_open_occurrences_only() {
+        let db = setup_test_db();
+        let service = TaskService::new(&db);
+        let occurrences = create_series(&service);
+        service
+            .mark_done(&occurrences[2].id, None, None, false, false)
+            .unwrap();
+
+        let result = service
+            .update_task_scoped(
+                &occurrences[1].id,
+                priority_update(TaskPriority::High),
+                EditScope::ThisAndFuture,
+                false,
+            )
+            .unwrap();
+
+        assert_eq!(result.task.priority, TaskPriority::High);
+        assert!(result.updated_occurrence_ids.is_empty());
+
+        // The earlier open occurrence and the completed later one keep their values
+        let priority = |id: &str| service.get_task(id).unwrap().priority;
+        assert_eq!(priority(&occurrences[0].id), TaskPriority::Medium);
+        assert_eq!(priority(&occurrences[1].id), TaskPriority::High);
+        assert_eq!(priority(&occurrences[2].id), TaskPriority::Medium);
+
+        let result = service
+            .update_task_scoped(
+                &occurrences[0].id,
+                priority_update(TaskPriority::Low),
+                EditScope::ThisAndFuture,
+                false,
+            )
+            .unwrap();
+        assert_eq!(
+            result.updated_occurrence_ids,
+            vec![occurrences[1].id.clone()]
+        );
+    }
+
+    #[test]
+    fn test_this_only_leaves_series_alone() {
+        let db = setup_test_db();
+        let service = TaskService::new(&db);
+        let occurrences = create_series(&service);
+
+        let result = service
+            .update_task_scoped(
+                &occurrences[1].id,
+                priority_update(TaskPriority::Urgent),
+                EditScope::ThisOnly,
+                false,
+            )
+            .unwrap();
+
+        assert!(result.updated_occurrence_ids.is_empty());
+        assert_eq!(
+            service.get_task(&occurrences[2].id).unwrap().priority,
+            TaskPriority::Medium
+        );
+
+        // Each occurrence keeps its own due date
+        let err = service
+            .update_series(&occurrences[0].id, due_date_update(Utc::now()))
+            .unwrap_err();
+        assert!(
+            matches!(err, DomainError::FieldValidation { ref field, .. } if field == "due_date")
+        );
+    }
+
+    #[test]
+    fn test_get_task_detail_includes_ordered_reminders() {
+        let db = setup_test_db();
+        let service = TaskService::new(&db);
+        let task = create_simple_task(&service, "Parent");
+        let now = Utc::now();
+
+        let reminder_repo = ReminderRepository::new(&db);
+        for (title, offset) in [("Later", 3), ("Sooner", 1), ("Middle", 2)] {
+            reminder_repo
+                .create(CreateReminderDto {
+                    task_id: Some(task.id.clone()),
+                    title: title.to_string(),
+                    description: None,
+                    remind_at: now + Duration::hours(offset),
+                    repeat_interval: RepeatInterval::none(),
+                    escalation_minutes: None,
+                    delivery: None,
+                })
+                .unwrap();
+        }
+        service.add_checklist_item(&task.id, "Step one").unwrap();
+        let subtask = service
+            .create_task(CreateTaskDto {
+                title: "Child".to_string(),
+                description: None,
+                priority: TaskPriority::Medium,
+                due_date: None,
+                notes: None,
+                estimated_minutes: None,
+                image_path: None,
+                tag_ids: vec![],
+                parent_id: Some(task.id.clone()),
+                color: None,
+            })
+            .unwrap();
+
+        let detail = service.get_task_detail(&task.id).unwrap();
+
+        assert_eq!(detail.task.id, task.id);
+        let titles: Vec<&str> = detail.reminders.iter().map(|r| r.title.as_str()).collect();
+        assert_eq!(titles, vec!["Sooner", "Middle", "Later"]);
+        assert_eq!(detail.subtasks.len(), 1);
+        assert_eq!(detail.subtasks[0].id, subtask.id);
+        assert_eq!(detail.checklist.len(), 1);
+    }
+
+    #[test]
+    fn test_get_task_detail_missing_task() {
+        let db = setup_test_db();
+        let service = TaskService::new(&db);
+
+        let result = service.get_task_detail("missing");
+        assert!(matches!(result, Err(DomainError::TaskNotFound(_))));
+    }
+
+    fn insert_tag(db: &Database, id: &str, name: &str) {
+        TagRepository::new(db)
+            .insert_if_absent(&Tag {
+                id: id.to_string(),
+                name: name.to_string(),
+                color: "#10b981".to_string(),
+                created_at: Utc::now(),
+            })
+            .unwrap();
+    }
+
+    fn tag_names(tags: &[Tag]) -> Vec<&str> {
+        tags.iter().map(|t| t.name.as_str()).collect()
+    }
+
+    #[test]
+    fn test_set_task_tags_sets_replaces_and_clears() {
+        let db = setup_test_db();
+        let service = TaskService::new(&db);
+        insert_tag(&db, "t-work", "work");
+        insert_tag(&db, "t-home", "home");
+        insert_tag(&db, "t-urgent", "urgent");
+        let task = create_simple_task(&service, "Tagged");
+
+        let assignment = service
+            .set_task_tags(&task.id, vec!["t-work".to_string(), "t-home".to_string()])
+            .unwrap();
+        assert_eq!(tag_names(&assignment.tags), vec!["home", "work"]);
+        assert!(assignment.invalid_tag_ids.is_empty());
+
+        service
+            .set_task_tags(&task.id, vec!["t-urgent".to_string()])
+            .unwrap();
+        assert_eq!(
+            tag_names(&service.get_task_tags(&task.id).unwrap()),
+            vec!["urgent"]
+        );
+
+        assert!(service
+            .set_task_tags(&task.id, vec![])
+            .unwrap()
+            .tags
+            .is_empty());
+        assert!(service.get_task_tags(&task.id).unwrap().is_empty());
+    }
+
+    #[test]
+    fn test_set_task_tags_reports_unknown_tags_and_rejects_unknown_task() {
+        let db = setup_test_db();
+        let service = TaskService::new(&db);
+        insert_tag(&db, "t-work", "work");
+        insert_tag(&db, "t-home", "home");
+        let task = create_simple_task(&service, "Tagged");
+
+        let assignment = service
+            .set_task_tags(
+                &task.id,
+                vec![
+                    "t-missing".to_string(),
+                    "t-work".to_string(),
+                    "t-gone".to_string(),
+                    "t-home".to_string(),
+                    "t-missing".to_string(),
+                ],
+            )
+            .unwrap();
+        assert_eq!(assignment.invalid_tag_ids, vec!["t-missing", "t-gone"]);
+        // The valid tags are still applied
+        assert_eq!(tag_names(&assignment.tags), vec!["home", "work"]);
+        assert_eq!(
+            tag_names(&service.get_task_tags(&task.id).unwrap()),
+            vec!["home", "work"]
+        );
+
+        let assignment = service
+            .set_task_tags(&task.id, vec!["t-missing".to_string()])
+            .unwrap();
+        assert_eq!(assignment.invalid_tag_ids, vec!["t-missing"]);
+        assert!(assignment.tags.is_empty());
+
+        assert!(matches!(
+            service.set_task_tags("missing", vec![]),
+            Err(DomainError::TaskNotFound(_))
+        ));
+        assert!(matches!(
+            service.get_task_tags("missing"),
+            Err(DomainError::TaskNotFound(_))
+        ));
+    }
+
+    #[test]
+    fn test_create_and_update_report_unknown_tags() {
+        let db = setup_test_db();
+        let service = TaskService::new(&db);
+        insert_tag(&db, "t-work", "work");
+        insert_tag(&db, "t-home", "home");
+
+        let created = service
+            .create_task_with_result(CreateTaskDto {
+                title: "Tagged".to_string(),
+                description: None,
+                priority: TaskPriority::Medium,
+                due_date: None,
+                image_path: None,
+                notes: None,
+                estimated_minutes: None,
+                tag_ids: vec!["t-work".to_string(), "t-missing".to_string()],
+                parent_id: None,
+                color: None,
+            })
+            .unwrap();
+        assert_eq!(created.invalid_tag_ids, vec!["t-missing"]);
+        assert_eq!(tag_names(&created.task.tags), vec!["work"]);
+
+        let updated = service
+            .update_task(
+                &created.task.id,
+                UpdateTaskDto {
+                    tag_ids: Some(vec!["t-gone".to_string(), "t-home".to_string()]),
+                    image_path: None,
+                    ..image_update("")
+                },
+                false,
+            )
+            .unwrap();
+        assert_eq!(updated.invalid_tag_ids, vec!["t-gone"]);
+        assert_eq!(tag_names(&updated.task.tags), vec!["home"]);
+        assert_eq!(
+            tag_names(&service.get_task_tags(&created.task.id).unwrap()),
+            vec!["home"]
+        );
+    }
+
+    #[test]
+    fn test_get_filter_facets_counts_used_values() {
+        let db = setup_test_db();
+        let service = TaskService::new(&db);
+
+        let tag_repo = TagRepository::new(&db);
+        for (id, name) in [
+            ("t-work", "work"),
+            ("t-home", "home"),
+            ("t-unused", "unused"),
+        ] {
+            tag_repo
+                .insert_if_absent(&Tag {
+                    id: id.to_string(),
+                    name: name.to_string(),
+                    color: "#3b82f6".to_string(),
+                    created_at: Utc::now(),
+                })
+                .unwrap();
+        }
+
+        let create = |title: &str, priority: TaskPriority, tag_ids: &[&str]| {
+            service
+                .create_task(CreateTaskDto {
+                    title: title.to_string(),
+                    description: None,
+                    priority,
+                    due_date: None,
+                    notes: None,
+                    estimated_minutes: None,
+                    image_path: None,
+                    tag_ids: tag_ids.iter().map(|id| id.to_string()).collect(),
+                    parent_id: None,
+                    color: None,
+                })
+                .unwrap()
+        };
+        let report = create("Report", TaskPriority::High, &["t-work"]);
+        create("Slides", TaskPriority::High, &["t-work", "t-home"]);
+        create("Groceries", TaskPriority::Low, &["t-home"]);
+        create("Laundry", TaskPriority::Low, &[]);
+        service
+            .mark_done(&report.id, None, None, false, false)
+            .unwrap();
+
+        let facets = service.get_filter_facets().unwrap();
+
+        let statuses: Vec<(TaskStatus, u32)> = facets
+            .statuses
+            .into_iter()
+            .map(|f| (f.value, f.count))
+            .collect();
+        assert_eq!(
+            statuses,
+            vec![(TaskStatus::Pending, 3), (TaskStatus::Completed, 1)]
+        );
+
+        let priorities: Vec<(TaskPriority, u32)> = facets
+            .priorities
+            .into_iter()
+            .map(|f| (f.value, f.count))
+            .collect();
+        assert_eq!(
+            priorities,
+            vec![(TaskPriority::High, 2), (TaskPriority::Low, 2)]
+        );
+
+        let tags: Vec<(String, u32)> = facets
+            .tags
+            .into_iter()
+            .map(|f| (f.value.name, f.count))
+            .collect();
+        assert_eq!(tags, vec![("home".to_string(), 2), ("work".to_string(), 2)]);
+    }
+
+    #[test]
+    fn test_global_search_returns_tasks_and_reminders() {
+        let db = setup_test_db();
+        let service = TaskService::new(&db);
+
+        create_simple_task(&service, "Quarterly Report");
+        create_simple_task(&service, "Buy milk");
+        ReminderRepository::new(&db)
+            .create(CreateReminderDto {
+                task_id: None,
+                title: "Send the report".to_string(),
+                description: None,
+                remind_at: Utc::now() + Duration::hours(1),
+                repeat_interval: RepeatInterval::none(),
+                escalation_minutes: None,
+                delivery: None,
+            })
+            .unwrap();
+
+        let result = service.global_search("REPORT").unwrap();
+        assert_eq!(result.tasks.len(), 1);
+        assert_eq!(result.tasks[0].title, "Quarterly Report");
+        assert_eq!(result.reminders.len(), 1);
+        assert_eq!(result.reminders[0].title, "Send the report");
+
+        let empty = service.global_search("   ").unwrap();
+        assert!(empty.tasks.is_empty() && empty.reminders.is_empty());
+    }
+
+    #[test]
+    fn test_search_ranks_title_above_description() {
+        let db = setup_test_db();
+        let service = TaskService::new(&db);
+
+        let title_hit = create_simple_task(&service, "Budget review");
+        // Created last, so it is the most recent match
+        let description_hit = service
+            .create_task(CreateTaskDto {
+                title: "Quarterly planning".to_string(),
+                description: Some("Check the budget numbers".to_string()),
+                priority: TaskPriority::Medium,
+                due_date: None,
+                notes: None,
+                estimated_minutes: None,
+                image_path: None,
+                tag_ids: vec![],
+                parent_id: None,
+                color: None,
+            })
+            .unwrap();
+
+        let results = service.search_tasks("budget", None).unwrap();
+        let ids: Vec<&str> = results.iter().map(|t| t.id.as_str()).collect();
+        assert_eq!(
+            ids,
+            vec![title_hit.id.as_str(), description_hit.id.as_str()]
+        );
+
+        // A match at the start of a word ranks above one inside a word
+        let word_hit = create_simple_task(&service, "Port visit");
+        let inner_hit = create_simple_task(&service, "Passport renewal");
+        let ids: Vec<String> = service
+            .search_tasks("port", None)
+            .unwrap()
+            .into_iter()
+            .map(|t| t.id)
+            .collect();
+        assert_eq!(ids, vec![word_hit.id, inner_hit.id]);
+
+        let limited = service.search_tasks("budget", Some(1)).unwrap();
+        assert_eq!(limited.len(), 1);
+        assert_eq!(limited[0].id, title_hit.id);
+
+        let result = service.search_tasks("budget", Some(0));
+        assert!(matches!(result, Err(DomainError::FieldValidation { .. })));
+    }
+
+    #[test]
+    fn test_search_ignores_accents() {
+        let db = setup_test_db();
+        let service = TaskService::new(&db);
+
+        create_simple_task(&service, "Café meeting");
+        create_simple_task(&service, "Buy milk");
+
+        let found = service.search_tasks("cafe", None).unwrap();
+        assert_eq!(found.len(), 1);
+        assert_eq!(found[0].title, "Café meeting");
+        assert_eq!(service.search_tasks("CAFÉ", None).unwrap().len(), 1);
+
+        let result = service.global_search("cafe").unwrap();
+        assert_eq!(result.tasks.len(), 1);
+
+        let filter = TaskFilter {
+            search: Some("Cafe".to_string()),
+            ..Default::default()
+        };
+        let listed = TaskRepository::new(&db)
+            .find_all(Some(filter), None, Pagination::default())
+            .unwrap();
+        assert_eq!(listed.items.len(), 1);
+    }
+
+    #[test]
+    fn test_checklist_progress_reported_on_task() {
+        let db = setup_test_db();
+        let service = TaskService::new(&db);
+        let task = create_simple_task(&service, "Trip");
+
+        let first = service
+            .add_checklist_item(&task.id, "  Book hotel  ")
+            .unwrap();
+        assert_eq!(first.text, "Book hotel");
+        service.add_checklist_item(&task.id, "Book flight").unwrap();
+        service.add_checklist_item(&task.id, "Rent car").unwrap();
+        service.add_checklist_item(&task.id, "Pack").unwrap();
+
+        let toggled = service.toggle_checklist_item(&first.id).unwrap();
+        assert!(toggled.is_done);
+
+        let task = service.get_task(&task.id).unwrap();
+        assert_eq!(task.checklist_progress, Some(0.25));
+
+        assert!(service.delete_checklist_item(&first.id).unwrap());
+        let task = service.get_task(&task.id).unwrap();
+        assert_eq!(task.checklist_progress, Some(0.0));
+        assert_eq!(service.list_checklist_items(&task.id).unwrap().len(), 3);
+    }
+
+    #[test]
+    fn test_checklist_item_text_validation() {
+        let db = setup_test_db();
+        let service = TaskService::new(&db);
+        let task = create_simple_task(&service, "Trip");
+
+        let result = service.add_checklist_item(&task.id, "   ");
+        assert!(matches!(result, Err(DomainError::ValidationError(_))));
+
+        let result = service.add_checklist_item("missing", "Item");
+        assert!(matches!(result, Err(DomainError::TaskNotFound(_))));
+
+        let result = service.toggle_checklist_item("missing");
+        assert!(matches!(result, Err(DomainError::InvalidInput(_))));
+    }
+
+    #[test]
+    fn test_duplicate_copies_fields_with_new_id() {
+        let db = setup_test_db();
+        let service = TaskService::new(&db);
+
+        let original = service
+            .create_task(CreateTaskDto {
+                title: "Weekly report".to_string(),
+                description: Some("Summarize progress".to_string()),
+                priority: TaskPriority::High,
+                due_date: Some(Utc::now() + Duration::days(2)),
+                notes: Some("Send to team".to_string()),
+                estimated_minutes: Some(45),
+                image_path: None,
+                tag_ids: vec![],
+                parent_id: None,
+                color: None,
+            })
+            .unwrap();
+        service
+            .mark_done(&original.id, Some(40), None, false, false)
+            .unwrap();
+
+        let copy = service.duplicate(&original.id, true, true).unwrap();
+
+        assert_ne!(copy.id, original.id);
+        assert_eq!(copy.title, "Weekly report (copy)");
+        assert_eq!(copy.description, original.description);
+        assert_eq!(copy.priority, TaskPriority::High);
+        assert_eq!(copy.notes, original.notes);
+        assert_eq!(copy.estimated_minutes, Some(45));
+        assert_eq!(copy.status, TaskStatus::Pending);
+        assert!(copy.completed_at.is_none());
+        assert!(copy.actual_minutes.is_none());
+        assert!(copy.created_at >= original.created_at);
+    }
+
+    #[test]
+    fn test_duplicate_rejects_invalid_copy_without_writing() {
+        let db = setup_test_db();
+        let service = TaskService::new(&db);
+        let original = create_simple_task(&service, "Legacy row");
+        db.connection()
+            .execute(
+                "UPDATE tasks SET estimated_minutes = 0 WHERE id = ?1",
+                rusqlite::params![original.id],
+            )
+            .unwrap();
+
+        assert!(matches!(
+            service.duplicate(&original.id, false, false),
+            Err(DomainError::ValidationError(_))
+        ));
+        let tasks: i64 = db
+            .connection()
+            .query_row("SELECT COUNT(*) FROM tasks", [], |row| row.get(0))
+            .unwrap();
+        assert_eq!(tasks, 1);
+    }
+
+    #[test]
+    fn test_duplicate_copies_reminders() {
+        let db = setup_test_db();
+        let service = TaskService::new(&db);
+        let original = create_simple_task(&service, "Water plants");
+
+        let reminder_repo = ReminderRepository::new(&db);
+        let future_at = Utc::now() + Duration::hours(3);
+        let past_at = Utc::now() - Duration::hours(3);
+        for (title, remind_at, interval) in [
+            ("future", future_at, "none"),
+            ("past", past_at, "none"),
+            ("daily", past_at, "every_1_day"),
+        ] {
+            reminder_repo
+                .create(CreateReminderDto {
+                    task_id: Some(original.id.clone()),
+                    title: title.to_string(),
+                    description: None,
+                    remind_at,
+                    repeat_interval: RepeatInterval::from_str(interval),
+                    escalation_minutes: None,
+                    delivery: None,
+                })
+                .unwrap();
+        }
+
+        let copy = service.duplicate(&original.id, false, true).unwrap();
+        let copied = reminder_repo.find_by_task_id(&copy.id).unwrap();
+        assert_eq!(copied.len(), 3);
+
+        let by_title = |t: &str| copied.iter().find(|r| r.title == t).unwrap();
+        assert!(by_title("future").is_active);
+        assert!(!by_title("past").is_active);
+
+        let daily = by_title("daily");
+        assert!(daily.is_active);
+        assert!(daily.remind_at > Utc::now());
+        assert_eq!(daily.remind_at, past_at + Duration::days(1));
+
+        let without = service.duplicate(&original.id, false, false).unwrap();
+        assert!(reminder_repo
+            .find_by_task_id(&without.id)
+            .unwrap()
+            .is_empty());
+    }
+
+    #[test]
+    fn test_add_comment_validation() {
+        let db = setup_test_db();
+        let service = TaskService::new(&db);
+        let task = create_simple_task(&service, "Discuss");
+
+        let result = service.add_comment(&task.id, "   ");
+        assert!(matches!(result, Err(DomainError::ValidationError(_))));
+
+        let too_long = "x".repeat(MAX_COMMENT_LENGTH + 1);
+        let result = service.add_comment(&task.id, &too_long);
+        assert!(matches!(result, Err(DomainError::ValidationError(_))));
+
+        let at_limit = "x".repeat(MAX_COMMENT_LENGTH);
+        assert!(service.add_comment(&task.id, &at_limit).is_ok());
+
+        let comment = service.add_comment(&task.id, "  Looks good  ").unwrap();
+        assert_eq!(comment.body, "Looks good");
+        assert_eq!(service.list_comments(&task.id).unwrap().len(), 2);
+
+        let result = service.add_comment("missing", "Hello");
+        assert!(matches!(result, Err(DomainError::TaskNotFound(_))));
+    }
+
+    #[test]
+    fn test_task_history_tracks_transitions() {
+        let db = setup_test_db();
+        let service = TaskService::new(&db);
+        let task = create_simple_task(&service, "Ship release");
+
+        service
+            .update_task(
+                &task.id,
+                UpdateTaskDto {
+                    title: Some("Ship release v2".to_string()),
+                    description: None,
+                    status: None,
+                    priority: None,
+                    due_date: None,
+                    image_path: None,
+                    notes: None,
+                    estimated_minutes: None,
+                    actual_minutes: None,
+                    tag_ids: None,
+                    color: None,
+                    clear_fields: vec![],
+                },
+                false,
+            )
+            .unwrap();
+        service
+            .update_task(
+                &task.id,
+                UpdateTaskDto {
+                    title: None,
+                    description: None,
+                    status: Some(TaskStatus::InProgress),
+                    priority: None,
+                    due_date: None,
+                    image_path: None,
+                    notes: None,
+                    estimated_minutes: None,
+                    actual_minutes: None,
+                    tag_ids: None,
+                    color: None,
+                    clear_fields: vec![],
+                },
+                false,
+            )
+            .unwrap();
+        service
+            .mark_done(&task.id, None, None, false, false)
+            .unwrap();
+
+        let history = service.get_task_history(&task.id).unwrap();
+        let transitions: Vec<(TaskStatus, TaskStatus)> = history
+            .into_iter()
+            .map(|h| (h.from_status, h.to_status))
+            .collect();
+        assert_eq!(
+            transitions,
+            vec![
+                (TaskStatus::Pending, TaskStatus::InProgress),
+                (TaskStatus::InProgress, TaskStatus::Completed),
+            ]
+        );
+    }
+
+    fn create_task_due_in(service: &TaskService, title: &str, due_in: Duration) -> Task {
+        service
+            .create_task(CreateTaskDto {
+                title: title.to_string(),
+                description: None,
+                priority: TaskPriority::Medium,
+                due_date: Some(Utc::now() + due_in),
+                notes: None,
+                estimated_minutes: None,
+                image_path: None,
+                tag_ids: vec![],
+                parent_id: None,
+                color: None,
+            })
+            .unwrap()
+    }
+
+    fn set_auto_reminder_lead(db: &Database, minutes: i64) {
+        db.connection()
+            .execute(
+                "INSERT OR REPLACE INTO settings (key, value) VALUES ('auto_reminder_lead_minutes', ?1)",
+                rusqlite::params![minutes.to_string()],
+            )
+            .unwrap();
+    }
+
+    #[test]
+    fn test_create_task_adds_auto_reminder() {
+        let db = setup_test_db();
+        set_auto_reminder_lead(&db, 30);
+        let service = TaskService::new(&db);
+
+        let task = create_task_due_in(&service, "Submit report", Duration::hours(2));
+
+        let reminders = ReminderRepository::new(&db)
+            .find_by_task_id(&task.id)
+            .unwrap();
+        assert_eq!(reminders.len(), 1);
+        assert_eq!(reminders[0].title, "Submit report");
+        assert_eq!(
+            reminders[0].remind_at,
+            task.due_date.unwrap() - Duration::minutes(30)
+        );
+    }
+
+    #[test]
+    fn test_create_task_skips_auto_reminder() {
+        let db = setup_test_db();
+        let service = TaskService::new(&db);
+        let reminder_repo = ReminderRepository::new(&db);
+
+        // Setting absent
+        let task = create_task_due_in(&service, "No setting", Duration::hours(2));
+        assert!(reminder_repo.find_by_task_id(&task.id).unwrap().is_empty());
+
+        set_auto_reminder_lead(&db, 60);
+
+        // Lead time would put the reminder in the past
+        let task = create_task_due_in(&service, "Too soon", Duration::minutes(30));
+        assert!(reminder_repo.find_by_task_id(&task.id).unwrap().is_empty());
+
+        // No due date
+        let task = create_simple_task(&service, "Someday");
+        assert!(reminder_repo.find_by_task_id(&task.id).unwrap().is_empty());
+
+        // Restores and imports bring their own reminders
+        let task = service
+            .create_task_without_auto_reminder(CreateTaskDto {
+                title: "Restored".to_string(),
+                description: None,
+                priority: TaskPriority::Medium,
+                due_date: Some(Utc::now() + Duration::hours(2)),
+                image_path: None,
+                notes: None,
+                estimated_minutes: None,
+                tag_ids: vec![],
+                parent_id: None,
+                color: None,
+            })
+            .unwrap();
+        assert!(reminder_repo.find_by_task_id(&task.id).unwrap().is_empty());
+
+        // A stored lead too large to subtract
+        set_auto_reminder_lead(&db, i64::MAX);
+        let task = create_task_due_in(&service, "Huge lead", Duration::hours(2));
+        assert!(reminder_repo.find_by_task_id(&task.id).unwrap().is_empty());
+    }
+
+    #[test]
+    fn test_create_task_rolls_back_when_auto_reminder_fails() {
+        let db = setup_test_db();
+        set_auto_reminder_lead(&db, 30);
+        db.connection()
+            .execute_batch(
+                "CREATE TRIGGER reject_reminder BEFORE INSERT ON reminders
+                 BEGIN SELECT RAISE(ABORT, 'rejected'); END;",
+            )
+            .unwrap();
+        let service = TaskService::new(&db);
+
+        assert!(service
+            .create_task(CreateTaskDto {
+                title: "Submit report".to_string(),
+                description: None,
+                priority: TaskPriority::Medium,
+                due_date: Some(Utc::now() + Duration::hours(2)),
+                image_path: None,
+                notes: None,
+                estimated_minutes: None,
+                tag_ids: vec![],
+                parent_id: None,
+                color: None,
+            })
+            .is_err());
+        let tasks: i64 = db
+            .connection()
+            .query_row("SELECT COUNT(*) FROM tasks", [], |row| row.get(0))
+            .unwrap();
+        assert_eq!(tasks, 0);
+    }
+
+    #[test]
+    fn test_delete_task_removes_reminders() {
+        let db = setup_test_db();
+        let service = TaskService::new(&db);
+        let task = create_simple_task(&service, "Temporary");
+
+        let reminder_repo = ReminderRepository::new(&db);
+        reminder_repo
+            .create(CreateReminderDto {
+                task_id: Some(task.id.clone()),
+                title: "Ping".to_string(),
+                description: None,
+                remind_at: Utc::now() + Duration::hours(1),
+                repeat_interval: RepeatInterval::none(),
+                escalation_minutes: None,
+                delivery: None,
             })
-            .collect();
+            .unwrap();
 
-        Ok(tasks_with_status)
+        assert!(service.delete_task(&task.id).unwrap());
+        assert!(reminder_repo.find_by_task_id(&task.id).unwrap().is_empty());
     }
 
-    /// Transition a task to a new status with validation
-    ///
-    /// Business rules:
-    /// - Status transition must be valid according to state machine
-    /// - Cannot transition from terminal states
-    pub fn transition_status(&self, id: &str, new_status: TaskStatus) -> DomainResult<Task> {
-        let repo = TaskRepository::new(self.db);
+    #[test]
+    fn test_get_tasks_modified_since_cutoff() {
+        let db = setup_test_db();
+        let service = TaskService::new(&db);
+        let set_updated_at = |id: &str, updated_at: String| {
+            db.connection()
+                .execute(
+                    "UPDATE tasks SET updated_at = ?1 WHERE id = ?2",
+                    rusqlite::params![updated_at, id],
+                )
+                .unwrap();
+        };
+        let two_hours_ago = Utc::now() - Duration::hours(2);
+        let untouched = create_simple_task(&service, "Untouched");
+        let edited = create_simple_task(&service, "Edited");
+        let removed = create_simple_task(&service, "Removed");
+        for task in [&untouched, &edited, &removed] {
+            set_updated_at(&task.id, two_hours_ago.to_rfc3339());
+        }
+        // Written by the timestamp trigger in the datetime('now') format
+        let trigger_touched = create_simple_task(&service, "Trigger touched");
+        set_updated_at(
+            &trigger_touched.id,
+            (Utc::now() - Duration::minutes(30))
+                .format("%Y-%m-%d %H:%M:%S")
+                .to_string(),
+        );
 
-        // Fetch existing task
-        let existing_task = repo
-            .find_by_id(id)
-            .map_err(|e| DomainError::BusinessRuleViolation(format!("Database error: {}", e)))?
-            .ok_or_else(|| DomainError::TaskNotFound(id.to_string()))?;
+        let cutoff = Utc::now() - Duration::hours(1);
+        service.pin_task(&edited.id).unwrap();
+        let created = create_simple_task(&service, "Created");
+        service.delete_task(&removed.id).unwrap();
 
-        // Validate transition
-        if !existing_task.status.can_transition_to(&new_status) {
-            return Err(DomainError::InvalidStatusTransition {
-                from: existing_task.status.as_str().to_string(),
-                to: new_status.as_str().to_string(),
-                reason: format!(
-                    "Cannot transition from {} to {}. Valid transitions: {}",
-                    existing_task.status.as_str(),
-                    new_status.as_str(),
-                    self.get_valid_transitions(&existing_task.status)
-                ),
-            });
-        }
+        let delta = service.get_tasks_modified_since(cutoff).unwrap();
 
-        // Update status
-        let update_dto = UpdateTaskDto {
-            title: None,
-            description: None,
-            status: Some(new_status),
-            priority: None,
-            due_date: None,
-            image_path: None,
-            notes: None,
-            estimated_minutes: None,
-            actual_minutes: None,
-            tag_ids: None,
-        };
+        let mut ids: Vec<&str> = delta.tasks.iter().map(|t| t.id.as_str()).collect();
+        ids.sort();
+        let mut expected = vec![
+            edited.id.as_str(),
+            created.id.as_str(),
+            trigger_touched.id.as_str(),
+        ];
+        expected.sort();
+        assert_eq!(ids, expected);
+        assert_eq!(delta.deleted_ids, vec![removed.id.clone()]);
+        assert!(delta.as_of >= cutoff);
 
-        repo.update(id, update_dto).map_err(|e| {
-            DomainError::BusinessRuleViolation(format!("Failed to transition status: {}", e))
-        })
+        let later = service.get_tasks_modified_since(delta.as_of).unwrap();
+        assert!(later.tasks.is_empty());
+        assert!(later.deleted_ids.is_empty());
     }
 
-    /// Get valid transition states for a given status
-    fn get_valid_transitions(&self, status: &TaskStatus) -> String {
-        match status {
-            TaskStatus::Pending => "InProgress, Completed, Cancelled".to_string(),
-            TaskStatus::InProgress => "Pending, Completed, Cancelled".to_string(),
-            TaskStatus::Completed => "None (terminal state)".to_string(),
-            TaskStatus::Cancelled => "None (terminal state)".to_string(),
-        }
-    }
+    #[test]
+    fn test_undo_delete_task_restores_tags_and_reminders() {
+        let db = setup_test_db();
+        db.connection()
+            .execute_batch("PRAGMA foreign_keys = ON;")
+            .unwrap();
+        let service = TaskService::new(&db);
+        let tag = Tag {
+            id: "tag-1".to_string(),
+            name: "Work".to_string(),
+            color: "#3b82f6".to_string(),
+            created_at: Utc::now(),
+        };
+        assert!(TagRepository::new(&db).insert_if_absent(&tag).unwrap());
+        let task = create_simple_task(&service, "Undo me");
+        let subtask = service
+            .create_task(CreateTaskDto {
+                title: "Child".to_string(),
+                description: None,
+                priority: TaskPriority::Low,
+                due_date: None,
+                notes: None,
+                estimated_minutes: None,
+                image_path: None,
+                tag_ids: vec![],
+                parent_id: Some(task.id.clone()),
+                color: None,
+            })
+            .unwrap();
+        service
+            .set_task_tags(&task.id, vec![tag.id.clone()])
+            .unwrap();
+        let reminder_repo = ReminderRepository::new(&db);
+        let reminder = reminder_repo
+            .create(CreateReminderDto {
+                task_id: Some(task.id.clone()),
+                title: "Ping".to_string(),
+                description: None,
+                remind_at: Utc::now() + Duration::hours(1),
+                repeat_interval: RepeatInterval::none(),
+                escalation_minutes: None,
+                delivery: None,
+            })
+            .unwrap();
 
-    /// Count tasks by their effective status (including overdue)
-    pub fn count_by_effective_status(&self) -> DomainResult<Vec<(EffectiveTaskStatus, usize)>> {
-        let tasks_with_status = self.get_tasks_with_effective_status()?;
+        service.delete_task(&task.id).unwrap();
+        assert!(service.get_task(&task.id).is_err());
+        assert!(service.get_task(&subtask.id).is_err());
+        let tag_links: i64 = db
+            .connection()
+            .query_row("SELECT COUNT(*) FROM task_tags", [], |row| row.get(0))
+            .unwrap();
+        assert_eq!(tag_links, 0);
 
-        // Count by effective status
-        let mut counts = std::collections::HashMap::new();
-        for (_, status) in tasks_with_status {
-            *counts.entry(status).or_insert(0) += 1;
-        }
+        let entry = UndoService::new(&db).undo_last_delete().unwrap();
 
-        let result: Vec<(EffectiveTaskStatus, usize)> = counts.into_iter().collect();
-        Ok(result)
+        assert_eq!(entry.entity_type, UndoEntityType::Task);
+        assert_eq!(entry.entity_id, task.id);
+        assert_eq!(service.get_task(&task.id).unwrap().title, "Undo me");
+        assert_eq!(
+            service.get_task(&subtask.id).unwrap().parent_id.as_deref(),
+            Some(task.id.as_str())
+        );
+        let tags = service.get_task_tags(&task.id).unwrap();
+        assert_eq!(tags.len(), 1);
+        assert_eq!(tags[0].id, tag.id);
+        let reminders = reminder_repo.find_by_task_id(&task.id).unwrap();
+        assert_eq!(reminders.len(), 1);
+        assert_eq!(reminders[0].id, reminder.id);
+        assert!(UndoService::new(&db).undo_last_delete().is_err());
     }
-}
 
-#[cfg(test)]
-mod tests {
-    use super::*;
-    use crate::db::Database;
-    use crate::models::{CreateTaskDto, TaskPriority, TaskStatus, UpdateTaskDto};
-    use rusqlite::Connection;
+    fn create_estimated_task(service: &TaskService, estimated_minutes: Option<i32>) -> Task {
+        service
+            .create_task(CreateTaskDto {
+                title: "Estimated".to_string(),
+                description: None,
+                priority: TaskPriority::Medium,
+                due_date: None,
+                notes: None,
+                estimated_minutes,
+                image_path: None,
+                tag_ids: vec![],
+                parent_id: None,
+                color: None,
+            })
+            .unwrap()
+    }
 
-    fn setup_test_db() -> Database {
-        let conn = Connection::open_in_memory().unwrap();
+    #[test]
+    fn test_mark_done_under_estimate() {
+        let db = setup_test_db();
+        let service = TaskService::new(&db);
+        let task = create_estimated_task(&service, Some(60));
 
-        // Create tables matching actual schema
-        conn.execute(
-            "CREATE TABLE tasks (
-                id TEXT PRIMARY KEY,
-                title TEXT NOT NULL,
-                description TEXT,
-                status TEXT NOT NULL,
-                priority TEXT NOT NULL,
-                due_date TEXT,
-                completed_at TEXT,
-                image_path TEXT,
-                notes TEXT,
-                estimated_minutes INTEGER,
-                actual_minutes INTEGER,
-                created_at TEXT NOT NULL,
-                updated_at TEXT NOT NULL
-            )",
-            [],
-        )
-        .unwrap();
+        let result = service
+            .mark_done(&task.id, Some(30), None, false, false)
+            .unwrap();
+        assert_eq!(result.overrun_ratio, Some(0.5));
+        assert!(result.warning.is_none());
+    }
 
-        conn.execute(
-            "CREATE TABLE task_tags (
-                task_id TEXT NOT NULL,
-                tag_id TEXT NOT NULL,
-                PRIMARY KEY (task_id, tag_id)
-            )",
-            [],
-        )
-        .unwrap();
+    #[test]
+    fn test_mark_done_over_estimate_warns() {
+        let db = setup_test_db();
+        let service = TaskService::new(&db);
+        let task = create_estimated_task(&service, Some(30));
 
-        conn.execute(
-            "CREATE TABLE tags (
-                id TEXT PRIMARY KEY,
-                name TEXT NOT NULL,
-                color TEXT NOT NULL,
-                created_at TEXT NOT NULL
-            )",
-            [],
-        )
-        .unwrap();
+        let result = service
+            .mark_done(&task.id, Some(60), None, false, false)
+            .unwrap();
+        assert_eq!(result.overrun_ratio, Some(2.0));
+        assert_eq!(
+            result.warning.as_deref(),
+            Some("Took 2.0x longer than estimated")
+        );
+    }
 
-        Database::new_from_connection(conn)
+    #[test]
+    fn test_mark_done_without_estimate_has_no_ratio() {
+        let db = setup_test_db();
+        let service = TaskService::new(&db);
+        let task = create_estimated_task(&service, None);
+
+        let result = service
+            .mark_done(&task.id, Some(45), None, false, false)
+            .unwrap();
+        assert_eq!(result.task.actual_minutes, Some(45));
+        assert!(result.task.completed_at.is_some());
+        assert!(result.overrun_ratio.is_none());
+        assert!(result.warning.is_none());
     }
 
     #[test]
-    fn test_status_transitions() {
-        // Pending can go to any state
-        assert!(TaskStatus::Pending.can_transition_to(&TaskStatus::InProgress));
-        assert!(TaskStatus::Pending.can_transition_to(&TaskStatus::Completed));
-        assert!(TaskStatus::Pending.can_transition_to(&TaskStatus::Cancelled));
+    fn test_estimation_report_variances() {
+        let db = setup_test_db();
+        let service = TaskService::new(&db);
+        let mut ids = Vec::new();
+        for (estimated, actual) in [(60, 90), (100, 80), (30, 30)] {
+            let task = create_estimated_task(&service, Some(estimated));
+            service
+                .mark_done(&task.id, Some(actual), None, false, false)
+                .unwrap();
+            ids.push(task.id);
+        }
 
-        // InProgress can go to Pending, Completed, or Cancelled
-        assert!(TaskStatus::InProgress.can_transition_to(&TaskStatus::Pending));
-        assert!(TaskStatus::InProgress.can_transition_to(&TaskStatus::Completed));
-        assert!(TaskStatus::InProgress.can_transition_to(&TaskStatus::Cancelled));
+        // Completed before the window, without an estimate, or still open
+        let stale = create_estimated_task(&service, Some(10));
+        service
+            .mark_done(&stale.id, Some(20), None, false, false)
+            .unwrap();
+        db.connection()
+            .execute(
+                "UPDATE tasks SET completed_at = ?1 WHERE id = ?2",
+                rusqlite::params![(Utc::now() - Duration::days(10)).to_rfc3339(), stale.id],
+            )
+            .unwrap();
+        let unestimated = create_estimated_task(&service, None);
+        service
+            .mark_done(&unestimated.id, Some(20), None, false, false)
+            .unwrap();
+        create_estimated_task(&service, Some(15));
 
-        // Terminal states cannot transition
-        assert!(!TaskStatus::Completed.can_transition_to(&TaskStatus::Pending));
-        assert!(!TaskStatus::Completed.can_transition_to(&TaskStatus::InProgress));
-        assert!(!TaskStatus::Cancelled.can_transition_to(&TaskStatus::Pending));
+        let report = service.get_estimation_report(7).unwrap();
+
+        let mut variances: Vec<(String, f32)> = report
+            .entries
+            .iter()
+            .map(|entry| (entry.id.clone(), entry.variance_pct))
+            .collect();
+        variances.sort_by_key(|(id, _)| ids.iter().position(|known| known == id));
+        assert_eq!(
+            variances,
+            vec![
+                (ids[0].clone(), 50.0),
+                (ids[1].clone(), -20.0),
+                (ids[2].clone(), 0.0),
+            ]
+        );
+        assert_eq!(report.average_variance_pct, Some(10.0));
     }
 
     #[test]
-    fn test_terminal_states() {
-        assert!(TaskStatus::Completed.is_terminal());
-        assert!(TaskStatus::Cancelled.is_terminal());
-        assert!(!TaskStatus::Pending.is_terminal());
-        assert!(!TaskStatus::InProgress.is_terminal());
+    fn test_estimation_report_empty_and_invalid_window() {
+        let db = setup_test_db();
+        let service = TaskService::new(&db);
+
+        let report = service.get_estimation_report(30).unwrap();
+        assert!(report.entries.is_empty());
+        assert_eq!(report.average_variance_pct, None);
+
+        assert!(matches!(
+            service.get_estimation_report(0),
+            Err(DomainError::FieldValidation { ref field, .. }) if field == "days"
+        ));
+        assert!(matches!(
+            service.get_estimation_report(u32::MAX),
+            Err(DomainError::FieldValidation { ref field, .. }) if field == "days"
+        ));
     }
 
     #[test]
-    fn test_create_task_success() {
+    fn test_focus_session_schedules_transition_reminders() {
         let db = setup_test_db();
         let service = TaskService::new(&db);
+        let task = create_simple_task(&service, "Write report");
 
-        let dto = CreateTaskDto {
-            title: "Test Task".to_string(),
-            description: Some("Test Description".to_string()),
-            priority: TaskPriority::High,
-            due_date: None,
-            notes: None,
-            estimated_minutes: Some(60),
-            image_path: None,
-            tag_ids: vec![],
-        };
+        let session = service.start_focus_session(&task.id, 25, 5).unwrap();
+        assert!(session.is_active());
 
-        let result = service.create_task(dto);
-        assert!(result.is_ok());
+        let reminders = ReminderRepository::new(&db)
+            .find_by_task_id(&task.id)
+            .unwrap();
+        assert_eq!(reminders.len(), 2);
 
-        let task = result.unwrap();
-        assert_eq!(task.title, "Test Task");
-        assert_eq!(task.priority, TaskPriority::High);
-        assert_eq!(task.status, TaskStatus::Pending);
+        let break_reminder = reminders
+            .iter()
+            .find(|r| Some(&r.id) == session.break_reminder_id.as_ref())
+            .unwrap();
+        let work_reminder = reminders
+            .iter()
+            .find(|r| Some(&r.id) == session.work_reminder_id.as_ref())
+            .unwrap();
+        assert_eq!(
+            break_reminder.remind_at,
+            session.started_at + Duration::minutes(25)
+        );
+        assert_eq!(
+            work_reminder.remind_at,
+            session.started_at + Duration::minutes(30)
+        );
+        for reminder in [break_reminder, work_reminder] {
+            assert_eq!(reminder.repeat_interval.as_str(), "every_30_minutes");
+        }
+
+        assert!(matches!(
+            service.start_focus_session(&task.id, 25, 5),
+            Err(DomainError::BusinessRuleViolation(_))
+        ));
+        assert!(matches!(
+            service.start_focus_session(&task.id, 0, 5),
+            Err(DomainError::FieldValidation { ref field, .. }) if field == "work_minutes"
+        ));
     }
 
     #[test]
-    fn test_create_task_validates_title_length() {
+    fn test_focus_session_rolls_back_reminders_when_session_insert_fails() {
         let db = setup_test_db();
         let service = TaskService::new(&db);
+        let task = create_simple_task(&service, "Write report");
+        db.connection()
+            .execute_batch(
+                "CREATE TRIGGER reject_focus_session BEFORE INSERT ON task_focus_sessions
+                 BEGIN SELECT RAISE(ABORT, 'rejected'); END;",
+            )
+            .unwrap();
 
-        // Empty title
-        let dto = CreateTaskDto {
-            title: "   ".to_string(), // Whitespace only - will be trimmed to empty
-            description: None,
-            priority: TaskPriority::Medium,
-            due_date: None,
-            notes: None,
-            estimated_minutes: None,
-            image_path: None,
-            tag_ids: vec![],
-        };
+        assert!(service.start_focus_session(&task.id, 25, 5).is_err());
+        assert!(ReminderRepository::new(&db)
+            .find_by_task_id(&task.id)
+            .unwrap()
+            .is_empty());
+    }
+
+    #[test]
+    fn test_stop_focus_session_accrues_work_minutes() {
+        let db = setup_test_db();
+        let service = TaskService::new(&db);
+        let task = create_simple_task(&service, "Write report");
+
+        let session = service.start_focus_session(&task.id, 25, 5).unwrap();
+        // Two full cycles (50 work minutes) plus 10 minutes into the third work period
+        db.connection()
+            .execute(
+                "UPDATE task_focus_sessions SET started_at = ?1 WHERE id = ?2",
+                rusqlite::params![
+                    (Utc::now() - Duration::minutes(70)).to_rfc3339(),
+                    session.id
+                ],
+            )
+            .unwrap();
+
+        let stopped = service.stop_focus_session(&task.id).unwrap();
+        assert!(!stopped.is_active());
+        assert_eq!(stopped.logged_minutes, Some(60));
+        assert_eq!(service.get_task(&task.id).unwrap().actual_minutes, Some(60));
+        assert!(ReminderRepository::new(&db)
+            .find_by_task_id(&task.id)
+            .unwrap()
+            .is_empty());
 
-        let result = service.create_task(dto);
-        assert!(result.is_err());
         assert!(matches!(
-            result.unwrap_err(),
-            DomainError::ValidationError(_)
+            service.stop_focus_session(&task.id),
+            Err(DomainError::BusinessRuleViolation(_))
         ));
+
+        // A new session accrues on top of the logged minutes
+        service.start_focus_session(&task.id, 25, 5).unwrap();
+        let stopped = service.stop_focus_session(&task.id).unwrap();
+        assert_eq!(stopped.logged_minutes, Some(0));
+        assert_eq!(service.get_task(&task.id).unwrap().actual_minutes, Some(60));
     }
 
     #[test]
-    fn test_delete_task_success() {
+    fn test_inbox_lists_undated_tasks_oldest_first() {
         let db = setup_test_db();
         let service = TaskService::new(&db);
 
-        // Create a task
-        let dto = CreateTaskDto {
-            title: "Task to Delete".to_string(),
+        let first = create_simple_task(&service, "First idea");
+        let second = create_simple_task(&service, "Second idea");
+        service
+            .create_task(CreateTaskDto {
+                title: "Dated".to_string(),
+                description: None,
+                priority: TaskPriority::Medium,
+                due_date: Some(Utc::now() + Duration::days(2)),
+                notes: None,
+                estimated_minutes: None,
+                image_path: None,
+                tag_ids: vec![],
+                parent_id: None,
+                color: None,
+            })
+            .unwrap();
+        db.connection()
+            .execute(
+                "UPDATE tasks SET created_at = ?1 WHERE id = ?2",
+                rusqlite::params![(Utc::now() - Duration::hours(1)).to_rfc3339(), first.id],
+            )
+            .unwrap();
+        service
+            .mark_done(&second.id, None, None, false, false)
+            .unwrap();
+
+        let inbox = service.get_inbox_tasks(None, true).unwrap();
+        let titles: Vec<&str> = inbox.iter().map(|t| t.title.as_str()).collect();
+        assert_eq!(titles, vec!["First idea", "Second idea"]);
+
+        let open = service.get_inbox_tasks(None, false).unwrap();
+        assert_eq!(open.len(), 1);
+        assert_eq!(open[0].id, first.id);
+
+        let done = service
+            .get_inbox_tasks(Some(TaskStatus::Completed), false)
+            .unwrap();
+        assert_eq!(done.len(), 1);
+        assert_eq!(done[0].id, second.id);
+    }
+
+    #[test]
+    fn test_task_color_set_clear_and_validation() {
+        let db = setup_test_db();
+        let service = TaskService::new(&db);
+
+        let colored = |color: &str| CreateTaskDto {
+            title: "Colored".to_string(),
             description: None,
             priority: TaskPriority::Medium,
             due_date: None,
@@ -566,17 +4747,51 @@ mod tests {
             estimated_minutes: None,
             image_path: None,
             tag_ids: vec![],
+            parent_id: None,
+            color: Some(color.to_string()),
         };
 
-        let task = service.create_task(dto).unwrap();
+        let task = service.create_task(colored("#3b82f6")).unwrap();
+        assert_eq!(task.color.as_deref(), Some("#3b82f6"));
 
-        // Delete it
-        let result = service.delete_task(&task.id);
-        assert!(result.is_ok());
-        assert_eq!(result.unwrap(), true);
+        let color_update = |color: Option<&str>, clear_fields: Vec<String>| UpdateTaskDto {
+            title: None,
+            description: None,
+            status: None,
+            priority: None,
+            due_date: None,
+            image_path: None,
+            notes: None,
+            estimated_minutes: None,
+            actual_minutes: None,
+            tag_ids: None,
+            color: color.map(str::to_string),
+            clear_fields,
+        };
 
-        // Verify it's deleted
-        let get_result = service.get_task(&task.id);
-        assert!(get_result.is_err());
+        let updated = service
+            .update_task(&task.id, color_update(Some("#F0A"), vec![]), false)
+            .unwrap();
+        assert_eq!(updated.task.color.as_deref(), Some("#F0A"));
+
+        let cleared = service
+            .update_task(
+                &task.id,
+                color_update(None, vec!["color".to_string()]),
+                false,
+            )
+            .unwrap();
+        assert_eq!(cleared.task.color, None);
+
+        for invalid in ["blue", "#12345", "3b82f6", "#gggggg"] {
+            let err = service
+                .update_task(&task.id, color_update(Some(invalid), vec![]), false)
+                .unwrap_err();
+            assert!(matches!(
+                err,
+                DomainError::FieldValidation { ref field, .. } if field == "color"
+            ));
+        }
+        assert!(service.create_task(colored("red")).is_err());
     }
 }
@@ -166,6 +166,7 @@ mod tests {
             estimated_minutes: None,
             actual_minutes: None,
             tag_ids: None,
+            clear_fields: vec![],
         };
 
         let result = service.update_task(&task.id, update_dto);
@@ -242,12 +243,12 @@ mod tests {
         }).unwrap();
 
         // Search
-        let results = service.search_tasks("groceries").unwrap();
+        let results = service.search_tasks("groceries", None).unwrap();
         assert_eq!(results.len(), 1);
         assert_eq!(results[0].title, "Buy groceries");
 
         // Search in description
-        let results2 = service.search_tasks("project").unwrap();
+        let results2 = service.search_tasks("project", None).unwrap();
         assert_eq!(results2.len(), 1);
         assert_eq!(results2[0].title, "Meeting with client");
     }
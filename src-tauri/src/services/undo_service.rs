@@ -0,0 +1,180 @@
+use crate::db::Database;
+use crate::error::{DomainError, DomainResult};
+use crate::models::{UndoEntityType, UndoEntry, UndoSnapshot};
+use crate::repositories::UndoRepository;
+
+/// Service recording deletions and restoring the most recent one
+pub struct UndoService<'a> {
+    db: &'a Database,
+}
+
+impl<'a> UndoService<'a> {
+    /// Create a new UndoService instance
+    pub fn new(db: &'a Database) -> Self {
+        Self { db }
+    }
+
+    /// Capture the rows a delete is about to remove
+    pub fn snapshot(&self, entity_type: UndoEntityType, id: &str) -> DomainResult<UndoSnapshot> {
+        UndoRepository::new(self.db)
+            .snapshot(entity_type, id)
+            .map_err(|e| {
+                DomainError::BusinessRuleViolation(format!(
+                    "Failed to snapshot {}: {}",
+                    entity_type.as_str(),
+                    e
+                ))
+            })
+    }
+
+    /// Record a delete that succeeded so it can be undone
+    pub fn record(
+        &self,
+        entity_type: UndoEntityType,
+        id: &str,
+        snapshot: &UndoSnapshot,
+    ) -> DomainResult<()> {
+        UndoRepository::new(self.db)
+            .push(entity_type, id, snapshot)
+            .map_err(|e| {
+                DomainError::BusinessRuleViolation(format!("Failed to record undo entry: {}", e))
+            })
+    }
+
+    /// Restore the most recently deleted task, reminder or tag with its original ids
+    ///
+    /// Deletions that can no longer be restored are dropped and listed in `dropped`.
+    pub fn undo_last_delete(&self) -> DomainResult<UndoEntry> {
+        let (entry, dropped) = UndoRepository::new(self.db).restore_latest().map_err(|e| {
+            DomainError::BusinessRuleViolation(format!("Failed to undo delete: {}", e))
+        })?;
+
+        match entry {
+            Some(entry) => Ok(UndoEntry { dropped, ..entry }),
+            None if dropped.is_empty() => {
+                Err(DomainError::InvalidInput("Nothing to undo".to_string()))
+            }
+            None => Err(DomainError::BusinessRuleViolation(format!(
+                "Nothing left to undo; dropped deletions that could not be restored: {}",
+                dropped.join("; ")
+            ))),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::repositories::undo_repository::MAX_UNDO_ENTRIES;
+    use rusqlite::Connection;
+
+    fn setup_test_db() -> Database {
+        let conn = Connection::open_in_memory().unwrap();
+
+        conn.execute_batch(
+            "CREATE TABLE tags (id TEXT PRIMARY KEY, name TEXT NOT NULL UNIQUE);
+             CREATE TABLE task_tags (
+                 task_id TEXT NOT NULL,
+                 tag_id TEXT NOT NULL,
+                 PRIMARY KEY (task_id, tag_id)
+             );
+             CREATE TABLE undo_log (
+                 id INTEGER PRIMARY KEY AUTOINCREMENT,
+                 entity_type TEXT NOT NULL,
+                 entity_id TEXT NOT NULL,
+                 snapshot TEXT NOT NULL,
+                 deleted_at TEXT NOT NULL
+             );",
+        )
+        .unwrap();
+
+        Database::new_from_connection(conn)
+    }
+
+    #[test]
+    fn test_undo_with_empty_log_fails() {
+        let db = setup_test_db();
+
+        let result = UndoService::new(&db).undo_last_delete();
+
+        assert!(matches!(result, Err(DomainError::InvalidInput(ref m)) if m == "Nothing to undo"));
+    }
+
+    fn delete_tag(db: &Database, service: &UndoService, id: &str, name: &str) {
+        db.connection()
+            .execute(
+                "INSERT INTO tags (id, name) VALUES (?1, ?2)",
+                rusqlite::params![id, name],
+            )
+            .unwrap();
+        let snapshot = service.snapshot(UndoEntityType::Tag, id).unwrap();
+        db.connection()
+            .execute("DELETE FROM tags WHERE id = ?1", rusqlite::params![id])
+            .unwrap();
+        service.record(UndoEntityType::Tag, id, &snapshot).unwrap();
+    }
+
+    #[test]
+    fn test_undo_drops_unrestorable_entries_and_reports_them() {
+        let db = setup_test_db();
+        let service = UndoService::new(&db);
+        delete_tag(&db, &service, "tag-a", "Home");
+        delete_tag(&db, &service, "tag-b", "Work");
+
+        // The newest deletion can't come back: its name is taken again
+        db.connection()
+            .execute("INSERT INTO tags (id, name) VALUES ('tag-c', 'Work')", [])
+            .unwrap();
+
+        let entry = service.undo_last_delete().unwrap();
+        assert_eq!(entry.entity_id, "tag-a");
+        assert_eq!(entry.dropped.len(), 1);
+        assert!(entry.dropped[0].contains("tag-b"));
+        assert_eq!(UndoRepository::new(&db).count().unwrap(), 0);
+
+        // When nothing restorable is left, the reasons come back as the error
+        delete_tag(&db, &service, "tag-d", "Errands");
+        db.connection()
+            .execute(
+                "INSERT INTO tags (id, name) VALUES ('tag-e', 'Errands')",
+                [],
+            )
+            .unwrap();
+        let result = service.undo_last_delete();
+        assert!(
+            matches!(result, Err(DomainError::BusinessRuleViolation(ref m)) if m.contains("tag-d"))
+        );
+        assert_eq!(UndoRepository::new(&db).count().unwrap(), 0);
+    }
+
+    #[test]
+    fn test_undo_log_is_capped_and_restores_newest_first() {
+        let db = setup_test_db();
+        let service = UndoService::new(&db);
+
+        for i in 0..MAX_UNDO_ENTRIES + 5 {
+            let id = format!("tag-{}", i);
+            db.connection()
+                .execute(
+                    "INSERT INTO tags (id, name) VALUES (?1, ?1)",
+                    rusqlite::params![id],
+                )
+                .unwrap();
+            let snapshot = service.snapshot(UndoEntityType::Tag, &id).unwrap();
+            db.connection()
+                .execute("DELETE FROM tags WHERE id = ?1", rusqlite::params![id])
+                .unwrap();
+            service.record(UndoEntityType::Tag, &id, &snapshot).unwrap();
+        }
+
+        assert_eq!(UndoRepository::new(&db).count().unwrap(), MAX_UNDO_ENTRIES);
+
+        let entry = service.undo_last_delete().unwrap();
+        assert_eq!(entry.entity_type, UndoEntityType::Tag);
+        assert_eq!(entry.entity_id, format!("tag-{}", MAX_UNDO_ENTRIES + 4));
+        assert_eq!(
+            UndoRepository::new(&db).count().unwrap(),
+            MAX_UNDO_ENTRIES - 1
+        );
+    }
+}
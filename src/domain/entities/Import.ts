@@ -0,0 +1,7 @@
+// Result of an import command
+export interface ImportReport {
+  imported: number;
+  overwritten: number;
+  skipped: number;
+  errors: string[];
+}
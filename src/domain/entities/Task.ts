@@ -49,6 +49,11 @@ export interface Tag {
   created_at: string;
 }
 
+// Task returned by create/update, with requested tag ids that matched no tag
+export interface TaskWriteResult extends Task {
+  invalid_tag_ids?: string[];
+}
+
 // Create task DTO
 export interface CreateTaskDto {
   title: string;
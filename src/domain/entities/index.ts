@@ -1,2 +1,3 @@
 export * from './Task';
 export * from './Reminder';
+export * from './Import';
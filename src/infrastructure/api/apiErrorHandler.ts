@@ -38,6 +38,18 @@ export function formatApiError(error: unknown, operation: string): ApiError {
     );
   }
 
+  // Handle structured command errors: { type, field?, message }
+  if (isCommandError(error)) {
+    return new ApiError(
+      error.type === 'FieldValidation'
+        ? error.message
+        : getUserFriendlyMessage(error.message, operation),
+      error.type,
+      undefined,
+      error
+    );
+  }
+
   // Handle Error objects
   if (error instanceof Error) {
     return new ApiError(
@@ -57,6 +69,24 @@ export function formatApiError(error: unknown, operation: string): ApiError {
   );
 }
 
+/**
+ * Shape of an `AppError` returned by validating Tauri commands
+ */
+export interface CommandError {
+  type: string;
+  field?: string;
+  message: string;
+}
+
+function isCommandError(error: unknown): error is CommandError {
+  return (
+    typeof error === 'object' &&
+    error !== null &&
+    typeof (error as CommandError).type === 'string' &&
+    typeof (error as CommandError).message === 'string'
+  );
+}
+
 /**
  * Convert technical error messages to user-friendly ones
  */
@@ -1,5 +1,7 @@
 import { invoke } from '@tauri-apps/api/core';
 import type { Reminder, CreateReminderDto, UpdateReminderDto } from '@domain/entities/Reminder';
+import type { ImportReport } from '@domain/entities/Import';
+import { handleApiCall } from './apiErrorHandler';
 
 /**
  * Get all reminders for a task
@@ -26,14 +28,16 @@ export async function getReminder(id: string): Promise<Reminder> {
  * Create a new reminder
  */
 export async function createReminder(data: CreateReminderDto): Promise<Reminder> {
-  return await invoke<Reminder>('create_reminder', { data });
+  return handleApiCall('create reminder', () => invoke<Reminder>('create_reminder', { data }));
 }
 
 /**
  * Update an existing reminder
  */
 export async function updateReminder(id: string, data: UpdateReminderDto): Promise<Reminder> {
-  return await invoke<Reminder>('update_reminder', { id, data });
+  return handleApiCall('update reminder', () =>
+    invoke<Reminder>('update_reminder', { id, data })
+  );
 }
 
 /**
@@ -81,7 +85,7 @@ export async function exportRemindersToCSV(): Promise<string> {
  */
 export async function importRemindersFromJSON(jsonData: string): Promise<number> {
   try {
-    const count = await invoke<number>('import_reminders_json', { jsonData });
+    const { imported: count } = await invoke<ImportReport>('import_reminders_json', { jsonData });
     return count;
   } catch (error) {
     console.error('Failed to import reminders:', error);
@@ -94,7 +98,7 @@ export async function importRemindersFromJSON(jsonData: string): Promise<number>
  */
 export async function importRemindersFromCSV(csvData: string): Promise<number> {
   try {
-    const count = await invoke<number>('import_reminders_csv', { csvData });
+    const { imported: count } = await invoke<ImportReport>('import_reminders_csv', { csvData });
     return count;
   } catch (error) {
     console.error('Failed to import reminders:', error);
@@ -1,4 +1,5 @@
 import { invoke } from '@tauri-apps/api/core';
+import { handleApiCall } from './apiErrorHandler';
 
 export interface AppSettings {
   theme: string;
@@ -27,5 +28,7 @@ export async function getSettings(): Promise<AppSettings> {
  * Update application settings
  */
 export async function updateSettings(data: UpdateSettingsDto): Promise<AppSettings> {
-  return await invoke<AppSettings>('update_settings', { dto: data });
+  return handleApiCall('update settings', () =>
+    invoke<AppSettings>('update_settings', { dto: data })
+  );
 }
@@ -1,6 +1,7 @@
 import { invoke } from '@tauri-apps/api/core';
-import type { Task, CreateTaskDto, UpdateTaskDto } from '@domain/entities/Task';
-import { log } from '@shared/utils/logger';
+import type { Task, CreateTaskDto, UpdateTaskDto, TaskWriteResult } from '@domain/entities/Task';
+import type { ImportReport } from '@domain/entities/Import';
+import { log, logger } from '@shared/utils/logger';
 import { handleApiCall } from './apiErrorHandler';
 import { dedupedRequest, generateCacheKey, requestDeduplicator } from './requestDeduplicator';
 
@@ -63,15 +64,25 @@ export async function getTask(id: string): Promise<Task> {
   );
 }
 
+/**
+ * Log requested tag ids the backend skipped because no tag matched them
+ */
+function warnInvalidTags(task: TaskWriteResult): void {
+  if (task.invalid_tag_ids?.length) {
+    logger.warn(`Skipped unknown tag ids for task ${task.id}`, task.invalid_tag_ids);
+  }
+}
+
 /**
  * Create a new task
  * Invalidates task list cache after creation
  */
-export async function createTask(data: CreateTaskDto): Promise<Task> {
+export async function createTask(data: CreateTaskDto): Promise<TaskWriteResult> {
   return handleApiCall('create task', async () => {
     log.api.request('POST', '/tasks', data);
-    const task = await invoke<Task>('create_task', { data });
+    const task = await invoke<TaskWriteResult>('create_task', { data });
     log.api.response('/tasks', task);
+    warnInvalidTags(task);
     
     // Invalidate all task list caches
     requestDeduplicator.invalidatePattern(/^tasks/);
@@ -84,11 +95,12 @@ export async function createTask(data: CreateTaskDto): Promise<Task> {
  * Update an existing task
  * Invalidates related caches after update
  */
-export async function updateTask(id: string, data: UpdateTaskDto): Promise<Task> {
+export async function updateTask(id: string, data: UpdateTaskDto): Promise<TaskWriteResult> {
   return handleApiCall('update task', async () => {
     log.api.request('PUT', `/tasks/${id}`, data);
-    const task = await invoke<Task>('update_task', { id, data });
+    const task = await invoke<TaskWriteResult>('update_task', { id, data });
     log.api.response(`/tasks/${id}`, task);
+    warnInvalidTags(task);
     
     // Invalidate task and task list caches
     requestDeduplicator.invalidate(generateCacheKey('task', { id }));
@@ -178,7 +190,7 @@ export async function exportTasksToCSV(): Promise<string> {
 export async function importTasksFromJSON(jsonData: string): Promise<number> {
   try {
     log.api.request('POST', '/tasks/import');
-    const count = await invoke<number>('import_tasks_json', { jsonData });
+    const { imported: count } = await invoke<ImportReport>('import_tasks_json', { jsonData });
     log.api.response('/tasks/import', `Imported ${count} tasks`);
     return count;
   } catch (error) {
@@ -193,7 +205,7 @@ export async function importTasksFromJSON(jsonData: string): Promise<number> {
 export async function importTasksFromCSV(csvData: string): Promise<number> {
   try {
     log.api.request('POST', '/tasks/import/csv');
-    const count = await invoke<number>('import_tasks_csv', { csvData });
+    const { imported: count } = await invoke<ImportReport>('import_tasks_csv', { csvData });
     log.api.response('/tasks/import/csv', `Imported ${count} tasks`);
     return count;
   } catch (error) {